@@ -0,0 +1,321 @@
+//! JSON-RPC 2.0 [batch](https://www.jsonrpc.org/specification#batch) support.
+//!
+//! [`JsonRpcPayload`] accepts either a single request object or an array of
+//! them. Batch entries are parsed independently, so one malformed entry yields
+//! its own `Invalid Request` response instead of failing the whole batch, and
+//! entries without an `id` member are surfaced as notifications whose responses
+//! must be omitted. [`JsonRpcPayloadResponse`] renders the matching reply: an
+//! object for a single request, an array for a batch, and an empty body when a
+//! batch contained only notifications.
+
+use axum::body::Bytes;
+use axum::extract::{FromRequest, Request};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use cfg_if::cfg_if;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Deserializer};
+
+use crate::{
+    invalid_request, parse_single, read_json_body, Id, JsonRpcExtractor, JsonRpcResponse, Value,
+    JSONRPC,
+};
+
+/// Parses a JSON-RPC body that is either a single request or a batch.
+/// ```rust
+/// use axum_jrpc::batch::{BatchEntry, JsonRpcPayload, JsonRpcPayloadResponse};
+/// use axum_jrpc::{JsonRpcExtractor, JsonRpcResponse};
+///
+/// fn call(req: JsonRpcExtractor) -> JsonRpcResponse {
+///   let method = req.method().to_owned();
+///   req.method_not_found(&method)
+/// }
+///
+/// fn router(payload: JsonRpcPayload) -> JsonRpcPayloadResponse {
+///   match payload {
+///     JsonRpcPayload::Single(req) => JsonRpcPayloadResponse::Single(call(req)),
+///     JsonRpcPayload::Batch(entries) => JsonRpcPayloadResponse::from_batch(
+///       entries.into_iter().filter_map(|entry| match entry {
+///         BatchEntry::Call(req) => Some(call(req)),
+///         BatchEntry::Notification(req) => {
+///           call(req);
+///           None
+///         }
+///         BatchEntry::Invalid(resp) => Some(resp),
+///       }),
+///     ),
+///   }
+/// }
+/// ```
+#[derive(Debug)]
+pub enum JsonRpcPayload {
+    /// A single request object.
+    Single(JsonRpcExtractor),
+    /// A non-empty array of request objects, in request order.
+    Batch(Vec<BatchEntry>),
+}
+
+/// One element of a batch.
+#[derive(Debug)]
+pub enum BatchEntry {
+    /// A request with an `id`; its response belongs in the reply.
+    Call(JsonRpcExtractor),
+    /// A request without an `id`; it is executed but gets no response.
+    Notification(JsonRpcExtractor),
+    /// An element that is not a valid request object; the ready-made
+    /// `Invalid Request` response belongs in the reply.
+    Invalid(JsonRpcResponse),
+}
+
+impl JsonRpcPayload {
+    /// Number of requests carried by the payload.
+    pub fn len(&self) -> usize {
+        match self {
+            Self::Single(_) => 1,
+            Self::Batch(entries) => entries.len(),
+        }
+    }
+
+    /// Always false: an empty batch is rejected at extraction time.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<S> FromRequest<S> for JsonRpcPayload
+where
+    Bytes: FromRequest<S>,
+    S: Send + Sync,
+{
+    type Rejection = JsonRpcResponse;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let mut bytes = read_json_body(req, state).await?;
+        if bytes.iter().find(|b| !b.is_ascii_whitespace()) != Some(&b'[') {
+            return parse_single(&mut bytes).map(Self::Single);
+        }
+
+        cfg_if!(
+            if #[cfg(feature = "simd")] {
+                let items: Vec<Value> = simd_json::from_slice(&mut bytes)
+                    .map_err(|e| invalid_request(e.to_string()))?;
+            } else if #[cfg(feature = "serde_json")] {
+                let items: Vec<Value> = serde_json::from_slice(&bytes)
+                    .map_err(|e| invalid_request(e.to_string()))?;
+            }
+        );
+        if items.is_empty() {
+            return Err(invalid_request("Empty batch".to_owned()));
+        }
+
+        Ok(Self::Batch(items.into_iter().map(parse_entry).collect()))
+    }
+}
+
+fn parse_entry(item: Value) -> BatchEntry {
+    let entry: EntryHelper = match from_value(item) {
+        Ok(entry) => entry,
+        Err(message) => return BatchEntry::Invalid(invalid_request(message)),
+    };
+    if entry.jsonrpc != JSONRPC {
+        return BatchEntry::Invalid(invalid_request("Unknown jsonrpc version".to_owned()));
+    }
+
+    let params = entry.params.unwrap_or(Value::Null);
+    match entry.id {
+        Some(id) => BatchEntry::Call(JsonRpcExtractor {
+            parsed: params,
+            method: entry.method,
+            id,
+        }),
+        None => BatchEntry::Notification(JsonRpcExtractor {
+            parsed: params,
+            method: entry.method,
+            id: Id::None(()),
+        }),
+    }
+}
+
+/// Batch element. Unlike [`crate::JsonRpcRequest`] the `id` member is
+/// optional: absent means notification, while an explicit `null` is a call
+/// answered with a null id.
+#[derive(Deserialize)]
+struct EntryHelper {
+    jsonrpc: String,
+    #[serde(default, deserialize_with = "present_id")]
+    id: Option<Id>,
+    method: String,
+    params: Option<Value>,
+}
+
+fn present_id<'de, D>(deserializer: D) -> Result<Option<Id>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Id::deserialize(deserializer).map(Some)
+}
+
+fn from_value<T: DeserializeOwned>(value: Value) -> Result<T, String> {
+    cfg_if! {
+        if #[cfg(feature = "simd")] {
+            simd_json::serde::from_owned_value(value).map_err(|e| e.to_string())
+        } else if #[cfg(feature = "serde_json")] {
+            serde_json::from_value(value).map_err(|e| e.to_string())
+        }
+    }
+}
+
+/// Reply to a [`JsonRpcPayload`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum JsonRpcPayloadResponse {
+    /// Response object for a single request (or a whole-batch rejection).
+    Single(JsonRpcResponse),
+    /// Responses for the calls and invalid entries of a batch.
+    Batch(Vec<JsonRpcResponse>),
+    /// A batch made only of notifications: the spec requires no body at all.
+    Empty,
+}
+
+impl JsonRpcPayloadResponse {
+    /// Collects batch responses, mapping an all-notification batch to
+    /// [`Self::Empty`] rather than an empty array.
+    pub fn from_batch(responses: impl IntoIterator<Item = JsonRpcResponse>) -> Self {
+        let responses: Vec<_> = responses.into_iter().collect();
+        if responses.is_empty() {
+            Self::Empty
+        } else {
+            Self::Batch(responses)
+        }
+    }
+}
+
+impl IntoResponse for JsonRpcPayloadResponse {
+    fn into_response(self) -> Response {
+        match self {
+            Self::Single(response) => response.into_response(),
+            Self::Batch(responses) => Json(responses).into_response(),
+            Self::Empty => StatusCode::OK.into_response(),
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "serde_json")]
+mod test {
+    use super::{BatchEntry, JsonRpcPayload, JsonRpcPayloadResponse};
+    use crate::error::{JsonRpcError, JsonRpcErrorReason};
+    use crate::{Id, JsonRpcAnswer, JsonRpcExtractor, JsonRpcResponse};
+    use axum::http::StatusCode;
+    use axum::routing::post;
+    use axum::Router;
+    use axum_test::TestServer;
+    use serde_json::{json, Value};
+
+    fn call(req: JsonRpcExtractor) -> JsonRpcResponse {
+        let id = req.get_answer_id();
+        match req.method() {
+            "add" => match req.parse_params::<[i32; 2]>() {
+                Ok([a, b]) => JsonRpcResponse::success(id, a + b),
+                Err(e) => e,
+            },
+            method => req.method_not_found(method),
+        }
+    }
+
+    async fn handler(payload: JsonRpcPayload) -> JsonRpcPayloadResponse {
+        match payload {
+            JsonRpcPayload::Single(req) => JsonRpcPayloadResponse::Single(call(req)),
+            JsonRpcPayload::Batch(entries) => {
+                JsonRpcPayloadResponse::from_batch(entries.into_iter().filter_map(|e| match e {
+                    BatchEntry::Call(req) => Some(call(req)),
+                    BatchEntry::Notification(req) => {
+                        call(req);
+                        None
+                    }
+                    BatchEntry::Invalid(resp) => Some(resp),
+                }))
+            }
+        }
+    }
+
+    fn server() -> TestServer {
+        TestServer::new(Router::new().route("/", post(handler))).unwrap()
+    }
+
+    #[tokio::test]
+    async fn single_request_still_returns_an_object() {
+        let res = server()
+            .post("/")
+            .json(&json!({"jsonrpc": "2.0", "id": 1, "method": "add", "params": [1, 2]}))
+            .await;
+        assert_eq!(res.status_code(), StatusCode::OK);
+        let response = res.json::<JsonRpcResponse>();
+        assert_eq!(response.id, Id::Num(1));
+        assert_eq!(response.result, JsonRpcAnswer::Result(3.into()));
+    }
+
+    /// Array in, array out, in request order; notifications are executed but
+    /// omitted; a malformed entry and an unknown method each get their own
+    /// error without affecting their neighbours.
+    #[tokio::test]
+    async fn batch_answers_each_call_and_omits_notifications() {
+        let res = server()
+            .post("/")
+            .json(&json!([
+                {"jsonrpc": "2.0", "id": 1, "method": "add", "params": [1, 2]},
+                {"jsonrpc": "2.0", "method": "add", "params": [5, 5]},
+                {"jsonrpc": "2.0", "id": "b", "method": "nope"},
+                1,
+                {"jsonrpc": "2.0", "id": null, "method": "add", "params": [2, 2]},
+            ]))
+            .await;
+        assert_eq!(res.status_code(), StatusCode::OK);
+        let responses = res.json::<Vec<JsonRpcResponse>>();
+        assert_eq!(responses.len(), 4, "{responses:?}");
+
+        assert_eq!(responses[0].id, Id::Num(1));
+        assert_eq!(responses[0].result, JsonRpcAnswer::Result(3.into()));
+
+        let not_found = JsonRpcError::new(
+            JsonRpcErrorReason::MethodNotFound,
+            "Method `nope` not found".to_owned(),
+            Value::Null,
+        );
+        assert_eq!(
+            responses[1],
+            JsonRpcResponse::error("b".to_owned(), not_found)
+        );
+
+        assert_eq!(responses[2].id, Id::None(()));
+        let JsonRpcAnswer::Error(e) = &responses[2].result else {
+            panic!("expected invalid request, got {:?}", responses[2]);
+        };
+        assert_eq!(e.code(), crate::error::INVALID_REQUEST);
+
+        // Explicit `"id": null` is a call, not a notification.
+        assert_eq!(responses[3].id, Id::None(()));
+        assert_eq!(responses[3].result, JsonRpcAnswer::Result(4.into()));
+    }
+
+    #[tokio::test]
+    async fn notification_only_batch_has_an_empty_body() {
+        let res = server()
+            .post("/")
+            .json(&json!([{"jsonrpc": "2.0", "method": "add", "params": [1, 1]}]))
+            .await;
+        assert_eq!(res.status_code(), StatusCode::OK);
+        assert!(res.as_bytes().is_empty());
+    }
+
+    #[tokio::test]
+    async fn empty_batch_is_a_single_invalid_request() {
+        let res = server().post("/").json(&json!([])).await;
+        let response = res.json::<JsonRpcResponse>();
+        assert_eq!(response.id, Id::None(()));
+        let JsonRpcAnswer::Error(e) = &response.result else {
+            panic!("expected invalid request, got {response:?}");
+        };
+        assert_eq!(e.code(), crate::error::INVALID_REQUEST);
+    }
+}
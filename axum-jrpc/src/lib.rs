@@ -62,6 +62,8 @@ cfg_if! {
     }
 }
 
+pub mod batch;
+
 /// Hack until [try_trait_v2](https://github.com/rust-lang/rust/issues/84277) is not stabilized
 pub type JrpcResult = Result<JsonRpcResponse, JsonRpcResponse>;
 
@@ -207,69 +209,61 @@ where
     type Rejection = JsonRpcResponse;
 
     async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
-        if !json_content_type(req.headers()) {
-            return Err(JsonRpcResponse {
-                id: Id::None(()),
-                result: JsonRpcAnswer::Error(JsonRpcError::new(
-                    JsonRpcErrorReason::InvalidRequest,
-                    "Invalid content type".to_owned(),
-                    Value::default(),
-                )),
-            });
-        }
-
         #[allow(unused_mut)]
-        let mut bytes = match Bytes::from_request(req, state).await {
-            Ok(a) => a.to_vec(),
-            Err(_) => {
-                return Err(JsonRpcResponse {
-                    id: Id::None(()),
-                    result: JsonRpcAnswer::Error(JsonRpcError::new(
-                        JsonRpcErrorReason::InvalidRequest,
-                        "Invalid request".to_owned(),
-                        Value::default(),
-                    )),
-                })
-            }
-        };
+        let mut bytes = read_json_body(req, state).await?;
+        parse_single(&mut bytes)
+    }
+}
 
-        cfg_if!(
-            if #[cfg(feature = "simd")] {
-               let parsed: JsonRpcRequest = match simd_json::from_slice(&mut bytes){
-                    Ok(a) => a,
-                    Err(e) => {
-                        return Err(JsonRpcResponse {
-                            id: Id::None(()),
-                            result: JsonRpcAnswer::Error(JsonRpcError::new(
-                                JsonRpcErrorReason::InvalidRequest,
-                                e.to_string(),
-                                Value::default(),
-                            )),
-                        })
-                    }
-                };
-            } else if #[cfg(feature = "serde_json")] {
-               let parsed: JsonRpcRequest = match serde_json::from_slice(&bytes){
-                    Ok(a) => a,
-                    Err(e) => {
-                        return Err(JsonRpcResponse {
-                            id: Id::None(()),
-                            result: JsonRpcAnswer::Error(JsonRpcError::new(
-                                JsonRpcErrorReason::InvalidRequest,
-                                e.to_string(),
-                                Value::default(),
-                            )),
-                        })
-                    }
-                };
-            }
-        );
+/// Checks the content type and buffers the request body.
+pub(crate) async fn read_json_body<S>(req: Request, state: &S) -> Result<Vec<u8>, JsonRpcResponse>
+where
+    Bytes: FromRequest<S>,
+    S: Send + Sync,
+{
+    if !json_content_type(req.headers()) {
+        return Err(invalid_request("Invalid content type".to_owned()));
+    }
+
+    match Bytes::from_request(req, state).await {
+        Ok(a) => Ok(a.to_vec()),
+        Err(_) => Err(invalid_request("Invalid request".to_owned())),
+    }
+}
+
+/// Parses a single request object.
+#[allow(clippy::ptr_arg)]
+pub(crate) fn parse_single(bytes: &mut Vec<u8>) -> Result<JsonRpcExtractor, JsonRpcResponse> {
+    cfg_if!(
+        if #[cfg(feature = "simd")] {
+           let parsed: JsonRpcRequest = match simd_json::from_slice(bytes){
+                Ok(a) => a,
+                Err(e) => return Err(invalid_request(e.to_string())),
+            };
+        } else if #[cfg(feature = "serde_json")] {
+           let parsed: JsonRpcRequest = match serde_json::from_slice(bytes){
+                Ok(a) => a,
+                Err(e) => return Err(invalid_request(e.to_string())),
+            };
+        }
+    );
 
-        Ok(Self {
-            parsed: parsed.params,
-            method: parsed.method,
-            id: parsed.id,
-        })
+    Ok(JsonRpcExtractor {
+        parsed: parsed.params,
+        method: parsed.method,
+        id: parsed.id,
+    })
+}
+
+/// An `Invalid Request` error response with a null id.
+pub(crate) fn invalid_request(message: String) -> JsonRpcResponse {
+    JsonRpcResponse {
+        id: Id::None(()),
+        result: JsonRpcAnswer::Error(JsonRpcError::new(
+            JsonRpcErrorReason::InvalidRequest,
+            message,
+            Value::default(),
+        )),
     }
 }
 
@@ -0,0 +1,23 @@
+-- ============================================================================
+-- Synthetic block headers — real Miden block timestamps
+-- ============================================================================
+--
+-- `BlockState` used to derive every synthetic header timestamp as
+-- `GENESIS_TIMESTAMP + number * BLOCK_TIME`, so `eth_getBlockByNumber` showed
+-- dates unrelated to when the Miden block was produced. The
+-- `BlockTimestampRecorder` sync listener (src/block_timestamps.rs) now fetches
+-- each new Miden block header and persists its timestamp here BEFORE anything
+-- hashes the synthetic block, so the header RLP hash is computed from the real
+-- timestamp the first time and never changes afterwards.
+--
+-- Rows are write-once (`ON CONFLICT DO NOTHING`): a block's timestamp is part
+-- of its hash, and the hash chain may already have been served.
+--
+-- Migration path: no historical rows are backfilled. Blocks that were already
+-- synced before this migration (and any block the recorder had to skip) keep
+-- the legacy deterministic timestamp, so every hash served before the upgrade
+-- stays stable. Loaded in full into `BlockState` at startup.
+CREATE TABLE IF NOT EXISTS synthetic_blocks (
+    block_number BIGINT PRIMARY KEY,
+    timestamp    BIGINT NOT NULL
+);
@@ -0,0 +1,23 @@
+-- ============================================================================
+-- Synthetic block headers — logsBloom and receiptsRoot
+-- ============================================================================
+--
+-- Synthetic headers used to carry a zero `logsBloom` and the empty-trie
+-- `receiptsRoot`, so bloom-based log scanners skipped every block and a
+-- receipt could not be checked against its header. The `SyntheticProjector`
+-- now computes both from the block's synthetic logs when it seals the block
+-- (after every log is written, before the tip advance that exposes it) and
+-- persists them here, so the header RLP hash can be rebuilt identically after
+-- a restart.
+--
+-- A block's header is immutable once exposed; the projector only writes this
+-- row for blocks above the exposed tip.
+--
+-- Migration path: blocks exposed before this migration have no roots and keep
+-- the zero bloom / empty root they were hashed with. The timestamp becomes
+-- nullable because a sealed block without a recorded Miden timestamp still
+-- gets a row for its roots; NULL keeps the legacy deterministic timestamp.
+ALTER TABLE synthetic_blocks
+    ALTER COLUMN timestamp DROP NOT NULL,
+    ADD COLUMN IF NOT EXISTS logs_bloom BYTEA,
+    ADD COLUMN IF NOT EXISTS receipts_root BYTEA;
@@ -0,0 +1,8 @@
+-- Per-block transaction listing (`eth_getBlockByNumber` transaction lists,
+-- `eth_getBlockTransactionCountByNumber`, `eth_getTransactionByBlock*AndIndex`,
+-- `eth_getBlockReceipts`) looks up every terminal transaction committed at a
+-- block. Pending rows carry `block_number = 0` and are never listed, so the
+-- partial index stays off the hot pending-recovery path.
+CREATE INDEX IF NOT EXISTS idx_txns_terminal_block
+    ON transactions (block_number)
+    WHERE status IN ('success', 'failed');
@@ -0,0 +1,8 @@
+-- Writer jobs still queued or mid-dispatch when a graceful shutdown's drain
+-- deadline passes are handed to the next boot through the store instead of a
+-- tmpfile. The shutdown path clears their `next_recovery_at` so orphan
+-- recovery re-drives them immediately, and sets `undrained_at_shutdown` so the
+-- next boot can report how many it inherited. The boot clears the flag once
+-- counted; it is meaningless for terminal rows.
+ALTER TABLE transactions
+    ADD COLUMN IF NOT EXISTS undrained_at_shutdown BOOLEAN NOT NULL DEFAULT FALSE;
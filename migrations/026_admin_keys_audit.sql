@@ -0,0 +1,38 @@
+-- Named, scoped admin API keys and the append-only admin audit log.
+--
+-- `admin_keys` holds only the keccak256 of each bearer token; `methods` lists
+-- the admin methods the key may call (`*` and trailing-`*` prefixes allowed).
+-- Keys here are merged at startup with `--admin-api-key` and
+-- `--admin-keys-file`; names must be unique across all three sources.
+CREATE TABLE IF NOT EXISTS admin_keys (
+    name TEXT PRIMARY KEY,
+    key_hash BYTEA NOT NULL CHECK (octet_length(key_hash) = 32),
+    methods TEXT[] NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+-- One row per admin call made with a known key: outcome `ok` or `error` once
+-- answered, `forbidden` when the key may not call the method. Calls without a
+-- valid key are only counted (`rpc_admin_auth_rejects_total`), so `key_name`
+-- is always set. `params_digest` is keccak256 of the JSON params so the log
+-- never stores secrets passed as arguments.
+CREATE TABLE IF NOT EXISTS admin_audit_log (
+    id BIGSERIAL PRIMARY KEY,
+    recorded_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    key_name TEXT,
+    method TEXT NOT NULL,
+    params_digest TEXT NOT NULL,
+    outcome TEXT NOT NULL
+);
+
+CREATE OR REPLACE FUNCTION admin_audit_log_append_only() RETURNS trigger AS $$
+BEGIN
+    RAISE EXCEPTION 'admin_audit_log is append-only';
+END;
+$$ LANGUAGE plpgsql;
+
+DROP TRIGGER IF EXISTS admin_audit_log_append_only ON admin_audit_log;
+CREATE TRIGGER admin_audit_log_append_only
+    BEFORE UPDATE OR DELETE ON admin_audit_log
+    FOR EACH ROW EXECUTE FUNCTION admin_audit_log_append_only();
@@ -0,0 +1,29 @@
+-- Reconstructed L1 info tree.
+--
+-- The L1InfoTreeIndexer appends one row per `UpdateL1InfoTree` event, in
+-- contract leaf order, and keeps the `DepositContractBase` frontier (leaf
+-- count + 32 branch hashes, level 0 first) in `l1_info_tree_state` so the
+-- next leaf can be appended without replaying history. `root` is the
+-- `l1InfoRoot` after the leaf; `verified_leaf_count` marks how many leaves
+-- are covered by a root that matched the contract's `getRoot()`.
+CREATE TABLE IF NOT EXISTS l1_info_tree_leaves (
+    leaf_index          BIGINT PRIMARY KEY CHECK (leaf_index >= 0),
+    ger                 BYTEA NOT NULL CHECK (octet_length(ger) = 32),
+    previous_block_hash BYTEA NOT NULL CHECK (octet_length(previous_block_hash) = 32),
+    timestamp           BIGINT NOT NULL,
+    block_number        BIGINT NOT NULL,
+    root                BYTEA NOT NULL CHECK (octet_length(root) = 32)
+);
+
+CREATE INDEX IF NOT EXISTS l1_info_tree_leaves_ger_idx ON l1_info_tree_leaves (ger);
+
+CREATE TABLE IF NOT EXISTS l1_info_tree_state (
+    id                  INT PRIMARY KEY DEFAULT 1 CHECK (id = 1),
+    leaf_count          BIGINT NOT NULL DEFAULT 0,
+    frontier            BYTEA NOT NULL CHECK (octet_length(frontier) = 1024),
+    verified_leaf_count BIGINT NOT NULL DEFAULT 0,
+    updated_at          TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+INSERT INTO l1_info_tree_state (id, frontier)
+VALUES (1, decode(repeat('00', 1024), 'hex'))
+ON CONFLICT (id) DO NOTHING;
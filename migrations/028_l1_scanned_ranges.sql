@@ -0,0 +1,14 @@
+-- L1 reorg detection for the evidence scan.
+--
+-- After each batch the L1InfoTreeIndexer records the range it processed and
+-- the hash of the range's last block. The next poll compares the following
+-- block's parent hash with the newest `end_hash`; on a mismatch it walks the
+-- retained ranges back to the newest one still on the canonical chain and
+-- rewinds the cursor there, clearing the evidence recorded above it. Only the
+-- newest ranges are retained (the indexer prunes on every insert).
+CREATE TABLE IF NOT EXISTS l1_scanned_ranges (
+    range_end   BIGINT PRIMARY KEY,
+    range_start BIGINT NOT NULL,
+    end_hash    BYTEA NOT NULL CHECK (octet_length(end_hash) = 32),
+    recorded_at TIMESTAMPTZ NOT NULL DEFAULT now()
+);
@@ -0,0 +1,10 @@
+-- GER removal (GER-remover role).
+--
+-- The projector folds every consumed RemoveGerNote into a second keccak chain,
+-- mirroring the sovereign GER manager's `removedGERHashChain()`:
+-- `chain_n = keccak256(chain_{n-1} || removed_ger_n)`, zero before the first
+-- removal. A removed GER's `ger_entries` row keeps its roots but drops back to
+-- `is_injected = FALSE`, so it can be injected again.
+ALTER TABLE service_state
+    ADD COLUMN IF NOT EXISTS removal_hash_chain_value BYTEA NOT NULL
+        DEFAULT '\x0000000000000000000000000000000000000000000000000000000000000000';
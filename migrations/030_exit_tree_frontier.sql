@@ -0,0 +1,17 @@
+-- Local exit tree frontier for the bridge view calls.
+--
+-- `getRoot()` / `depositCount()` / `lastUpdatedDepositCount()` answer from the
+-- `DepositContractBase` frontier (leaf count + 32 branch hashes, level 0
+-- first) of the exposed `BridgeEvent`s. `next_block` is the first synthetic
+-- block not folded in yet, so each call only reads the blocks sealed since
+-- the previous one.
+CREATE TABLE IF NOT EXISTS exit_tree_state (
+    id         INT PRIMARY KEY DEFAULT 1 CHECK (id = 1),
+    leaf_count BIGINT NOT NULL DEFAULT 0,
+    frontier   BYTEA NOT NULL CHECK (octet_length(frontier) = 1024),
+    next_block BIGINT NOT NULL DEFAULT 0,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+INSERT INTO exit_tree_state (id, frontier)
+VALUES (1, decode(repeat('00', 1024), 'hex'))
+ON CONFLICT (id) DO NOTHING;
@@ -0,0 +1,19 @@
+-- Retention for the append-only admin audit log.
+--
+-- Updates stay rejected. A DELETE is let through only for rows older than 90
+-- days, and only for a session whose user is a member of the
+-- `admin_audit_retention` role, so the proxy's own role still cannot rewrite
+-- history. Operators grant that role to the account running the retention
+-- job; until the role exists every DELETE is rejected as before.
+CREATE OR REPLACE FUNCTION admin_audit_log_append_only() RETURNS trigger AS $$
+BEGIN
+    IF TG_OP = 'DELETE'
+       AND OLD.recorded_at < now() - interval '90 days'
+       AND EXISTS (SELECT 1 FROM pg_roles WHERE rolname = 'admin_audit_retention') THEN
+        IF pg_has_role(session_user, 'admin_audit_retention', 'MEMBER') THEN
+            RETURN OLD;
+        END IF;
+    END IF;
+    RAISE EXCEPTION 'admin_audit_log is append-only';
+END;
+$$ LANGUAGE plpgsql;
@@ -0,0 +1,14 @@
+-- GER removals in flight.
+--
+-- The writer records the transaction of each GER removal just before it
+-- builds the RemoveGerNote. A removal stays outstanding while that
+-- transaction is pending: the projector finalises it when the bridge consumes
+-- the note, and a failed removal gets a status-0 receipt. Both the signed
+-- `removeGlobalExitRoots` path and `admin_removeGlobalExitRoot` refuse a GER
+-- with an outstanding removal, across restarts.
+CREATE TABLE IF NOT EXISTS ger_removals (
+    tx_hash          TEXT PRIMARY KEY,
+    global_exit_root BYTEA NOT NULL,
+    created_at       TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+CREATE INDEX IF NOT EXISTS idx_ger_removals_ger ON ger_removals (global_exit_root);
@@ -0,0 +1,258 @@
+//! Named, method-scoped admin API keys.
+//!
+//! Keys come from three sources, merged once at startup into an
+//! [`AdminKeyring`]:
+//!
+//! - `--admin-api-key`: the legacy shared token, loaded as the key `default`
+//!   scoped to every admin method;
+//! - `--admin-keys-file`: a TOML file of `[[keys]]` entries;
+//! - the store's `admin_keys` table ([`crate::store::Store::list_admin_keys`]).
+//!
+//! Only the keccak256 of a bearer token is ever held, in memory or at rest
+//! (`cast keccak <token>` prints it). A presented token is hashed and
+//! compared in constant time against every key, so response timing reveals
+//! neither which key matched nor how much of a hash did.
+//!
+//! A key's `methods` lists the admin methods it may call: exact names, a
+//! trailing-`*` prefix (`admin_list*`) or `*` for all of them.
+//!
+//! ```toml
+//! [[keys]]
+//! name = "ops-readonly"
+//! key_hash = "0x…"  # keccak256 of the bearer token
+//! methods = ["admin_list*", "admin_get*"]
+//! ```
+
+use crate::store::{AdminKeyRecord, Store};
+use alloy::primitives::keccak256;
+use serde::Deserialize;
+use std::path::Path;
+
+/// Name the legacy `--admin-api-key` token is loaded under.
+pub const LEGACY_KEY_NAME: &str = "default";
+
+/// keccak256 of a bearer token, the only form in which keys are kept.
+pub fn hash_admin_token(token: &str) -> [u8; 32] {
+    keccak256(token.as_bytes()).0
+}
+
+/// Whether `pattern` (an entry of a key's `methods`) covers `method`.
+fn scope_matches(pattern: &str, method: &str) -> bool {
+    match pattern.strip_suffix('*') {
+        Some(prefix) => method.starts_with(prefix),
+        None => pattern == method,
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct KeysFile {
+    #[serde(default)]
+    keys: Vec<KeysFileEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct KeysFileEntry {
+    name: String,
+    key_hash: String,
+    methods: Vec<String>,
+}
+
+/// The admin keys this process accepts.
+#[derive(Debug, Clone, Default)]
+pub struct AdminKeyring {
+    keys: Vec<AdminKeyRecord>,
+}
+
+impl AdminKeyring {
+    /// Build from already-loaded records. Fails on an empty or duplicate name
+    /// or an empty scope.
+    pub fn new(keys: Vec<AdminKeyRecord>) -> anyhow::Result<Self> {
+        let mut seen = std::collections::HashSet::new();
+        for key in &keys {
+            anyhow::ensure!(!key.name.is_empty(), "admin key with an empty name");
+            anyhow::ensure!(
+                seen.insert(key.name.as_str()),
+                "admin key {} is defined more than once",
+                key.name
+            );
+            anyhow::ensure!(
+                !key.methods.is_empty(),
+                "admin key {} is not scoped to any method",
+                key.name
+            );
+        }
+        Ok(Self { keys })
+    }
+
+    /// A keyring holding only the legacy shared token, if one is set.
+    pub fn from_token(token: Option<&str>) -> Self {
+        Self {
+            keys: token.map(legacy_key).into_iter().collect(),
+        }
+    }
+
+    /// Merge the legacy token, the keys file and the store's keys.
+    pub async fn load(
+        token: Option<&str>,
+        keys_file: Option<&Path>,
+        store: &dyn Store,
+    ) -> anyhow::Result<Self> {
+        let mut keys: Vec<AdminKeyRecord> = token.map(legacy_key).into_iter().collect();
+        if let Some(path) = keys_file {
+            keys.extend(read_keys_file(path)?);
+        }
+        keys.extend(store.list_admin_keys().await?);
+        Self::new(keys)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Key names with their scopes, for the startup log.
+    pub fn summary(&self) -> Vec<(String, Vec<String>)> {
+        self.keys
+            .iter()
+            .map(|k| (k.name.clone(), k.methods.clone()))
+            .collect()
+    }
+
+    /// The key whose hash matches `token`. Every key is compared, in
+    /// constant time, whatever matched earlier.
+    pub fn authenticate(&self, token: &str) -> Option<&AdminKeyRecord> {
+        let presented = hash_admin_token(token);
+        let mut found = None;
+        for key in &self.keys {
+            if constant_time_eq(&presented, &key.key_hash) && found.is_none() {
+                found = Some(key);
+            }
+        }
+        found
+    }
+}
+
+impl AdminKeyRecord {
+    /// Whether this key is scoped for `method`.
+    pub fn allows(&self, method: &str) -> bool {
+        self.methods.iter().any(|p| scope_matches(p, method))
+    }
+}
+
+fn legacy_key(token: &str) -> AdminKeyRecord {
+    AdminKeyRecord {
+        name: LEGACY_KEY_NAME.to_string(),
+        key_hash: hash_admin_token(token),
+        methods: vec!["*".to_string()],
+    }
+}
+
+fn read_keys_file(path: &Path) -> anyhow::Result<Vec<AdminKeyRecord>> {
+    let text = std::fs::read_to_string(path)
+        .map_err(|e| anyhow::anyhow!("reading admin keys file {}: {e}", path.display()))?;
+    let file: KeysFile = toml::from_str(&text)
+        .map_err(|e| anyhow::anyhow!("parsing admin keys file {}: {e}", path.display()))?;
+    file.keys
+        .into_iter()
+        .map(|entry| {
+            let hex = entry.key_hash.strip_prefix("0x").unwrap_or(&entry.key_hash);
+            let key_hash: [u8; 32] = hex::decode(hex)
+                .ok()
+                .and_then(|bytes| bytes.try_into().ok())
+                .ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "admin key {}: key_hash must be 32 hex-encoded bytes",
+                        entry.name
+                    )
+                })?;
+            Ok(AdminKeyRecord {
+                name: entry.name,
+                key_hash,
+                methods: entry.methods,
+            })
+        })
+        .collect()
+}
+
+/// Constant-time byte equality — prevents an attacker from learning prefix-match
+/// length OR length-mismatch from response timing.
+///
+/// Self-review of-the-fix follow-up: the previous implementation early-returned
+/// `false` on `a.len() != b.len()`, which leaks the configured token length via
+/// timing. Now we walk the longer slice in full, treating any out-of-bounds
+/// byte from the shorter slice as zero. Constant work irrespective of input
+/// shape; the only timing channel is "input length", which is already
+/// observable in the request's wire size.
+pub(crate) fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    let max_len = a.len().max(b.len());
+    let mut diff: u32 = 0;
+    // Length difference contributes to `diff` so unequal lengths reject.
+    diff |= (a.len() as u32) ^ (b.len() as u32);
+    for i in 0..max_len {
+        let x = *a.get(i).unwrap_or(&0);
+        let y = *b.get(i).unwrap_or(&0);
+        diff |= u32::from(x ^ y);
+    }
+    diff == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(name: &str, token: &str, methods: &[&str]) -> AdminKeyRecord {
+        AdminKeyRecord {
+            name: name.to_string(),
+            key_hash: hash_admin_token(token),
+            methods: methods.iter().map(|m| m.to_string()).collect(),
+        }
+    }
+
+    #[tokio::test]
+    async fn keys_merge_from_token_file_and_store_and_stay_scoped() {
+        let store = crate::store::memory::InMemoryStore::new();
+        store
+            .put_admin_key(key("faucets", "t-faucets", &["admin_registerFaucet"]))
+            .await
+            .unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("admin_keys.toml");
+        std::fs::write(
+            &path,
+            format!(
+                "[[keys]]\nname = \"readonly\"\nkey_hash = \"0x{}\"\nmethods = [\"admin_list*\"]\n",
+                hex::encode(hash_admin_token("t-read"))
+            ),
+        )
+        .unwrap();
+
+        let ring = AdminKeyring::load(Some("t-legacy"), Some(&path), &store)
+            .await
+            .unwrap();
+        let read = ring.authenticate("t-read").unwrap();
+        assert_eq!(read.name, "readonly");
+        assert!(read.allows("admin_listFaucets"));
+        assert!(!read.allows("admin_registerFaucet"));
+        let faucets = ring.authenticate("t-faucets").unwrap();
+        assert!(faucets.allows("admin_registerFaucet"));
+        assert!(!faucets.allows("admin_listFaucets"));
+        let legacy = ring.authenticate("t-legacy").unwrap();
+        assert_eq!(legacy.name, LEGACY_KEY_NAME);
+        assert!(legacy.allows("admin_cancelWriterJob"));
+        assert!(ring.authenticate("t-nope").is_none());
+
+        // A store key may not shadow a file or legacy key.
+        store
+            .put_admin_key(key("readonly", "t-other", &["*"]))
+            .await
+            .unwrap();
+        assert!(AdminKeyring::load(None, Some(&path), &store).await.is_err());
+    }
+
+    #[test]
+    fn keyring_rejects_unscoped_keys() {
+        assert!(AdminKeyring::new(vec![key("empty", "t", &[])]).is_err());
+        assert!(AdminKeyring::from_token(None).is_empty());
+    }
+}
@@ -292,6 +292,26 @@ pub(crate) async fn reconcile_claim_handoff_with_client(
     ))
 }
 
+/// Exact-note classification for a GER removal: the effect is "applied" once
+/// the bridge no longer holds the GER. A removal that already projected (store
+/// no longer injected) with our receipt still pending was another removal's.
+pub(crate) async fn reconcile_ger_removal_handoff_with_client(
+    store: &dyn Store,
+    client: &mut MidenClientLib,
+    bridge_id: AccountId,
+    ger: [u8; 32],
+    note_id: String,
+) -> anyhow::Result<ExactNoteOutcome> {
+    let snapshot =
+        bridge_snapshot_with_client(client, bridge_id, Some(ger), None, Some(note_id)).await?;
+    let removed = !snapshot.ger_applied.unwrap_or(true);
+    if removed && !store.is_ger_injected(&ger).await? && snapshot.note != NoteObservation::Consumed
+    {
+        return Ok(ExactNoteOutcome::AppliedElsewhere);
+    }
+    Ok(classify_exact_note(removed, snapshot.note))
+}
+
 /// Recovery-oriented, FRESH GER effect classification (reviewers #4 + #5). Syncs the
 /// Miden view first, then — like claims — uses the EXACT handoff note. This matters
 /// because the Miden bridge state (nullifier/GER map) can show a GER applied BEFORE
@@ -364,6 +384,65 @@ pub(crate) async fn reconcile_ger_recovery(
         .context("recovery GER reconcile produced no outcome")
 }
 
+/// Recovery classification for a GER removal, mirroring `reconcile_ger_recovery`
+/// with the effect inverted: exact RemoveGerNote consumed → `AppliedByExactNote`
+/// (projector-owned); GER already gone from the bridge without our note →
+/// `AppliedElsewhere` (the caller reverts with `GlobalExitRootNotFound()`); GER
+/// still held → `NotApplied` (re-drive).
+#[cfg(not(test))]
+pub(crate) async fn reconcile_ger_removal_recovery(
+    service: &ServiceState,
+    ger: [u8; 32],
+    note_id: Option<String>,
+    has_handoff: bool,
+) -> anyhow::Result<ExactNoteOutcome> {
+    if note_id.is_none() && has_handoff {
+        return Ok(ExactNoteOutcome::Uncertain);
+    }
+    let store = service.store.clone();
+    let bridge_id = service.accounts.0.bridge.0;
+    let result: Arc<Mutex<Option<ExactNoteOutcome>>> = Arc::new(Mutex::new(None));
+    let result_in = result.clone();
+    service
+        .miden_client
+        .with(move |client| {
+            Box::new(async move {
+                client
+                    .sync_state()
+                    .await
+                    .context("fresh Miden sync before recovery GER-removal reconcile")?;
+                let outcome = match note_id {
+                    Some(note_id) => {
+                        reconcile_ger_removal_handoff_with_client(
+                            &*store, client, bridge_id, ger, note_id,
+                        )
+                        .await?
+                    }
+                    None => {
+                        let held =
+                            bridge_snapshot_with_client(client, bridge_id, Some(ger), None, None)
+                                .await?
+                                .ger_applied
+                                .unwrap_or(false);
+                        if held {
+                            ExactNoteOutcome::NotApplied
+                        } else {
+                            ExactNoteOutcome::AppliedElsewhere
+                        }
+                    }
+                };
+                *result_in.lock().expect("recovery reconcile mutex poisoned") = Some(outcome);
+                Ok(())
+            })
+        })
+        .await?;
+    result
+        .lock()
+        .expect("recovery reconcile mutex poisoned")
+        .take()
+        .context("recovery GER-removal reconcile produced no outcome")
+}
+
 /// Recovery-oriented, FRESH claim effect classification (reviewers #3 + #5). Syncs
 /// the Miden view first, then — when the durable handoff `note_id` is known — uses
 /// the EXACT-note classifier so a claim landed by ANOTHER transaction/note is
@@ -472,6 +551,24 @@ pub(crate) async fn reconcile_ger_recovery(
     }
 }
 
+#[cfg(test)]
+pub(crate) async fn reconcile_ger_removal_recovery(
+    service: &ServiceState,
+    ger: [u8; 32],
+    note_id: Option<String>,
+    has_handoff: bool,
+) -> anyhow::Result<ExactNoteOutcome> {
+    if note_id.is_none() && has_handoff {
+        return Ok(ExactNoteOutcome::Uncertain);
+    }
+    // A GER the local projection no longer holds was removed elsewhere.
+    if service.store.is_ger_injected(&ger).await? {
+        Ok(ExactNoteOutcome::NotApplied)
+    } else {
+        Ok(ExactNoteOutcome::AppliedElsewhere)
+    }
+}
+
 #[cfg(test)]
 pub(crate) async fn reconcile_claim_recovery(
     service: &ServiceState,
@@ -25,14 +25,32 @@
 //! The parent_hash field in each header is the RLP hash of the previous block's
 //! header, forming a proper hash chain. Hash computation is iterative from genesis
 //! to avoid recursion, and results are cached in BlockState.
-
-use alloy::consensus::Header;
-use alloy::primitives::{B64, B256, Bloom, U256};
+//!
+//! # Timestamps and roots
+//!
+//! A block's timestamp is the real Miden block timestamp when one was recorded
+//! (see `block_timestamps::BlockTimestampRecorder`, persisted in migration 022),
+//! otherwise the legacy `GENESIS_TIMESTAMP + number * BLOCK_TIME`. Its
+//! `logsBloom` and `receiptsRoot` are computed from the block's synthetic logs
+//! when the `SyntheticProjector` seals it (migration 023); unsealed and legacy
+//! blocks carry the zero bloom and the empty-trie root.
+//!
+//! All three are hashed into the header, so they are frozen when the block is
+//! SEALED — right before the tip advance that exposes it. Hashes of blocks
+//! above the sealed tip are provisional: recording a timestamp or sealing
+//! drops them from the cache and they are recomputed. A sealed block's hash
+//! never changes.
+
+use alloy::consensus::{Eip658Value, Header, Receipt, ReceiptEnvelope, ReceiptWithBloom};
+use alloy::primitives::{Address, B64, B256, Bloom, Bytes, Log, U256};
 use parking_lot::RwLock;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::str::FromStr;
 
+use crate::log_synthesis::SyntheticLog;
 use crate::miden_client::SyncListener;
+use crate::store::Store;
 use miden_client::sync::SyncSummary;
 
 /// Genesis timestamp for synthetic blocks (2024-01-01 00:00:00 UTC)
@@ -53,6 +71,93 @@ const EMPTY_ROOT_HASH: [u8; 32] = [
     0x5b, 0x48, 0xe0, 0x1b, 0x99, 0x6c, 0xad, 0xc0, 0x01, 0x62, 0x2f, 0xb5, 0xe3, 0x63, 0xb4, 0x21,
 ];
 
+/// Header roots sealed into a synthetic block, computed from its synthetic
+/// logs by [`compute_block_roots`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BlockRoots {
+    pub logs_bloom: Bloom,
+    pub receipts_root: [u8; 32],
+}
+
+impl Default for BlockRoots {
+    /// The roots of a block without logs (and of every legacy block).
+    fn default() -> Self {
+        Self {
+            logs_bloom: Bloom::ZERO,
+            receipts_root: EMPTY_ROOT_HASH,
+        }
+    }
+}
+
+/// Convert a stored synthetic log to its consensus form.
+fn primitive_log(log: &SyntheticLog) -> anyhow::Result<Log> {
+    let address = Address::from_str(&log.address)
+        .map_err(|e| anyhow::anyhow!("synthetic log address {:?}: {e}", log.address))?;
+    let topics = log
+        .topics
+        .iter()
+        .map(|t| B256::from_str(t).map_err(|e| anyhow::anyhow!("synthetic log topic {t:?}: {e}")))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    let data = Bytes::from_str(&log.data)
+        .map_err(|e| anyhow::anyhow!("synthetic log data {:?}: {e}", log.data))?;
+    Log::new(address, topics, data)
+        .ok_or_else(|| anyhow::anyhow!("synthetic log has more than 4 topics"))
+}
+
+/// The consensus receipt of one synthetic transaction: successful, no gas,
+/// carrying `logs` (in log-index order) and their bloom. This is the receipt
+/// `eth_getTransactionReceipt` serves and the one hashed into `receiptsRoot`.
+pub fn synthetic_receipt(logs: &[SyntheticLog]) -> anyhow::Result<ReceiptWithBloom<Receipt<Log>>> {
+    let receipt = Receipt {
+        status: Eip658Value::Eip658(true),
+        cumulative_gas_used: 0,
+        logs: logs
+            .iter()
+            .map(primitive_log)
+            .collect::<anyhow::Result<_>>()?,
+    };
+    Ok(receipt.with_bloom())
+}
+
+/// Group a block's logs by transaction (lowercase hash), transactions ordered
+/// by their first log index and each group in log-index order. This is the
+/// block's transaction order for every transaction that emitted logs.
+pub fn logs_by_transaction(logs: &[SyntheticLog]) -> Vec<(String, Vec<SyntheticLog>)> {
+    let mut ordered: Vec<&SyntheticLog> = logs.iter().collect();
+    ordered.sort_by_key(|log| log.log_index);
+    let mut by_tx: Vec<(String, Vec<SyntheticLog>)> = Vec::new();
+    for log in ordered {
+        let tx = log.transaction_hash.to_lowercase();
+        match by_tx.iter_mut().find(|(hash, _)| *hash == tx) {
+            Some((_, tx_logs)) => tx_logs.push(log.clone()),
+            None => by_tx.push((tx, vec![log.clone()])),
+        }
+    }
+    by_tx
+}
+
+/// Compute a block's `logsBloom` and `receiptsRoot` from every synthetic log
+/// in it. One EIP-1559 receipt per transaction that emitted logs, in
+/// [`logs_by_transaction`] order; transactions without logs are not part of
+/// the trie.
+pub fn compute_block_roots(logs: &[SyntheticLog]) -> anyhow::Result<BlockRoots> {
+    if logs.is_empty() {
+        return Ok(BlockRoots::default());
+    }
+    let by_tx = logs_by_transaction(logs);
+    let mut logs_bloom = Bloom::ZERO;
+    let mut receipts = Vec::with_capacity(by_tx.len());
+    for (_, tx_logs) in &by_tx {
+        let receipt = synthetic_receipt(tx_logs)?;
+        logs_bloom.accrue_bloom(&receipt.logs_bloom);
+        receipts.push(ReceiptEnvelope::Eip1559(receipt));
+    }
+    Ok(BlockRoots {
+        logs_bloom,
+        receipts_root: alloy::consensus::proofs::calculate_receipt_root(&receipts).0,
+    })
+}
+
 /// Synthetic EVM block generated from Miden batch
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SyntheticBlock {
@@ -61,22 +166,21 @@ pub struct SyntheticBlock {
     pub parent_hash: [u8; 32],
     pub timestamp: u64,
     pub state_root: [u8; 32],
+    pub roots: BlockRoots,
     pub transactions: Vec<String>,
 }
 
 impl SyntheticBlock {
     /// Build a block header for hash computation.
-    fn build_header(number: u64, parent_hash: B256) -> Header {
-        let timestamp = GENESIS_TIMESTAMP + number * BLOCK_TIME;
-
+    fn build_header(number: u64, parent_hash: B256, timestamp: u64, roots: BlockRoots) -> Header {
         Header {
             parent_hash,
             ommers_hash: B256::from(EMPTY_OMMERS_HASH),
             beneficiary: Default::default(),
             state_root: B256::ZERO,
             transactions_root: B256::from(EMPTY_ROOT_HASH),
-            receipts_root: B256::from(EMPTY_ROOT_HASH),
-            logs_bloom: Bloom::ZERO,
+            receipts_root: B256::from(roots.receipts_root),
+            logs_bloom: roots.logs_bloom,
             difficulty: U256::ZERO,
             number,
             gas_limit: 30_000_000,
@@ -91,11 +195,17 @@ impl SyntheticBlock {
     }
 
     /// Compute the hash for a block number by building the entire chain from
-    /// genesis. This is O(N) but results should be cached by BlockState.
+    /// genesis with legacy deterministic timestamps and empty roots. This is
+    /// O(N) but results should be cached by BlockState.
     pub fn compute_hash_for_number(number: u64) -> [u8; 32] {
         let mut parent_hash = B256::ZERO;
         for n in 0..=number {
-            let header = Self::build_header(n, parent_hash);
+            let header = Self::build_header(
+                n,
+                parent_hash,
+                BlockState::deterministic_timestamp(n),
+                BlockRoots::default(),
+            );
             parent_hash = header.hash_slow();
         }
         parent_hash.0
@@ -111,8 +221,8 @@ impl SyntheticBlock {
             "timestamp": format!("0x{:x}", self.timestamp),
             "stateRoot": format!("0x{}", hex::encode(self.state_root)),
             "transactionsRoot": format!("0x{}", hex::encode(EMPTY_ROOT_HASH)),
-            "receiptsRoot": format!("0x{}", hex::encode(EMPTY_ROOT_HASH)),
-            "logsBloom": format!("0x{}", "00".repeat(256)),
+            "receiptsRoot": format!("0x{}", hex::encode(self.roots.receipts_root)),
+            "logsBloom": format!("0x{}", hex::encode(self.roots.logs_bloom)),
             "difficulty": "0x0",
             "totalDifficulty": "0x0",
             "gasLimit": "0x1c9c380",
@@ -133,11 +243,25 @@ impl SyntheticBlock {
 /// Block state tracking for synthetic EVM blocks.
 ///
 /// Caches block hashes so the iterative chain computation only runs once per
-/// block number. Blocks are created on demand and never evicted.
+/// block number. Blocks are created on demand and the cache always covers a
+/// contiguous range starting at genesis; only provisional (unsealed) blocks
+/// are ever evicted.
 pub struct BlockState {
     blocks: RwLock<HashMap<u64, SyntheticBlock>>,
     hash_to_number: RwLock<HashMap<[u8; 32], u64>>,
     current_block: RwLock<u64>,
+    /// Recorded real Miden block timestamps. Blocks absent here use the
+    /// legacy deterministic timestamp.
+    timestamps: RwLock<HashMap<u64, u64>>,
+    /// Sealed `logsBloom` / `receiptsRoot`. Blocks absent here use the
+    /// empty defaults.
+    roots: RwLock<HashMap<u64, BlockRoots>>,
+    /// Highest sealed (exposed) block. Headers at or below it are immutable.
+    sealed_through: RwLock<u64>,
+    /// Held by the projector from reading a block's logs until the tip
+    /// advance exposes it, and by anyone else writing logs at `tip + 1`, so
+    /// no log lands in a block after its roots were computed.
+    seal_lock: tokio::sync::Mutex<()>,
 }
 
 impl BlockState {
@@ -146,6 +270,10 @@ impl BlockState {
             blocks: RwLock::new(HashMap::new()),
             hash_to_number: RwLock::new(HashMap::new()),
             current_block: RwLock::new(0),
+            timestamps: RwLock::new(HashMap::new()),
+            roots: RwLock::new(HashMap::new()),
+            sealed_through: RwLock::new(0),
+            seal_lock: tokio::sync::Mutex::new(()),
         };
         state.ensure_block_exists(0);
         state
@@ -164,19 +292,116 @@ impl BlockState {
         GENESIS_TIMESTAMP + block_num * BLOCK_TIME
     }
 
-    /// Compute the deterministic timestamp for any block number.
+    /// The timestamp hashed into `block_num`'s header: the recorded Miden
+    /// block timestamp, or the legacy deterministic one.
     pub fn get_block_timestamp(&self, block_num: u64) -> u64 {
-        Self::deterministic_timestamp(block_num)
+        self.timestamps
+            .read()
+            .get(&block_num)
+            .copied()
+            .unwrap_or_else(|| Self::deterministic_timestamp(block_num))
+    }
+
+    /// Highest sealed block. Its header and every one below it are final.
+    pub fn sealed_through(&self) -> u64 {
+        *self.sealed_through.read()
+    }
+
+    /// Restore persisted header inputs at startup, before anything hashes a
+    /// block: recorded timestamps, sealed roots, and the exposed synthetic tip
+    /// (everything at or below it is sealed).
+    pub async fn load(&self, store: &dyn Store) -> anyhow::Result<()> {
+        let timestamps = store.get_block_timestamps().await?;
+        let roots = store.get_block_roots().await?;
+        let tip = store.get_latest_block_number().await?;
+        let mut hash_to_number = self.hash_to_number.write();
+        let mut blocks = self.blocks.write();
+        self.timestamps.write().extend(timestamps);
+        self.roots.write().extend(roots);
+        *self.sealed_through.write() = tip;
+        Self::evict_from(&mut hash_to_number, &mut blocks, 1);
+        Ok(())
+    }
+
+    /// Drop cached blocks at or above `from` (never genesis). Callers hold
+    /// both cache locks.
+    fn evict_from(
+        hash_to_number: &mut HashMap<[u8; 32], u64>,
+        blocks: &mut HashMap<u64, SyntheticBlock>,
+        from: u64,
+    ) {
+        let from = from.max(1);
+        blocks.retain(|&n, block| {
+            let keep = n < from;
+            if !keep {
+                hash_to_number.remove(&block.hash);
+            }
+            keep
+        });
+    }
+
+    /// Record real Miden block timestamps as `(block_number, unix_seconds)`.
+    ///
+    /// Entries at or below [`Self::sealed_through`] are dropped: their header
+    /// is already exposed. Already-recorded blocks are never overwritten.
+    /// Provisional hashes from the earliest accepted block up are evicted.
+    /// Returns the entries that were accepted.
+    pub fn record_timestamps(&self, entries: &[(u64, u64)]) -> Vec<(u64, u64)> {
+        // Lock order matches `ensure_block_exists`: hash_to_number, blocks,
+        // timestamps, then sealed_through.
+        let mut hash_to_number = self.hash_to_number.write();
+        let mut blocks = self.blocks.write();
+        let mut timestamps = self.timestamps.write();
+        let sealed_through = *self.sealed_through.read();
+        let mut accepted = Vec::new();
+        for &(block_num, timestamp) in entries {
+            if block_num <= sealed_through || timestamps.contains_key(&block_num) {
+                continue;
+            }
+            timestamps.insert(block_num, timestamp);
+            accepted.push((block_num, timestamp));
+        }
+        if let Some(&(first, _)) = accepted.iter().min() {
+            Self::evict_from(&mut hash_to_number, &mut blocks, first);
+        }
+        accepted
+    }
+
+    /// Serialize sealing the next block against writes into it; see
+    /// `seal_lock`.
+    pub async fn lock_seal(&self) -> tokio::sync::MutexGuard<'_, ()> {
+        self.seal_lock.lock().await
+    }
+
+    /// Seal `block_num` with the roots computed from its logs and return its
+    /// final hash. Called by the projector after every log of the block is
+    /// written and before the tip advance exposes it. Re-sealing an already
+    /// sealed block keeps its existing header.
+    pub fn seal_block(&self, block_num: u64, roots: BlockRoots) -> [u8; 32] {
+        {
+            let mut hash_to_number = self.hash_to_number.write();
+            let mut blocks = self.blocks.write();
+            let mut sealed_through = self.sealed_through.write();
+            if block_num > *sealed_through {
+                self.roots.write().insert(block_num, roots);
+                Self::evict_from(&mut hash_to_number, &mut blocks, block_num);
+                *sealed_through = block_num;
+            }
+        }
+        self.get_block_hash(block_num)
     }
 
     fn ensure_block_exists(&self, block_num: u64) {
         // Acquire both locks before mutating to prevent deadlock from
-        // inconsistent lock ordering. Always: hash_to_number first, then blocks.
+        // inconsistent lock ordering. Always: hash_to_number first, then
+        // blocks, then timestamps, then roots.
         let mut hash_to_number = self.hash_to_number.write();
         let mut blocks = self.blocks.write();
         if blocks.contains_key(&block_num) {
             return;
         }
+        let timestamps = self.timestamps.read();
+        let roots = self.roots.read();
 
         // Build the chain iteratively, reusing cached hashes where possible.
         // Find the highest cached block below block_num to avoid recomputing
@@ -196,14 +421,20 @@ impl BlockState {
                 parent_hash = B256::from(cached.hash);
                 continue;
             }
-            let header = SyntheticBlock::build_header(n, parent_hash);
+            let timestamp = timestamps
+                .get(&n)
+                .copied()
+                .unwrap_or_else(|| Self::deterministic_timestamp(n));
+            let block_roots = roots.get(&n).copied().unwrap_or_default();
+            let header = SyntheticBlock::build_header(n, parent_hash, timestamp, block_roots);
             let hash = header.hash_slow().0;
             let block = SyntheticBlock {
                 number: n,
                 hash,
                 parent_hash: parent_hash.0,
-                timestamp: Self::deterministic_timestamp(n),
+                timestamp,
                 state_root: [0u8; 32],
+                roots: block_roots,
                 transactions: Vec::new(),
             };
             parent_hash = B256::from(hash);
@@ -275,7 +506,12 @@ mod tests {
     #[test]
     fn test_hash_is_real_rlp_hash() {
         let parent_hash = B256::ZERO; // genesis has no parent
-        let header = SyntheticBlock::build_header(0, parent_hash);
+        let header = SyntheticBlock::build_header(
+            0,
+            parent_hash,
+            BlockState::deterministic_timestamp(0),
+            BlockRoots::default(),
+        );
         let expected = header.hash_slow().0;
         let actual = SyntheticBlock::compute_hash_for_number(0);
         assert_eq!(actual, expected, "Hash must be keccak256(rlp(header))");
@@ -312,6 +548,131 @@ mod tests {
         assert_eq!(block.timestamp, expected_ts);
     }
 
+    #[test]
+    fn test_recorded_timestamp_is_served_and_hashed() {
+        let state = BlockState::new();
+        let _ = state.get_block_by_number(5);
+        let legacy_5 = state.get_block_hash(5);
+        let legacy_7 = SyntheticBlock::compute_hash_for_number(7);
+
+        let accepted = state.record_timestamps(&[(6, 1_750_000_000), (7, 1_750_000_004)]);
+        assert_eq!(accepted, vec![(6, 1_750_000_000), (7, 1_750_000_004)]);
+
+        let block = state.get_block_by_number(7).unwrap();
+        assert_eq!(block.timestamp, 1_750_000_004);
+        assert_eq!(state.get_block_timestamp(6), 1_750_000_000);
+        assert_ne!(
+            block.hash, legacy_7,
+            "the real timestamp is part of the hash"
+        );
+        let header = SyntheticBlock::build_header(
+            7,
+            B256::from(block.parent_hash),
+            block.timestamp,
+            block.roots,
+        );
+        assert_eq!(block.hash, header.hash_slow().0);
+        // Blocks hashed before the recording are untouched.
+        assert_eq!(state.get_block_hash(5), legacy_5);
+        assert_eq!(block.parent_hash, state.get_block_hash(6));
+    }
+
+    #[test]
+    fn test_record_timestamps_never_rehashes_sealed_blocks() {
+        let state = BlockState::new();
+        let served = state.seal_block(10, BlockRoots::default());
+        assert_eq!(state.sealed_through(), 10);
+
+        // Already sealed: dropped, so the served hash stays stable.
+        assert!(state.record_timestamps(&[(10, 1_750_000_000)]).is_empty());
+        assert_eq!(state.get_block_hash(10), served);
+
+        // A provisional hash above the sealed tip is recomputed; first write wins.
+        let provisional = state.get_block_hash(11);
+        assert_eq!(
+            state.record_timestamps(&[(11, 1_750_000_000), (11, 1_760_000_000)]),
+            vec![(11, 1_750_000_000)]
+        );
+        assert_eq!(state.get_block_timestamp(11), 1_750_000_000);
+        assert_ne!(state.get_block_hash(11), provisional);
+        assert_eq!(state.get_block_hash(10), served);
+    }
+
+    fn log(tx: &str, log_index: u64, topic0: &str) -> SyntheticLog {
+        SyntheticLog {
+            address: crate::log_synthesis::L2_GLOBAL_EXIT_ROOT_ADDRESS.to_string(),
+            topics: vec![topic0.to_string(), format!("0x{}", "11".repeat(32))],
+            data: "0x0102".to_string(),
+            block_number: 5,
+            block_hash: [0u8; 32],
+            transaction_hash: tx.to_string(),
+            transaction_index: 0,
+            log_index,
+            removed: false,
+        }
+    }
+
+    #[test]
+    fn test_block_roots_from_logs() {
+        assert_eq!(compute_block_roots(&[]).unwrap(), BlockRoots::default());
+
+        let tx_a = format!("0x{}", "aa".repeat(32));
+        let tx_b = format!("0x{}", "bb".repeat(32));
+        let logs = vec![
+            log(&tx_b, 9, crate::log_synthesis::CLAIM_EVENT_TOPIC),
+            log(
+                &tx_a,
+                7,
+                crate::log_synthesis::UPDATE_HASH_CHAIN_VALUE_TOPIC,
+            ),
+            log(&tx_a, 8, crate::log_synthesis::CLAIM_EVENT_TOPIC),
+        ];
+        let roots = compute_block_roots(&logs).unwrap();
+        let address = Address::from_str(crate::log_synthesis::L2_GLOBAL_EXIT_ROOT_ADDRESS).unwrap();
+        let topic = B256::from_str(crate::log_synthesis::CLAIM_EVENT_TOPIC).unwrap();
+        assert!(roots.logs_bloom.contains_raw_log(address, &[topic]));
+        assert!(!roots.logs_bloom.contains_raw_log(
+            address,
+            &[B256::from_str(crate::log_synthesis::BRIDGE_EVENT_TOPIC).unwrap()]
+        ));
+
+        // Receipts are ordered by first log index (tx_a, then tx_b), whatever
+        // order the store returned the logs in.
+        let expected_root = alloy::consensus::proofs::calculate_receipt_root(&[
+            ReceiptEnvelope::Eip1559(
+                synthetic_receipt(&[logs[1].clone(), logs[2].clone()]).unwrap(),
+            ),
+            ReceiptEnvelope::Eip1559(synthetic_receipt(&[logs[0].clone()]).unwrap()),
+        ]);
+        assert_eq!(roots.receipts_root, expected_root.0);
+        assert_ne!(roots.receipts_root, EMPTY_ROOT_HASH);
+    }
+
+    #[test]
+    fn test_seal_block_fixes_header() {
+        let state = BlockState::new();
+        let provisional = state.get_block_hash(6);
+        let tx = format!("0x{}", "aa".repeat(32));
+        let roots =
+            compute_block_roots(&[log(&tx, 0, crate::log_synthesis::CLAIM_EVENT_TOPIC)]).unwrap();
+
+        let sealed = state.seal_block(5, roots);
+        let block = state.get_block_by_number(5).unwrap();
+        assert_eq!(block.hash, sealed);
+        assert_eq!(block.roots, roots);
+        assert_eq!(
+            state.get_block_hash(4),
+            SyntheticBlock::compute_hash_for_number(4)
+        );
+        // Descendants are rebuilt on the sealed parent.
+        assert_ne!(state.get_block_hash(6), provisional);
+        assert_eq!(state.get_block_by_number(6).unwrap().parent_hash, sealed);
+        assert_eq!(state.get_block_by_hash(&sealed).unwrap().number, 5);
+
+        // Re-sealing (crash replay) keeps the exposed header.
+        assert_eq!(state.seal_block(5, BlockRoots::default()), sealed);
+    }
+
     #[test]
     fn test_get_block_hash_without_cache() {
         let state = BlockState::new();
@@ -352,7 +713,12 @@ mod tests {
         // Verify that the hash is keccak256(rlp(header)) with the correct parent_hash
         for n in 0..10u64 {
             let block = blocks.get(&n).unwrap();
-            let header = SyntheticBlock::build_header(n, B256::from(block.parent_hash));
+            let header = SyntheticBlock::build_header(
+                n,
+                B256::from(block.parent_hash),
+                block.timestamp,
+                block.roots,
+            );
             let expected_hash = header.hash_slow().0;
             assert_eq!(
                 block.hash, expected_hash,
@@ -366,6 +732,11 @@ mod tests {
     #[test]
     fn test_json_hash_matches_computed_hash() {
         let state = BlockState::new();
+        let tx = format!("0x{}", "aa".repeat(32));
+        state.seal_block(
+            5,
+            compute_block_roots(&[log(&tx, 0, crate::log_synthesis::CLAIM_EVENT_TOPIC)]).unwrap(),
+        );
         let block = state.get_block_by_number(5).unwrap();
         let json = block.to_json(false);
 
@@ -376,7 +747,20 @@ mod tests {
         let mut parent_hash = [0u8; 32];
         parent_hash.copy_from_slice(&parent_hash_bytes);
 
-        let header = SyntheticBlock::build_header(5, B256::from(parent_hash));
+        let timestamp = u64::from_str_radix(
+            json["timestamp"].as_str().unwrap().trim_start_matches("0x"),
+            16,
+        )
+        .unwrap();
+
+        let roots = BlockRoots {
+            logs_bloom: Bloom::from_str(json["logsBloom"].as_str().unwrap()).unwrap(),
+            receipts_root: B256::from_str(json["receiptsRoot"].as_str().unwrap())
+                .unwrap()
+                .0,
+        };
+
+        let header = SyntheticBlock::build_header(5, B256::from(parent_hash), timestamp, roots);
         let recomputed = header.hash_slow().0;
         assert_eq!(
             block.hash, recomputed,
@@ -0,0 +1,284 @@
+//! Real Miden block timestamps for synthetic blocks.
+//!
+//! Synthetic block N mirrors Miden block N, but its header timestamp used to be
+//! fabricated as `GENESIS_TIMESTAMP + N * BLOCK_TIME`. [`BlockTimestampRecorder`]
+//! fetches each new Miden block header from the node, persists its timestamp
+//! (`Store::put_block_timestamps`, migration 022) and hands it to
+//! [`BlockState`], so `eth_getBlockByNumber`, receipts (via the block hash) and
+//! the projector's GER entries (`zkevm_getExitRootsByGER`) carry the real time.
+//!
+//! # Hash stability
+//!
+//! The timestamp is part of the header RLP hash, and the hash chain is served
+//! to reorg-checking clients. A timestamp must therefore be known BEFORE
+//! anything hashes the block. The recorder is registered as the FIRST sync
+//! listener: each sync tick it records every block up to the new Miden tip
+//! before `StoreSyncListener` commits receipts at that tip, `BlockState` caches
+//! it, or the projector writes logs into it. `BlockState::record_timestamps`
+//! enforces the rest — a block that was already sealed (see
+//! `BlockState::seal_block`) keeps the timestamp it was sealed with.
+//!
+//! # Migration path
+//!
+//! Nothing is backfilled. On a deployment with no recorded rows the first sync
+//! only sets the starting point, so every block synced before the upgrade keeps
+//! its legacy timestamp and hash. A restart resumes after the highest persisted
+//! row; a gap larger than [`MAX_CATCH_UP_BLOCKS`] (long downtime) leaves its
+//! oldest blocks on the legacy timestamp rather than stalling the sync tick.
+
+use crate::block_state::BlockState;
+use crate::miden_client::{MidenClientLib, SyncListener};
+use crate::store::Store;
+use miden_client::rpc::NodeRpcClient;
+use miden_client::sync::SyncSummary;
+use miden_protocol::block::BlockNumber;
+use std::sync::Arc;
+
+/// Most block headers fetched in one sync tick. Blocks further behind the tip
+/// keep the legacy timestamp.
+pub const MAX_CATCH_UP_BLOCKS: u64 = 1024;
+
+/// Source of Miden block timestamps, split out so tests can drive the recorder
+/// without a node.
+#[async_trait::async_trait]
+trait TimestampSource: Send + Sync {
+    async fn block_timestamp(&self, block: u64) -> anyhow::Result<u64>;
+}
+
+struct NodeTimestampSource(Arc<dyn NodeRpcClient>);
+
+#[async_trait::async_trait]
+impl TimestampSource for NodeTimestampSource {
+    async fn block_timestamp(&self, block: u64) -> anyhow::Result<u64> {
+        let (header, _) = self
+            .0
+            .get_block_header_by_number(Some(BlockNumber::from(block as u32)), false)
+            .await
+            .map_err(|e| anyhow::anyhow!("get_block_header_by_number({block}): {e}"))?;
+        Ok(u64::from(header.timestamp()))
+    }
+}
+
+/// Sync listener that records each new Miden block's timestamp before the
+/// synthetic block is hashed. See the module docs.
+pub struct BlockTimestampRecorder {
+    store: Arc<dyn Store>,
+    block_state: Arc<BlockState>,
+    source: Box<dyn TimestampSource>,
+    /// Highest Miden block already handled (recorded, or deliberately left on
+    /// the legacy timestamp). `None` until the first sync on a deployment
+    /// with no recorded rows.
+    cursor: std::sync::Mutex<Option<u64>>,
+    /// Tip from the latest `on_sync`, consumed by `on_post_sync`.
+    pending: std::sync::Mutex<Option<u64>>,
+}
+
+impl BlockTimestampRecorder {
+    /// Resume after the highest persisted timestamp. `BlockState::load` must
+    /// have run first so the persisted rows are already in `block_state`.
+    pub async fn new(
+        store: Arc<dyn Store>,
+        block_state: Arc<BlockState>,
+        node_url: String,
+        node_api_key: Option<String>,
+    ) -> anyhow::Result<Self> {
+        let endpoint = crate::miden_client::parse_node_url(&node_url)?;
+        let node_rpc =
+            crate::miden_client::build_rpc_client(&endpoint, 10_000, node_api_key.as_deref());
+        Self::with_source(store, block_state, Box::new(NodeTimestampSource(node_rpc))).await
+    }
+
+    async fn with_source(
+        store: Arc<dyn Store>,
+        block_state: Arc<BlockState>,
+        source: Box<dyn TimestampSource>,
+    ) -> anyhow::Result<Self> {
+        let cursor = store
+            .get_block_timestamps()
+            .await?
+            .last()
+            .map(|&(block, _)| block);
+        tracing::info!(resume_after = ?cursor, "block timestamp recorder resuming");
+        Ok(Self {
+            store,
+            block_state,
+            source,
+            cursor: std::sync::Mutex::new(cursor),
+            pending: std::sync::Mutex::new(None),
+        })
+    }
+
+    /// Record timestamps for every unhandled block up to `tip`. The batch is
+    /// persisted before `block_state` sees it and the cursor advances, so a
+    /// failed fetch or write leaves nothing recorded and is retried on the
+    /// next tick.
+    async fn record_through(&self, tip: u64) -> anyhow::Result<()> {
+        let cursor = *self.cursor.lock().unwrap_or_else(|e| e.into_inner());
+        let Some(cursor) = cursor else {
+            // First sync without any recorded rows: everything up to here
+            // keeps the legacy timestamp it may already have been served with.
+            tracing::info!(
+                tip,
+                "block timestamps: no recorded rows, recording starts after block {tip}"
+            );
+            *self.cursor.lock().unwrap_or_else(|e| e.into_inner()) = Some(tip);
+            return Ok(());
+        };
+        if tip <= cursor {
+            return Ok(());
+        }
+        let from = (cursor + 1).max(tip.saturating_sub(MAX_CATCH_UP_BLOCKS - 1));
+        if from > cursor + 1 {
+            let skipped = from - cursor - 1;
+            ::metrics::counter!("block_timestamps_skipped_total").increment(skipped);
+            tracing::warn!(
+                skipped,
+                from = cursor + 1,
+                to = from - 1,
+                "block timestamps: catch-up gap too large, older blocks keep the legacy timestamp"
+            );
+        }
+        // Never ask for a block that is already sealed; it would be dropped.
+        let from = from.max(self.block_state.sealed_through() + 1);
+
+        let mut entries = Vec::new();
+        for block in from..=tip {
+            entries.push((block, self.source.block_timestamp(block).await?));
+        }
+        // Persist before `block_state` takes the batch, the way the projector
+        // stores a block's roots before sealing it. The seal lock keeps a
+        // block from being sealed with its legacy timestamp in between, which
+        // would give it a different hash after a restart.
+        let _seal = self.block_state.lock_seal().await;
+        let sealed_through = self.block_state.sealed_through();
+        entries.retain(|&(block, _)| block > sealed_through);
+        self.store.put_block_timestamps(&entries).await?;
+        let accepted = self.block_state.record_timestamps(&entries);
+        ::metrics::counter!("block_timestamps_recorded_total").increment(accepted.len() as u64);
+        *self.cursor.lock().unwrap_or_else(|e| e.into_inner()) = Some(tip);
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl SyncListener for BlockTimestampRecorder {
+    fn on_sync(&self, summary: &SyncSummary) {
+        *self.pending.lock().unwrap_or_else(|e| e.into_inner()) = Some(summary.block_num.as_u64());
+    }
+
+    async fn on_post_sync(&self, _client: &mut MidenClientLib) -> anyhow::Result<()> {
+        let tip = self
+            .pending
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .take();
+        if let Some(tip) = tip {
+            // An error aborts the rest of this sync tick, so no later listener
+            // hashes the tip with a placeholder timestamp; the tick retries.
+            self.record_through(tip).await?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::store::memory::InMemoryStore;
+
+    /// Miden block N was produced at `1_750_000_000 + 3N`.
+    struct FakeSource;
+
+    #[async_trait::async_trait]
+    impl TimestampSource for FakeSource {
+        async fn block_timestamp(&self, block: u64) -> anyhow::Result<u64> {
+            Ok(1_750_000_000 + 3 * block)
+        }
+    }
+
+    async fn recorder(store: Arc<dyn Store>) -> (BlockTimestampRecorder, Arc<BlockState>) {
+        let block_state = Arc::new(BlockState::new());
+        block_state.load(&*store).await.unwrap();
+        let recorder =
+            BlockTimestampRecorder::with_source(store, block_state.clone(), Box::new(FakeSource))
+                .await
+                .unwrap();
+        (recorder, block_state)
+    }
+
+    #[tokio::test]
+    async fn first_sync_keeps_history_on_legacy_timestamps() {
+        let store: Arc<dyn Store> = Arc::new(InMemoryStore::new());
+        let (recorder, block_state) = recorder(store.clone()).await;
+        let legacy = block_state.get_block_timestamp(10);
+
+        recorder.record_through(10).await.unwrap();
+        assert!(store.get_block_timestamps().await.unwrap().is_empty());
+        assert_eq!(block_state.get_block_timestamp(10), legacy);
+
+        recorder.record_through(12).await.unwrap();
+        assert_eq!(
+            store.get_block_timestamps().await.unwrap(),
+            vec![(11, 1_750_000_033), (12, 1_750_000_036)]
+        );
+        assert_eq!(
+            block_state.get_block_by_number(12).unwrap().timestamp,
+            1_750_000_036
+        );
+    }
+
+    #[tokio::test]
+    async fn restart_reproduces_served_hashes() {
+        let store: Arc<dyn Store> = Arc::new(InMemoryStore::new());
+        let (recorder, block_state) = recorder(store.clone()).await;
+        recorder.record_through(3).await.unwrap();
+        recorder.record_through(8).await.unwrap();
+        let served = block_state.get_block_hash(8);
+
+        let (restarted, block_state) = recorder(store.clone()).await;
+        assert_eq!(block_state.get_block_hash(8), served);
+        // Resumes after the highest persisted block.
+        restarted.record_through(9).await.unwrap();
+        assert_eq!(store.get_block_timestamps().await.unwrap().len(), 6);
+    }
+
+    /// A failed store write leaves the batch unrecorded in memory too, so the
+    /// retry persists it and a restart serves the same hashes.
+    #[tokio::test]
+    async fn failed_write_is_retried_and_persisted() {
+        let memory = Arc::new(InMemoryStore::new());
+        let store: Arc<dyn Store> = memory.clone();
+        let (recorder, block_state) = recorder(store.clone()).await;
+        recorder.record_through(3).await.unwrap();
+
+        memory.test_fail_next_block_timestamps_write();
+        assert!(recorder.record_through(5).await.is_err());
+        assert_ne!(block_state.get_block_timestamp(5), 1_750_000_015);
+
+        recorder.record_through(5).await.unwrap();
+        assert_eq!(
+            store.get_block_timestamps().await.unwrap(),
+            vec![(4, 1_750_000_012), (5, 1_750_000_015)]
+        );
+        let served = block_state.get_block_hash(5);
+        let (_, restarted) = recorder(store).await;
+        assert_eq!(restarted.get_block_hash(5), served);
+    }
+
+    #[tokio::test]
+    async fn never_records_an_already_sealed_block() {
+        let store: Arc<dyn Store> = Arc::new(InMemoryStore::new());
+        let (recorder, block_state) = recorder(store.clone()).await;
+        recorder.record_through(5).await.unwrap();
+        // The projector sealed ahead of the recorder (e.g. a block the node
+        // reported early).
+        let served = block_state.seal_block(7, Default::default());
+
+        recorder.record_through(9).await.unwrap();
+        assert_eq!(block_state.get_block_hash(7), served);
+        assert_eq!(
+            store.get_block_timestamps().await.unwrap(),
+            vec![(8, 1_750_000_024), (9, 1_750_000_027)]
+        );
+    }
+}
@@ -467,7 +467,7 @@ async fn provision_faucet(
             &bridge_account,
             existing_id,
             &conversion,
-            None,
+            &[],
         )
         .await
         {
@@ -815,6 +815,22 @@ pub struct PublishClaimTxn {
     pub note_commitment: String,
 }
 
+/// One claim of a Miden transaction: the decoded call and the fence that
+/// owns its `global_index`.
+pub(crate) struct ClaimToPublish {
+    pub(crate) params: claimAssetCall,
+    pub(crate) submission_fence: crate::service_send_raw_txn::ClaimSubmissionFence,
+}
+
+/// A claim of a batch together with the eth transaction it answers — see
+/// [`publish_claim_batch`].
+pub(crate) struct BatchedClaim {
+    pub(crate) claim: ClaimToPublish,
+    pub(crate) txn_hash: alloy::primitives::TxHash,
+    pub(crate) txn_envelope: alloy::consensus::TxEnvelope,
+    pub(crate) signer: alloy::primitives::Address,
+}
+
 #[allow(clippy::too_many_arguments)]
 async fn publish_claim_internal(
     params: claimAssetCall,
@@ -834,48 +850,181 @@ async fn publish_claim_internal(
     // the two prove attempts are split across the outcome label.
     local_prover_fallback: Option<Arc<dyn TransactionProver + Send + Sync>>,
 ) -> anyhow::Result<PublishClaimTxn> {
-    let faucet = find_or_create_faucet(
-        params.originTokenAddress,
-        params.originNetwork,
-        &params.metadata,
-        store,
+    let mut results = publish_claims_internal(
+        vec![ClaimToPublish {
+            params,
+            submission_fence,
+        }],
         client,
         accounts,
-    )
-    .await?;
-
-    tracing::info!(
-        global_index = %params.globalIndex,
-        origin_network = %params.originNetwork,
-        dest_address = %params.destinationAddress,
-        amount = %params.amount,
-        faucet_id = %crate::accounts_config::AccountIdBech32(faucet.id),
-        mainnet_exit_root = %alloy::hex::encode(params.mainnetExitRoot.0),
-        rollup_exit_root = %alloy::hex::encode(params.rollupExitRoot.0),
-        "creating CLAIM note"
-    );
-
-    let claim_note = create_claim(
-        params.clone(),
-        faucet,
-        accounts,
         store,
-        client.rng(),
+        latest_block_num,
         reject_zero_padding,
+        expected_mints,
+        local_prover_fallback,
     )
     .await?;
-    let claim_note_id = claim_note.id().to_string();
-    // The note's details-commitment, encoded identically to how the projector
-    // keys consumed notes (`InputNoteRecord::details_commitment()`). This ties
-    // the real claim eth-tx to the on-chain CLAIM note so the SyntheticProjector
-    // can emit the ClaimEvent under the REAL tx hash (which carries the
-    // `claimAsset` calldata aggkit decodes for the claim's GER boundary) instead
-    // of a derived hash whose synthetic tx has empty calldata.
-    let note_commitment = hex::encode(
-        miden_protocol::note::NoteDetails::from(&claim_note)
-            .commitment()
-            .as_bytes(),
-    );
+    results
+        .pop()
+        .unwrap_or_else(|| Err(anyhow::anyhow!("claim publish returned no result")))
+}
+
+/// A claim that became one of the CLAIM notes of a batch transaction.
+pub(crate) struct IncludedClaim {
+    /// Position of the claim in the batch.
+    pub(crate) index: usize,
+    pub(crate) global_index: alloy::primitives::U256,
+    pub(crate) submission_fence: crate::service_send_raw_txn::ClaimSubmissionFence,
+    pub(crate) note_id: String,
+    pub(crate) note_id_bytes: [u8; 32],
+    pub(crate) note_commitment: String,
+}
+
+/// The CLAIM notes built for a batch, next to the results of the claims
+/// that could not become one.
+pub(crate) struct ClaimNotes {
+    /// One slot per input claim; filled for a claim that failed alone.
+    pub(crate) results: Vec<Option<anyhow::Result<PublishClaimTxn>>>,
+    pub(crate) included: Vec<IncludedClaim>,
+    pub(crate) notes: Vec<Note>,
+}
+
+/// Build a CLAIM note for each entry of `claims`. A claim that cannot
+/// become a note (faucet lookup, destination resolution, amount scaling)
+/// gets its error in `results` and is left out of `included`.
+pub(crate) async fn build_claim_notes(
+    claims: Vec<ClaimToPublish>,
+    client: &mut MidenClientLib,
+    accounts: &AccountsConfig,
+    store: &dyn Store,
+    reject_zero_padding: bool,
+) -> ClaimNotes {
+    let mut results: Vec<Option<anyhow::Result<PublishClaimTxn>>> =
+        claims.iter().map(|_| None).collect();
+    let mut included = Vec::with_capacity(claims.len());
+    let mut notes = Vec::with_capacity(claims.len());
+    for (index, claim) in claims.into_iter().enumerate() {
+        let ClaimToPublish {
+            params,
+            submission_fence,
+        } = claim;
+        let global_index = params.globalIndex;
+        let note = async {
+            let faucet = find_or_create_faucet(
+                params.originTokenAddress,
+                params.originNetwork,
+                &params.metadata,
+                store,
+                client,
+                accounts,
+            )
+            .await?;
+
+            tracing::info!(
+                global_index = %params.globalIndex,
+                origin_network = %params.originNetwork,
+                dest_address = %params.destinationAddress,
+                amount = %params.amount,
+                faucet_id = %crate::accounts_config::AccountIdBech32(faucet.id),
+                mainnet_exit_root = %alloy::hex::encode(params.mainnetExitRoot.0),
+                rollup_exit_root = %alloy::hex::encode(params.rollupExitRoot.0),
+                "creating CLAIM note"
+            );
+
+            create_claim(
+                params,
+                faucet,
+                accounts,
+                store,
+                client.rng(),
+                reject_zero_padding,
+            )
+            .await
+        }
+        .await;
+        let claim_note = match note {
+            Ok(note) => note,
+            Err(err) => {
+                results[index] = Some(Err(err));
+                continue;
+            }
+        };
+        // The note's details-commitment, encoded identically to how the projector
+        // keys consumed notes (`InputNoteRecord::details_commitment()`). This ties
+        // the real claim eth-tx to the on-chain CLAIM note so the SyntheticProjector
+        // can emit the ClaimEvent under the REAL tx hash (which carries the
+        // `claimAsset` calldata aggkit decodes for the claim's GER boundary) instead
+        // of a derived hash whose synthetic tx has empty calldata.
+        let note_commitment = hex::encode(
+            miden_protocol::note::NoteDetails::from(&claim_note)
+                .commitment()
+                .as_bytes(),
+        );
+        included.push(IncludedClaim {
+            index,
+            global_index,
+            submission_fence,
+            note_id: claim_note.id().to_string(),
+            note_id_bytes: claim_note.id().as_bytes(),
+            note_commitment,
+        });
+        notes.push(claim_note);
+    }
+    ClaimNotes {
+        results,
+        included,
+        notes,
+    }
+}
+
+/// Atomically seal each claim's fence and persist its exact note identity
+/// before the first external side effect. They remain PREPARED until commit
+/// or exact-note observation proves inclusion. A fence lost part-way aborts
+/// the whole transaction: the notes are already proven together, and
+/// submitting would double-publish the lost index. Handoffs prepared before
+/// the loss are never submitted and expire through recovery.
+pub(crate) async fn prepare_claim_handoffs(
+    included: &[IncludedClaim],
+    expiration_block: u64,
+) -> anyhow::Result<()> {
+    for claim in included {
+        claim
+            .submission_fence
+            .prepare(&claim.note_commitment, &claim.note_id, expiration_block)
+            .await?;
+    }
+    Ok(())
+}
+
+/// Build, prove and submit ONE Miden transaction carrying a CLAIM note per
+/// entry of `claims`, and wait for it to commit.
+///
+/// The outer `Err` is a transaction-level failure; the inner results are per
+/// claim, in input order. A claim that cannot become a note (faucet lookup,
+/// destination resolution, amount scaling) fails alone and is left out of
+/// the transaction; if none is left no transaction is built. Every included
+/// claim's fence is prepared before submission, so once the transaction may
+/// have reached the node each claim has its own durable note handoff and a
+/// later error leaves all of them to commit/observation reconciliation.
+#[allow(clippy::too_many_arguments)]
+async fn publish_claims_internal(
+    claims: Vec<ClaimToPublish>,
+    client: &mut MidenClientLib,
+    accounts: &AccountsConfig,
+    store: &dyn Store,
+    latest_block_num: BlockNumber,
+    reject_zero_padding: bool,
+    expected_mints: Option<&Arc<crate::expected_mint_tracker::ExpectedMintTracker>>,
+    local_prover_fallback: Option<Arc<dyn TransactionProver + Send + Sync>>,
+) -> anyhow::Result<Vec<anyhow::Result<PublishClaimTxn>>> {
+    let ClaimNotes {
+        results,
+        included,
+        notes: claim_notes,
+    } = build_claim_notes(claims, client, accounts, store, reject_zero_padding).await;
+    if included.is_empty() {
+        return Ok(results.into_iter().flatten().collect());
+    }
 
     let expires_at = latest_block_num + claim_receipt_expiration_blocks();
 
@@ -893,7 +1042,7 @@ async fn publish_claim_internal(
     // after publication. Polling here would stall the serialized
     // `MidenClient::with` slot for every queued write.
     let txn_request = TransactionRequestBuilder::new()
-        .own_output_notes(vec![claim_note])
+        .own_output_notes(claim_notes)
         // Bound the creating tx's inclusion window (see
         // `submission_note_expiration_delta`) so a prepared-but-unconfirmed claim
         // handoff can be declared dead and re-driven by recovery rather than
@@ -943,7 +1092,10 @@ async fn publish_claim_internal(
     // Proof-boundary marker: the Miden proof is the slow, crash-prone window. Logged
     // at INFO for ops visibility and as the deterministic "kill during proving"
     // trigger in the recovery-scenario e2e.
-    tracing::info!("proving CLAIM note (Miden proof in progress)");
+    tracing::info!(
+        claims = included.len(),
+        "proving CLAIM note (Miden proof in progress)"
+    );
     let primary_start = std::time::Instant::now();
     let primary_res = client.prove_transaction(&tx_result).await;
     let primary_elapsed = primary_start.elapsed().as_secs_f64();
@@ -990,19 +1142,19 @@ async fn publish_claim_internal(
     // prove step for the remote→local prover fallback above), so it must
     // call the chokepoint check itself before touching the node.
     crate::miden_client::ensure_writable(accounts.service.0)?;
-    // Atomically seal the current claim fence and persist the exact note
-    // identity before the first external side effect. It remains PREPARED
-    // until commit or exact-note observation proves inclusion.
-    submission_fence
-        .prepare(&note_commitment, &claim_note_id, expiration_block)
-        .await?;
+    prepare_claim_handoffs(&included, expiration_block).await?;
     let _submission_height = client
         .submit_proven_transaction(proven_tx, &tx_result)
         .await?;
     client
         .apply_transaction(&tx_result, _submission_height)
         .await?;
-    tracing::info!("submitted claim note txn: {txn_id}, claim_note_id: {claim_note_id}");
+    for claim in &included {
+        tracing::info!(
+            "submitted claim note txn: {txn_id}, claim_note_id: {}",
+            claim.note_id
+        );
+    }
 
     // Cantina #7: record the submitted CLAIM in the expected-MINT tracker
     // BEFORE awaiting commit. If wait_for_transaction_commit times out (20s)
@@ -1011,36 +1163,26 @@ async fn publish_claim_internal(
     // giving on-call a list of stuck CLAIMs by L1 leaf. On successful
     // commit (the next code block) we mark_landed to drop the entry.
     if let Some(tracker) = expected_mints {
-        let global_index_bytes: [u8; 32] = params.globalIndex.to_be_bytes();
-        let claim_id_bytes: [u8; 32] = tx_result
-            .executed_transaction()
-            .output_notes()
-            .iter()
-            .map(|n| match n {
-                miden_protocol::transaction::RawOutputNote::Full(full) => full.id().as_bytes(),
-                miden_protocol::transaction::RawOutputNote::Partial(partial) => {
-                    partial.id().as_bytes()
-                }
-            })
-            .next()
-            .unwrap_or_default();
-        if claim_id_bytes != [0u8; 32]
-            && let Err(e) = tracker
-                .record_expected(global_index_bytes, claim_id_bytes)
-                .await
-        {
-            // RD-913: tracker is now store-backed. A store hiccup here
-            // means we won't get a StaleAlert later if the MINT is
-            // censored — log it loudly, but don't fail the CLAIM
-            // submission itself (the claim has been submitted at this
-            // point; refusing to return would just mean the user can't
-            // get a receipt for a tx that already went on-chain).
-            tracing::warn!(
-                target: "claim",
-                global_index = ?global_index_bytes,
-                error = ?e,
-                "RD-913: expected-MINT record store failure; no staleness alert will fire"
-            );
+        for claim in &included {
+            let global_index_bytes: [u8; 32] = claim.global_index.to_be_bytes();
+            if claim.note_id_bytes != [0u8; 32]
+                && let Err(e) = tracker
+                    .record_expected(global_index_bytes, claim.note_id_bytes)
+                    .await
+            {
+                // RD-913: tracker is now store-backed. A store hiccup here
+                // means we won't get a StaleAlert later if the MINT is
+                // censored — log it loudly, but don't fail the CLAIM
+                // submission itself (the claim has been submitted at this
+                // point; refusing to return would just mean the user can't
+                // get a receipt for a tx that already went on-chain).
+                tracing::warn!(
+                    target: "claim",
+                    global_index = ?global_index_bytes,
+                    error = ?e,
+                    "RD-913: expected-MINT record store failure; no staleness alert will fire"
+                );
+            }
         }
     }
 
@@ -1051,9 +1193,32 @@ async fn publish_claim_internal(
         std::time::Duration::from_secs(1),
     )
     .await?;
-    if committed {
-        submission_fence.confirm(&note_commitment).await?;
-        tracing::info!("claim tx {txn_id} committed to block");
+    if !committed {
+        anyhow::bail!("claim tx {txn_id} was submitted but not committed within 20s");
+    }
+    tracing::info!(
+        claims = included.len(),
+        "claim tx {txn_id} committed to block"
+    );
+    Ok(settle_committed_claims(results, included, txn_id, expires_at, expected_mints).await)
+}
+
+/// Settle each claim of a committed batch transaction: confirm its note
+/// handoff and close its expected-MINT entry. Returns one result per input
+/// claim, in input order; `results` carries the claims that never became a
+/// note.
+pub(crate) async fn settle_committed_claims(
+    mut results: Vec<Option<anyhow::Result<PublishClaimTxn>>>,
+    included: Vec<IncludedClaim>,
+    txn_id: TransactionId,
+    expires_at: BlockNumber,
+    expected_mints: Option<&Arc<crate::expected_mint_tracker::ExpectedMintTracker>>,
+) -> Vec<anyhow::Result<PublishClaimTxn>> {
+    for claim in included {
+        if let Err(err) = claim.submission_fence.confirm(&claim.note_commitment).await {
+            results[claim.index] = Some(Err(err));
+            continue;
+        }
         // Cantina #7: mark Landed once `wait_for_transaction_commit`
         // confirms the CLAIM tx was committed. Aggkit's miden-client
         // operates on the proxy's service account — it CANNOT observe
@@ -1070,7 +1235,7 @@ async fn publish_claim_internal(
         // tick eventually escalates to StaleAlert with the global_index
         // for operator triage.
         if let Some(tracker) = expected_mints {
-            let global_index_bytes: [u8; 32] = params.globalIndex.to_be_bytes();
+            let global_index_bytes: [u8; 32] = claim.global_index.to_be_bytes();
             if let Err(e) = tracker.mark_landed(global_index_bytes).await {
                 tracing::warn!(
                     target: "claim",
@@ -1081,15 +1246,17 @@ async fn publish_claim_internal(
                 );
             }
         }
-    } else {
-        anyhow::bail!("claim tx {txn_id} was submitted but not committed within 20s");
+        results[claim.index] = Some(Ok(PublishClaimTxn {
+            txn_id,
+            expires_at,
+            note_commitment: claim.note_commitment,
+        }));
     }
 
-    Ok(PublishClaimTxn {
-        txn_id,
-        expires_at,
-        note_commitment,
-    })
+    results
+        .into_iter()
+        .map(|result| result.unwrap_or_else(|| Err(anyhow::anyhow!("claim left out of its batch"))))
+        .collect()
 }
 
 /// Publish a claim through the long-lived `MidenClient` event loop.
@@ -1291,6 +1458,112 @@ async fn attempt_publish_claim(
         .ok_or_else(|| anyhow::anyhow!("publish_claim: closure completed but result was not set"))
 }
 
+/// Publish several claims as ONE Miden transaction with a CLAIM note each,
+/// through the same serialised `MidenClient::with` slot as [`publish_claim`].
+/// Proving dominates a claim's cost, so coalescing queued claims divides it
+/// across the batch.
+///
+/// Per-claim results come back in input order; each successful claim has its
+/// own note handoff and PENDING receipt exactly as if published alone, and a
+/// claim that cannot become a note fails without affecting the rest. The
+/// outer `Err` reports a transaction-level failure. Unlike [`publish_claim`]
+/// there is no reimport-and-retry here: the caller re-drives every claim that
+/// has no durable note handoff through [`publish_claim`] individually, which
+/// both self-heals and isolates a claim that broke the shared transaction.
+pub(crate) async fn publish_claim_batch(
+    claims: Vec<BatchedClaim>,
+    client: &MidenClient,
+    accounts: crate::AccountsConfig,
+    store: Arc<dyn Store>,
+    latest_block_num: BlockNumber,
+    reject_zero_padding: bool,
+    expected_mints: Option<Arc<crate::expected_mint_tracker::ExpectedMintTracker>>,
+) -> anyhow::Result<Vec<anyhow::Result<PublishClaimTxn>>> {
+    let local_prover_fallback = client.local_prover_fallback();
+    let result = Arc::new(Mutex::new(None::<Vec<anyhow::Result<PublishClaimTxn>>>));
+    let result_inner = result.clone();
+    client
+        .with(move |client| {
+            Box::new(async move {
+                let mut receipts = Vec::with_capacity(claims.len());
+                let mut to_publish = Vec::with_capacity(claims.len());
+                for batched in claims {
+                    receipts.push((batched.txn_hash, batched.txn_envelope, batched.signer));
+                    to_publish.push(batched.claim);
+                }
+                let published = publish_claims_internal(
+                    to_publish,
+                    client,
+                    &accounts.0,
+                    &*store,
+                    latest_block_num,
+                    reject_zero_padding,
+                    expected_mints.as_ref(),
+                    local_prover_fallback,
+                )
+                .await?;
+                let values = record_batch_receipts(&*store, published, receipts).await;
+                *result_inner.lock().unwrap_or_else(|e| e.into_inner()) = Some(values);
+                Ok(())
+            })
+        })
+        .await?;
+    let values = result.lock().unwrap_or_else(|e| e.into_inner()).take();
+    values.ok_or_else(|| {
+        anyhow::anyhow!("publish_claim_batch: closure completed but result was not set")
+    })
+}
+
+/// Record the PENDING receipt of each published claim of a batch, pairing
+/// `published` with the `(hash, envelope, signer)` of the eth transaction it
+/// answers. Same receipt `attempt_publish_claim` records; the projector
+/// finalises each claim on its own note's consumption.
+pub(crate) async fn record_batch_receipts(
+    store: &dyn Store,
+    published: Vec<anyhow::Result<PublishClaimTxn>>,
+    receipts: Vec<(
+        alloy::primitives::TxHash,
+        alloy::consensus::TxEnvelope,
+        alloy::primitives::Address,
+    )>,
+) -> Vec<anyhow::Result<PublishClaimTxn>> {
+    let mut values = Vec::with_capacity(published.len());
+    for (value, (txn_hash, txn_envelope, signer)) in published.into_iter().zip(receipts) {
+        let value = match value {
+            Ok(value) => value,
+            Err(err) => {
+                values.push(Err(err));
+                continue;
+            }
+        };
+        let begun = store
+            .txn_begin_if_absent(
+                txn_hash,
+                crate::store::TxnEntry {
+                    id: None,
+                    envelope: txn_envelope,
+                    signer,
+                    expires_at: Some(value.expires_at),
+                    logs: vec![],
+                },
+            )
+            .await;
+        match begun {
+            Ok(_) => {
+                tracing::info!(
+                    eth_tx = %txn_hash,
+                    miden_tx = %value.txn_id,
+                    "batched claim tx recorded pending; durable note handoff lets \
+                     projector finalise receipt + ClaimEvent on consumption"
+                );
+                values.push(Ok(value));
+            }
+            Err(err) => values.push(Err(err)),
+        }
+    }
+    values
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,772 @@
+//! `--config <file.toml>`: every service setting in one structured file.
+//!
+//! Each key in [`SETTINGS`] targets either a command-line argument or one of
+//! the env-only tuning knobs (`AGGLAYER_WRITER_QUEUE_DEPTH`, …). Precedence is
+//! flag > env > file > built-in default:
+//!
+//! - an argument key becomes that argument's default, so clap still prefers a
+//!   flag or its env var over it;
+//! - an env-only key is exported into the process environment when the
+//!   variable is unset, before the runtime (and any other thread) starts.
+//!
+//! A boolean flag only ever sets `true`, so a `true` from the file cannot be
+//! turned off on the command line; set its env var to `false` instead (e.g.
+//! `REQUIRE_HARDENING=false`).
+//!
+//! Unknown sections or keys, wrongly typed values and values the argument's
+//! own parser rejects all fail the load with the dotted key in the message.
+//! One-shot modes (`--init`, `--restore`, `--reset-miden-store`,
+//! `--unlock-miden-accounts`, `--resweep-from-genesis`) stay command-line only.
+//!
+//! ```toml
+//! [server]
+//! port = 8546
+//! chain_id = 2
+//!
+//! [writer]
+//! queue_depth = 64
+//!
+//! [security]
+//! allowed_signers = ["0x…"]
+//! require_hardening = true
+//! ```
+
+use crate::writer_worker::{
+    CLAIM_BATCH_MAX_ENV, CLAIM_LANE_WEIGHT_ENV, CLAIM_QUEUE_DEPTH_ENV, DRAIN_TIMEOUT_ENV,
+    GER_LANE_WEIGHT_ENV, GER_QUEUE_DEPTH_ENV, QUEUE_DEPTH_ENV, TX_TTL_ENV, WriterLanes,
+    WriterWorker,
+};
+use alloy::primitives::Address;
+use clap::parser::ValueSource;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// The file's sections, in the order `--print-effective-config` renders them.
+pub const SECTIONS: &[&str] = &[
+    "server", "database", "miden", "l1", "writer", "monitors", "security",
+];
+
+/// What a key holds; checked before the value reaches its target.
+#[derive(Debug, Clone, Copy)]
+pub enum Kind {
+    Int { min: u64, max: u64 },
+    Bool,
+    Str,
+    Path,
+    List,
+    AddressList,
+}
+
+const U16: Kind = Kind::Int {
+    min: 0,
+    max: u16::MAX as u64,
+};
+const U32: Kind = Kind::Int {
+    min: 0,
+    max: u32::MAX as u64,
+};
+const U64: Kind = Kind::Int {
+    min: 0,
+    max: u64::MAX,
+};
+const POSITIVE: Kind = Kind::Int {
+    min: 1,
+    max: u64::MAX,
+};
+
+/// Where a key's value goes.
+#[derive(Debug, Clone, Copy)]
+pub enum Target {
+    /// A `Command` argument, by clap id.
+    Arg(&'static str),
+    /// An env-only knob. `effective` is what the service reads for it now.
+    Env {
+        var: &'static str,
+        effective: fn() -> Option<String>,
+    },
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Setting {
+    /// Dotted `section.key`.
+    pub key: &'static str,
+    pub target: Target,
+    pub kind: Kind,
+    /// Redacted by `--print-effective-config`.
+    pub secret: bool,
+}
+
+const fn arg(key: &'static str, id: &'static str, kind: Kind) -> Setting {
+    Setting {
+        key,
+        target: Target::Arg(id),
+        kind,
+        secret: false,
+    }
+}
+
+const fn secret_arg(key: &'static str, id: &'static str, kind: Kind) -> Setting {
+    Setting {
+        key,
+        target: Target::Arg(id),
+        kind,
+        secret: true,
+    }
+}
+
+const fn env(
+    key: &'static str,
+    var: &'static str,
+    kind: Kind,
+    effective: fn() -> Option<String>,
+) -> Setting {
+    Setting {
+        key,
+        target: Target::Env { var, effective },
+        kind,
+        secret: false,
+    }
+}
+
+fn env_value(var: &str) -> Option<String> {
+    std::env::var(var).ok()
+}
+
+/// Every file key.
+pub const SETTINGS: &[Setting] = &[
+    // [server]
+    arg("server.port", "port", U16),
+    arg("server.bind", "bind", Kind::Str),
+    arg("server.chain_id", "chain_id", U64),
+    arg("server.network_id", "network_id", U64),
+    arg("server.bridge_address", "bridge_address", Kind::Str),
+    arg("server.rpc_max_batch_size", "rpc_max_batch_size", U64),
+    arg("server.safe_block_lag", "safe_block_lag", U64),
+    arg("server.finalized_block_lag", "finalized_block_lag", U64),
+    arg("server.read_only", "read_only", Kind::Bool),
+    env("server.log_filter", "RUST_LOG", Kind::Str, || {
+        env_value("RUST_LOG")
+    }),
+    // [database]
+    secret_arg("database.url", "database_url", Kind::Str),
+    arg("database.ca_cert", "database_ca_cert", Kind::Path),
+    arg("database.client_cert", "database_client_cert", Kind::Path),
+    arg("database.client_key", "database_client_key", Kind::Path),
+    // [miden]
+    arg("miden.node", "miden_node", Kind::Str),
+    arg("miden.store_dir", "miden_store_dir", Kind::Path),
+    env("miden.store_base", "MIDEN_STORE_BASE", Kind::Path, || {
+        env_value("MIDEN_STORE_BASE")
+    }),
+    secret_arg("miden.api_key", "miden_api_key", Kind::Str),
+    secret_arg("miden.prover_url", "miden_prover_url", Kind::Str),
+    arg(
+        "miden.prover_timeout_secs",
+        "miden_prover_timeout_secs",
+        U64,
+    ),
+    arg(
+        "miden.prover_fallback_to_local",
+        "miden_prover_fallback_to_local",
+        Kind::Bool,
+    ),
+    arg("miden.debug", "miden_debug", Kind::Bool),
+    env(
+        "miden.claim_receipt_expiration_blocks",
+        crate::claim::CLAIM_RECEIPT_EXPIRATION_BLOCKS_ENV,
+        POSITIVE,
+        || Some(crate::claim::claim_receipt_expiration_blocks().to_string()),
+    ),
+    env(
+        "miden.submission_note_expiration_delta_blocks",
+        crate::claim::SUBMISSION_NOTE_EXPIRATION_DELTA_ENV,
+        Kind::Int {
+            min: 1,
+            max: u16::MAX as u64,
+        },
+        || Some(crate::claim::submission_note_expiration_delta().to_string()),
+    ),
+    // [l1]
+    secret_arg("l1.rpc_url", "l1_rpc_url", Kind::Str),
+    secret_arg("l1.rpc_fallback_urls", "l1_rpc_fallback_urls", Kind::List),
+    arg("l1.rpc_quorum", "l1_rpc_quorum", POSITIVE),
+    secret_arg("l1.network_rpc_urls", "network_rpc_urls", Kind::List),
+    arg("l1.ger_address", "ger_l1_address", Kind::Str),
+    arg("l1.indexer_from_block", "l1_indexer_from_block", U64),
+    arg("l1.evidence_tag", "l1_evidence_tag", Kind::Str),
+    arg("l1.ger_relayer", "ger_relayer", Kind::Bool),
+    secret_arg(
+        "l1.ger_relayer_private_key",
+        "ger_relayer_private_key",
+        Kind::Str,
+    ),
+    arg(
+        "l1.ger_relayer_min_interval_secs",
+        "ger_relayer_min_interval_secs",
+        U64,
+    ),
+    env(
+        "l1.ger_evidence_wait_timeout_secs",
+        "GER_EVIDENCE_WAIT_TIMEOUT_SECS",
+        U64,
+        || {
+            Some(
+                crate::ger::ger_evidence_wait_timeout()
+                    .as_secs()
+                    .to_string(),
+            )
+        },
+    ),
+    // [writer]
+    env("writer.queue_depth", QUEUE_DEPTH_ENV, POSITIVE, || {
+        Some(WriterWorker::parse_queue_depth_env().to_string())
+    }),
+    env("writer.tx_ttl_secs", TX_TTL_ENV, POSITIVE, || {
+        Some(WriterWorker::parse_tx_ttl_env().as_secs().to_string())
+    }),
+    env("writer.drain_timeout_secs", DRAIN_TIMEOUT_ENV, U64, || {
+        Some(
+            WriterWorker::parse_drain_timeout_env()
+                .as_secs()
+                .to_string(),
+        )
+    }),
+    env(
+        "writer.ger_queue_depth",
+        GER_QUEUE_DEPTH_ENV,
+        POSITIVE,
+        || Some(WriterLanes::from_env().ger_insert.depth.to_string()),
+    ),
+    env(
+        "writer.claim_queue_depth",
+        CLAIM_QUEUE_DEPTH_ENV,
+        POSITIVE,
+        || Some(WriterLanes::from_env().claim.depth.to_string()),
+    ),
+    env("writer.ger_weight", GER_LANE_WEIGHT_ENV, POSITIVE, || {
+        Some(WriterLanes::from_env().ger_insert.weight.to_string())
+    }),
+    env(
+        "writer.claim_weight",
+        CLAIM_LANE_WEIGHT_ENV,
+        POSITIVE,
+        || Some(WriterLanes::from_env().claim.weight.to_string()),
+    ),
+    env(
+        "writer.claim_batch_max",
+        CLAIM_BATCH_MAX_ENV,
+        POSITIVE,
+        || Some(WriterLanes::from_env().claim_batch.to_string()),
+    ),
+    env(
+        "writer.claim_resubmit_ttl_secs",
+        "CLAIM_RESUBMIT_TTL_SECS",
+        U64,
+        || {
+            Some(
+                crate::service_send_raw_txn::claim_resubmit_ttl()
+                    .as_secs()
+                    .to_string(),
+            )
+        },
+    ),
+    env(
+        "writer.nonce_reservation_lease_secs",
+        "NONCE_RESERVATION_LEASE_SECS",
+        U64,
+        || {
+            Some(
+                crate::service_send_raw_txn::reservation_lease()
+                    .as_secs()
+                    .to_string(),
+            )
+        },
+    ),
+    arg("writer.signer_inflight_quota", "signer_inflight_quota", U64),
+    arg(
+        "writer.future_nonce_hold_per_signer",
+        "future_nonce_hold_per_signer",
+        U64,
+    ),
+    arg(
+        "writer.future_nonce_hold_total",
+        "future_nonce_hold_total",
+        U64,
+    ),
+    // [monitors]
+    arg(
+        "monitors.faucet_reconciler_poll_secs",
+        "faucet_reconciler_poll_secs",
+        U64,
+    ),
+    arg(
+        "monitors.faucet_reconciler_grace_ticks",
+        "faucet_reconciler_grace_ticks",
+        U32,
+    ),
+    env("monitors.reconcile_chunk", "RECONCILE_CHUNK", U64, || {
+        Some(
+            crate::synthetic_projector::reconcile_tuning()
+                .chunk
+                .to_string(),
+        )
+    }),
+    env(
+        "monitors.reconcile_concurrency",
+        "RECONCILE_CONCURRENCY",
+        U64,
+        || {
+            Some(
+                crate::synthetic_projector::reconcile_tuning()
+                    .concurrency
+                    .to_string(),
+            )
+        },
+    ),
+    env(
+        "monitors.reconcile_tick_budget_ms",
+        "RECONCILE_TICK_BUDGET_MS",
+        U64,
+        || {
+            Some(
+                crate::synthetic_projector::reconcile_tuning()
+                    .budget
+                    .as_millis()
+                    .to_string(),
+            )
+        },
+    ),
+    // [security]
+    secret_arg("security.admin_api_key", "admin_api_key", Kind::Str),
+    arg("security.admin_keys_file", "admin_keys_file", Kind::Path),
+    arg(
+        "security.allowed_signers",
+        "allowed_signers",
+        Kind::AddressList,
+    ),
+    arg(
+        "security.ger_remover_signers",
+        "ger_remover_signers",
+        Kind::AddressList,
+    ),
+    arg(
+        "security.insecure_allow_any_signer",
+        "insecure_allow_any_signer",
+        Kind::Bool,
+    ),
+    arg(
+        "security.cors_allowed_origins",
+        "cors_allowed_origins",
+        Kind::List,
+    ),
+    arg("security.policy_file", "policy_file", Kind::Path),
+    arg(
+        "security.rate_limit_per_second",
+        "rate_limit_per_second",
+        U64,
+    ),
+    arg("security.rate_limit_burst", "rate_limit_burst", U32),
+    arg(
+        "security.reject_unverified_ger_injection",
+        "reject_unverified_ger",
+        Kind::Bool,
+    ),
+    arg(
+        "security.reject_zero_padding_addresses",
+        "reject_zero_padding_addresses",
+        Kind::Bool,
+    ),
+    arg(
+        "security.require_hardening",
+        "require_hardening",
+        Kind::Bool,
+    ),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum FileValue {
+    One(String),
+    Many(Vec<String>),
+}
+
+/// A parsed and type-checked `--config` file.
+#[derive(Debug)]
+pub struct ConfigFile {
+    path: PathBuf,
+    values: BTreeMap<&'static str, FileValue>,
+    /// Env-only keys this file exported (their variable was unset).
+    exported: Vec<&'static str>,
+}
+
+impl ConfigFile {
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| anyhow::anyhow!("reading config file {}: {e}", path.display()))?;
+        Self::parse(path, &text)
+    }
+
+    fn parse(path: &Path, text: &str) -> anyhow::Result<Self> {
+        let fail = |msg: String| anyhow::anyhow!("config file {}: {msg}", path.display());
+        let table: toml::Table = toml::from_str(text).map_err(|e| fail(e.to_string()))?;
+        let mut values = BTreeMap::new();
+        for (section, entries) in &table {
+            if !SECTIONS.contains(&section.as_str()) {
+                return Err(fail(format!(
+                    "unknown section `[{section}]`; expected one of {}",
+                    SECTIONS.join(", ")
+                )));
+            }
+            let toml::Value::Table(entries) = entries else {
+                return Err(fail(format!("`{section}` must be a table (`[{section}]`)")));
+            };
+            for (name, value) in entries {
+                let key = format!("{section}.{name}");
+                let setting = SETTINGS
+                    .iter()
+                    .find(|s| s.key == key)
+                    .ok_or_else(|| fail(format!("unknown key `{key}`")))?;
+                let value =
+                    convert(setting.kind, value).map_err(|e| fail(format!("`{key}`: {e}")))?;
+                values.insert(setting.key, value);
+            }
+        }
+        Ok(Self {
+            path: path.to_path_buf(),
+            values,
+            exported: Vec::new(),
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Check each argument key with that argument's own parser, then make the
+    /// file value the argument's default.
+    pub fn apply(&self, mut command: clap::Command) -> anyhow::Result<clap::Command> {
+        for setting in SETTINGS {
+            let (Target::Arg(id), Some(value)) = (setting.target, self.values.get(setting.key))
+            else {
+                continue;
+            };
+            let arg = command
+                .get_arguments()
+                .find(|a| a.get_id() == id)
+                .ok_or_else(|| {
+                    anyhow::anyhow!("config key `{}` has no argument `{id}`", setting.key)
+                })?
+                .clone();
+            if !matches!(setting.kind, Kind::Bool) {
+                self.check_with_parser(setting.key, &arg, value)?;
+            }
+            command = command.mut_arg(id, |a| match value {
+                FileValue::One(v) => a.default_value(v.clone()),
+                FileValue::Many(vs) => a.default_values(vs.clone()),
+            });
+        }
+        Ok(command)
+    }
+
+    fn check_with_parser(
+        &self,
+        key: &str,
+        arg: &clap::Arg,
+        value: &FileValue,
+    ) -> anyhow::Result<()> {
+        let long = arg.get_long().unwrap_or(key);
+        let probe = arg.clone().env(None::<&'static str>).required(false);
+        let mut argv = vec!["config".to_string()];
+        let items = match value {
+            FileValue::One(v) => std::slice::from_ref(v),
+            FileValue::Many(vs) => vs.as_slice(),
+        };
+        for item in items {
+            argv.push(format!("--{long}={item}"));
+        }
+        clap::Command::new("config")
+            .arg(probe)
+            .try_get_matches_from(argv)
+            .map(|_| ())
+            .map_err(|e| {
+                let reason = e.to_string();
+                let reason = reason
+                    .lines()
+                    .next()
+                    .unwrap_or_default()
+                    .trim_start_matches("error: ");
+                anyhow::anyhow!("config file {}: `{key}`: {reason}", self.path.display())
+            })
+    }
+
+    /// Export the env-only keys whose variable is unset.
+    ///
+    /// # Safety
+    ///
+    /// Mutates the process environment: call before any other thread exists.
+    pub unsafe fn export_env(&mut self) {
+        for setting in SETTINGS {
+            let (Target::Env { var, .. }, Some(FileValue::One(value))) =
+                (setting.target, self.values.get(setting.key))
+            else {
+                continue;
+            };
+            if std::env::var_os(var).is_none() {
+                // SAFETY: the caller guarantees the process is single-threaded.
+                unsafe { std::env::set_var(var, value) };
+                self.exported.push(setting.key);
+            }
+        }
+    }
+}
+
+fn convert(kind: Kind, value: &toml::Value) -> Result<FileValue, String> {
+    let one = |s: String| Ok(FileValue::One(s));
+    match (kind, value) {
+        (Kind::Int { min, max }, toml::Value::Integer(n)) => match u64::try_from(*n) {
+            Ok(n) if (min..=max).contains(&n) => one(n.to_string()),
+            _ => Err(format!("{n} is out of range {min}..={max}")),
+        },
+        (Kind::Int { .. }, other) => Err(format!("expected an integer, got {}", other.type_str())),
+        (Kind::Bool, toml::Value::Boolean(b)) => one(b.to_string()),
+        (Kind::Bool, other) => Err(format!("expected true or false, got {}", other.type_str())),
+        (Kind::Str | Kind::Path, toml::Value::String(s)) => one(s.clone()),
+        (Kind::Str | Kind::Path, other) => {
+            Err(format!("expected a string, got {}", other.type_str()))
+        }
+        (Kind::List | Kind::AddressList, toml::Value::Array(items)) => {
+            let items = items
+                .iter()
+                .enumerate()
+                .map(|(i, item)| match item {
+                    toml::Value::String(s) => {
+                        if matches!(kind, Kind::AddressList) && s.parse::<Address>().is_err() {
+                            return Err(format!("[{i}] `{s}` is not a 20-byte hex address"));
+                        }
+                        Ok(s.clone())
+                    }
+                    other => Err(format!("[{i}] expected a string, got {}", other.type_str())),
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(FileValue::Many(items))
+        }
+        (Kind::List | Kind::AddressList, other) => Err(format!(
+            "expected an array of strings, got {}",
+            other.type_str()
+        )),
+    }
+}
+
+/// Render every setting as TOML with its source (`flag`, `env`, `file`,
+/// `default`) and secrets redacted; unset optional keys are commented out.
+pub fn render_effective(matches: &clap::ArgMatches, file: Option<&ConfigFile>) -> String {
+    let from_file = |key: &str| file.is_some_and(|f| f.values.contains_key(key));
+    let mut out = String::new();
+    if let Some(file) = file {
+        out.push_str(&format!("# config file: {}\n", file.path.display()));
+    }
+    for section in SECTIONS {
+        out.push_str(&format!("\n[{section}]\n"));
+        for setting in SETTINGS
+            .iter()
+            .filter(|s| s.key.split('.').next() == Some(section))
+        {
+            let name = &setting.key[section.len() + 1..];
+            let (values, source) = match setting.target {
+                Target::Arg(id) => {
+                    // `value_source` panics on ids the command never defined.
+                    let present = matches.ids().any(|i| i.as_str() == id);
+                    let values: Option<Vec<String>> = present
+                        .then(|| matches.get_raw(id))
+                        .flatten()
+                        .map(|raw| raw.map(|v| v.to_string_lossy().into_owned()).collect());
+                    let source = match present.then(|| matches.value_source(id)).flatten() {
+                        Some(ValueSource::CommandLine) => "flag",
+                        Some(ValueSource::EnvVariable) => "env",
+                        _ if from_file(setting.key) => "file",
+                        _ => "default",
+                    };
+                    (values, source)
+                }
+                Target::Env { var, effective } => {
+                    let exported = file.is_some_and(|f| f.exported.contains(&setting.key));
+                    let source = if exported {
+                        "file"
+                    } else if std::env::var_os(var).is_some() {
+                        "env"
+                    } else {
+                        "default"
+                    };
+                    (effective().map(|v| vec![v]), source)
+                }
+            };
+            let Some(values) = values.filter(|v| !v.is_empty()) else {
+                out.push_str(&format!("# {name} is unset\n"));
+                continue;
+            };
+            let rendered = if setting.secret {
+                "\"[REDACTED]\"".to_string()
+            } else {
+                render_value(setting.kind, &values)
+            };
+            out.push_str(&format!("{name} = {rendered}  # {source}\n"));
+        }
+    }
+    out
+}
+
+fn render_value(kind: Kind, values: &[String]) -> String {
+    let quote = |s: &String| toml::Value::String(s.clone()).to_string();
+    match kind {
+        Kind::List | Kind::AddressList => {
+            let items: Vec<String> = values.iter().map(quote).collect();
+            format!("[{}]", items.join(", "))
+        }
+        Kind::Int { .. } | Kind::Bool => values[0].clone(),
+        Kind::Str | Kind::Path => quote(&values[0]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(text: &str) -> anyhow::Result<ConfigFile> {
+        ConfigFile::parse(Path::new("test.toml"), text)
+    }
+
+    /// Every failure names the offending dotted key.
+    #[test]
+    fn errors_point_at_the_offending_key() {
+        let err = parse("[server]\nprot = 1\n").unwrap_err().to_string();
+        assert!(err.contains("unknown key `server.prot`"), "{err}");
+        let err = parse("[sever]\nport = 1\n").unwrap_err().to_string();
+        assert!(err.contains("unknown section `[sever]`"), "{err}");
+        let err = parse("[server]\nport = 70000\n").unwrap_err().to_string();
+        assert!(
+            err.contains("`server.port`") && err.contains("out of range"),
+            "{err}"
+        );
+        let err = parse("[writer]\nqueue_depth = 0\n")
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("`writer.queue_depth`"), "{err}");
+        let err = parse("[security]\nallowed_signers = [\"0x12\"]\n")
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("`security.allowed_signers`: [0]"), "{err}");
+        let err = parse("[server]\nread_only = \"yes\"\n")
+            .unwrap_err()
+            .to_string();
+        assert!(
+            err.contains("`server.read_only`: expected true or false"),
+            "{err}"
+        );
+
+        // Values the argument's own parser rejects fail `apply`.
+        let command = clap::Command::new("svc").arg(
+            clap::Arg::new("bind")
+                .long("bind")
+                .value_parser(clap::value_parser!(std::net::IpAddr)),
+        );
+        let file = parse("[server]\nbind = \"localhost:80\"\n").unwrap();
+        let err = file.apply(command).unwrap_err().to_string();
+        assert!(
+            err.contains("`server.bind`") && err.contains("localhost:80"),
+            "{err}"
+        );
+    }
+
+    /// Serializes the tests that set env vars; they are process-global and
+    /// cargo runs tests in parallel.
+    static ENV_LOCK: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
+    /// A file value becomes the argument's default: the flag and its env var
+    /// still win, and the effective rendering reports where each came from.
+    #[test]
+    fn file_values_rank_below_flags_and_env() {
+        const ENV: &str = "CONFIG_FILE_TEST_CHAIN_ID";
+        const BOOL_ENV: &str = "CONFIG_FILE_TEST_READ_ONLY";
+        let command = || {
+            clap::Command::new("svc")
+                .arg(
+                    clap::Arg::new("port")
+                        .long("port")
+                        .value_parser(clap::value_parser!(u16))
+                        .default_value("8546"),
+                )
+                .arg(
+                    clap::Arg::new("chain_id")
+                        .long("chain-id")
+                        .env(ENV)
+                        .value_parser(clap::value_parser!(u64)),
+                )
+                .arg(
+                    clap::Arg::new("network_id")
+                        .long("network-id")
+                        .value_parser(clap::value_parser!(u64)),
+                )
+                .arg(
+                    clap::Arg::new("read_only")
+                        .long("read-only")
+                        .env(BOOL_ENV)
+                        .action(clap::ArgAction::SetTrue),
+                )
+        };
+        let file = parse("[server]\nport = 9000\nchain_id = 7\nnetwork_id = 3\nread_only = true\n")
+            .unwrap();
+        let applied = file.apply(command()).unwrap();
+
+        let m = applied.clone().get_matches_from(["svc"]);
+        assert_eq!(m.get_one::<u16>("port"), Some(&9000));
+        assert_eq!(m.get_one::<u64>("chain_id"), Some(&7));
+        assert!(m.get_flag("read_only"));
+
+        let m = applied
+            .clone()
+            .get_matches_from(["svc", "--port", "9100", "--network-id", "4"]);
+        assert_eq!(m.get_one::<u16>("port"), Some(&9100));
+        assert_eq!(m.get_one::<u64>("network_id"), Some(&4));
+
+        // clap reads an argument's env var when the argument is built, so the
+        // command is rebuilt with the variables set. Only the env var can turn
+        // off a bool the file sets.
+        let m = {
+            let _env_guard = ENV_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+            // SAFETY: the variables are private to this test and the lock
+            // keeps other env-setting tests out.
+            unsafe {
+                std::env::set_var(ENV, "11");
+                std::env::set_var(BOOL_ENV, "false");
+            }
+            let applied = file.apply(command());
+            unsafe {
+                std::env::remove_var(ENV);
+                std::env::remove_var(BOOL_ENV);
+            }
+            applied.unwrap().get_matches_from(["svc"])
+        };
+        assert_eq!(m.get_one::<u64>("chain_id"), Some(&11));
+        assert!(!m.get_flag("read_only"));
+        let rendered = render_effective(&m, Some(&file));
+        assert!(rendered.contains("port = 9000  # file"), "{rendered}");
+        assert!(rendered.contains("chain_id = 11  # env"), "{rendered}");
+        assert!(rendered.contains("# url is unset"), "{rendered}");
+    }
+
+    #[test]
+    fn secrets_are_redacted() {
+        let command = clap::Command::new("svc").arg(
+            clap::Arg::new("database_url")
+                .long("database-url")
+                .value_parser(clap::value_parser!(String)),
+        );
+        let file = parse("[database]\nurl = \"postgres://u:hunter2@db/x\"\n").unwrap();
+        let m = file.apply(command).unwrap().get_matches_from(["svc"]);
+        let rendered = render_effective(&m, Some(&file));
+        assert!(
+            rendered.contains("url = \"[REDACTED]\"  # file"),
+            "{rendered}"
+        );
+        assert!(!rendered.contains("hunter2"));
+    }
+}
@@ -1,3 +1,5 @@
+use alloy::primitives::{Address, B256, U256, keccak256};
+
 alloy_core::sol! {
     // https://github.com/agglayer/agglayer-contracts/blob/main/contracts/v2/PolygonZkEVMBridgeV2.sol#L125
     #[derive(Debug)]
@@ -12,3 +14,74 @@ alloy_core::sol! {
         uint32 depositCount
     );
 }
+
+/// Depth of the bridge's local exit tree (`DepositContractBase._DEPOSIT_CONTRACT_TREE_DEPTH`).
+pub const LOCAL_EXIT_TREE_DEPTH: usize = 32;
+
+/// `DepositContractV2.getLeafValue`: the keccak of the packed leaf fields, with
+/// the metadata folded in as its own keccak.
+#[allow(clippy::too_many_arguments)]
+pub fn leaf_value(
+    leaf_type: u8,
+    origin_network: u32,
+    origin_address: Address,
+    destination_network: u32,
+    destination_address: Address,
+    amount: U256,
+    metadata: &[u8],
+) -> B256 {
+    let mut packed = Vec::with_capacity(1 + 4 + 20 + 4 + 20 + 32 + 32);
+    packed.push(leaf_type);
+    packed.extend(origin_network.to_be_bytes());
+    packed.extend(origin_address.as_slice());
+    packed.extend(destination_network.to_be_bytes());
+    packed.extend(destination_address.as_slice());
+    packed.extend(amount.to_be_bytes::<32>());
+    packed.extend(keccak256(metadata).as_slice());
+    keccak256(packed)
+}
+
+/// Root of an append-only exit tree holding `leaves` at indices `0..len`, the
+/// value `DepositContractBase.getRoot()` returns after those deposits.
+pub fn exit_tree_root(leaves: &[B256]) -> B256 {
+    let hash_pair =
+        |left: &B256, right: &B256| keccak256([left.as_slice(), right.as_slice()].concat());
+    let mut level = leaves.to_vec();
+    let mut zero = B256::ZERO;
+    for _ in 0..LOCAL_EXIT_TREE_DEPTH {
+        if level.len() % 2 == 1 {
+            level.push(zero);
+        }
+        level = level
+            .chunks(2)
+            .map(|pair| hash_pair(&pair[0], &pair[1]))
+            .collect();
+        zero = hash_pair(&zero, &zero);
+    }
+    level.first().copied().unwrap_or(zero)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn exit_tree_root_matches_the_deposit_contract() {
+        // Root of the empty depth-32 tree, as returned by a fresh bridge.
+        assert_eq!(
+            exit_tree_root(&[]),
+            B256::from_str("0x27ae5ba08d7291c96c8cbddcc148bf48a6d68c7974b94356f53754ef6171d757")
+                .unwrap()
+        );
+        let leaf = leaf_value(0, 0, Address::ZERO, 1, Address::ZERO, U256::from(1), &[]);
+        let mut expected = leaf;
+        let mut zero = B256::ZERO;
+        for _ in 0..LOCAL_EXIT_TREE_DEPTH {
+            expected = keccak256([expected.as_slice(), zero.as_slice()].concat());
+            zero = keccak256([zero.as_slice(), zero.as_slice()].concat());
+        }
+        assert_eq!(exit_tree_root(&[leaf]), expected);
+        assert_ne!(exit_tree_root(&[leaf, leaf]), exit_tree_root(&[leaf]));
+    }
+}
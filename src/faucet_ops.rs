@@ -163,7 +163,7 @@ pub async fn rebuild_faucet_entry_from_chain(
     bridge_account: &Account,
     faucet_id: AccountId,
     conversion: &FaucetConversion,
-    l1_rpc_url: Option<&str>,
+    rpc_urls: &[String],
 ) -> anyhow::Result<FaucetEntry> {
     // Ensure the faucet account is available locally (best-effort import; if it is
     // already tracked this is a refresh). A prior process's dynamically-created
@@ -206,7 +206,7 @@ pub async fn rebuild_faucet_entry_from_chain(
         faucet_id,
         Some(bridge_account),
         Some(&faucet_account),
-        l1_rpc_url,
+        rpc_urls,
     )
     .await
     {
@@ -0,0 +1,158 @@
+//! `safe` / `finalized` block tags for the synthetic chain.
+//!
+//! Synthetic block N mirrors Miden block N, and the projector advances the
+//! synthetic tip only to blocks the Miden node has committed, so `latest` is
+//! already the node's committed frontier. `safe` and `finalized` trail it by a
+//! configurable number of blocks ([`FinalityLags`]); a lag of 0 makes the tag
+//! track the committed frontier itself. Consumers that follow `finalized`
+//! (aggsender, bridge-service) then only see blocks that have been committed
+//! for at least that long.
+//!
+//! Every tag resolution goes through [`Frontiers`], computed from ONE read of
+//! the synthetic tip, so `eth_getBlockByNumber`, `eth_getLogs` and
+//! `eth_syncing` agree within a request.
+
+use crate::log_synthesis::LogFilter;
+
+/// Default blocks `safe` trails `latest`; 0 keeps `safe == latest`.
+pub const DEFAULT_SAFE_BLOCK_LAG: u64 = 0;
+/// Default blocks `finalized` trails `latest`; 0 keeps `finalized == latest`.
+pub const DEFAULT_FINALIZED_BLOCK_LAG: u64 = 0;
+
+/// How far `safe` and `finalized` trail the synthetic tip.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FinalityLags {
+    safe: u64,
+    finalized: u64,
+}
+
+impl Default for FinalityLags {
+    fn default() -> Self {
+        Self {
+            safe: DEFAULT_SAFE_BLOCK_LAG,
+            finalized: DEFAULT_FINALIZED_BLOCK_LAG,
+        }
+    }
+}
+
+impl FinalityLags {
+    /// `safe` is the intermediate frontier, so it may not trail further than
+    /// `finalized`.
+    pub fn new(safe: u64, finalized: u64) -> anyhow::Result<Self> {
+        if safe > finalized {
+            anyhow::bail!(
+                "safe block lag ({safe}) must not exceed the finalized block lag ({finalized})"
+            );
+        }
+        Ok(Self { safe, finalized })
+    }
+
+    pub fn safe(&self) -> u64 {
+        self.safe
+    }
+
+    pub fn finalized(&self) -> u64 {
+        self.finalized
+    }
+
+    /// Frontiers for a synthetic tip of `latest`. Lags saturate at genesis.
+    pub fn frontiers(&self, latest: u64) -> Frontiers {
+        Frontiers {
+            latest,
+            safe: latest.saturating_sub(self.safe),
+            finalized: latest.saturating_sub(self.finalized),
+        }
+    }
+}
+
+/// The synthetic chain's `latest` / `safe` / `finalized` block numbers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Frontiers {
+    pub latest: u64,
+    pub safe: u64,
+    pub finalized: u64,
+}
+
+impl Frontiers {
+    /// Block number for a named tag (case-insensitive); `None` for anything
+    /// else, including explicit block numbers.
+    pub fn resolve(&self, tag: &str) -> Option<u64> {
+        match tag.to_lowercase().as_str() {
+            "latest" | "pending" => Some(self.latest),
+            "safe" => Some(self.safe),
+            "finalized" => Some(self.finalized),
+            "earliest" => Some(0),
+            _ => None,
+        }
+    }
+
+    /// Rewrite `safe` / `finalized` bounds of an `eth_getLogs` filter to hex
+    /// block numbers. The store only understands `earliest` / `latest` /
+    /// `pending` and numbers.
+    pub fn resolve_log_filter(&self, filter: &mut LogFilter) {
+        for bound in [&mut filter.from_block, &mut filter.to_block] {
+            if let Some(tag) = bound.as_deref()
+                && matches!(tag.to_lowercase().as_str(), "safe" | "finalized")
+                && let Some(number) = self.resolve(tag)
+            {
+                *bound = Some(format!("{number:#x}"));
+            }
+        }
+    }
+
+    /// Publish the three frontiers as gauges.
+    pub fn record_metrics(&self) {
+        ::metrics::gauge!("synthetic_latest_block").set(self.latest as f64);
+        ::metrics::gauge!("synthetic_safe_block").set(self.safe as f64);
+        ::metrics::gauge!("synthetic_finalized_block").set(self.finalized as f64);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn frontiers_trail_the_tip_and_saturate_at_genesis() {
+        let lags = FinalityLags::new(2, 10).unwrap();
+        assert_eq!(
+            lags.frontiers(100),
+            Frontiers {
+                latest: 100,
+                safe: 98,
+                finalized: 90
+            }
+        );
+        let young = lags.frontiers(5);
+        assert_eq!((young.safe, young.finalized), (3, 0));
+        assert!(FinalityLags::new(11, 10).is_err());
+        // Zero lag tracks the committed frontier.
+        assert_eq!(FinalityLags::new(0, 0).unwrap().frontiers(7).finalized, 7);
+    }
+
+    #[test]
+    fn resolves_tags_and_log_filter_bounds() {
+        let frontiers = FinalityLags::new(2, 10).unwrap().frontiers(100);
+        assert_eq!(frontiers.resolve("FINALIZED"), Some(90));
+        assert_eq!(frontiers.resolve("pending"), Some(100));
+        assert_eq!(frontiers.resolve("0x10"), None);
+
+        let mut filter = LogFilter {
+            from_block: Some("finalized".to_string()),
+            to_block: Some("safe".to_string()),
+            ..Default::default()
+        };
+        frontiers.resolve_log_filter(&mut filter);
+        assert_eq!(filter.from_block.as_deref(), Some("0x5a"));
+        assert_eq!(filter.to_block.as_deref(), Some("0x62"));
+
+        let mut untouched = LogFilter {
+            from_block: Some("0x1".to_string()),
+            to_block: Some("latest".to_string()),
+            ..Default::default()
+        };
+        frontiers.resolve_log_filter(&mut untouched);
+        assert_eq!(untouched.from_block.as_deref(), Some("0x1"));
+        assert_eq!(untouched.to_block.as_deref(), Some("latest"));
+    }
+}
@@ -0,0 +1,362 @@
+//! Bounded holding area for `eth_sendRawTransaction` envelopes whose nonce is
+//! ahead of the signer's next expected nonce.
+//!
+//! R4 admits nonces strictly in order. When nonce N+1 arrives and N has not
+//! been accepted within the short in-request reorder wait, the signed envelope
+//! is parked here and the caller gets `-32007` ("nonce too high, held"). The
+//! release loop ([`crate::service_send_raw_txn::spawn_future_nonce_release`])
+//! resubmits it through the normal admission path once the signer's stored
+//! nonce reaches it, so every R4, allow-list, reservation and writer check
+//! still applies to a released transaction.
+//!
+//! The area is bounded three ways: the nonce gap a signer may leave open, the
+//! envelopes held per signer, and the envelopes held in total. Nothing here is
+//! durable; a restart drops held envelopes and the submitter re-sends them.
+
+use alloy::primitives::{Address, TxHash};
+use parking_lot::Mutex;
+use std::collections::{BTreeMap, HashMap};
+use std::time::{Duration, Instant};
+
+/// Default per-signer cap on non-terminal writer jobs (`--signer-inflight-quota`).
+pub const DEFAULT_SIGNER_INFLIGHT_QUOTA: usize = 16;
+/// Default envelopes held per signer; also the largest nonce gap accepted.
+pub const DEFAULT_HOLD_PER_SIGNER: usize = 16;
+/// Default envelopes held across all signers.
+pub const DEFAULT_HOLD_TOTAL: usize = 256;
+/// A held envelope whose gap is not filled within this window is dropped.
+pub const HELD_TX_TTL: Duration = Duration::from_secs(300);
+
+/// JSON-RPC code for a future-nonce envelope parked in the holding area.
+pub const NONCE_HELD_ERROR_CODE: i32 = -32007;
+/// JSON-RPC code for a signer over its in-flight or holding quota.
+pub const SIGNER_QUOTA_ERROR_CODE: i32 = -32006;
+
+/// The envelope is held; it is admitted once `expected` is accepted. Mapped to
+/// JSON-RPC `-32007` with the hash and both nonces in `data`.
+#[derive(Debug, thiserror::Error)]
+#[error(
+    "nonce too high for {signer:#x}: tx.nonce = {nonce}, expected {expected}; transaction {tx_hash:#x} is held until the gap is filled"
+)]
+pub struct NonceTooHighHeldError {
+    pub tx_hash: TxHash,
+    pub signer: Address,
+    pub nonce: u64,
+    pub expected: u64,
+}
+
+/// The signer already has `count` transactions in flight (or held), at or
+/// over `quota`. Mapped to JSON-RPC `-32006`.
+#[derive(Debug, thiserror::Error)]
+#[error(
+    "signer {signer:#x} has {count} {what} transactions (quota {quota}); retry after earlier transactions land"
+)]
+pub struct SignerQuotaExceededError {
+    pub signer: Address,
+    pub what: &'static str,
+    pub count: usize,
+    pub quota: usize,
+}
+
+/// Every signer's held envelopes together fill the holding area. Mapped to
+/// JSON-RPC `-32005`, like a saturated writer queue.
+#[derive(Debug, thiserror::Error)]
+#[error("future-nonce holding area full; retry")]
+pub struct FutureNoncePoolFullError;
+
+/// Why [`FutureNoncePool::hold`] refused an envelope.
+#[derive(Debug, PartialEq, Eq)]
+pub enum HoldRefusal {
+    /// `nonce - expected` exceeds the per-signer bound: treated as an R4
+    /// mismatch, not as reordering.
+    GapTooLarge,
+    /// The signer already holds its share.
+    SignerFull { held: usize },
+    /// The whole area is full.
+    PoolFull,
+    /// A different envelope is already held at this nonce.
+    NonceTaken(TxHash),
+}
+
+#[derive(Debug, Clone)]
+pub struct HeldTx {
+    pub tx_hash: TxHash,
+    pub nonce: u64,
+    /// The `0x`-hex raw envelope exactly as submitted.
+    pub raw: String,
+    held_at: Instant,
+}
+
+#[derive(Default)]
+struct PoolInner {
+    by_signer: HashMap<Address, BTreeMap<u64, HeldTx>>,
+    len: usize,
+}
+
+pub struct FutureNoncePool {
+    per_signer: usize,
+    total: usize,
+    inner: Mutex<PoolInner>,
+    wake: tokio::sync::Notify,
+}
+
+impl Default for FutureNoncePool {
+    fn default() -> Self {
+        Self::new(DEFAULT_HOLD_PER_SIGNER, DEFAULT_HOLD_TOTAL)
+    }
+}
+
+impl FutureNoncePool {
+    /// `per_signer == 0` disables holding: every future nonce past the reorder
+    /// wait is refused as an R4 mismatch.
+    pub fn new(per_signer: usize, total: usize) -> Self {
+        Self {
+            per_signer,
+            total,
+            inner: Mutex::new(PoolInner::default()),
+            wake: tokio::sync::Notify::new(),
+        }
+    }
+
+    pub fn per_signer(&self) -> usize {
+        self.per_signer
+    }
+
+    /// Park `raw` until `signer`'s nonce reaches `nonce`. Re-holding the same
+    /// hash is a no-op success, so a submitter retrying a held envelope keeps
+    /// getting the same `-32007`.
+    pub fn hold(
+        &self,
+        signer: Address,
+        nonce: u64,
+        expected: u64,
+        tx_hash: TxHash,
+        raw: String,
+    ) -> Result<(), HoldRefusal> {
+        if nonce.saturating_sub(expected) > self.per_signer as u64 {
+            return Err(HoldRefusal::GapTooLarge);
+        }
+        let mut inner = self.inner.lock();
+        let held = inner.by_signer.get(&signer).map_or(0, BTreeMap::len);
+        if let Some(existing) = inner.by_signer.get(&signer).and_then(|m| m.get(&nonce)) {
+            return if existing.tx_hash == tx_hash {
+                Ok(())
+            } else {
+                Err(HoldRefusal::NonceTaken(existing.tx_hash))
+            };
+        }
+        if held >= self.per_signer {
+            return Err(HoldRefusal::SignerFull { held });
+        }
+        if inner.len >= self.total {
+            return Err(HoldRefusal::PoolFull);
+        }
+        inner.by_signer.entry(signer).or_default().insert(
+            nonce,
+            HeldTx {
+                tx_hash,
+                nonce,
+                raw,
+                held_at: Instant::now(),
+            },
+        );
+        inner.len += 1;
+        ::metrics::gauge!("rpc_future_nonce_held").set(inner.len as f64);
+        Ok(())
+    }
+
+    /// Take `signer`'s envelope at `expected`, dropping any it holds below
+    /// `expected` (those nonces were consumed by other transactions).
+    pub fn take_ready(&self, signer: Address, expected: u64) -> Option<HeldTx> {
+        let mut inner = self.inner.lock();
+        let map = inner.by_signer.get_mut(&signer)?;
+        let keep = map.split_off(&expected);
+        let stale = std::mem::replace(map, keep).len();
+        let ready = map.remove(&expected);
+        let empty = map.is_empty();
+        if empty {
+            inner.by_signer.remove(&signer);
+        }
+        inner.len -= stale + usize::from(ready.is_some());
+        if stale > 0 {
+            ::metrics::counter!("rpc_future_nonce_dropped_total", "reason" => "stale")
+                .increment(stale as u64);
+        }
+        ::metrics::gauge!("rpc_future_nonce_held").set(inner.len as f64);
+        ready
+    }
+
+    /// Put back an envelope [`FutureNoncePool::take_ready`] handed out whose
+    /// resubmission was refused for a transient reason. It keeps its original
+    /// hold time, so [`HELD_TX_TTL`] still bounds it, and counts against the
+    /// same per-signer and total bounds as [`FutureNoncePool::hold`]. Returns
+    /// false, dropping it, when another envelope took its nonce in the
+    /// meantime or the pool filled up.
+    pub fn restore(&self, signer: Address, held: HeldTx) -> bool {
+        let mut inner = self.inner.lock();
+        let signer_held = inner.by_signer.get(&signer);
+        if signer_held.is_some_and(|map| map.contains_key(&held.nonce))
+            || signer_held.map_or(0, BTreeMap::len) >= self.per_signer
+            || inner.len >= self.total
+        {
+            return false;
+        }
+        inner
+            .by_signer
+            .entry(signer)
+            .or_default()
+            .insert(held.nonce, held);
+        inner.len += 1;
+        ::metrics::gauge!("rpc_future_nonce_held").set(inner.len as f64);
+        true
+    }
+
+    /// Drop envelopes held longer than `ttl`; returns how many.
+    pub fn expire(&self, ttl: Duration) -> usize {
+        let mut inner = self.inner.lock();
+        let mut dropped = 0;
+        inner.by_signer.retain(|_, map| {
+            let before = map.len();
+            map.retain(|_, held| held.held_at.elapsed() < ttl);
+            dropped += before - map.len();
+            !map.is_empty()
+        });
+        if dropped > 0 {
+            inner.len -= dropped;
+            ::metrics::counter!("rpc_future_nonce_dropped_total", "reason" => "expired")
+                .increment(dropped as u64);
+            ::metrics::gauge!("rpc_future_nonce_held").set(inner.len as f64);
+        }
+        dropped
+    }
+
+    /// Signers with at least one held envelope.
+    pub fn signers(&self) -> Vec<Address> {
+        self.inner.lock().by_signer.keys().copied().collect()
+    }
+
+    pub fn held_for(&self, signer: &Address) -> usize {
+        self.inner
+            .lock()
+            .by_signer
+            .get(signer)
+            .map_or(0, BTreeMap::len)
+    }
+
+    pub fn len(&self) -> usize {
+        self.inner.lock().len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Wake the release loop: some signer's nonce advanced.
+    pub fn notify(&self) {
+        self.wake.notify_one();
+    }
+
+    pub async fn notified(&self) {
+        self.wake.notified().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hash(b: u8) -> TxHash {
+        TxHash::from([b; 32])
+    }
+
+    #[test]
+    fn hold_is_bounded_per_signer_in_total_and_by_gap() {
+        let pool = FutureNoncePool::new(2, 3);
+        let (a, b) = (Address::from([1; 20]), Address::from([2; 20]));
+
+        assert_eq!(
+            pool.hold(a, 5, 2, hash(1), "0x01".into()),
+            Err(HoldRefusal::GapTooLarge)
+        );
+        pool.hold(a, 3, 2, hash(1), "0x01".into()).unwrap();
+        // Same hash again is idempotent; a different hash at that nonce is not.
+        pool.hold(a, 3, 2, hash(1), "0x01".into()).unwrap();
+        assert_eq!(
+            pool.hold(a, 3, 2, hash(9), "0x09".into()),
+            Err(HoldRefusal::NonceTaken(hash(1)))
+        );
+        pool.hold(a, 4, 2, hash(2), "0x02".into()).unwrap();
+        assert_eq!(
+            pool.hold(a, 4, 3, hash(3), "0x03".into()),
+            Err(HoldRefusal::NonceTaken(hash(2)))
+        );
+        assert_eq!(
+            pool.hold(a, 1, 0, hash(4), "0x04".into()),
+            Err(HoldRefusal::SignerFull { held: 2 })
+        );
+        pool.hold(b, 1, 0, hash(5), "0x05".into()).unwrap();
+        assert_eq!(
+            pool.hold(b, 2, 0, hash(6), "0x06".into()),
+            Err(HoldRefusal::PoolFull)
+        );
+        assert_eq!(pool.len(), 3);
+    }
+
+    #[test]
+    fn take_ready_releases_in_order_and_drops_consumed_nonces() {
+        let pool = FutureNoncePool::new(4, 8);
+        let a = Address::from([1; 20]);
+        for (nonce, b) in [(2, 2), (3, 3), (4, 4)] {
+            pool.hold(a, nonce, 1, hash(b), format!("0x0{b}")).unwrap();
+        }
+        assert!(pool.take_ready(a, 1).is_none(), "gap at 1 still open");
+        assert_eq!(pool.take_ready(a, 2).unwrap().tx_hash, hash(2));
+        // Nonce 3 was consumed by some other transaction: 3 is dropped, 4 is ready.
+        assert_eq!(pool.take_ready(a, 4).unwrap().tx_hash, hash(4));
+        assert!(pool.is_empty());
+        assert!(pool.signers().is_empty());
+
+        pool.hold(a, 6, 5, hash(6), "0x06".into()).unwrap();
+        assert_eq!(pool.expire(Duration::ZERO), 1);
+        assert!(pool.is_empty());
+    }
+
+    #[test]
+    fn restore_puts_a_taken_envelope_back_unless_its_nonce_was_retaken() {
+        let pool = FutureNoncePool::new(4, 8);
+        let a = Address::from([1; 20]);
+        pool.hold(a, 2, 1, hash(2), "0x02".into()).unwrap();
+        let held = pool.take_ready(a, 2).unwrap();
+        assert!(pool.restore(a, held.clone()));
+        assert_eq!(pool.held_for(&a), 1);
+        assert_eq!(pool.take_ready(a, 2).unwrap().tx_hash, hash(2));
+
+        pool.hold(a, 2, 1, hash(9), "0x09".into()).unwrap();
+        assert!(!pool.restore(a, held));
+        assert_eq!(pool.len(), 1);
+    }
+
+    #[test]
+    fn restore_respects_the_hold_bounds() {
+        let a = Address::from([1; 20]);
+        let b = Address::from([2; 20]);
+
+        // The signer's slot was refilled while its envelope was out.
+        let pool = FutureNoncePool::new(1, 8);
+        pool.hold(a, 2, 1, hash(2), "0x02".into()).unwrap();
+        let held = pool.take_ready(a, 2).unwrap();
+        pool.hold(a, 3, 2, hash(3), "0x03".into()).unwrap();
+        assert!(!pool.restore(a, held));
+        assert_eq!(pool.held_for(&a), 1);
+
+        // Other signers filled the pool.
+        let pool = FutureNoncePool::new(2, 2);
+        pool.hold(a, 2, 1, hash(2), "0x02".into()).unwrap();
+        let held = pool.take_ready(a, 2).unwrap();
+        pool.hold(b, 1, 0, hash(5), "0x05".into()).unwrap();
+        pool.hold(b, 2, 0, hash(6), "0x06".into()).unwrap();
+        assert!(!pool.restore(a, held));
+        assert_eq!(pool.len(), 2);
+        assert_eq!(pool.signers(), vec![b]);
+    }
+}
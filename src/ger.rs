@@ -1,7 +1,7 @@
 use crate::miden_client::MidenClient;
 use alloy::consensus::TxEnvelope;
 use alloy::primitives::{Address, TxHash};
-use miden_base_agglayer::{ExitRoot, UpdateGerNote};
+use miden_base_agglayer::{ExitRoot, RemoveGerNote, UpdateGerNote};
 use miden_client::transaction::TransactionRequestBuilder;
 use sha3::{Digest, Keccak256};
 use std::{
@@ -29,7 +29,7 @@ const GER_EVIDENCE_WAIT_TIMEOUT: Duration = Duration::from_millis(100);
 /// corroborates and would otherwise burn the full 15 min (twice) per e2e run. A legit
 /// GER resolves in milliseconds regardless, so shortening the budget only speeds up
 /// the negative (refusal) path; production keeps the finality-safe default.
-fn ger_evidence_wait_timeout() -> Duration {
+pub(crate) fn ger_evidence_wait_timeout() -> Duration {
     match std::env::var("GER_EVIDENCE_WAIT_TIMEOUT_SECS") {
         Ok(s) => match s.trim().parse::<u64>() {
             Ok(secs) => Duration::from_secs(secs),
@@ -59,6 +59,12 @@ alloy_core::sol! {
     function updateExitRoot(bytes32 newRollupExitRoot, bytes32 newMainnetExitRoot);
 }
 
+alloy_core::sol! {
+    // https://github.com/agglayer/agglayer-contracts/blob/main/contracts/v2/sovereignChains/GlobalExitRootManagerL2SovereignChain.sol
+    #[derive(Debug)]
+    function removeGlobalExitRoots(bytes32[] gersToRemove);
+}
+
 /// The single L1 evidence scan setting (audit H6). The indexer scans exactly one
 /// canonical frontier and stores roots only from that frontier:
 /// Parsed from `--l1-evidence-tag` / `L1_EVIDENCE_TAG`:
@@ -150,7 +156,68 @@ async fn submit_update_ger_note(
     txn_envelope: TxEnvelope,
     signer: Address,
 ) -> anyhow::Result<()> {
+    submit_ger_manager_note(
+        GerNoteOp::Update,
+        miden_client,
+        accounts,
+        store,
+        ger_bytes,
+        txn_hash,
+        txn_envelope,
+        signer,
+    )
+    .await
+    .map(|_| ())
+}
+
+/// Which GER-manager note a submission builds. Both carry the same 8-felt GER
+/// payload from the same sender to the bridge; only the note script differs.
+#[derive(Clone, Copy, Debug)]
+enum GerNoteOp {
+    Update,
+    Remove,
+}
+
+impl GerNoteOp {
+    fn note_name(self) -> &'static str {
+        match self {
+            Self::Update => "UpdateGerNote",
+            Self::Remove => "RemoveGerNote",
+        }
+    }
+
+    fn create_note(
+        self,
+        ger: ExitRoot,
+        sender: miden_protocol::account::AccountId,
+        target: miden_protocol::account::AccountId,
+        rng: &mut impl miden_protocol::crypto::rand::FeltRng,
+    ) -> Result<miden_protocol::note::Note, miden_protocol::errors::NoteError> {
+        match self {
+            Self::Update => UpdateGerNote::create(ger, sender, target, rng),
+            Self::Remove => RemoveGerNote::create(ger, sender, target, rng),
+        }
+    }
+}
+
+/// Shared body of the UpdateGerNote / RemoveGerNote submissions (see
+/// `submit_update_ger_note`). `txn_envelope` and `signer` are the eth
+/// transaction the note answers. Returns the created note id (`None` only
+/// when the closure never ran, i.e. under the test client stub).
+async fn submit_ger_manager_note(
+    op: GerNoteOp,
+    miden_client: &MidenClient,
+    accounts: crate::AccountsConfig,
+    store: Arc<dyn crate::store::Store>,
+    ger_bytes: [u8; 32],
+    txn_hash: TxHash,
+    txn_envelope: TxEnvelope,
+    signer: Address,
+) -> anyhow::Result<Option<String>> {
     let inner_accounts = accounts.0.clone();
+    let note_name = op.note_name();
+    let created = Arc::new(std::sync::Mutex::new(None::<String>));
+    let created_write = created.clone();
     miden_client
         .with(move |client| {
             Box::new(async move {
@@ -162,7 +229,7 @@ async fn submit_update_ger_note(
                     .unwrap_or(inner_accounts.service.0);
                 let bridge_id = inner_accounts.bridge.0;
                 let ger = ExitRoot::new(ger_bytes);
-                let note = UpdateGerNote::create(ger, ger_manager_id, bridge_id, client.rng())?;
+                let note = op.create_note(ger, ger_manager_id, bridge_id, client.rng())?;
                 let note_id = note.id().to_string();
                 // Commitment of the on-chain note, matching the projector's
                 // consumed-note key (`InputNoteRecord::details_commitment()`).
@@ -174,7 +241,7 @@ async fn submit_update_ger_note(
                 tracing::info!(
                     note_id = %note.id(),
                     ger = %hex::encode(ger_bytes),
-                    "UpdateGerNote created"
+                    "{note_name} created"
                 );
                 let tx_request = TransactionRequestBuilder::new()
                     .own_output_notes(vec![note])
@@ -197,7 +264,7 @@ async fn submit_update_ger_note(
                 // "kill during proving" trigger in the recovery-scenario e2e.
                 tracing::info!(
                     ger = %hex::encode(ger_bytes),
-                    "proving UpdateGerNote (Miden proof in progress)"
+                    "proving {note_name} (Miden proof in progress)"
                 );
                 let proven_tx = crate::metrics::meter_proof(
                     crate::metrics::ProofKind::Ger,
@@ -208,7 +275,7 @@ async fn submit_update_ger_note(
                 // The note identity and pending receipt become durable immediately
                 // before the first external submit. A crash after this point is
                 // fail-closed: same-hash rebroadcasts observe the link and never
-                // build a second random UpdateGerNote.
+                // build a second random GER note.
                 record_ger_submission_handoff(
                     &*store,
                     txn_hash,
@@ -228,7 +295,7 @@ async fn submit_update_ger_note(
                 tracing::info!(
                     tx_id = %tx_id,
                     ger = %hex::encode(ger_bytes),
-                    "UpdateGerNote submitted, waiting for commit..."
+                    "{note_name} submitted, waiting for commit..."
                 );
 
                 let committed = crate::miden_client::wait_for_transaction_commit(
@@ -239,7 +306,7 @@ async fn submit_update_ger_note(
                 )
                 .await?;
                 if !committed {
-                    anyhow::bail!("UpdateGerNote tx {tx_id} not committed after 30s");
+                    anyhow::bail!("{note_name} tx {tx_id} not committed after 30s");
                 }
                 let tx_key = format!("{txn_hash:#x}");
                 if !store
@@ -248,11 +315,14 @@ async fn submit_update_ger_note(
                 {
                     anyhow::bail!("GER note handoff changed before commit confirmation");
                 }
-                tracing::info!(tx_id = %tx_id, "UpdateGerNote transaction committed");
+                tracing::info!(tx_id = %tx_id, "{note_name} transaction committed");
+                *created_write.lock().unwrap() = Some(note_id);
                 Ok(())
             })
         })
-        .await
+        .await?;
+    let note_id = created.lock().unwrap().take();
+    Ok(note_id)
 }
 
 /// Durable pre-submit handoff for an `UpdateGerNote`: record the exact note link
@@ -445,6 +515,83 @@ pub async fn insert_ger(
     Ok(is_new)
 }
 
+/// Submit a GER removal to Miden: a `RemoveGerNote` from the GER-manager
+/// account (the bridge's configured GER remover) to the bridge. Returns the
+/// created note id (see `submit_ger_manager_note`). The projector emits the removal logs and rolls the
+/// removal hash chain when the bridge consumes the note.
+///
+/// Callers validate first (GER applied, no removal already in flight for it);
+/// the on-chain script is the final gate and fails the consumption with
+/// `ERR_GER_NOT_FOUND` for a GER the bridge does not hold. `txn_envelope` and
+/// `signer` are the `removeGlobalExitRoots` transaction, signed by the caller
+/// or, for `admin_removeGlobalExitRoot`, by the proxy. Same
+/// recoverable-account-error self-heal as `insert_ger`.
+pub async fn remove_ger(
+    ger_bytes: [u8; 32],
+    miden_client: &MidenClient,
+    accounts: crate::AccountsConfig,
+    store: &Arc<dyn crate::store::Store>,
+    txn_hash: TxHash,
+    txn_envelope: TxEnvelope,
+    signer: Address,
+) -> anyhow::Result<Option<String>> {
+    tracing::info!(
+        ger = %hex::encode(ger_bytes),
+        %txn_hash,
+        "GER removal: submitting to Miden..."
+    );
+    let first = submit_ger_manager_note(
+        GerNoteOp::Remove,
+        miden_client,
+        accounts.clone(),
+        store.clone(),
+        ger_bytes,
+        txn_hash,
+        txn_envelope.clone(),
+        signer,
+    )
+    .await;
+    let err = match first {
+        Ok(note_id) => return Ok(note_id),
+        Err(err) if crate::account_recovery::is_recoverable_account_error(&err) => err,
+        Err(err) => return Err(err),
+    };
+    if store
+        .get_note_link_for_tx(&format!("{txn_hash:#x}"))
+        .await?
+        .is_some()
+    {
+        tracing::error!(
+            %txn_hash, error = %err,
+            "GER removal outcome is ambiguous after durable handoff; refusing to rebuild a second note"
+        );
+        return Err(err);
+    }
+    tracing::warn!(
+        err = %err,
+        ger = %hex::encode(ger_bytes),
+        "GER removal: recoverable account error, reimporting ger_manager and retrying"
+    );
+    let ger_manager_id = accounts
+        .0
+        .ger_manager
+        .as_ref()
+        .map(|a| a.0)
+        .unwrap_or(accounts.0.service.0);
+    crate::account_recovery::reimport_account(miden_client, ger_manager_id, "ger_manager").await?;
+    submit_ger_manager_note(
+        GerNoteOp::Remove,
+        miden_client,
+        accounts,
+        store.clone(),
+        ger_bytes,
+        txn_hash,
+        txn_envelope,
+        signer,
+    )
+    .await
+}
+
 /// Audit H6 — the pre-admission L1-corroboration gate for GER injections
 /// (PR #121 review: the gate MUST run before every enqueue path, nonce
 /// increment, txn_begin, or receipt creation).
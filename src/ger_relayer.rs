@@ -0,0 +1,393 @@
+//! Built-in GER relayer — injects L1 GERs without an external aggoracle.
+//!
+//! Normally a GER reaches Miden only when aggoracle signs an
+//! `insertGlobalExitRoot` and posts it through `eth_sendRawTransaction`. With
+//! `--ger-relayer` the proxy does that itself: every poll it reads the GERs the
+//! [`crate::l1_info_tree_indexer::L1InfoTreeIndexer`] recorded in the highest
+//! L1 block of its selected evidence scan, and when the newest of them is not
+//! yet injected it signs an `insertGlobalExitRoot` with its own key and admits
+//! it straight into the writer, where the worker runs [`crate::ger::insert_ger`]
+//! like for any other GER job.
+//!
+//! Like aggoracle it relays only the newest GER — within one L1 block, the
+//! one with the highest info-tree leaf index: a GER commits to every deposit
+//! before it, so skipping an intermediate one strands nothing. Once the newest
+//! is injected the relayer is idle; it never falls back to an older one.
+//!
+//! ## Admission
+//!
+//! The relayer is one more signer, so its transactions go through the same
+//! durable admission as a submitted one — per-signer lock, fenced
+//! `(signer, nonce)` reservation, pending row before the nonce advance — and
+//! orphan recovery re-drives them after a crash. Only the request-path checks
+//! that do not apply to a locally signed transaction (chain id, allow-list,
+//! future-nonce holding) are skipped.
+//!
+//! ## Dedup and rate limits
+//!
+//! A GER is skipped when `Store::is_ger_injected` says it landed, or when any
+//! non-terminal writer job (aggoracle's included) already carries it. The
+//! relayer keeps at most one injection of its own outstanding — queued,
+//! submitting, or awaiting projection — and admits at most one per
+//! `--ger-relayer-min-interval-secs`.
+
+use alloy::consensus::Transaction;
+use alloy::primitives::{Address, FixedBytes, TxHash};
+use alloy::signers::local::PrivateKeySigner;
+use alloy_core::sol_types::SolCall;
+use std::time::{Duration, Instant};
+use tokio::sync::oneshot;
+
+use crate::ger::insertGlobalExitRootCall;
+use crate::service_state::ServiceState;
+use crate::writer_worker::{DecodedWriteCall, WriteJobKind};
+
+/// Default poll cadence, matching the indexer's.
+const DEFAULT_POLL_INTERVAL: Duration = Duration::from_millis(1_000);
+
+/// Default minimum spacing between two relayed injections, in seconds.
+pub const DEFAULT_MIN_INTERVAL_SECS: u64 = 10;
+
+/// Gas limit stamped on relayed transactions. Nothing meters it; it only has
+/// to look like a real `insertGlobalExitRoot` to RPC readers.
+const RELAYED_TX_GAS_LIMIT: u64 = 100_000;
+
+/// What one relayer poll did.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RelayOutcome {
+    /// Every newest observed GER is already injected (or none is observed).
+    Idle,
+    /// Not relayed this poll; the label says why.
+    Skipped(&'static str),
+    Relayed(TxHash),
+}
+
+/// Polls the indexer's observed GERs and injects the newest one it finds
+/// not yet injected. See the module docs.
+pub struct GerRelayer {
+    service: ServiceState,
+    key: PrivateKeySigner,
+    poll_interval: Duration,
+    min_interval: Duration,
+    /// The relayer's last admitted injection and when it was admitted.
+    last: Option<(TxHash, Instant)>,
+}
+
+impl GerRelayer {
+    pub fn new(service: ServiceState, key: PrivateKeySigner) -> Self {
+        Self {
+            service,
+            key,
+            poll_interval: DEFAULT_POLL_INTERVAL,
+            min_interval: Duration::from_secs(DEFAULT_MIN_INTERVAL_SECS),
+            last: None,
+        }
+    }
+
+    pub fn with_poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = interval;
+        self
+    }
+
+    pub fn with_min_interval(mut self, interval: Duration) -> Self {
+        self.min_interval = interval;
+        self
+    }
+
+    /// The signer relayed transactions recover to.
+    pub fn address(&self) -> Address {
+        self.key.address()
+    }
+
+    /// Spawn the relay loop. Poll errors are logged and retried on the next
+    /// tick. Drop the returned sender or send `()` to stop it.
+    pub fn spawn(mut self) -> oneshot::Sender<()> {
+        let (shutdown_tx, mut shutdown_rx) = oneshot::channel();
+        tokio::spawn(async move {
+            tracing::info!(
+                signer = %self.address(),
+                poll_interval_ms = self.poll_interval.as_millis() as u64,
+                min_interval_secs = self.min_interval.as_secs(),
+                "GerRelayer starting"
+            );
+            let mut ticker = tokio::time::interval(self.poll_interval);
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            loop {
+                tokio::select! {
+                    biased;
+                    _ = &mut shutdown_rx => break,
+                    _ = ticker.tick() => {}
+                }
+                match self.relay_once().await {
+                    Ok(RelayOutcome::Idle) => {}
+                    Ok(RelayOutcome::Skipped(reason)) => {
+                        metrics::counter!("ger_relayer_skipped_total", "reason" => reason)
+                            .increment(1);
+                    }
+                    Ok(RelayOutcome::Relayed(_)) => {
+                        metrics::counter!("ger_relayer_relayed_total").increment(1);
+                    }
+                    Err(e) => {
+                        tracing::warn!(error = %e, "GerRelayer poll failed, retrying");
+                        metrics::counter!("ger_relayer_errors_total").increment(1);
+                    }
+                }
+            }
+            tracing::info!("GerRelayer stopped");
+        });
+        shutdown_tx
+    }
+
+    async fn relay_once(&mut self) -> anyhow::Result<RelayOutcome> {
+        let store = &self.service.store;
+        // One L1 block can append several GERs; the newest is the one with
+        // the highest info-tree leaf index. A GER whose leaf is not indexed
+        // yet ranks below any indexed one.
+        let mut newest: Option<(Option<u32>, [u8; 32])> = None;
+        for ger in store.get_latest_l1_observed_gers().await? {
+            let leaf_index = store
+                .get_l1_info_tree_leaf_by_ger(&ger)
+                .await?
+                .map(|leaf| leaf.leaf_index);
+            if newest.is_none_or(|best| (leaf_index, ger) > best) {
+                newest = Some((leaf_index, ger));
+            }
+        }
+        let Some((_, ger)) = newest else {
+            return Ok(RelayOutcome::Idle);
+        };
+        if store.is_ger_injected(&ger).await? {
+            return Ok(RelayOutcome::Idle);
+        }
+
+        if self.ger_in_writer(&ger) {
+            return Ok(RelayOutcome::Skipped("in_writer"));
+        }
+        if let Some((hash, admitted_at)) = self.last {
+            if self.own_injection_outstanding(hash).await? {
+                return Ok(RelayOutcome::Skipped("outstanding"));
+            }
+            if admitted_at.elapsed() < self.min_interval {
+                return Ok(RelayOutcome::Skipped("rate_limited"));
+            }
+        }
+
+        let hash = self.admit(ger).await?;
+        self.last = Some((hash, Instant::now()));
+        tracing::info!(
+            ger = %hex::encode(ger),
+            tx = %hash,
+            signer = %self.address(),
+            "GerRelayer: GER injection admitted to the writer"
+        );
+        Ok(RelayOutcome::Relayed(hash))
+    }
+
+    /// Whether a non-terminal writer job from any signer already injects `ger`.
+    fn ger_in_writer(&self, ger: &[u8; 32]) -> bool {
+        let Some(handle) = self.service.writer_handle.as_ref() else {
+            return false;
+        };
+        handle.entries().iter().any(|entry| {
+            entry.kind == WriteJobKind::GerInsert
+                && !entry.state.is_terminal()
+                && matches!(
+                    crate::service_send_raw_txn::decode_write_call(entry.envelope.input()),
+                    Ok(DecodedWriteCall::Ger { ger_bytes }) if ger_bytes == *ger
+                )
+        })
+    }
+
+    /// An admitted injection stays outstanding until its receipt is final:
+    /// the projector writes it when the bridge consumes the note.
+    async fn own_injection_outstanding(&self, hash: TxHash) -> anyhow::Result<bool> {
+        if self
+            .service
+            .writer_handle
+            .as_ref()
+            .is_some_and(|handle| handle.is_inflight(&hash))
+        {
+            return Ok(true);
+        }
+        Ok(self
+            .service
+            .store
+            .txn_get(hash)
+            .await?
+            .is_some_and(|txn| txn.result.is_none()))
+    }
+
+    /// Sign `insertGlobalExitRoot(ger)` at the relayer's next nonce and admit
+    /// it into the writer the way `eth_sendRawTransaction` admits a submitted
+    /// one.
+    async fn admit(&self, ger: [u8; 32]) -> anyhow::Result<TxHash> {
+        let input = insertGlobalExitRootCall {
+            root: FixedBytes::from(ger),
+        }
+        .abi_encode();
+        crate::service_send_raw_txn::admit_locally_signed(
+            &self.service,
+            &self.key,
+            input,
+            RELAYED_TX_GAS_LIMIT,
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::store::Store;
+    use crate::store::memory::InMemoryStore;
+    use crate::writer_worker::WriterWorker;
+    use std::sync::Arc;
+
+    fn relayer(store: Arc<dyn Store>) -> GerRelayer {
+        let mut service = crate::test_helpers::create_test_service_with_store(store);
+        let (handle, shutdown) = WriterWorker::spawn(service.clone(), 64, Duration::from_secs(60));
+        // Leak the shutdown sender so the worker lives for the test.
+        std::mem::forget(shutdown);
+        // Admission is under test, not the Miden submission behind it.
+        handle.pause();
+        service.writer_handle = Some(Arc::new(handle));
+        GerRelayer::new(service, PrivateKeySigner::random())
+            .with_min_interval(Duration::from_secs(3_600))
+    }
+
+    async fn observe(store: &Arc<dyn Store>, marker: u8, l1_block: u64) -> [u8; 32] {
+        let (mainnet, rollup) = ([marker; 32], [marker.wrapping_add(1); 32]);
+        let ger = crate::ger::combined_ger(&mainnet, &rollup);
+        store
+            .set_ger_exit_roots(&ger, mainnet, rollup, l1_block, 1_000 + l1_block)
+            .await
+            .unwrap();
+        ger
+    }
+
+    /// The newest observed GER is signed by the relayer's key and admitted at
+    /// its nonce; an injected one or one still in the writer is not relayed
+    /// again.
+    #[tokio::test]
+    async fn relays_newest_observed_ger_once() {
+        let store: Arc<dyn Store> = Arc::new(InMemoryStore::new());
+        let mut relayer = relayer(store.clone());
+        assert_eq!(relayer.relay_once().await.unwrap(), RelayOutcome::Idle);
+
+        observe(&store, 0x10, 7).await;
+        let newest = observe(&store, 0x20, 9).await;
+        let RelayOutcome::Relayed(hash) = relayer.relay_once().await.unwrap() else {
+            panic!("the newest observed GER must be relayed");
+        };
+        let signer = format!("{:#x}", relayer.address());
+        assert_eq!(store.nonce_get(&signer).await.unwrap(), 1);
+        let txn = store.txn_get(hash).await.unwrap().expect("durable intent");
+        assert_eq!(txn.signer, relayer.address());
+        assert!(matches!(
+            crate::service_send_raw_txn::decode_write_call(txn.envelope.input()).unwrap(),
+            DecodedWriteCall::Ger { ger_bytes } if ger_bytes == newest
+        ));
+        assert!(
+            relayer
+                .service
+                .writer_handle
+                .as_ref()
+                .unwrap()
+                .is_inflight(&hash)
+        );
+
+        assert_eq!(
+            relayer.relay_once().await.unwrap(),
+            RelayOutcome::Skipped("in_writer")
+        );
+
+        store
+            .commit_ger_event_atomic(1, [1; 32], "0x01", &newest, None, None, 0)
+            .await
+            .unwrap();
+        assert_eq!(relayer.relay_once().await.unwrap(), RelayOutcome::Idle);
+        assert_eq!(store.nonce_get(&signer).await.unwrap(), 1);
+    }
+
+    /// Of several GERs appended in one L1 block, the one with the highest
+    /// info-tree leaf index is relayed, whatever its hash, and none once it
+    /// is injected.
+    #[tokio::test]
+    async fn relays_the_highest_leaf_of_one_block() {
+        use crate::l1_info_tree::{Frontier, leaf_value};
+        use crate::store::L1InfoTreeLeaf;
+
+        let store: Arc<dyn Store> = Arc::new(InMemoryStore::new());
+        let mut relayer = relayer(store.clone());
+        let mut gers = [
+            observe(&store, 0x50, 8).await,
+            observe(&store, 0x60, 8).await,
+        ];
+        // Append the larger hash first so hash order and leaf order disagree.
+        gers.sort_unstable_by(|a, b| b.cmp(a));
+        let mut frontier = Frontier::default();
+        let mut leaves = Vec::new();
+        for (index, ger) in gers.iter().enumerate() {
+            frontier.push(leaf_value(ger, &[0; 32], 1_008)).unwrap();
+            leaves.push(L1InfoTreeLeaf {
+                leaf_index: index as u32,
+                ger: *ger,
+                previous_block_hash: [0; 32],
+                timestamp: 1_008,
+                block_number: 8,
+                root: frontier.root(),
+            });
+        }
+        store
+            .append_l1_info_tree_leaves(&leaves, &frontier)
+            .await
+            .unwrap();
+
+        let RelayOutcome::Relayed(hash) = relayer.relay_once().await.unwrap() else {
+            panic!("a GER of the block must be relayed");
+        };
+        let txn = store.txn_get(hash).await.unwrap().expect("durable intent");
+        assert!(matches!(
+            crate::service_send_raw_txn::decode_write_call(txn.envelope.input()).unwrap(),
+            DecodedWriteCall::Ger { ger_bytes } if ger_bytes == gers[1]
+        ));
+
+        // Once the newest is injected the older GER of the block is not
+        // relayed in its place.
+        store
+            .commit_ger_event_atomic(1, [1; 32], "0x01", &gers[1], None, None, 0)
+            .await
+            .unwrap();
+        assert_eq!(relayer.relay_once().await.unwrap(), RelayOutcome::Idle);
+    }
+
+    /// A newer GER waits for the relayer's outstanding injection, then for
+    /// the minimum interval.
+    #[tokio::test]
+    async fn relayed_injections_are_spaced() {
+        let store: Arc<dyn Store> = Arc::new(InMemoryStore::new());
+        let mut relayer = relayer(store.clone());
+
+        observe(&store, 0x30, 5).await;
+        let RelayOutcome::Relayed(first) = relayer.relay_once().await.unwrap() else {
+            panic!("first GER must be relayed");
+        };
+        observe(&store, 0x40, 6).await;
+        assert_eq!(
+            relayer.relay_once().await.unwrap(),
+            RelayOutcome::Skipped("outstanding")
+        );
+
+        relayer
+            .service
+            .store
+            .txn_commit(first, Ok(()), 1, [1; 32])
+            .await
+            .unwrap();
+        relayer.service.writer_handle = None;
+        assert_eq!(
+            relayer.relay_once().await.unwrap(),
+            RelayOutcome::Skipped("rate_limited")
+        );
+    }
+}
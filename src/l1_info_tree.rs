@@ -0,0 +1,157 @@
+//! L1 info tree reconstruction.
+//!
+//! `PolygonZkEVMGlobalExitRootV2.updateExitRoot` appends one leaf per new
+//! global exit root to a 32-level append-only tree and emits
+//! `UpdateL1InfoTree`. The leaf is
+//! `keccak256(GER ‖ blockhash(block.number - 1) ‖ uint64(block.timestamp))`
+//! and `getRoot()` is the `l1InfoRoot` that claims are proven against.
+//!
+//! [`Frontier`] mirrors `DepositContractBase`'s `depositCount` + `_branch`
+//! state, so the indexer can grow the tree one leaf at a time and compare its
+//! root with the contract's without replaying history.
+
+use alloy::primitives::keccak256;
+
+/// Depth of the L1 info tree (the same `DepositContractBase` depth as the
+/// bridge's exit tree).
+pub const L1_INFO_TREE_DEPTH: usize = crate::exit::LOCAL_EXIT_TREE_DEPTH;
+
+/// `PolygonZkEVMGlobalExitRootV2.getLeafValue`.
+pub fn leaf_value(ger: &[u8; 32], previous_block_hash: &[u8; 32], timestamp: u64) -> [u8; 32] {
+    let mut packed = Vec::with_capacity(32 + 32 + 8);
+    packed.extend_from_slice(ger);
+    packed.extend_from_slice(previous_block_hash);
+    packed.extend_from_slice(&timestamp.to_be_bytes());
+    keccak256(packed).0
+}
+
+fn hash_pair(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    keccak256([left.as_slice(), right.as_slice()].concat()).0
+}
+
+/// Append-only frontier of the tree: the leaf count plus, per level, the
+/// left sibling still waiting for its right half.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frontier {
+    leaf_count: u32,
+    branch: [[u8; 32]; L1_INFO_TREE_DEPTH],
+}
+
+impl Default for Frontier {
+    fn default() -> Self {
+        Self {
+            leaf_count: 0,
+            branch: [[0u8; 32]; L1_INFO_TREE_DEPTH],
+        }
+    }
+}
+
+impl Frontier {
+    /// Rebuild a persisted frontier.
+    pub fn from_parts(leaf_count: u32, branch: [[u8; 32]; L1_INFO_TREE_DEPTH]) -> Self {
+        Self { leaf_count, branch }
+    }
+
+    pub fn leaf_count(&self) -> u32 {
+        self.leaf_count
+    }
+
+    pub fn branch(&self) -> &[[u8; 32]; L1_INFO_TREE_DEPTH] {
+        &self.branch
+    }
+
+    /// The branch as one 1024-byte blob, level 0 first (the persisted form).
+    pub fn branch_bytes(&self) -> Vec<u8> {
+        self.branch.concat()
+    }
+
+    /// Inverse of [`Frontier::branch_bytes`].
+    pub fn from_branch_bytes(leaf_count: u32, bytes: &[u8]) -> anyhow::Result<Self> {
+        anyhow::ensure!(
+            bytes.len() == 32 * L1_INFO_TREE_DEPTH,
+            "L1 info tree frontier is {} bytes, expected {}",
+            bytes.len(),
+            32 * L1_INFO_TREE_DEPTH
+        );
+        let mut branch = [[0u8; 32]; L1_INFO_TREE_DEPTH];
+        for (level, chunk) in branch.iter_mut().zip(bytes.chunks_exact(32)) {
+            level.copy_from_slice(chunk);
+        }
+        Ok(Self::from_parts(leaf_count, branch))
+    }
+
+    /// `DepositContractBase._addLeaf`.
+    pub fn push(&mut self, leaf: [u8; 32]) -> anyhow::Result<()> {
+        let size = self
+            .leaf_count
+            .checked_add(1)
+            .ok_or_else(|| anyhow::anyhow!("L1 info tree is full"))?;
+        self.leaf_count = size;
+        let mut node = leaf;
+        for height in 0..L1_INFO_TREE_DEPTH {
+            if (size >> height) & 1 == 1 {
+                self.branch[height] = node;
+                return Ok(());
+            }
+            node = hash_pair(&self.branch[height], &node);
+        }
+        unreachable!("a u32 leaf count always has a set bit below the tree depth")
+    }
+
+    /// `DepositContractBase.getRoot`.
+    pub fn root(&self) -> [u8; 32] {
+        let mut node = [0u8; 32];
+        let mut zero = [0u8; 32];
+        for height in 0..L1_INFO_TREE_DEPTH {
+            node = if (self.leaf_count >> height) & 1 == 1 {
+                hash_pair(&self.branch[height], &node)
+            } else {
+                hash_pair(&node, &zero)
+            };
+            zero = hash_pair(&zero, &zero);
+        }
+        node
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::primitives::B256;
+
+    #[test]
+    fn frontier_root_matches_full_tree_root() {
+        let mut frontier = Frontier::default();
+        let mut leaves = Vec::new();
+        assert_eq!(
+            B256::from(frontier.root()),
+            crate::exit::exit_tree_root(&leaves)
+        );
+        for i in 0..9u64 {
+            let leaf = leaf_value(&[i as u8; 32], &[0xAB; 32], 1_700_000_000 + i);
+            frontier.push(leaf).unwrap();
+            leaves.push(B256::from(leaf));
+            assert_eq!(
+                B256::from(frontier.root()),
+                crate::exit::exit_tree_root(&leaves),
+                "root diverged after {} leaves",
+                leaves.len()
+            );
+        }
+
+        let restored =
+            Frontier::from_branch_bytes(frontier.leaf_count(), &frontier.branch_bytes()).unwrap();
+        assert_eq!(restored, frontier);
+        assert!(Frontier::from_branch_bytes(1, &[0u8; 31]).is_err());
+    }
+
+    #[test]
+    fn leaf_value_packs_ger_block_hash_and_timestamp() {
+        let ger = [0x11; 32];
+        let parent = [0x22; 32];
+        let mut packed = [ger.as_slice(), parent.as_slice()].concat();
+        packed.extend_from_slice(&42u64.to_be_bytes());
+        assert_eq!(packed.len(), 72);
+        assert_eq!(leaf_value(&ger, &parent, 42), keccak256(packed).0);
+    }
+}
@@ -18,8 +18,9 @@
 //!
 //! ## How it integrates
 //!
-//! Spawned from `main.rs` after `ServiceState` is ready, given an L1 RPC URL
-//! and the GER manager contract address. Polls `eth_getLogs` for the two
+//! Spawned from `main.rs` after `ServiceState` is ready, given the L1 RPC
+//! endpoints (see [`crate::l1_rpc_pool`] for failover and the optional N-of-M
+//! quorum) and the GER manager contract address. Polls `eth_getLogs` for the two
 //! event signatures `PolygonZkEVMGlobalExitRootV2` is known to emit:
 //!   - `UpdateL1InfoTree(bytes32 mainnetExitRoot, bytes32 rollupExitRoot)`
 //!   - `UpdateGlobalExitRoot(bytes32 mainnetExitRoot, bytes32 rollupExitRoot)`
@@ -36,19 +37,46 @@
 //!     re-queries `zkevm_getExitRootsByGER` and gets resolved roots.
 //!
 //! Either ordering converges to a resolved entry. No race window.
+//!
+//! ## L1 info tree
+//!
+//! Each `UpdateL1InfoTree` is also one leaf of the contract's L1 info tree.
+//! The indexer appends those leaves to a local copy (see
+//! [`crate::l1_info_tree`]), numbering them from the contract's
+//! `depositCount()` just before the batch, and compares the resulting root
+//! with `getRoot()` at the batch frontier. A batch whose leaf count differs
+//! from the rise in `depositCount()` across it is retried. Matching roots advance the store's
+//! verified leaf count, which `zkevm_getL1InfoTreeLeaf` reports, so a claim's
+//! leaf and `l1InfoRoot` can be checked locally. The tree can only be rebuilt
+//! from leaf 0: a scan that starts mid-history keeps indexing exit-root pairs
+//! but leaves the tree alone until it is backfilled from the contract's
+//! deployment block.
+//!
+//! ## L1 reorgs
+//!
+//! After each batch the indexer records the hash of the batch's last block
+//! (see [`crate::store::L1ScannedRange`]). The next poll compares the parent
+//! hash of the following block with it; a mismatch means the chain it
+//! scanned was replaced. The indexer then walks the retained ranges back to
+//! the newest one whose last block is still canonical, rewinds the cursor
+//! there, clears `finalized_verified` on every `ger_entries` row observed
+//! above that block and truncates the L1 info tree to match, and re-scans.
+//! A reorg deeper than the retained ranges rewinds to the oldest of them.
 
-use alloy::eips::BlockNumberOrTag;
+use alloy::eips::{BlockId, BlockNumberOrTag};
 use alloy::primitives::Address;
-use alloy::providers::{Provider, ProviderBuilder};
 use alloy::rpc::types::{Filter, Log};
-use alloy::sol_types::SolEvent;
+use alloy::sol_types::{SolCall, SolEvent};
+use alloy_rpc_types_eth::TransactionRequest;
 use sha3::{Digest, Keccak256};
 use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::oneshot;
 
-use crate::store::Store;
+use crate::l1_info_tree::leaf_value;
+use crate::l1_rpc_pool::L1RpcPool;
+use crate::store::{L1InfoTreeLeaf, L1ScannedRange, Store};
 
 alloy_core::sol! {
     /// Standard PolygonZkEVMGlobalExitRootV2 event (current contracts).
@@ -65,6 +93,21 @@ alloy_core::sol! {
         bytes32 indexed mainnetExitRoot,
         bytes32 indexed rollupExitRoot,
     );
+
+    /// The L1 info tree views the indexer cross-checks its copy against.
+    interface PolygonZkEVMGlobalExitRootV2 {
+        function depositCount() external view returns (uint256);
+        function getRoot() external view returns (bytes32);
+    }
+}
+
+/// The parts of an L1 block header the indexer records.
+#[derive(Debug, Clone, Copy)]
+struct L1BlockInfo {
+    hash: [u8; 32],
+    timestamp: u64,
+    /// `blockhash(number - 1)`, the L1 info tree leaf's previous block hash.
+    parent_hash: [u8; 32],
 }
 
 /// Default poll cadence. Anvil ticks at 1s by default in our e2e stack;
@@ -77,8 +120,16 @@ const DEFAULT_POLL_INTERVAL: Duration = Duration::from_millis(1_000);
 /// otherwise overwhelm a single `eth_getLogs`.
 const DEFAULT_MAX_RANGE: u64 = 1_000;
 
+/// Scanned ranges kept for reorg detection. At the steady-state cadence of
+/// one range per L1 block this covers well over any reorg seen on Ethereum
+/// L1; a deeper one rewinds to the oldest retained range.
+const REORG_CHECKPOINTS: usize = 256;
+
 pub struct L1InfoTreeIndexer {
-    rpc_url: String,
+    /// Primary endpoint first, then fallbacks.
+    rpc_urls: Vec<String>,
+    /// Endpoints that must return a log identically before it is recorded.
+    quorum: usize,
     contract_address: Address,
     store: Arc<dyn Store>,
     poll_interval: Duration,
@@ -100,7 +151,8 @@ pub struct L1InfoTreeIndexer {
 impl L1InfoTreeIndexer {
     pub fn new(rpc_url: String, contract_address: Address, store: Arc<dyn Store>) -> Self {
         Self {
-            rpc_url,
+            rpc_urls: vec![rpc_url],
+            quorum: crate::l1_rpc_pool::DEFAULT_L1_RPC_QUORUM,
             contract_address,
             store,
             poll_interval: DEFAULT_POLL_INTERVAL,
@@ -110,6 +162,18 @@ impl L1InfoTreeIndexer {
         }
     }
 
+    /// Extra endpoints tried after the primary when it fails.
+    pub fn with_fallback_urls(mut self, urls: Vec<String>) -> Self {
+        self.rpc_urls.extend(urls);
+        self
+    }
+
+    /// Record an exit-root pair only once `quorum` endpoints return its log.
+    pub fn with_quorum(mut self, quorum: usize) -> Self {
+        self.quorum = quorum;
+        self
+    }
+
     /// Configure the single L1 scan frontier.
     pub fn with_evidence_tag(mut self, tag: crate::ger::EvidenceTag) -> Self {
         self.evidence_tag = tag;
@@ -124,12 +188,12 @@ impl L1InfoTreeIndexer {
         }
     }
 
-    async fn scan_head<P: Provider>(&self, provider: &P) -> anyhow::Result<u64> {
-        let tag = self.scan_block_tag();
-        let block = provider.get_block_by_number(tag).await?.ok_or_else(|| {
-            anyhow::anyhow!("L1 `{}` block is unavailable", self.evidence_tag.describe())
-        })?;
-        Ok(block.header.number)
+    async fn scan_head(&self, rpc: &L1RpcPool) -> anyhow::Result<u64> {
+        rpc.block_number_at(self.scan_block_tag())
+            .await?
+            .ok_or_else(|| {
+                anyhow::anyhow!("L1 `{}` block is unavailable", self.evidence_tag.describe())
+            })
     }
 
     /// Operator override for the indexer start block. Overrides both the
@@ -152,20 +216,19 @@ impl L1InfoTreeIndexer {
     ///
     /// Errors during polling are logged and the loop continues; we never want a
     /// transient L1 RPC blip to take down the whole service. Permanent failure
-    /// (e.g. malformed contract address) returns Err synchronously.
+    /// (e.g. a malformed RPC URL or a quorum larger than the endpoint count)
+    /// returns Err synchronously.
     pub fn spawn(self) -> anyhow::Result<oneshot::Sender<()>> {
         let (shutdown_tx, mut shutdown_rx) = oneshot::channel();
 
-        let provider = ProviderBuilder::new().connect_http(
-            self.rpc_url
-                .parse()
-                .map_err(|e| anyhow::anyhow!("invalid L1 RPC URL '{}': {}", self.rpc_url, e))?,
-        );
+        let rpc = L1RpcPool::connect(&self.rpc_urls, self.quorum)?;
 
         tokio::spawn(async move {
             tracing::info!(
                 contract = %self.contract_address,
-                rpc = %self.rpc_url,
+                rpc = %crate::l2_to_l1_claimer::redact_rpc_url(&self.rpc_urls[0]),
+                endpoints = rpc.len(),
+                quorum = rpc.quorum(),
                 poll_interval_ms = self.poll_interval.as_millis() as u64,
                 "L1InfoTreeIndexer starting"
             );
@@ -183,7 +246,7 @@ impl L1InfoTreeIndexer {
             // cursor on first boot after the migration; treat 0 as "no
             // cursor recorded yet" and fall back to head to avoid a
             // multi-million-block backfill on the first boot.
-            let head = self.scan_head(&provider).await.unwrap_or_else(|e| {
+            let head = self.scan_head(&rpc).await.unwrap_or_else(|e| {
                 tracing::error!(error = %e, tag = %self.evidence_tag.describe(), "L1InfoTreeIndexer: failed to fetch initial selected L1 block; starting at 0");
                 0
             });
@@ -235,7 +298,7 @@ impl L1InfoTreeIndexer {
                     _ = ticker.tick() => {}
                 }
 
-                if let Err(e) = self.poll_once(&provider, &mut last_processed).await {
+                if let Err(e) = self.poll_once(&rpc, &mut last_processed).await {
                     tracing::warn!(error = %e, last_processed, "L1InfoTreeIndexer poll failed, retrying");
                     metrics::counter!("l1_info_tree_indexer_poll_errors_total").increment(1);
                 }
@@ -247,18 +310,14 @@ impl L1InfoTreeIndexer {
         Ok(shutdown_tx)
     }
 
-    async fn poll_once<P: Provider>(
-        &self,
-        provider: &P,
-        last_processed: &mut u64,
-    ) -> anyhow::Result<()> {
-        let head = self.scan_head(provider).await?;
-        self.poll_to_head(provider, last_processed, head).await
+    async fn poll_once(&self, rpc: &L1RpcPool, last_processed: &mut u64) -> anyhow::Result<()> {
+        let head = self.scan_head(rpc).await?;
+        self.poll_to_head(rpc, last_processed, head).await
     }
 
-    async fn poll_to_head<P: Provider>(
+    async fn poll_to_head(
         &self,
-        provider: &P,
+        rpc: &L1RpcPool,
         last_processed: &mut u64,
         head: u64,
     ) -> anyhow::Result<()> {
@@ -266,12 +325,35 @@ impl L1InfoTreeIndexer {
             return Ok(());
         }
 
+        // Per-poll cache: one `eth_getBlockByNumber` per *unique* L1 block in
+        // the batch, used to populate the L1 timestamp written to
+        // `ger_entries.timestamp`. Events from the same block share an RPC
+        // roundtrip, so a steady-state poll that sees 0–1 unique blocks per
+        // tick costs nothing extra in the common case.
+        let mut blocks: HashMap<u64, L1BlockInfo> = HashMap::new();
+
+        if self
+            .rewind_on_reorg(rpc, last_processed, &mut blocks)
+            .await?
+        {
+            return Ok(());
+        }
+
         // One configured scan is the sole source of L1 root evidence. In
         // `safe`/`finalized` mode, decomposition intentionally becomes visible
         // only when that frontier reaches the event.
         let from = *last_processed + 1;
         let to = head.min(from + self.max_range - 1);
 
+        // Fetched before the logs, so a reorg that lands mid-batch leaves a
+        // stale recorded hash (caught by the next poll) rather than a fresh
+        // hash over stale logs.
+        let to_hash = self
+            .fetch_block(rpc, to, &mut blocks)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("L1 block {to} is unavailable"))?
+            .hash;
+
         // Single filter matching either event signature; the topic-OR is
         // expressed by passing both signature hashes in topic[0].
         let filter = Filter::new()
@@ -283,22 +365,22 @@ impl L1InfoTreeIndexer {
                 UpdateGlobalExitRoot::SIGNATURE_HASH,
             ]);
 
-        let logs: Vec<Log> = provider.get_logs(&filter).await?;
+        // Under a quorum, only logs enough endpoints agree on; a read they
+        // cannot settle errors here and the same window is retried.
+        let logs: Vec<Log> = rpc.get_logs(&filter).await?;
         let log_count = logs.len();
 
-        // Per-poll cache: one `eth_getBlockByNumber` per *unique* L1 block in
-        // the batch, used to populate the L1 timestamp written to
-        // `ger_entries.timestamp`. Events from the same block share an RPC
-        // roundtrip, so a steady-state poll that sees 0–1 unique blocks per
-        // tick costs nothing extra in the common case.
-        let mut block_timestamps: HashMap<u64, u64> = HashMap::new();
-
         let mut indexed = 0usize;
+        // `(L1 block, GER)` of each L1 info tree leaf in the batch, in order.
+        let mut tree_leaves = Vec::new();
         for log in logs {
             let block_number = log.block_number.unwrap_or(0);
             let timestamp = self
-                .resolve_block_timestamp(provider, block_number, &mut block_timestamps)
+                .resolve_block_timestamp(rpc, block_number, &mut blocks)
                 .await;
+            if let Some(ger) = info_tree_leaf_ger(&log) {
+                tree_leaves.push((block_number, ger));
+            }
 
             match self.process_log(&log, block_number, timestamp).await {
                 Ok(true) => indexed += 1,
@@ -334,6 +416,26 @@ impl L1InfoTreeIndexer {
             }
         }
 
+        // The tree must stay contiguous, so a failure here keeps the batch
+        // retryable exactly like an evidence-write failure above.
+        if !tree_leaves.is_empty()
+            && let Err(e) = self
+                .extend_info_tree(rpc, &tree_leaves, from, to, &mut blocks)
+                .await
+        {
+            tracing::warn!(
+                error = %e,
+                from,
+                to,
+                "L1InfoTreeIndexer: L1 info tree update failed; leaving batch unadvanced for retry"
+            );
+            metrics::counter!("l1_info_tree_errors_total").increment(1);
+            return Err(e.context(format!(
+                "L1InfoTreeIndexer: L1 info tree update failed; batch [{from}, {to}] left \
+                 unadvanced (retryable)"
+            )));
+        }
+
         // INFO-level activity log: bumped from debug per Igor's review on PR #41.
         // Quiet ticks (no events in the polled range) are kept at debug so we
         // don't flood the log file at the 1s poll cadence, but any range that
@@ -354,6 +456,27 @@ impl L1InfoTreeIndexer {
 
         *last_processed = to;
 
+        // A missing checkpoint only suspends reorg detection until the next
+        // recorded batch, so it does not hold the scan back.
+        let range = L1ScannedRange {
+            start: from,
+            end: to,
+            end_hash: to_hash,
+        };
+        if let Err(e) = self
+            .store
+            .record_l1_scanned_range(range, REORG_CHECKPOINTS)
+            .await
+        {
+            tracing::warn!(
+                error = %e,
+                from,
+                to,
+                "L1InfoTreeIndexer: failed to record scanned range; reorg detection resumes \
+                 after the next recorded batch"
+            );
+        }
+
         // Persist the selected-policy cursor so a restart resumes from here.
         // Failure to persist is logged but does
         // not abort the loop — we'd rather keep indexing on a transient
@@ -370,6 +493,81 @@ impl L1InfoTreeIndexer {
         Ok(())
     }
 
+    /// Detect a reorg below the cursor and, if there was one, rewind the
+    /// cursor and the evidence recorded above the fork. Returns whether it
+    /// rewound; the caller then re-scans from the fork on the next poll.
+    ///
+    /// Only runs when the newest recorded range ends at the cursor, i.e. the
+    /// cursor has not been moved by an operator override since.
+    async fn rewind_on_reorg(
+        &self,
+        rpc: &L1RpcPool,
+        last_processed: &mut u64,
+        blocks: &mut HashMap<u64, L1BlockInfo>,
+    ) -> anyhow::Result<bool> {
+        let ranges = self.store.list_l1_scanned_ranges().await?;
+        let Some(newest) = ranges.first().filter(|r| r.end == *last_processed) else {
+            return Ok(false);
+        };
+        let next = newest.end + 1;
+        let parent_hash = self
+            .fetch_block(rpc, next, blocks)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("L1 block {next} is unavailable"))?
+            .parent_hash;
+        if parent_hash == newest.end_hash {
+            return Ok(false);
+        }
+
+        // The newest range's last block is gone; find the newest one still
+        // on the canonical chain.
+        let mut fork_block = None;
+        for range in &ranges[1..] {
+            let canonical = self
+                .fetch_block(rpc, range.end, blocks)
+                .await?
+                .is_some_and(|block| block.hash == range.end_hash);
+            if canonical {
+                fork_block = Some(range.end);
+                break;
+            }
+        }
+        let within_checkpoints = fork_block.is_some();
+        let oldest = ranges.last().unwrap_or(newest);
+        let fork_block = fork_block.unwrap_or(oldest.start.saturating_sub(1));
+
+        let rewind = self.store.rewind_l1_evidence(fork_block).await?;
+        let depth = *last_processed - fork_block;
+        tracing::warn!(
+            depth,
+            fork_block,
+            cursor = *last_processed,
+            recorded_hash = %hex::encode(newest.end_hash),
+            canonical_parent_hash = %hex::encode(parent_hash),
+            within_checkpoints,
+            unverified_gers = rewind.unverified_gers,
+            removed_leaves = rewind.removed_leaves,
+            "L1InfoTreeIndexer: L1 reorg detected; rewound cursor to the fork block"
+        );
+        if !within_checkpoints {
+            tracing::error!(
+                fork_block,
+                checkpoints = ranges.len(),
+                "L1InfoTreeIndexer: L1 reorg is deeper than the retained scanned ranges; \
+                 rewound to the oldest one. Evidence below it was not re-checked"
+            );
+        }
+        let outcome = if within_checkpoints {
+            "within_checkpoints"
+        } else {
+            "beyond_checkpoints"
+        };
+        metrics::counter!("l1_info_tree_indexer_reorgs_total", "outcome" => outcome).increment(1);
+        metrics::histogram!("l1_info_tree_indexer_reorg_depth_blocks").record(depth as f64);
+        *last_processed = fork_block;
+        Ok(true)
+    }
+
     async fn process_log(
         &self,
         log: &Log,
@@ -420,32 +618,219 @@ impl L1InfoTreeIndexer {
         Ok(true)
     }
 
+    /// Append the batch's `UpdateL1InfoTree` leaves to the reconstructed
+    /// L1 info tree and cross-check its root against `getRoot()` at `to`.
+    ///
+    /// The window `[from, to]` must hold exactly the leaves the contract
+    /// appended in it: `depositCount()` at `to` minus `depositCount()` at
+    /// `from - 1`. A window that does not (a log the quorum dropped, or an
+    /// endpoint serving a different chain) fails the batch so it is retried.
+    /// Otherwise its first leaf is number `depositCount()` at `from - 1`.
+    /// Leaves the store already holds (a retried or re-scanned window) are
+    /// compared, not re-appended. A window that starts past the stored tree
+    /// is skipped: the tree can only grow from leaf 0. Neither a skipped
+    /// window nor a root mismatch fails the batch, because retrying cannot
+    /// change either; both are logged and counted, and leave the verified
+    /// mark where it is. The views are read under the same quorum as the
+    /// logs.
+    async fn extend_info_tree(
+        &self,
+        rpc: &L1RpcPool,
+        leaves: &[(u64, [u8; 32])],
+        from: u64,
+        to: u64,
+        blocks: &mut HashMap<u64, L1BlockInfo>,
+    ) -> anyhow::Result<()> {
+        let deposit_count = self
+            .ger_manager_call(rpc, to, PolygonZkEVMGlobalExitRootV2::depositCountCall {})
+            .await?;
+        let on_chain_count = u32::try_from(deposit_count)?;
+        let first = match from.checked_sub(1) {
+            Some(before) => u32::try_from(
+                self.ger_manager_call(
+                    rpc,
+                    before,
+                    PolygonZkEVMGlobalExitRootV2::depositCountCall {},
+                )
+                .await?,
+            )?,
+            None => 0,
+        };
+        if on_chain_count.checked_sub(first) != Some(u32::try_from(leaves.len())?) {
+            metrics::counter!("l1_info_tree_window_mismatches_total").increment(1);
+            anyhow::bail!(
+                "depositCount() went from {first} to {on_chain_count} over L1 blocks \
+                 [{from}, {to}], but the window has {} UpdateL1InfoTree events",
+                leaves.len()
+            );
+        }
+
+        let mut frontier = self.store.get_l1_info_tree_state().await?.frontier;
+        let stored = frontier.leaf_count();
+        if first > stored {
+            tracing::warn!(
+                first_leaf = first,
+                stored_leaves = stored,
+                l1_block = to,
+                "L1InfoTreeIndexer: window starts past the reconstructed L1 info tree; \
+                 backfill with --l1-indexer-from-block at or before the GER manager's \
+                 deployment to rebuild it"
+            );
+            metrics::counter!("l1_info_tree_gaps_total").increment(1);
+            return Ok(());
+        }
+
+        let mut appended = Vec::new();
+        for (index, &(block_number, ger)) in (first..).zip(leaves) {
+            let block = self
+                .fetch_block(rpc, block_number, blocks)
+                .await?
+                .ok_or_else(|| anyhow::anyhow!("L1 block {block_number} is unavailable"))?;
+            if index < stored {
+                let same = self
+                    .store
+                    .get_l1_info_tree_leaf(index)
+                    .await?
+                    .is_some_and(|leaf| {
+                        leaf.ger == ger
+                            && leaf.previous_block_hash == block.parent_hash
+                            && leaf.timestamp == block.timestamp
+                    });
+                if !same {
+                    tracing::error!(
+                        leaf_index = index,
+                        l1_block = block_number,
+                        ger = %hex::encode(ger),
+                        "L1InfoTreeIndexer: L1 info tree leaf differs from the stored leaf"
+                    );
+                    metrics::counter!("l1_info_tree_root_checks_total", "outcome" => "mismatch")
+                        .increment(1);
+                    return Ok(());
+                }
+                continue;
+            }
+            frontier.push(leaf_value(&ger, &block.parent_hash, block.timestamp))?;
+            appended.push(L1InfoTreeLeaf {
+                leaf_index: index,
+                ger,
+                previous_block_hash: block.parent_hash,
+                timestamp: block.timestamp,
+                block_number,
+                root: frontier.root(),
+            });
+        }
+        if !appended.is_empty() {
+            self.store
+                .append_l1_info_tree_leaves(&appended, &frontier)
+                .await?;
+            metrics::counter!("l1_info_tree_leaves_appended_total")
+                .increment(appended.len() as u64);
+        }
+        metrics::gauge!("l1_info_tree_leaf_count").set(f64::from(frontier.leaf_count()));
+
+        // The root the contract had at `to` covers its first `on_chain_count`
+        // leaves; a re-scanned window can be behind the stored tip.
+        let local_root = if on_chain_count == frontier.leaf_count() {
+            frontier.root()
+        } else {
+            match on_chain_count.checked_sub(1) {
+                Some(last) => match self.store.get_l1_info_tree_leaf(last).await? {
+                    Some(leaf) => leaf.root,
+                    None => return Ok(()),
+                },
+                None => return Ok(()),
+            }
+        };
+        let on_chain_root = self
+            .ger_manager_call(rpc, to, PolygonZkEVMGlobalExitRootV2::getRootCall {})
+            .await?
+            .0;
+        if on_chain_root == local_root {
+            self.store.set_l1_info_tree_verified(on_chain_count).await?;
+            metrics::counter!("l1_info_tree_root_checks_total", "outcome" => "match").increment(1);
+            tracing::debug!(
+                leaf_count = on_chain_count,
+                root = %hex::encode(local_root),
+                l1_block = to,
+                "L1InfoTreeIndexer: L1 info root verified"
+            );
+        } else {
+            tracing::error!(
+                leaf_count = on_chain_count,
+                local_root = %hex::encode(local_root),
+                on_chain_root = %hex::encode(on_chain_root),
+                l1_block = to,
+                "L1InfoTreeIndexer: reconstructed L1 info root does not match getRoot()"
+            );
+            metrics::counter!("l1_info_tree_root_checks_total", "outcome" => "mismatch")
+                .increment(1);
+        }
+        Ok(())
+    }
+
+    /// `call` against the GER manager as of L1 block `block`.
+    async fn ger_manager_call<C: SolCall>(
+        &self,
+        rpc: &L1RpcPool,
+        block: u64,
+        call: C,
+    ) -> anyhow::Result<C::Return> {
+        let tx = TransactionRequest::default()
+            .to(self.contract_address)
+            .input(call.abi_encode().into());
+        let raw = rpc
+            .call_agreed(C::SIGNATURE, |p| {
+                let tx = tx.clone();
+                async move { p.call(tx).block(BlockId::number(block)).await }
+            })
+            .await?;
+        Ok(C::abi_decode_returns(&raw)?)
+    }
+
+    /// Fetch the header fields of L1 block `block_number`, using and updating
+    /// the per-poll cache. `None` when the endpoint does not know the block.
+    async fn fetch_block(
+        &self,
+        rpc: &L1RpcPool,
+        block_number: u64,
+        cache: &mut HashMap<u64, L1BlockInfo>,
+    ) -> anyhow::Result<Option<L1BlockInfo>> {
+        if let Some(&block) = cache.get(&block_number) {
+            return Ok(Some(block));
+        }
+        let block = rpc
+            .call("eth_getBlockByNumber", |p| async move {
+                p.get_block_by_number(BlockNumberOrTag::Number(block_number))
+                    .await
+            })
+            .await?
+            .map(|block| L1BlockInfo {
+                hash: block.header.hash.0,
+                timestamp: block.header.timestamp,
+                parent_hash: block.header.parent_hash.0,
+            });
+        if let Some(block) = block {
+            cache.insert(block_number, block);
+        }
+        Ok(block)
+    }
+
     /// Resolve the L1 block timestamp for a given block number, using and
     /// updating the per-poll cache. Returns 0 if the block is unknown
     /// (block_number == 0) or if the RPC lookup fails — the indexer's
     /// upsert path keeps the row writable in that case, and the next
     /// successful poll will overwrite with the real timestamp.
-    async fn resolve_block_timestamp<P: Provider>(
+    async fn resolve_block_timestamp(
         &self,
-        provider: &P,
+        rpc: &L1RpcPool,
         block_number: u64,
-        cache: &mut HashMap<u64, u64>,
+        cache: &mut HashMap<u64, L1BlockInfo>,
     ) -> u64 {
         if block_number == 0 {
             return 0;
         }
-        if let Some(&ts) = cache.get(&block_number) {
-            return ts;
-        }
-        match provider
-            .get_block_by_number(BlockNumberOrTag::Number(block_number))
-            .await
-        {
-            Ok(Some(block)) => {
-                let ts = block.header.timestamp;
-                cache.insert(block_number, ts);
-                ts
-            }
+        match self.fetch_block(rpc, block_number, cache).await {
+            Ok(Some(block)) => block.timestamp,
             Ok(None) => {
                 tracing::debug!(
                     block = block_number,
@@ -465,6 +850,14 @@ impl L1InfoTreeIndexer {
     }
 }
 
+/// The GER an `UpdateL1InfoTree` log appends to the L1 info tree. The legacy
+/// `UpdateGlobalExitRoot` event predates the tree and appends nothing.
+fn info_tree_leaf_ger(log: &Log) -> Option<[u8; 32]> {
+    let topics = log.topics();
+    (topics.len() >= 3 && topics[0] == UpdateL1InfoTree::SIGNATURE_HASH)
+        .then(|| combined_ger(&topics[1].0, &topics[2].0))
+}
+
 fn combined_ger(mainnet: &[u8; 32], rollup: &[u8; 32]) -> [u8; 32] {
     let mut hasher = Keccak256::new();
     hasher.update(mainnet);
@@ -477,7 +870,7 @@ mod tests {
     use super::*;
     use crate::store::memory::InMemoryStore;
     use alloy::primitives::{B256, Bytes, LogData, TxHash};
-    use alloy::providers::ProviderBuilder;
+    use alloy::providers::{Provider, ProviderBuilder};
     use alloy_transport::mock::Asserter;
 
     #[test]
@@ -515,6 +908,14 @@ mod tests {
         )
     }
 
+    /// A single-endpoint pool answering from `asserter`.
+    fn mocked_rpc(asserter: &Asserter) -> L1RpcPool {
+        let provider = ProviderBuilder::new()
+            .connect_mocked_client(asserter.clone())
+            .erased();
+        L1RpcPool::from_providers(vec![provider], 1).unwrap()
+    }
+
     /// Build an `UpdateL1InfoTree` log carrying the `(mainnet, rollup)` pair at
     /// `block`, shaped exactly as `process_log` decodes it (topic0 = event sig,
     /// topic1 = mainnet, topic2 = rollup).
@@ -538,6 +939,21 @@ mod tests {
         }
     }
 
+    /// An L1 block whose header carries `parent_hash` and `timestamp`.
+    fn l1_block(number: u64, parent_hash: [u8; 32], timestamp: u64) -> alloy::rpc::types::Block {
+        alloy::rpc::types::Block::empty(alloy::rpc::types::Header::new(alloy::consensus::Header {
+            number,
+            parent_hash: B256::from(parent_hash),
+            timestamp,
+            ..Default::default()
+        }))
+    }
+
+    /// ABI-encoded `uint256` / `bytes32` return data for a mocked `eth_call`.
+    fn word(value: impl Into<B256>) -> Bytes {
+        Bytes::copy_from_slice(value.into().as_slice())
+    }
+
     /// The one selected scan writes roots and provenance together, then advances
     /// its one cursor. The strict gate can therefore trust exactly those rows.
     #[tokio::test]
@@ -550,13 +966,21 @@ mod tests {
         let ger = combined_ger(&mainnet.0, &rollup.0);
 
         let asserter = Asserter::new();
-        let provider = ProviderBuilder::new().connect_mocked_client(asserter.clone());
+        let rpc = mocked_rpc(&asserter);
         let mut last_processed = 0u64;
 
+        asserter.push_success(&l1_block(10, [0x09; 32], 1_002));
         asserter.push_success(&vec![pair_log(mainnet, rollup, 8)]);
-        asserter.push_success(&Option::<serde_json::Value>::None); // block ts → 0
+        asserter.push_success(&l1_block(8, [0x07; 32], 1_000));
+        asserter.push_success(&word(B256::with_last_byte(1))); // depositCount() at 10
+        asserter.push_success(&word(B256::ZERO)); // depositCount() at 0
+        asserter.push_success(&word(crate::l1_info_tree::leaf_value(
+            &ger,
+            &[0x07; 32],
+            1_000,
+        ))); // getRoot(): not the 1-leaf root, so the tree stays unverified
         indexer
-            .poll_to_head(&provider, &mut last_processed, 10)
+            .poll_to_head(&rpc, &mut last_processed, 10)
             .await
             .unwrap();
         assert_eq!(last_processed, 10);
@@ -601,13 +1025,14 @@ mod tests {
         let rollup = B256::from([0x0Du8; 32]);
 
         let asserter = Asserter::new();
-        let provider = ProviderBuilder::new().connect_mocked_client(asserter.clone());
+        let rpc = mocked_rpc(&asserter);
+        asserter.push_success(&l1_block(100, [0x63; 32], 1_100));
         asserter.push_success(&vec![pair_log(mainnet, rollup, 8)]);
         asserter.push_success(&Option::<serde_json::Value>::None);
 
         let mut last_processed = 0u64;
         let err = indexer
-            .poll_to_head(&provider, &mut last_processed, 100)
+            .poll_to_head(&rpc, &mut last_processed, 100)
             .await
             .expect_err("a durable evidence-write failure must fail the batch");
         assert!(
@@ -620,6 +1045,279 @@ mod tests {
         );
     }
 
+    /// Under a 2-of-2 quorum, a pair only one endpoint returns fails the batch
+    /// without advancing the cursor; once both return it, it is indexed.
+    #[tokio::test]
+    async fn quorum_disagreement_leaves_batch_retryable() {
+        let store: Arc<dyn Store> = Arc::new(InMemoryStore::new());
+        let indexer = test_indexer(store.clone());
+        let (primary, fallback) = (Asserter::new(), Asserter::new());
+        let rpc = L1RpcPool::from_providers(
+            [&primary, &fallback]
+                .into_iter()
+                .map(|a| {
+                    ProviderBuilder::new()
+                        .connect_mocked_client(a.clone())
+                        .erased()
+                })
+                .collect(),
+            2,
+        )
+        .unwrap();
+
+        let mainnet = B256::from([0x0Eu8; 32]);
+        let rollup = B256::from([0x0Fu8; 32]);
+        let ger = combined_ger(&mainnet.0, &rollup.0);
+
+        primary.push_success(&l1_block(10, [0x09; 32], 1_002));
+        primary.push_success(&vec![pair_log(mainnet, rollup, 8)]);
+        fallback.push_success(&Vec::<alloy::rpc::types::Log>::new());
+        let mut last_processed = 0u64;
+        let err = indexer
+            .poll_to_head(&rpc, &mut last_processed, 10)
+            .await
+            .expect_err("an unsettled quorum read must fail the batch");
+        assert!(
+            err.downcast_ref::<crate::l1_rpc_pool::L1QuorumError>()
+                .is_some(),
+            "{err:#}"
+        );
+        assert_eq!(last_processed, 0);
+        assert!(store.get_ger_entry(&ger).await.unwrap().is_none());
+
+        primary.push_success(&l1_block(10, [0x09; 32], 1_002));
+        primary.push_success(&vec![pair_log(mainnet, rollup, 8)]);
+        fallback.push_success(&vec![pair_log(mainnet, rollup, 8)]);
+        primary.push_success(&l1_block(8, [0x07; 32], 1_000));
+        // depositCount() at 10 and 0, then getRoot(), from both endpoints.
+        for endpoint in [&primary, &fallback] {
+            endpoint.push_success(&word(B256::with_last_byte(1)));
+            endpoint.push_success(&word(B256::ZERO));
+            endpoint.push_success(&word(B256::ZERO));
+        }
+        indexer
+            .poll_to_head(&rpc, &mut last_processed, 10)
+            .await
+            .unwrap();
+        assert_eq!(last_processed, 10);
+        assert!(store.get_ger_entry(&ger).await.unwrap().is_some());
+    }
+
+    /// `UpdateL1InfoTree` leaves are appended in contract order and verified
+    /// against `getRoot()`. A later mismatching root keeps the leaf but not
+    /// the verified mark, and does not hold the batch back.
+    #[tokio::test]
+    async fn info_tree_leaves_append_and_verify_against_get_root() {
+        use crate::l1_info_tree::{Frontier, leaf_value};
+
+        let store: Arc<dyn Store> = Arc::new(InMemoryStore::new());
+        let indexer = test_indexer(store.clone());
+        let asserter = Asserter::new();
+        let rpc = mocked_rpc(&asserter);
+
+        let pairs = [
+            (B256::from([0x21u8; 32]), B256::from([0x22u8; 32]), 8u64),
+            (B256::from([0x23u8; 32]), B256::from([0x24u8; 32]), 9u64),
+        ];
+        let mut expected = Frontier::default();
+        let mut logs = Vec::new();
+        let mut blocks = Vec::new();
+        for (mainnet, rollup, block) in pairs {
+            let ger = combined_ger(&mainnet.0, &rollup.0);
+            expected
+                .push(leaf_value(&ger, &[block as u8; 32], 1_000 + block))
+                .unwrap();
+            logs.push(pair_log(mainnet, rollup, block));
+            blocks.push(l1_block(block, [block as u8; 32], 1_000 + block));
+        }
+        let tip = l1_block(10, [10; 32], 1_010);
+        asserter.push_success(&tip);
+        asserter.push_success(&logs);
+        for block in &blocks {
+            asserter.push_success(block);
+        }
+        asserter.push_success(&word(B256::with_last_byte(2)));
+        asserter.push_success(&word(B256::ZERO));
+        asserter.push_success(&word(expected.root()));
+
+        let mut last_processed = 0u64;
+        indexer
+            .poll_to_head(&rpc, &mut last_processed, 10)
+            .await
+            .unwrap();
+        let state = store.get_l1_info_tree_state().await.unwrap();
+        assert_eq!(state.frontier, expected);
+        assert_eq!(state.verified_leaf_count, 2);
+        let second_ger = combined_ger(&pairs[1].0.0, &pairs[1].1.0);
+        let leaf = store
+            .get_l1_info_tree_leaf_by_ger(&second_ger)
+            .await
+            .unwrap()
+            .expect("leaf indexed by GER");
+        assert_eq!(leaf.leaf_index, 1);
+        assert_eq!(leaf.previous_block_hash, [9u8; 32]);
+        assert_eq!(leaf.root, expected.root());
+
+        let (mainnet, rollup) = (B256::from([0x25u8; 32]), B256::from([0x26u8; 32]));
+        asserter.push_success(&l1_block(11, tip.header.hash.0, 1_011)); // reorg check
+        asserter.push_success(&l1_block(20, [19; 32], 1_020));
+        asserter.push_success(&vec![pair_log(mainnet, rollup, 12)]);
+        asserter.push_success(&l1_block(12, [12; 32], 1_012));
+        asserter.push_success(&word(B256::with_last_byte(3)));
+        asserter.push_success(&word(B256::with_last_byte(2)));
+        asserter.push_success(&word(B256::repeat_byte(0xEE)));
+        indexer
+            .poll_to_head(&rpc, &mut last_processed, 20)
+            .await
+            .unwrap();
+        assert_eq!(
+            last_processed, 20,
+            "a root mismatch must not stall the scan"
+        );
+        let state = store.get_l1_info_tree_state().await.unwrap();
+        assert_eq!(state.frontier.leaf_count(), 3);
+        assert_eq!(state.verified_leaf_count, 2);
+    }
+
+    /// A window whose `UpdateL1InfoTree` count differs from the rise in
+    /// `depositCount()` across it fails the batch, appends nothing and leaves
+    /// the cursor for a retry.
+    #[tokio::test]
+    async fn info_tree_window_short_of_deposit_count_is_retried() {
+        let store: Arc<dyn Store> = Arc::new(InMemoryStore::new());
+        let indexer = test_indexer(store.clone());
+        let asserter = Asserter::new();
+        let rpc = mocked_rpc(&asserter);
+
+        let (mainnet, rollup) = (B256::from([0x41u8; 32]), B256::from([0x42u8; 32]));
+        asserter.push_success(&l1_block(10, [0x09; 32], 1_010));
+        asserter.push_success(&vec![pair_log(mainnet, rollup, 8)]);
+        asserter.push_success(&l1_block(8, [0x07; 32], 1_008));
+        asserter.push_success(&word(B256::with_last_byte(2))); // depositCount() at 10
+        asserter.push_success(&word(B256::ZERO)); // depositCount() at 0
+
+        let mut last_processed = 0u64;
+        let err = indexer
+            .poll_to_head(&rpc, &mut last_processed, 10)
+            .await
+            .expect_err("a window missing a leaf must fail the batch");
+        assert!(format!("{err:#}").contains("went from 0 to 2"), "{err:#}");
+        assert_eq!(last_processed, 0);
+        assert_eq!(
+            store.get_l1_info_tree_state().await.unwrap(),
+            Default::default()
+        );
+    }
+
+    /// A window that starts past the stored tree (a scan begun mid-history)
+    /// still indexes its exit-root pairs but appends no leaves.
+    #[tokio::test]
+    async fn info_tree_waits_for_backfill_when_scan_starts_mid_history() {
+        let store: Arc<dyn Store> = Arc::new(InMemoryStore::new());
+        let indexer = test_indexer(store.clone());
+        let asserter = Asserter::new();
+        let rpc = mocked_rpc(&asserter);
+
+        let (mainnet, rollup) = (B256::from([0x31u8; 32]), B256::from([0x32u8; 32]));
+        asserter.push_success(&l1_block(510, [0x02; 32], 5_100));
+        asserter.push_success(&vec![pair_log(mainnet, rollup, 500)]);
+        asserter.push_success(&l1_block(500, [0x01; 32], 5_000));
+        asserter.push_success(&word(B256::with_last_byte(40)));
+        asserter.push_success(&word(B256::with_last_byte(39)));
+
+        let mut last_processed = 450u64;
+        indexer
+            .poll_to_head(&rpc, &mut last_processed, 510)
+            .await
+            .unwrap();
+        assert_eq!(last_processed, 510);
+        let ger = combined_ger(&mainnet.0, &rollup.0);
+        assert!(store.get_ger_entry(&ger).await.unwrap().is_some());
+        assert_eq!(
+            store.get_l1_info_tree_state().await.unwrap(),
+            Default::default()
+        );
+    }
+
+    /// A block whose parent hash no longer matches the recorded range end
+    /// rewinds the cursor to the newest range still on the canonical chain,
+    /// un-verifies the GERs observed above it and truncates the tree.
+    #[tokio::test]
+    async fn reorg_rewinds_cursor_and_evidence_to_the_fork_block() {
+        let store: Arc<dyn Store> = Arc::new(InMemoryStore::new());
+        let indexer = test_indexer(store.clone());
+        let asserter = Asserter::new();
+        let rpc = mocked_rpc(&asserter);
+        let mut last_processed = 0u64;
+
+        let block_10 = l1_block(10, [0x09; 32], 1_010);
+        asserter.push_success(&block_10);
+        asserter.push_success(&Vec::<alloy::rpc::types::Log>::new());
+        indexer
+            .poll_to_head(&rpc, &mut last_processed, 10)
+            .await
+            .unwrap();
+
+        let (mainnet, rollup) = (B256::from([0x41u8; 32]), B256::from([0x42u8; 32]));
+        let ger = combined_ger(&mainnet.0, &rollup.0);
+        let mut frontier = crate::l1_info_tree::Frontier::default();
+        frontier
+            .push(crate::l1_info_tree::leaf_value(&ger, &[0x0E; 32], 1_015))
+            .unwrap();
+        asserter.push_success(&l1_block(11, block_10.header.hash.0, 1_011));
+        asserter.push_success(&l1_block(20, [0x13; 32], 1_020));
+        asserter.push_success(&vec![pair_log(mainnet, rollup, 15)]);
+        asserter.push_success(&l1_block(15, [0x0E; 32], 1_015));
+        asserter.push_success(&word(B256::with_last_byte(1)));
+        asserter.push_success(&word(B256::ZERO));
+        asserter.push_success(&word(frontier.root()));
+        indexer
+            .poll_to_head(&rpc, &mut last_processed, 20)
+            .await
+            .unwrap();
+        assert!(
+            store
+                .get_ger_entry(&ger)
+                .await
+                .unwrap()
+                .unwrap()
+                .evidence_verified
+        );
+        assert_eq!(
+            store
+                .get_l1_info_tree_state()
+                .await
+                .unwrap()
+                .verified_leaf_count,
+            1
+        );
+
+        // Block 21 builds on a different block 20; block 10 is unchanged.
+        asserter.push_success(&l1_block(21, [0xFF; 32], 1_021));
+        asserter.push_success(&block_10);
+        indexer
+            .poll_to_head(&rpc, &mut last_processed, 30)
+            .await
+            .unwrap();
+        assert_eq!(last_processed, 10, "cursor rewinds to the fork block");
+        assert_eq!(store.get_l1_evidence_cursor().await.unwrap(), 10);
+        let entry = store.get_ger_entry(&ger).await.unwrap().unwrap();
+        assert!(
+            !entry.evidence_verified,
+            "a GER observed above the fork is no longer verified"
+        );
+        assert_eq!(
+            store.get_l1_info_tree_state().await.unwrap(),
+            Default::default()
+        );
+        let ranges = store.list_l1_scanned_ranges().await.unwrap();
+        assert_eq!(ranges.len(), 1);
+        assert_eq!(
+            (ranges[0].end, ranges[0].end_hash),
+            (10, block_10.header.hash.0)
+        );
+    }
+
     #[test]
     fn one_tag_and_from_block_drive_the_single_cursor() {
         let store: Arc<dyn Store> = Arc::new(InMemoryStore::new());
@@ -0,0 +1,518 @@
+//! Several L1 JSON-RPC endpoints behind one handle.
+//!
+//! Every call goes to the healthiest endpoint first and fails over down the
+//! list. An endpoint's score drops on each failure and recovers on success;
+//! after repeated failures it also sits out an exponential cooldown, so a dead
+//! provider costs one timeout per cooldown rather than one per call.
+//!
+//! With a quorum above 1, [`L1RpcPool::get_logs`] and
+//! [`L1RpcPool::block_number_at`] ask every endpoint and only trust an answer
+//! that `quorum` of them give: a log is returned only when `quorum` endpoints
+//! return it identically (block hash, position, topics and data), and the
+//! frontier is the highest block `quorum` endpoints have reached. A log fewer
+//! endpoints return is dropped when `quorum` other endpoints answered without
+//! it, and otherwise fails the read so the caller retries the same range.
+//! Contract views read next to those logs go through
+//! [`L1RpcPool::call_agreed`], which needs `quorum` identical answers. Other
+//! reads (block timestamps, ERC-20 metadata) use failover only.
+//!
+//! Endpoints are labelled by position (`0` is the primary) in logs and
+//! metrics; URLs often embed API keys.
+
+use alloy::eips::BlockNumberOrTag;
+use alloy::primitives::B256;
+use alloy::providers::{DynProvider, Provider, ProviderBuilder};
+use alloy::rpc::types::{Filter, Log};
+use parking_lot::Mutex;
+use std::collections::HashMap;
+use std::future::Future;
+use std::time::{Duration, Instant};
+
+/// Default `--l1-rpc-quorum`: failover only, no cross-checking.
+pub const DEFAULT_L1_RPC_QUORUM: usize = 1;
+
+const MAX_SCORE: u32 = 100;
+/// Score lost per failure; a success wins back `SCORE_RECOVERY`.
+const SCORE_PENALTY: u32 = 25;
+const SCORE_RECOVERY: u32 = 5;
+/// Consecutive failures before an endpoint starts sitting out cooldowns.
+const COOLDOWN_AFTER_FAILURES: u32 = 3;
+const BASE_COOLDOWN: Duration = Duration::from_secs(2);
+const MAX_COOLDOWN: Duration = Duration::from_secs(60);
+
+/// Fewer than `quorum` endpoints answered, or they answered differently and
+/// no `quorum` of them agree either way. The caller retries the same read.
+#[derive(Debug, thiserror::Error)]
+#[error(
+    "L1 RPC quorum not reached: {reason} ({responded} of {endpoints} endpoints answered, quorum {quorum})"
+)]
+pub struct L1QuorumError {
+    pub reason: &'static str,
+    pub responded: usize,
+    pub endpoints: usize,
+    pub quorum: usize,
+}
+
+struct Health {
+    score: u32,
+    consecutive_failures: u32,
+    cooldown_until: Option<Instant>,
+}
+
+impl Default for Health {
+    fn default() -> Self {
+        Self {
+            score: MAX_SCORE,
+            consecutive_failures: 0,
+            cooldown_until: None,
+        }
+    }
+}
+
+struct Endpoint {
+    label: String,
+    provider: DynProvider,
+    health: Mutex<Health>,
+}
+
+pub struct L1RpcPool {
+    endpoints: Vec<Endpoint>,
+    quorum: usize,
+}
+
+/// Identity of a log for quorum comparison: two endpoints agree on a log only
+/// if they place the same payload at the same position in the same block.
+type LogKey = (
+    Option<B256>,
+    Option<u64>,
+    Option<u64>,
+    alloy::primitives::Log,
+);
+
+impl L1RpcPool {
+    /// HTTP providers for `urls`, primary first. Fails on an unparsable URL or
+    /// a quorum outside `1..=urls.len()`; does not connect.
+    pub fn connect(urls: &[String], quorum: usize) -> anyhow::Result<Self> {
+        let providers = urls
+            .iter()
+            .map(|url| {
+                let parsed = url
+                    .parse()
+                    .map_err(|e| anyhow::anyhow!("invalid L1 RPC URL '{url}': {e}"))?;
+                Ok(ProviderBuilder::new().connect_http(parsed).erased())
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        Self::from_providers(providers, quorum)
+    }
+
+    pub fn from_providers(providers: Vec<DynProvider>, quorum: usize) -> anyhow::Result<Self> {
+        anyhow::ensure!(!providers.is_empty(), "no L1 RPC endpoint configured");
+        anyhow::ensure!(
+            (1..=providers.len()).contains(&quorum),
+            "L1 RPC quorum {quorum} must be between 1 and the {} configured endpoint(s)",
+            providers.len()
+        );
+        let endpoints = providers
+            .into_iter()
+            .enumerate()
+            .map(|(i, provider)| Endpoint {
+                label: i.to_string(),
+                provider,
+                health: Mutex::new(Health::default()),
+            })
+            .collect();
+        Ok(Self { endpoints, quorum })
+    }
+
+    pub fn len(&self) -> usize {
+        self.endpoints.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.endpoints.is_empty()
+    }
+
+    pub fn quorum(&self) -> usize {
+        self.quorum
+    }
+
+    /// Endpoint indices, best first: endpoints out of cooldown by score, then
+    /// cooling endpoints by how soon they come back. Ties keep configured order.
+    fn order(&self) -> Vec<usize> {
+        let now = Instant::now();
+        let mut ranked: Vec<(bool, Option<Instant>, u32, usize)> = self
+            .endpoints
+            .iter()
+            .enumerate()
+            .map(|(i, e)| {
+                let h = e.health.lock();
+                let cooling = h.cooldown_until.filter(|until| *until > now);
+                (cooling.is_some(), cooling, h.score, i)
+            })
+            .collect();
+        ranked.sort_by(|a, b| {
+            a.0.cmp(&b.0)
+                .then(a.1.cmp(&b.1))
+                .then(b.2.cmp(&a.2))
+                .then(a.3.cmp(&b.3))
+        });
+        ranked.into_iter().map(|(.., i)| i).collect()
+    }
+
+    fn record(&self, i: usize, ok: bool) {
+        let endpoint = &self.endpoints[i];
+        let mut h = endpoint.health.lock();
+        if ok {
+            h.score = (h.score + SCORE_RECOVERY).min(MAX_SCORE);
+            h.consecutive_failures = 0;
+            h.cooldown_until = None;
+        } else {
+            h.score = h.score.saturating_sub(SCORE_PENALTY);
+            h.consecutive_failures += 1;
+            if h.consecutive_failures >= COOLDOWN_AFTER_FAILURES {
+                let doublings = (h.consecutive_failures - COOLDOWN_AFTER_FAILURES).min(16);
+                let cooldown = BASE_COOLDOWN
+                    .saturating_mul(1 << doublings)
+                    .min(MAX_COOLDOWN);
+                h.cooldown_until = Some(Instant::now() + cooldown);
+            }
+        }
+        let outcome = if ok { "ok" } else { "error" };
+        ::metrics::counter!(
+            "l1_rpc_requests_total",
+            "endpoint" => endpoint.label.clone(),
+            "outcome" => outcome
+        )
+        .increment(1);
+        ::metrics::gauge!("l1_rpc_endpoint_score", "endpoint" => endpoint.label.clone())
+            .set(h.score as f64);
+    }
+
+    /// Run `f` against the best endpoint, failing over to the next on error.
+    /// Returns the last error once every endpoint has failed.
+    pub async fn call<T, E, F, Fut>(&self, what: &str, f: F) -> anyhow::Result<T>
+    where
+        F: Fn(DynProvider) -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+        E: std::fmt::Display,
+    {
+        let mut last_error = None;
+        for (attempt, i) in self.order().into_iter().enumerate() {
+            if attempt > 0 {
+                ::metrics::counter!("l1_rpc_failovers_total").increment(1);
+            }
+            match f(self.endpoints[i].provider.clone()).await {
+                Ok(value) => {
+                    self.record(i, true);
+                    return Ok(value);
+                }
+                Err(e) => {
+                    self.record(i, false);
+                    tracing::warn!(
+                        endpoint = %self.endpoints[i].label,
+                        error = %e,
+                        what,
+                        "L1 RPC call failed; trying the next endpoint"
+                    );
+                    last_error = Some(e.to_string());
+                }
+            }
+        }
+        anyhow::bail!(
+            "{what}: all {} L1 RPC endpoint(s) failed; last error: {}",
+            self.endpoints.len(),
+            last_error.unwrap_or_default()
+        )
+    }
+
+    /// Run `f` against every endpoint, best first, and return the answers
+    /// that succeeded.
+    async fn ask_all<T, E, F, Fut>(&self, what: &str, f: F) -> Vec<T>
+    where
+        F: Fn(DynProvider) -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+        E: std::fmt::Display,
+    {
+        let mut answers = Vec::with_capacity(self.endpoints.len());
+        for i in self.order() {
+            match f(self.endpoints[i].provider.clone()).await {
+                Ok(value) => {
+                    self.record(i, true);
+                    answers.push(value);
+                }
+                Err(e) => {
+                    self.record(i, false);
+                    tracing::warn!(
+                        endpoint = %self.endpoints[i].label,
+                        error = %e,
+                        what,
+                        "L1 RPC quorum read failed on one endpoint"
+                    );
+                }
+            }
+        }
+        answers
+    }
+
+    fn quorum_error(&self, reason: &'static str, responded: usize) -> anyhow::Error {
+        ::metrics::counter!("l1_rpc_quorum_failures_total", "reason" => reason).increment(1);
+        L1QuorumError {
+            reason,
+            responded,
+            endpoints: self.endpoints.len(),
+            quorum: self.quorum,
+        }
+        .into()
+    }
+
+    /// Like [`L1RpcPool::call`], but under a quorum ask every endpoint and
+    /// return only an answer `quorum` of them give identically.
+    pub async fn call_agreed<T, E, F, Fut>(&self, what: &str, f: F) -> anyhow::Result<T>
+    where
+        T: PartialEq,
+        F: Fn(DynProvider) -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+        E: std::fmt::Display,
+    {
+        if self.quorum == 1 {
+            return self.call(what, f).await;
+        }
+        let answers = self.ask_all(what, f).await;
+        let responded = answers.len();
+        if responded < self.quorum {
+            return Err(self.quorum_error("unavailable", responded));
+        }
+        let mut tally: Vec<(T, usize)> = Vec::new();
+        for answer in answers {
+            match tally.iter_mut().find(|(value, _)| *value == answer) {
+                Some((_, count)) => *count += 1,
+                None => tally.push((answer, 1)),
+            }
+        }
+        tally
+            .into_iter()
+            .find(|(_, count)| *count >= self.quorum)
+            .map(|(value, _)| value)
+            .ok_or_else(|| self.quorum_error("disagreement", responded))
+    }
+
+    /// Number of the block at `tag`. Under a quorum, the highest block that
+    /// `quorum` endpoints have reached.
+    pub async fn block_number_at(&self, tag: BlockNumberOrTag) -> anyhow::Result<Option<u64>> {
+        let fetch = |p: DynProvider| async move {
+            p.get_block_by_number(tag)
+                .await
+                .map(|b| b.map(|b| b.header.number))
+        };
+        if self.quorum == 1 {
+            return self.call("eth_getBlockByNumber", fetch).await;
+        }
+        let answers = self.ask_all("eth_getBlockByNumber", fetch).await;
+        let responded = answers.len();
+        let mut heads: Vec<u64> = answers.into_iter().flatten().collect();
+        if heads.len() < self.quorum {
+            return if responded < self.quorum {
+                Err(self.quorum_error("unavailable", responded))
+            } else {
+                Ok(None)
+            };
+        }
+        heads.sort_unstable_by(|a, b| b.cmp(a));
+        Ok(Some(heads[self.quorum - 1]))
+    }
+
+    /// `eth_getLogs` for `filter`. Under a quorum, only logs `quorum`
+    /// endpoints agree on, ordered by block and log index.
+    pub async fn get_logs(&self, filter: &Filter) -> anyhow::Result<Vec<Log>> {
+        let fetch = |p: DynProvider| async move { p.get_logs(filter).await };
+        if self.quorum == 1 {
+            return self.call("eth_getLogs", fetch).await;
+        }
+        let answers = self.ask_all("eth_getLogs", fetch).await;
+        let responded = answers.len();
+        if responded < self.quorum {
+            return Err(self.quorum_error("unavailable", responded));
+        }
+
+        let mut seen: HashMap<LogKey, (usize, Log)> = HashMap::new();
+        for logs in answers {
+            for log in logs {
+                let key = (
+                    log.block_hash,
+                    log.block_number,
+                    log.log_index,
+                    log.inner.clone(),
+                );
+                seen.entry(key).or_insert((0, log)).0 += 1;
+            }
+        }
+        let mut agreed = Vec::new();
+        let mut dropped = 0u64;
+        for (count, log) in seen.into_values() {
+            if count >= self.quorum {
+                agreed.push(log);
+            } else if responded - count >= self.quorum {
+                dropped += 1;
+            } else {
+                return Err(self.quorum_error("disagreement", responded));
+            }
+        }
+        if dropped > 0 {
+            ::metrics::counter!("l1_rpc_quorum_dropped_logs_total").increment(dropped);
+            tracing::warn!(
+                dropped,
+                responded,
+                quorum = self.quorum,
+                "L1 RPC quorum: dropped logs that only a minority of endpoints returned"
+            );
+        }
+        agreed.sort_by_key(|log| (log.block_number, log.log_index));
+        Ok(agreed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::primitives::{Address, Bytes, LogData};
+    use alloy_transport::mock::Asserter;
+
+    fn mocked(n: usize) -> (Vec<Asserter>, Vec<DynProvider>) {
+        (0..n)
+            .map(|_| {
+                let asserter = Asserter::new();
+                let provider = ProviderBuilder::new()
+                    .connect_mocked_client(asserter.clone())
+                    .erased();
+                (asserter, provider)
+            })
+            .unzip()
+    }
+
+    fn log_at(block: u64, index: u64, topic: u8) -> Log {
+        Log {
+            inner: alloy::primitives::Log {
+                address: Address::from([0x99u8; 20]),
+                data: LogData::new_unchecked(vec![B256::from([topic; 32])], Bytes::new()),
+            },
+            block_hash: Some(B256::from([block as u8; 32])),
+            block_number: Some(block),
+            block_timestamp: None,
+            transaction_hash: None,
+            transaction_index: None,
+            log_index: Some(index),
+            removed: false,
+        }
+    }
+
+    #[tokio::test]
+    async fn failover_skips_a_failing_endpoint_and_demotes_it() {
+        let (asserters, providers) = mocked(2);
+        let pool = L1RpcPool::from_providers(providers, 1).unwrap();
+
+        asserters[0].push_failure_msg("primary down");
+        asserters[1].push_success(&vec![log_at(5, 0, 1)]);
+        let logs = pool.get_logs(&Filter::new()).await.unwrap();
+        assert_eq!(logs.len(), 1);
+        // The failed primary now ranks behind the healthy fallback.
+        assert_eq!(pool.order(), vec![1, 0]);
+
+        asserters[1].push_failure_msg("fallback down");
+        asserters[0].push_failure_msg("primary still down");
+        let err = pool.get_logs(&Filter::new()).await.unwrap_err();
+        assert!(
+            err.to_string().contains("all 2 L1 RPC endpoint(s) failed"),
+            "{err:#}"
+        );
+    }
+
+    #[test]
+    fn repeated_failures_put_an_endpoint_in_cooldown() {
+        let (_asserters, providers) = mocked(2);
+        let pool = L1RpcPool::from_providers(providers, 1).unwrap();
+        for _ in 0..COOLDOWN_AFTER_FAILURES {
+            pool.record(0, false);
+        }
+        assert!(pool.endpoints[0].health.lock().cooldown_until.is_some());
+        assert_eq!(pool.order(), vec![1, 0]);
+        pool.record(0, true);
+        assert!(pool.endpoints[0].health.lock().cooldown_until.is_none());
+        assert!(L1RpcPool::from_providers(Vec::new(), 1).is_err());
+        let (_asserters, providers) = mocked(2);
+        assert!(L1RpcPool::from_providers(providers, 3).is_err());
+    }
+
+    #[tokio::test]
+    async fn quorum_keeps_agreed_logs_and_refuses_an_undecided_read() {
+        let (asserters, providers) = mocked(3);
+        let pool = L1RpcPool::from_providers(providers, 2).unwrap();
+
+        // All three agree on (5,0); one also returns (6,0), which the other
+        // two answered without: dropped.
+        asserters[0].push_success(&vec![log_at(5, 0, 1), log_at(6, 0, 2)]);
+        asserters[1].push_success(&vec![log_at(5, 0, 1)]);
+        asserters[2].push_success(&vec![log_at(5, 0, 1)]);
+        let logs = pool.get_logs(&Filter::new()).await.unwrap();
+        assert_eq!(logs, vec![log_at(5, 0, 1)]);
+
+        // Two answers that differ: neither side has a quorum.
+        asserters[0].push_success(&vec![log_at(5, 0, 1)]);
+        asserters[1].push_success(&vec![log_at(5, 0, 3)]);
+        asserters[2].push_failure_msg("down");
+        let err = pool.get_logs(&Filter::new()).await.unwrap_err();
+        let quorum = err.downcast_ref::<L1QuorumError>().unwrap();
+        assert_eq!((quorum.reason, quorum.responded), ("disagreement", 2));
+
+        // One answer is not enough.
+        asserters[0].push_failure_msg("down");
+        asserters[1].push_failure_msg("down");
+        asserters[2].push_success(&vec![log_at(5, 0, 1)]);
+        let err = pool.get_logs(&Filter::new()).await.unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<L1QuorumError>().unwrap().reason,
+            "unavailable"
+        );
+    }
+
+    #[tokio::test]
+    async fn agreed_call_needs_quorum_identical_answers() {
+        let (asserters, providers) = mocked(3);
+        let pool = L1RpcPool::from_providers(providers, 2).unwrap();
+        let number = |p: DynProvider| async move { p.get_block_number().await };
+
+        asserters[0].push_success(&"0x7");
+        asserters[1].push_success(&"0x8");
+        asserters[2].push_success(&"0x7");
+        assert_eq!(
+            pool.call_agreed("eth_blockNumber", number).await.unwrap(),
+            7
+        );
+
+        asserters[0].push_success(&"0x7");
+        asserters[1].push_success(&"0x8");
+        asserters[2].push_failure_msg("down");
+        let err = pool
+            .call_agreed("eth_blockNumber", number)
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<L1QuorumError>().unwrap().reason,
+            "disagreement"
+        );
+    }
+
+    #[tokio::test]
+    async fn quorum_frontier_is_the_highest_block_enough_endpoints_reached() {
+        let (asserters, providers) = mocked(3);
+        let pool = L1RpcPool::from_providers(providers, 2).unwrap();
+        for (asserter, number) in asserters.iter().zip([110u64, 100, 105]) {
+            let mut block = alloy::rpc::types::Block::<alloy::rpc::types::Transaction>::default();
+            block.header.inner.number = number;
+            asserter.push_success(&block);
+        }
+        assert_eq!(
+            pool.block_number_at(BlockNumberOrTag::Safe).await.unwrap(),
+            Some(105)
+        );
+    }
+}
@@ -1,24 +1,32 @@
 pub mod account_recovery;
 pub mod accounts_config;
 pub mod address_mapper;
+pub mod admin_auth;
 pub(crate) mod applied_state;
 pub mod block_monitor;
 pub mod block_state;
+pub mod block_timestamps;
 pub mod bridge_address;
 pub mod bridge_out;
 pub mod burn_serial_tracker;
 pub mod claim;
 pub mod claim_watcher;
+pub mod config_file;
 pub mod exit;
 pub mod expected_mint_tracker;
 pub mod faucet_ops;
 pub mod faucet_ownership_monitor;
 pub mod faucet_registry_reconciler;
+pub mod finality;
 pub mod forged_mint_detector;
+pub mod future_nonce_pool;
 pub mod ger;
+pub mod ger_relayer;
 pub mod hex;
 pub mod init;
+pub mod l1_info_tree;
 pub mod l1_info_tree_indexer;
+pub mod l1_rpc_pool;
 pub mod l2_to_l1_claimer;
 pub mod log_synthesis;
 pub mod logging;
@@ -29,22 +37,32 @@ pub mod mint_target_monitor;
 pub mod orphan_recovery;
 pub mod recovery;
 pub mod restore;
+pub mod runtime_policy;
 pub mod service;
 pub(crate) mod service_admin;
+pub(crate) mod service_admin_audit;
+pub(crate) mod service_admin_ger;
+pub(crate) mod service_admin_quarantine;
+pub(crate) mod service_admin_writer;
+pub(crate) mod service_block_txns;
 pub(crate) mod service_debug;
 pub(crate) mod service_estimate_gas;
 pub(crate) mod service_eth_call;
+pub mod service_filters;
 pub(crate) mod service_get_logs;
 pub mod service_get_txn_receipt;
 pub(crate) mod service_helpers;
 pub mod service_send_raw_txn;
+pub(crate) mod service_send_raw_txn_sync;
 pub mod service_state;
 pub(crate) mod service_zkevm;
 pub mod sqlite_pragmas;
 pub mod store;
+pub mod subscriptions;
 pub mod synthetic_projector;
 #[cfg(test)]
 pub mod test_helpers;
+pub mod token_balances;
 pub mod twin_note_detector;
 pub mod unknown_wrapper_detector;
 pub mod writer_worker;
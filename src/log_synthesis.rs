@@ -1,6 +1,6 @@
 //! Log Synthesis - Generate synthetic EVM logs for bridge service compatibility.
 //!
-//! Synthesizes ClaimEvent and UpdateHashChainValue logs from Miden transactions.
+//! Synthesizes ClaimEvent, UpdateHashChainValue and GER-removal logs from Miden transactions.
 
 use serde::{Deserialize, Serialize};
 
@@ -17,6 +17,16 @@ pub const BRIDGE_EVENT_TOPIC: &str =
 pub const UPDATE_HASH_CHAIN_VALUE_TOPIC: &str =
     "0x65d3bf36615f1f02a134d12dfa9ea6b1d4a52386e825973cd27ddb70895c2319";
 
+/// RemoveLastGlobalExitRoot topic hash: keccak256("RemoveLastGlobalExitRoot(bytes32)")
+/// Emitted by L2 GlobalExitRootManagerL2SovereignChain when the GER remover drops a GER
+pub const REMOVE_LAST_GLOBAL_EXIT_ROOT_TOPIC: &str =
+    "0x605764d0b65b62ecf05dc90f674a00a2e2531fabaf120fdde65790e407fcb7a2";
+
+/// UpdateRemovalHashChainValue topic hash: keccak256("UpdateRemovalHashChainValue(bytes32,bytes32)")
+/// Emitted alongside RemoveLastGlobalExitRoot with the rolled removal hash chain
+pub const UPDATE_REMOVAL_HASH_CHAIN_VALUE_TOPIC: &str =
+    "0xaafec9380147d2b2b14fe23b1343cbaa1b07f86c5adb060bd28cdf1af4c6f0d4";
+
 /// L2 GlobalExitRoot contract address (receives GER updates from aggoracle)
 pub const L2_GLOBAL_EXIT_ROOT_ADDRESS: &str = "0xa40D5f56745a118D0906a34E69aeC8C0Db1cB8fA";
 
@@ -805,6 +815,23 @@ mod tests {
         hasher2.update(bridge_sig.as_bytes());
         let bridge_hash = format!("0x{}", hex::encode(<[u8; 32]>::from(hasher2.finalize())));
         assert_eq!(BRIDGE_EVENT_TOPIC, bridge_hash);
+
+        for (sig, topic) in [
+            (
+                "RemoveLastGlobalExitRoot(bytes32)",
+                REMOVE_LAST_GLOBAL_EXIT_ROOT_TOPIC,
+            ),
+            (
+                "UpdateRemovalHashChainValue(bytes32,bytes32)",
+                UPDATE_REMOVAL_HASH_CHAIN_VALUE_TOPIC,
+            ),
+        ] {
+            let hash = format!(
+                "0x{}",
+                hex::encode(<[u8; 32]>::from(Keccak256::digest(sig)))
+            );
+            assert_eq!(topic, hash, "{sig}");
+        }
     }
 
     // LogStore-based tests (ger dedup, hash chain, log add/query, bridge event roundtrip)
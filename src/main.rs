@@ -1,5 +1,5 @@
 use anyhow::Context;
-use clap::Parser;
+use clap::{CommandFactory, FromArgMatches, Parser};
 use miden_agglayer_service::block_state::BlockState;
 use miden_agglayer_service::bridge_out::BridgeOutScanner;
 use miden_agglayer_service::service;
@@ -51,6 +51,23 @@ struct Command {
     #[arg(long, env = "DATABASE_URL")]
     database_url: Option<String>,
 
+    /// PEM bundle of CA certificates trusted for the Postgres server certificate.
+    /// Overrides `sslrootcert` in DATABASE_URL; when neither is set the system
+    /// root store is used. TLS itself is selected by `sslmode` in DATABASE_URL
+    /// (`disable`, `prefer`, `require`, `verify-ca`, `verify-full`).
+    #[arg(long, env = "DATABASE_CA_CERT")]
+    database_ca_cert: Option<PathBuf>,
+
+    /// PEM client certificate chain for Postgres mutual TLS. Overrides `sslcert`
+    /// in DATABASE_URL. Requires --database-client-key.
+    #[arg(long, env = "DATABASE_CLIENT_CERT")]
+    database_client_cert: Option<PathBuf>,
+
+    /// PEM private key matching --database-client-cert. Overrides `sslkey` in
+    /// DATABASE_URL.
+    #[arg(long, env = "DATABASE_CLIENT_KEY")]
+    database_client_key: Option<PathBuf>,
+
     /// Restore mode: reconstruct store state from miden node, then exit
     #[arg(long)]
     restore: bool,
@@ -96,9 +113,28 @@ struct Command {
     #[arg(long, env = "L1_RPC_URL")]
     l1_rpc_url: Option<String>,
 
+    /// Extra L1 RPC endpoints tried after `--l1-rpc-url`, comma-separated.
+    /// The indexer and L1 metadata recovery fail over between all of them,
+    /// preferring the healthiest endpoint. Repeatable.
+    #[arg(
+        long = "l1-rpc-fallback-url",
+        value_name = "URL",
+        env = "L1_RPC_FALLBACK_URLS",
+        value_delimiter = ','
+    )]
+    l1_rpc_fallback_urls: Vec<String>,
+
+    /// How many of the L1 endpoints must return the same `UpdateL1InfoTree`
+    /// log before the indexer records it. `1` (default) is plain failover;
+    /// anything higher is quorum mode and must not exceed the number of
+    /// configured endpoints (`--l1-rpc-url` plus fallbacks).
+    #[arg(long, env = "L1_RPC_QUORUM", default_value_t = miden_agglayer_service::l1_rpc_pool::DEFAULT_L1_RPC_QUORUM)]
+    l1_rpc_quorum: usize,
+
     /// Additional per-origin-network RPC endpoints for Cantina #13 metadata
     /// recovery, as `ID=URL` (e.g. `--network-rpc-url 2=http://anvil-l2b:8545`).
-    /// Network 0 is taken from `--l1-rpc-url`. Repeatable — one per network whose
+    /// Network 0 is taken from `--l1-rpc-url` (and `--l1-rpc-fallback-url`).
+    /// Repeat an ID to add failover endpoints for that network. Repeatable — one per network whose
     /// tokens (L2B, …) may need ERC-20 metadata recovered from their own chain
     /// during restore (finding #62). Without it, only network-0 (L1) tokens
     /// recover — the pre-#62 behavior.
@@ -131,6 +167,25 @@ struct Command {
     #[arg(long, env = "L1_EVIDENCE_TAG", default_value = "latest")]
     l1_evidence_tag: String,
 
+    /// Run the built-in GER relayer: inject the newest L1-observed GER through
+    /// the writer when no external aggoracle has done so. Intended for dev and
+    /// test deployments, and as a production fallback. Requires the L1 indexer.
+    #[arg(long, env = "GER_RELAYER", default_value_t = false)]
+    ger_relayer: bool,
+
+    /// Hex private key the GER relayer signs its `insertGlobalExitRoot`
+    /// transactions with. A random key is generated when unset.
+    #[arg(long, env = "GER_RELAYER_PRIVATE_KEY")]
+    ger_relayer_private_key: Option<String>,
+
+    /// Minimum seconds between two injections by the GER relayer.
+    #[arg(
+        long,
+        env = "GER_RELAYER_MIN_INTERVAL_SECS",
+        default_value_t = miden_agglayer_service::ger_relayer::DEFAULT_MIN_INTERVAL_SECS
+    )]
+    ger_relayer_min_interval_secs: u64,
+
     /// Faucet-registry security reconciler poll interval, in seconds. The reconciler is
     /// a TRIPWIRE: it scans the bridge's on-chain faucet registrations and halts the
     /// proxy (fail-closed) if it finds one with no local `faucet_registry` row — the
@@ -164,6 +219,13 @@ struct Command {
     #[arg(long, env = "ADMIN_API_KEY")]
     admin_api_key: Option<String>,
 
+    /// TOML file of named, method-scoped admin keys (`[[keys]]` with `name`,
+    /// `key_hash` = keccak256 of the bearer token, and `methods`). Merged with
+    /// `--admin-api-key` (loaded as the all-methods key `default`) and the
+    /// store's `admin_keys` table; names must be unique across all three.
+    #[arg(long, env = "ADMIN_KEYS_FILE")]
+    admin_keys_file: Option<PathBuf>,
+
     /// Allow-list of EVM signer addresses permitted to submit
     /// `eth_sendRawTransaction` (R2). Comma-separated 0x-prefixed addresses
     /// (case-insensitive). When unset, NO signer is accepted (audit C2 —
@@ -179,6 +241,21 @@ struct Command {
     /// and/or a network-level boundary.
     #[arg(long, env = "INSECURE_ALLOW_ANY_SIGNER", default_value_t = false)]
     insecure_allow_any_signer: bool,
+
+    /// Signers allowed to submit `removeGlobalExitRoots` through
+    /// `eth_sendRawTransaction`, like L1's `globalExitRootRemover` role. They
+    /// must also pass the signer allow-list. When unset, signed removals are
+    /// refused; `admin_removeGlobalExitRoot` is unaffected.
+    #[arg(long, env = "GER_REMOVER_SIGNERS", value_delimiter = ',')]
+    ger_remover_signers: Vec<alloy::primitives::Address>,
+
+    /// TOML file overriding `allowed_signers`, `cors_allowed_origins`,
+    /// `rate_limit_per_second` and `rate_limit_burst`. Re-read on SIGHUP and
+    /// `admin_reloadPolicy` and applied without a restart; a key missing from
+    /// the file falls back to its flag. Under `--require-hardening` the merged
+    /// policy must pass the same signer and CORS checks as the flags.
+    #[arg(long, env = "POLICY_FILE")]
+    policy_file: Option<PathBuf>,
     /// Audit H6 — refuse to inject a GER whose `(mainnet, rollup)` decomposition
     /// was NOT corroborated by the independent L1 InfoTree indexer (i.e. a GER
     /// supplied only by the aggoracle with no matching on-chain observation).
@@ -217,6 +294,39 @@ struct Command {
     #[arg(long, env = "RATE_LIMIT_BURST", default_value_t = miden_agglayer_service::service::DEFAULT_RATE_LIMIT_BURST)]
     rate_limit_burst: u32,
 
+    /// Maximum number of entries in one JSON-RPC batch request (0 refuses every
+    /// batch). Default 100.
+    /// Every entry is counted against the per-IP rate limit individually.
+    #[arg(long, env = "RPC_MAX_BATCH_SIZE", default_value_t = miden_agglayer_service::service::DEFAULT_RPC_MAX_BATCH_SIZE)]
+    rpc_max_batch_size: usize,
+
+    /// Most non-terminal writer jobs one signer may have before further
+    /// `eth_sendRawTransaction`s are refused with `-32006` (0 disables).
+    /// Default 16.
+    #[arg(long, env = "SIGNER_INFLIGHT_QUOTA", default_value_t = miden_agglayer_service::future_nonce_pool::DEFAULT_SIGNER_INFLIGHT_QUOTA)]
+    signer_inflight_quota: usize,
+
+    /// Future-nonce envelopes held per signer until their gap fills (`-32007`);
+    /// also the widest nonce gap held rather than rejected. 0 turns holding
+    /// off. Default 16.
+    #[arg(long, env = "FUTURE_NONCE_HOLD_PER_SIGNER", default_value_t = miden_agglayer_service::future_nonce_pool::DEFAULT_HOLD_PER_SIGNER)]
+    future_nonce_hold_per_signer: usize,
+
+    /// Future-nonce envelopes held across all signers; a full holding area
+    /// answers `-32005`. Default 256.
+    #[arg(long, env = "FUTURE_NONCE_HOLD_TOTAL", default_value_t = miden_agglayer_service::future_nonce_pool::DEFAULT_HOLD_TOTAL)]
+    future_nonce_hold_total: usize,
+
+    /// Blocks the `safe` block tag trails the synthetic tip (the Miden
+    /// committed frontier). Must not exceed `--finalized-block-lag`. Default 0.
+    #[arg(long, env = "SAFE_BLOCK_LAG", default_value_t = miden_agglayer_service::finality::DEFAULT_SAFE_BLOCK_LAG)]
+    safe_block_lag: u64,
+
+    /// Blocks the `finalized` block tag trails the synthetic tip; 0 makes
+    /// `finalized` track the Miden committed frontier. Default 0.
+    #[arg(long, env = "FINALIZED_BLOCK_LAG", default_value_t = miden_agglayer_service::finality::DEFAULT_FINALIZED_BLOCK_LAG)]
+    finalized_block_lag: u64,
+
     /// Reject the address-mapper zero-padding fallback (C5). When set,
     /// claims targeting an EVM address with no explicit store mapping are
     /// rejected immediately instead of falling through to the structural
@@ -230,6 +340,7 @@ struct Command {
     /// - `--admin-api-key` unset (admin endpoints accept any caller)
     /// - `--allowed-signers` unset (any signer can submit txs)
     /// - `--cors-allowed-origins` set to a wildcard `*`
+    /// - `--database-url` whose `sslmode` can fall back to plaintext
     ///
     /// Operators deploying to mainnet should set `--require-hardening`
     /// (env `REQUIRE_HARDENING`) to make these mistakes startup failures
@@ -273,6 +384,19 @@ struct Command {
     /// history (sync, sweep, reconcile) but can never send a transaction.
     #[arg(long, env = "AGGLAYER_READ_ONLY", default_value_t = false)]
     read_only: bool,
+
+    /// TOML file carrying any of the settings above plus the env-only tuning
+    /// knobs, grouped into `[server]`, `[database]`, `[miden]`, `[l1]`,
+    /// `[writer]`, `[monitors]` and `[security]` (see `config_file`). A flag
+    /// or its env var overrides the file; the file overrides built-in
+    /// defaults. Unknown or invalid keys fail startup, naming the key.
+    #[arg(long, env = "AGGLAYER_CONFIG")]
+    config: Option<PathBuf>,
+
+    /// Print every resolved setting with its source (flag / env / file /
+    /// default), secrets redacted, and exit.
+    #[arg(long)]
+    print_effective_config: bool,
 }
 
 /// Validate the `--require-hardening` invariants. Returns a list of
@@ -282,17 +406,21 @@ fn check_hardening_invariants(command: &Command) -> Result<(), Vec<String>> {
         return Ok(());
     }
     let mut reasons = Vec::new();
-    if command.admin_api_key.is_none() {
+    if command.admin_api_key.is_none() && command.admin_keys_file.is_none() {
         reasons.push(
-            "  - --admin-api-key is unset (admin_* methods would be open). \
-             Set ADMIN_API_KEY to a long random token."
+            "  - neither --admin-api-key nor --admin-keys-file is set (admin_* \
+             methods would be open). Set ADMIN_API_KEY to a long random token \
+             or ADMIN_KEYS_FILE to a scoped keys file."
                 .to_string(),
         );
     }
-    if command
-        .allowed_signers
-        .as_ref()
-        .is_none_or(|v| v.is_empty())
+    // With a policy file the merged signer and CORS settings are checked by
+    // `PolicyHandle::load` instead, so a list supplied only by the file counts.
+    if command.policy_file.is_none()
+        && command
+            .allowed_signers
+            .as_ref()
+            .is_none_or(|v| v.is_empty())
     {
         reasons.push(
             "  - --allowed-signers is unset (eth_sendRawTransaction would reject \
@@ -309,7 +437,8 @@ fn check_hardening_invariants(command: &Command) -> Result<(), Vec<String>> {
                 .to_string(),
         );
     }
-    if let Some(origins) = command.cors_allowed_origins.as_ref()
+    if command.policy_file.is_none()
+        && let Some(origins) = command.cors_allowed_origins.as_ref()
         && origins.iter().any(|o| o == "*")
     {
         reasons.push(
@@ -328,6 +457,29 @@ fn check_hardening_invariants(command: &Command) -> Result<(), Vec<String>> {
                 .to_string(),
         );
     }
+    if command.ger_relayer && !command.read_only && command.ger_relayer_private_key.is_none() {
+        reasons.push(
+            "  - --ger-relayer is set without --ger-relayer-private-key (the \
+             relayer would sign with a random key that changes on every \
+             restart). Set GER_RELAYER_PRIVATE_KEY to a key provisioned for \
+             the relayer."
+                .to_string(),
+        );
+    }
+    if let Some(db_url) = command.database_url.as_deref() {
+        // Missing `sslmode` means `prefer`, which silently downgrades to
+        // plaintext when the server (or a MITM) does not offer TLS.
+        match miden_agglayer_service::store::pg_tls::database_ssl_mode(db_url) {
+            Ok(mode) if mode.refuses_plaintext() => {}
+            Ok(mode) => reasons.push(format!(
+                "  - --database-url sslmode is `{}` (store traffic, including \
+                 claim calldata, may travel in plaintext). Add \
+                 sslmode=verify-full (or require / verify-ca) to DATABASE_URL.",
+                mode.as_str()
+            )),
+            Err(e) => reasons.push(format!("  - --database-url is invalid: {e}")),
+        }
+    }
     if reasons.is_empty() {
         Ok(())
     } else {
@@ -335,6 +487,28 @@ fn check_hardening_invariants(command: &Command) -> Result<(), Vec<String>> {
     }
 }
 
+/// `--l1-rpc-quorum` must be satisfiable by the configured L1 endpoints, or
+/// the indexer would refuse every batch (and only log the spawn error).
+fn check_l1_rpc_quorum(command: &Command) -> Result<(), String> {
+    let endpoints = usize::from(command.l1_rpc_url.is_some()) + command.l1_rpc_fallback_urls.len();
+    if command.l1_rpc_url.is_none() && !command.l1_rpc_fallback_urls.is_empty() {
+        return Err(
+            "--l1-rpc-fallback-url (L1_RPC_FALLBACK_URLS) requires --l1-rpc-url \
+                    (L1_RPC_URL) as the primary endpoint"
+                .to_string(),
+        );
+    }
+    if command.l1_rpc_quorum == 0 || (endpoints > 0 && command.l1_rpc_quorum > endpoints) {
+        return Err(format!(
+            "--l1-rpc-quorum (L1_RPC_QUORUM) is {} but {endpoints} L1 RPC endpoint(s) are \
+             configured: the quorum must be between 1 and the number of endpoints \
+             (--l1-rpc-url plus --l1-rpc-fallback-url)",
+            command.l1_rpc_quorum
+        ));
+    }
+    Ok(())
+}
+
 /// Audit H6 startup invariant (PR #121 review point 2). Strict H6 refuses any
 /// GER the L1 InfoTree indexer has not corroborated — the indexer IS the
 /// evidence source. If strict mode boots with the indexer disabled (missing
@@ -395,7 +569,11 @@ fn check_h6_evidence_source(command: &Command) -> Result<(), String> {
     // posture, NOT a startup abort. `url::Url` is exactly what alloy's
     // `connect_http` parses the string into, so "parses here" ⟺ "spawn won't
     // reject the URL".
-    if let Some(rpc) = command.l1_rpc_url.as_deref() {
+    for rpc in command
+        .l1_rpc_url
+        .iter()
+        .chain(&command.l1_rpc_fallback_urls)
+    {
         // `Url::parse` alone is too weak: it accepts `file:///…`, `ws://…`,
         // hostless URLs, and custom schemes (the common `anvil:8545` typo parses
         // as scheme=`anvil`). `connect_http` does NOT reject those synchronously
@@ -409,8 +587,8 @@ fn check_h6_evidence_source(command: &Command) -> Result<(), String> {
         });
         if !usable_http {
             return Err(format!(
-                "strict H6 GER corroboration is enabled via {trigger}, but --l1-rpc-url \
-                 `{rpc}` is not a usable HTTP(S) RPC endpoint: it must have an `http` or \
+                "strict H6 GER corroboration is enabled via {trigger}, but L1 RPC URL \
+                 `{rpc}` (--l1-rpc-url / --l1-rpc-fallback-url) is not a usable HTTP(S) RPC endpoint: it must have an `http` or \
                  `https` scheme AND a host (rejected examples: `file:///…`, `ws://…`, a \
                  hostless URL, or the `anvil:8545` custom-scheme typo). The L1 InfoTree \
                  indexer would start against it and retry failed HTTP posts forever while \
@@ -532,6 +710,9 @@ impl std::fmt::Debug for Command {
                 "database_url",
                 &self.database_url.as_ref().map(|_| "[REDACTED]"),
             )
+            .field("database_ca_cert", &self.database_ca_cert)
+            .field("database_client_cert", &self.database_client_cert)
+            .field("database_client_key", &self.database_client_key)
             .field("restore", &self.restore)
             .field("reset_miden_store", &self.reset_miden_store)
             .field("unlock_miden_accounts", &self.unlock_miden_accounts)
@@ -541,15 +722,23 @@ impl std::fmt::Debug for Command {
                 "l1_rpc_url",
                 &self.l1_rpc_url.as_ref().map(|_| "[REDACTED]"),
             )
+            .field(
+                "l1_rpc_fallback_urls",
+                &vec!["[REDACTED]"; self.l1_rpc_fallback_urls.len()],
+            )
+            .field("l1_rpc_quorum", &self.l1_rpc_quorum)
             .field("ger_l1_address", &self.ger_l1_address)
             .field("miden_debug", &self.miden_debug)
             .field(
                 "admin_api_key",
                 &self.admin_api_key.as_ref().map(|_| "[REDACTED]"),
             )
+            .field("admin_keys_file", &self.admin_keys_file)
             .field("cors_allowed_origins", &self.cors_allowed_origins)
             .field("allowed_signers", &self.allowed_signers)
             .field("insecure_allow_any_signer", &self.insecure_allow_any_signer)
+            .field("ger_remover_signers", &self.ger_remover_signers)
+            .field("policy_file", &self.policy_file)
             .field("reject_unverified_ger", &self.reject_unverified_ger)
             .field("require_hardening", &self.require_hardening)
             .field(
@@ -565,14 +754,54 @@ impl std::fmt::Debug for Command {
                 "miden_prover_fallback_to_local",
                 &self.miden_prover_fallback_to_local,
             )
+            .field("ger_relayer", &self.ger_relayer)
+            .field(
+                "ger_relayer_private_key",
+                &self.ger_relayer_private_key.as_ref().map(|_| "[REDACTED]"),
+            )
+            .field(
+                "ger_relayer_min_interval_secs",
+                &self.ger_relayer_min_interval_secs,
+            )
             .field("read_only", &self.read_only)
+            .field("config", &self.config)
             .finish()
     }
 }
 
-#[tokio::main]
-async fn main() -> anyhow::Result<()> {
-    let command = Command::parse();
+/// Parse the command line, layering `--config` underneath flags and env.
+/// Runs before the tokio runtime exists: exporting the file's env-only knobs
+/// mutates the process environment, which is only sound single-threaded.
+fn load_command() -> anyhow::Result<Option<Command>> {
+    let mut matches = Command::command().get_matches();
+    let mut file = None;
+    if let Some(path) = matches.get_one::<PathBuf>("config").cloned() {
+        let mut loaded = config_file::ConfigFile::load(&path)?;
+        // SAFETY: no runtime or other thread has been started yet.
+        unsafe { loaded.export_env() };
+        matches = loaded.apply(Command::command())?.get_matches();
+        file = Some(loaded);
+    }
+    if matches.get_flag("print_effective_config") {
+        print!("{}", config_file::render_effective(&matches, file.as_ref()));
+        return Ok(None);
+    }
+    Ok(Some(
+        Command::from_arg_matches(&matches).unwrap_or_else(|e| e.exit()),
+    ))
+}
+
+fn main() -> anyhow::Result<()> {
+    let Some(command) = load_command()? else {
+        return Ok(());
+    };
+    tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .build()?
+        .block_on(run(command))
+}
+
+async fn run(command: Command) -> anyhow::Result<()> {
     logging::setup_tracing()?;
     // Install the process-wide Prometheus recorder FIRST — before any thread
     // or runtime that can emit a metric exists. `metrics` resolves the global
@@ -616,6 +845,9 @@ async fn main() -> anyhow::Result<()> {
     if let Err(reason) = check_h6_evidence_source(&command) {
         anyhow::bail!("{reason}");
     }
+    if let Err(reason) = check_l1_rpc_quorum(&command) {
+        anyhow::bail!("{reason}");
+    }
     // Parse once for every serving mode. Silently defaulting an invalid value
     // would bind the database to evidence the operator did not configure.
     let l1_evidence_tag = miden_agglayer_service::ger::EvidenceTag::parse(
@@ -627,6 +859,11 @@ async fn main() -> anyhow::Result<()> {
             command.l1_evidence_tag
         )
     })?;
+    let finality = miden_agglayer_service::finality::FinalityLags::new(
+        command.safe_block_lag,
+        command.finalized_block_lag,
+    )
+    .map_err(|e| anyhow::anyhow!("--safe-block-lag / --finalized-block-lag: {e}"))?;
 
     // Startup probe — when --require-hardening is set AND a remote prover is
     // configured, dial the gRPC endpoint once at boot so a misconfigured
@@ -742,13 +979,18 @@ async fn main() -> anyhow::Result<()> {
     let store: Arc<dyn Store> = if let Some(_db_url) = &command.database_url {
         #[cfg(feature = "postgres")]
         {
+            let db_tls = miden_agglayer_service::store::pg_tls::PgTlsOptions {
+                ca_cert: command.database_ca_cert.clone(),
+                client_cert: command.database_client_cert.clone(),
+                client_key: command.database_client_key.clone(),
+            };
             // Run embedded SQL migrations BEFORE the connection pool opens.
             // This replaces the `agglayer-migrate` one-shot service that
             // hardcoded the migration list in docker-compose.e2e.yml — new
             // migrations are now part of the deploy artifact (compiled into
             // the binary via `include_str!`) so the proxy and its schema
             // can't drift out of sync.
-            let report = miden_agglayer_service::store::migrator::run_migrations(_db_url)
+            let report = miden_agglayer_service::store::migrator::run_migrations(_db_url, &db_tls)
                 .await
                 .context("running embedded DB migrations on startup")?;
             tracing::info!(
@@ -757,7 +999,9 @@ async fn main() -> anyhow::Result<()> {
                 "DB migrations complete"
             );
 
-            let pg = miden_agglayer_service::store::postgres::PgStore::new(_db_url).await?;
+            let pg = miden_agglayer_service::store::postgres::PgStore::new(_db_url, &db_tls)
+                .await
+                .context("connecting the Postgres store")?;
             Arc::new(pg)
         }
         #[cfg(not(feature = "postgres"))]
@@ -821,6 +1065,19 @@ async fn main() -> anyhow::Result<()> {
 
     // Phase 3: Load config and create full client
     let block_state = Arc::new(BlockState::new());
+    // Load recorded Miden block timestamps and sealed roots into `block_state`
+    // before anything (restore included) hashes a synthetic block: both are
+    // part of the header hash. The recorder is the FIRST sync listener below.
+    block_state.load(&*store).await?;
+    let timestamp_recorder = Arc::new(
+        miden_agglayer_service::block_timestamps::BlockTimestampRecorder::new(
+            store.clone(),
+            block_state.clone(),
+            miden_agglayer_service::miden_client::effective_node_url(command.miden_node.clone()),
+            command.miden_api_key.clone(),
+        )
+        .await?,
+    );
 
     let accounts = load_config(miden_store_dir.clone())?;
 
@@ -890,13 +1147,16 @@ async fn main() -> anyhow::Result<()> {
     let sync_listener = Arc::new(StoreSyncListener::new(store.clone(), block_state.clone()));
 
     // Finding #62: per-origin-network RPC map for Cantina #13 metadata recovery.
-    // Network 0 = L1 (from --l1-rpc-url); extra networks (L2B=2, …) from
-    // --network-rpc-url ID=URL. Consumed by both the live projector and --restore
+    // Network 0 = L1 (from --l1-rpc-url plus its fallbacks); extra networks
+    // (L2B=2, …) from --network-rpc-url ID=URL, where repeating an ID adds a
+    // failover endpoint for that network. Consumed by both the live projector and --restore
     // so an L2B-origin ERC-20 recovers its metadata from its OWN chain.
     let network_rpcs = {
         let mut m = miden_agglayer_service::metadata_recovery::NetworkRpcMap::new();
         if let Some(l1) = command.l1_rpc_url.clone() {
-            m.insert(0, l1);
+            let mut urls = vec![l1];
+            urls.extend(command.l1_rpc_fallback_urls.iter().cloned());
+            m.insert(0, urls);
         }
         for spec in &command.network_rpc_urls {
             let (id, url) = spec
@@ -905,11 +1165,15 @@ async fn main() -> anyhow::Result<()> {
             let id: u32 = id.parse().map_err(|e| {
                 anyhow::anyhow!("--network-rpc-url network id must be a u32, got '{id}': {e}")
             })?;
-            m.insert(id, url.to_string());
+            m.entry(id).or_default().push(url.to_string());
         }
         m
     };
 
+    // Shared with ServiceState below: the projector fires it after each tip
+    // write, WebSocket eth_subscribe sessions listen on it.
+    let tip_notifier = miden_agglayer_service::subscriptions::TipNotifier::new();
+
     // Register the projector LAST so it observes the same consumed-note feed the
     // monitors saw this tick, then advances the synthetic tip itself (no race —
     // it is the only writer of `latest_block_number`, Finding #5).
@@ -924,13 +1188,18 @@ async fn main() -> anyhow::Result<()> {
             miden_agglayer_service::miden_client::effective_node_url(command.miden_node.clone()),
             command.miden_api_key.clone(),
         )
-        .await?,
+        .await?
+        .with_tip_notifier(tip_notifier.clone())
+        .with_finality(finality),
     );
     tracing::info!(
         "SyntheticProjector registered: the SOLE synthetic-event producer and the SINGLE owner of \
          the synthetic tip. SINGLE-PROCESS ONLY — multiple replicas are NOT supported."
     );
+    // The timestamp recorder runs FIRST so every new block's real timestamp is
+    // known before `sync_listener` / `block_state` hash it.
     let sync_listeners: Vec<Arc<dyn miden_agglayer_service::miden_client::SyncListener>> = vec![
+        timestamp_recorder,
         sync_listener,
         block_state.clone(),
         bridge_out_scanner,
@@ -1020,17 +1289,38 @@ async fn main() -> anyhow::Result<()> {
     );
     state.l1_rpc_url = command.l1_rpc_url;
     state.ger_l1_address = command.ger_l1_address;
-    state.cors_allowed_origins = command.cors_allowed_origins;
-    state.admin_api_key = command.admin_api_key;
-    state.allowed_signers = command.allowed_signers;
+    state.network_rpcs = network_rpcs;
+    state.policy = miden_agglayer_service::runtime_policy::PolicyHandle::load(
+        miden_agglayer_service::runtime_policy::PolicySettings {
+            allowed_signers: command.allowed_signers,
+            cors_allowed_origins: command.cors_allowed_origins,
+            rate_limit_per_second: command.rate_limit_per_second,
+            rate_limit_burst: command.rate_limit_burst,
+        },
+        command.policy_file,
+        command.require_hardening,
+    )?;
+    miden_agglayer_service::runtime_policy::spawn_sighup_reload(state.policy.clone())?;
+    let admin_keys = miden_agglayer_service::admin_auth::AdminKeyring::load(
+        command.admin_api_key.as_deref(),
+        command.admin_keys_file.as_deref(),
+        &*state.store,
+    )
+    .await?;
+    for (name, methods) in admin_keys.summary() {
+        tracing::info!(key = %name, methods = ?methods, "admin key loaded");
+    }
+    state.admin_keys = Arc::new(admin_keys);
     state.allow_any_signer = command.insecure_allow_any_signer;
+    state.ger_remover_signers = command.ger_remover_signers.clone();
     // H6 — strict L1 GER corroboration is implied by --require-hardening.
     state.reject_unverified_ger = command.reject_unverified_ger || command.require_hardening;
     // H6 — the canonical, startup-validated setting is also persisted by the
     // store binding above, so its markers cannot be reused under another tag.
     state.l1_evidence_tag = l1_evidence_tag;
-    state.rate_limit_per_second = command.rate_limit_per_second;
-    state.rate_limit_burst = command.rate_limit_burst;
+    state.rpc_max_batch_size = command.rpc_max_batch_size;
+    state.finality = finality;
+    state.tip_notifier = tip_notifier;
     state.reject_zero_padding_addresses = command.reject_zero_padding_addresses;
     // Cantina #7: share the BridgeOutScanner's expected-MINT tracker so
     // `publish_claim_internal` can record the CLAIM NoteId and the scanner
@@ -1038,6 +1328,12 @@ async fn main() -> anyhow::Result<()> {
     state.expected_mints = expected_mints_handle;
     state.miden_store_dir = miden_store_dir.clone().unwrap_or_default();
     state.miden_api_key = command.miden_api_key;
+    state.token_source = Arc::new(
+        miden_agglayer_service::token_balances::NodeTokenSource::new(
+            &miden_agglayer_service::miden_client::effective_node_url(command.miden_node.clone()),
+            state.miden_api_key.as_deref(),
+        )?,
+    );
     // The bounded single writer is the only production write path. Accepted
     // work must outlive the HTTP request that admitted it; a synchronous
     // fallback would reintroduce cancellation windows after nonce advancement.
@@ -1047,19 +1343,33 @@ async fn main() -> anyhow::Result<()> {
     // the same writer channel and inflight DashMap. Phase 5: the oneshot
     // shutdown sender is held in a local so we can fire it on graceful
     // SIGTERM and drain the queue before the process exits.
-    let queue_depth = miden_agglayer_service::writer_worker::WriterWorker::parse_queue_depth_env();
+    let lanes = miden_agglayer_service::writer_worker::WriterLanes::from_env();
     let tx_ttl = miden_agglayer_service::writer_worker::WriterWorker::parse_tx_ttl_env();
-    let (handle, writer_shutdown) = miden_agglayer_service::writer_worker::WriterWorker::spawn(
-        state.clone(),
-        queue_depth,
-        tx_ttl,
-    );
+    let (handle, writer_shutdown) =
+        miden_agglayer_service::writer_worker::WriterWorker::spawn_with_lanes(
+            state.clone(),
+            lanes,
+            tx_ttl,
+        );
     tracing::info!(
-        queue_depth,
+        ger_queue_depth = lanes.ger_insert.depth,
+        ger_weight = lanes.ger_insert.weight,
+        claim_queue_depth = lanes.claim.depth,
+        claim_weight = lanes.claim.weight,
+        claim_batch = lanes.claim_batch,
         tx_ttl_secs = tx_ttl.as_secs(),
         "single writer worker spawned"
     );
     state.writer_handle = Some(Arc::new(handle));
+    state.signer_inflight_quota = command.signer_inflight_quota;
+    state.future_nonces = Arc::new(
+        miden_agglayer_service::future_nonce_pool::FutureNoncePool::new(
+            command.future_nonce_hold_per_signer,
+            command.future_nonce_hold_total,
+        ),
+    );
+    let _future_nonce_release =
+        miden_agglayer_service::service_send_raw_txn::spawn_future_nonce_release(state.clone());
 
     // L1 InfoTree indexer — eliminates the RD-862 GER decomposition race by
     // proactively indexing every (mainnet, rollup) pair as L1 emits it,
@@ -1088,7 +1398,9 @@ async fn main() -> anyhow::Result<()> {
                         l1_rpc_url,
                         ger_addr,
                         state.store.clone(),
-                    );
+                    )
+                    .with_fallback_urls(command.l1_rpc_fallback_urls.clone())
+                    .with_quorum(command.l1_rpc_quorum);
                 if let Some(from_block) = command.l1_indexer_from_block {
                     indexer = indexer.with_from_block_override(from_block);
                 }
@@ -1145,6 +1457,36 @@ async fn main() -> anyhow::Result<()> {
         );
     }
 
+    // Built-in GER relayer (opt-in). It only relays GERs the indexer has
+    // observed, so without the indexer it has nothing to do.
+    if command.ger_relayer && !command.read_only {
+        let key = match command.ger_relayer_private_key.as_deref() {
+            Some(hex) => hex
+                .parse::<alloy::signers::local::PrivateKeySigner>()
+                .map_err(|e| anyhow::anyhow!("invalid --ger-relayer-private-key: {e}"))?,
+            None => {
+                tracing::warn!(
+                    "--ger-relayer-private-key not set; the GER relayer signs with a random key"
+                );
+                alloy::signers::local::PrivateKeySigner::random()
+            }
+        };
+        if state.l1_rpc_url.is_none() || state.ger_l1_address.is_none() {
+            tracing::warn!(
+                "GER relayer enabled but the L1InfoTreeIndexer is disabled; \
+                 it will never observe a GER to relay"
+            );
+        }
+        let relayer = miden_agglayer_service::ger_relayer::GerRelayer::new(state.clone(), key)
+            .with_min_interval(std::time::Duration::from_secs(
+                command.ger_relayer_min_interval_secs,
+            ));
+        let signer = relayer.address();
+        // Lives for the runtime, like the indexer above.
+        std::mem::forget(relayer.spawn());
+        tracing::info!(%signer, "GER relayer spawned");
+    }
+
     // Faucet-registry security reconciler (tripwire). Only the proxy (bridge admin) may
     // register a faucet, and it writes the local row alongside the on-chain note; a
     // bridge registration with no local row means the admin key was used elsewhere.
@@ -1177,21 +1519,22 @@ async fn main() -> anyhow::Result<()> {
     // main, before any metric-emitting thread exists — see
     // `metrics::install_prometheus_recorder`.)
 
-    // RD-940 Phase 5 — read the previous process's graceful-shutdown
-    // snapshot. A non-zero value means in-flight WriteJobs whose hashes
-    // had already been returned to callers were dropped on the last
-    // restart. Page hard on this counter: every increment is real
-    // unrecovered work and callers MUST re-submit. Must run AFTER
-    // `init_metrics` so the recorder is registered.
-    let dropped = miden_agglayer_service::writer_worker::read_and_clear_drop_snapshot();
-    if dropped > 0 {
-        tracing::error!(
-            count = dropped,
-            "RD-940 dropped_on_restart: previous shutdown left {dropped} in-flight job(s). \
-             Their tx hashes were returned to callers but the work is unrecoverable in v1. \
-             Callers MUST re-submit. Hard-page on this counter."
-        );
-        ::metrics::counter!("agglayer_writer_dropped_on_restart_total").increment(dropped);
+    // RD-940 Phase 5 — count the jobs the previous process's shutdown drain
+    // could not finish. They were marked in the store for immediate recovery,
+    // so orphan recovery re-drives them on its first sweep; the counter is the
+    // restart-pressure signal. Must run AFTER `init_metrics` so the recorder
+    // is registered.
+    match state.store.take_undrained_at_shutdown().await {
+        Ok(0) => {}
+        Ok(inherited) => {
+            tracing::warn!(
+                count = inherited,
+                "previous shutdown left {inherited} writer job(s) undrained; \
+                 orphan recovery re-drives them without backoff"
+            );
+            ::metrics::counter!("agglayer_writer_dropped_on_restart_total").increment(inherited);
+        }
+        Err(e) => tracing::warn!(error = %e, "failed to read undrained-at-shutdown marks"),
     }
 
     // Startup diagnostic: once the initial sync completes, check whether any
@@ -1336,46 +1679,14 @@ async fn main() -> anyhow::Result<()> {
     let url = build_service_url(&command.bind, command.port)?;
     service::serve(url, state.clone(), metrics_handle).await?;
 
-    // RD-940 Phase 5 — graceful drain. When `service::serve` returns
-    // (SIGTERM or upstream error), signal the writer worker to stop
-    // accepting new jobs, give it a short window to finish work that's
-    // already mid-Miden-roundtrip, then snapshot any residual non-terminal
-    // count to the dropped_on_restart tmpfile for the next boot. The
-    // budget (20 s) sits inside aggkit's `WaitTxToBeMined = 2 m` so even
-    // a partial drain doesn't leave aggkit wedged.
+    // RD-940 Phase 5 — graceful drain. The SIGTERM handler in `serve` has
+    // already closed writer admission; keep dispatching queued jobs until a
+    // deadline planned from recent job durations (capped by
+    // `AGGLAYER_WRITER_DRAIN_TIMEOUT`), then mark the remainder in the store
+    // for immediate recovery on the next boot.
+    let drain_cap = miden_agglayer_service::writer_worker::WriterWorker::parse_drain_timeout_env();
+    miden_agglayer_service::writer_worker::drain_on_shutdown(&state, drain_cap).await;
     let _ = writer_shutdown.send(());
-    tracing::info!("single writer worker: drain signal sent");
-    // Light wait — the worker exits its recv loop on the next
-    // iteration. We don't await a JoinHandle (Phase 1 didn't expose
-    // one). A short sleep gives the worker time to flip terminal_at
-    // on anything currently mid-dispatch.
-    tokio::time::sleep(std::time::Duration::from_secs(20)).await;
-    let residual = state
-        .writer_handle
-        .as_ref()
-        .map(|h| h.inflight_non_terminal_count())
-        .unwrap_or(0);
-    if residual > 0 {
-        tracing::warn!(
-            residual,
-            "RD-940 graceful drain: {residual} job(s) still in non-terminal state; \
-             writing snapshot to {} for next-boot dropped_on_restart accounting",
-            miden_agglayer_service::writer_worker::DROP_SNAPSHOT_PATH
-        );
-        miden_agglayer_service::writer_worker::write_drop_snapshot(residual as u64);
-        ::metrics::counter!(
-            "agglayer_writer_drain_outcome_total",
-            "outcome" => "partial",
-        )
-        .increment(1);
-    } else {
-        tracing::info!("RD-940 graceful drain: queue empty, clean shutdown");
-        ::metrics::counter!(
-            "agglayer_writer_drain_outcome_total",
-            "outcome" => "clean",
-        )
-        .increment(1);
-    }
 
     state.miden_client.shutdown()?;
 
@@ -1421,6 +1732,9 @@ mod hardening_tests {
             network_id: 1,
             init: false,
             database_url: None,
+            database_ca_cert: None,
+            database_client_cert: None,
+            database_client_key: None,
             restore: false,
             reset_miden_store: false,
             unlock_miden_accounts: false,
@@ -1428,20 +1742,36 @@ mod hardening_tests {
             bridge_address: miden_agglayer_service::bridge_address::DEFAULT_BRIDGE_ADDRESS
                 .to_string(),
             l1_rpc_url: None,
+            l1_rpc_fallback_urls: vec![],
+            l1_rpc_quorum: 1,
             network_rpc_urls: vec![],
             ger_l1_address: None,
             l1_indexer_from_block: None,
             l1_evidence_tag: "latest".to_string(),
+            ger_relayer: false,
+            ger_relayer_private_key: None,
+            ger_relayer_min_interval_secs: 10,
             faucet_reconciler_poll_secs: 30,
             faucet_reconciler_grace_ticks: 3,
             miden_debug: false,
             cors_allowed_origins: cors,
             admin_api_key: admin,
+            admin_keys_file: None,
             allowed_signers: signers,
             insecure_allow_any_signer: false,
+            ger_remover_signers: Vec::new(),
+            policy_file: None,
             reject_unverified_ger: false,
             rate_limit_per_second: miden_agglayer_service::service::DEFAULT_RATE_LIMIT_PER_SECOND,
             rate_limit_burst: miden_agglayer_service::service::DEFAULT_RATE_LIMIT_BURST,
+            rpc_max_batch_size: miden_agglayer_service::service::DEFAULT_RPC_MAX_BATCH_SIZE,
+            signer_inflight_quota:
+                miden_agglayer_service::future_nonce_pool::DEFAULT_SIGNER_INFLIGHT_QUOTA,
+            future_nonce_hold_per_signer:
+                miden_agglayer_service::future_nonce_pool::DEFAULT_HOLD_PER_SIGNER,
+            future_nonce_hold_total: miden_agglayer_service::future_nonce_pool::DEFAULT_HOLD_TOTAL,
+            safe_block_lag: miden_agglayer_service::finality::DEFAULT_SAFE_BLOCK_LAG,
+            finalized_block_lag: miden_agglayer_service::finality::DEFAULT_FINALIZED_BLOCK_LAG,
             reject_zero_padding_addresses: false,
             require_hardening: require,
             miden_api_key: None,
@@ -1449,6 +1779,8 @@ mod hardening_tests {
             miden_prover_timeout_secs: 120,
             miden_prover_fallback_to_local: false,
             read_only: false,
+            config: None,
+            print_effective_config: false,
         }
     }
 
@@ -1517,6 +1849,39 @@ mod hardening_tests {
         assert!(reasons[0].contains("--insecure-allow-any-signer"));
     }
 
+    /// A Postgres store reachable over plaintext is refused under
+    /// `--require-hardening`: `disable` explicitly, and a missing `sslmode`
+    /// because the driver default (`prefer`) silently downgrades when the
+    /// server does not offer TLS. Only `require` / `verify-ca` / `verify-full`
+    /// pass, in both the URL and the libpq key/value forms.
+    #[test]
+    fn hardening_refuses_plaintext_capable_database_url() {
+        let mut c = cmd(
+            true,
+            Some("strong-admin-key".into()),
+            Some(vec![alloy::primitives::Address::ZERO]),
+            None,
+        );
+        for url in [
+            "host=agglayer-postgres user=agglayer dbname=agglayer_store",
+            "postgres://agglayer@db/agglayer_store?sslmode=disable",
+            "postgres://agglayer@db/agglayer_store?sslmode=prefer",
+        ] {
+            c.database_url = Some(url.into());
+            let reasons = check_hardening_invariants(&c).unwrap_err();
+            assert_eq!(reasons.len(), 1, "{url}: {reasons:?}");
+            assert!(reasons[0].contains("sslmode"), "{url}: {reasons:?}");
+        }
+        for url in [
+            "host=agglayer-postgres sslmode=require dbname=agglayer_store",
+            "postgres://agglayer@db/agglayer_store?sslmode=verify-ca",
+            "postgresql://agglayer@db/agglayer_store?sslmode=verify-full",
+        ] {
+            c.database_url = Some(url.into());
+            assert!(check_hardening_invariants(&c).is_ok(), "{url}");
+        }
+    }
+
     /// When hardening is enabled and the remote prover is unset, the gate
     /// must reject — local proving is the documented bali OOM cause.
     #[test]
@@ -1533,6 +1898,25 @@ mod hardening_tests {
         assert!(reasons[0].contains("--miden-prover-url"));
     }
 
+    /// Under `--require-hardening` the GER relayer must sign with a
+    /// provisioned key, not a random one.
+    #[test]
+    fn hardening_requires_a_ger_relayer_key() {
+        let mut c = cmd(
+            true,
+            Some("strong-admin-key".into()),
+            Some(vec![alloy::primitives::Address::ZERO]),
+            None,
+        );
+        c.ger_relayer = true;
+        let reasons = check_hardening_invariants(&c).unwrap_err();
+        assert_eq!(reasons.len(), 1, "{reasons:?}");
+        assert!(reasons[0].contains("--ger-relayer-private-key"));
+
+        c.ger_relayer_private_key = Some(format!("0x{}", "11".repeat(32)));
+        assert!(check_hardening_invariants(&c).is_ok());
+    }
+
     /// Regression: the H6 strict-mode flag must be spelled
     /// `--reject-unverified-ger-injection` (matching the bail message, the e2e
     /// script, and the env var). Before the explicit `long = ...`, clap derived
@@ -1549,6 +1933,27 @@ mod hardening_tests {
         );
     }
 
+    /// `--l1-rpc-quorum` must fit the configured L1 endpoints, and a fallback
+    /// endpoint needs a primary.
+    #[test]
+    fn l1_rpc_quorum_must_fit_the_configured_endpoints() {
+        let mut c = cmd(false, None, None, None);
+        assert!(check_l1_rpc_quorum(&c).is_ok(), "no L1 RPC, default quorum");
+
+        c.l1_rpc_fallback_urls = vec!["http://fallback:8545".into()];
+        let reason = check_l1_rpc_quorum(&c).unwrap_err();
+        assert!(reason.contains("requires --l1-rpc-url"), "{reason}");
+
+        c.l1_rpc_url = Some("http://anvil:8545".into());
+        c.l1_rpc_quorum = 2;
+        assert!(check_l1_rpc_quorum(&c).is_ok());
+        c.l1_rpc_quorum = 3;
+        let reason = check_l1_rpc_quorum(&c).unwrap_err();
+        assert!(reason.contains("2 L1 RPC endpoint(s)"), "{reason}");
+        c.l1_rpc_quorum = 0;
+        assert!(check_l1_rpc_quorum(&c).is_err());
+    }
+
     // ── Audit H6 startup invariant (PR #121 review point 2) ────────────────
 
     /// Lenient mode (neither strict trigger) needs no L1 evidence source.
@@ -1795,6 +2200,17 @@ mod hardening_tests {
         );
     }
 
+    /// Fallback endpoints get the same URL gate as the primary: a malformed
+    /// fallback would be just as dead an evidence source.
+    #[test]
+    fn h6_strict_with_malformed_fallback_url_refused_at_startup() {
+        let mut c = strict_cmd_with_rpc("http://203.0.113.1:8545");
+        c.l1_rpc_fallback_urls = vec!["http://203.0.113.2:8545".into(), "anvil:8545".into()];
+        let reason = check_h6_evidence_source(&c).unwrap_err();
+        assert!(reason.contains("anvil:8545"), "{reason}");
+        assert!(reason.contains("--l1-rpc-fallback-url"), "{reason}");
+    }
+
     // ── Blocker 2: fresh-DB backfill is an invariant, not advisory ───────────
 
     /// Non-strict mode never enforces the backfill invariant, even on a fresh
@@ -1890,3 +2306,71 @@ mod bind_tests {
         assert_eq!(url.port(), Some(8546));
     }
 }
+
+#[cfg(test)]
+mod config_file_tests {
+    use super::*;
+    use miden_agglayer_service::config_file::{ConfigFile, SETTINGS, Target};
+
+    /// One-shot modes and the config switches themselves stay CLI-only.
+    const CLI_ONLY: &[&str] = &[
+        "init",
+        "restore",
+        "reset_miden_store",
+        "unlock_miden_accounts",
+        "resweep_from_genesis",
+        "config",
+        "print_effective_config",
+    ];
+
+    /// Every serving setting has a file key, and every file key targets a real
+    /// argument — adding a flag without a `[section]` home fails here.
+    #[test]
+    fn every_setting_has_a_file_key() {
+        let command = Command::command();
+        let file_args: Vec<&str> = SETTINGS
+            .iter()
+            .filter_map(|s| match s.target {
+                Target::Arg(id) => Some(id),
+                Target::Env { .. } => None,
+            })
+            .collect();
+        for arg in command.get_arguments() {
+            let id = arg.get_id().as_str();
+            if matches!(id, "help" | "version") || CLI_ONLY.contains(&id) {
+                continue;
+            }
+            assert!(file_args.contains(&id), "--{id} has no config file key");
+        }
+        for id in file_args {
+            assert!(
+                command.get_arguments().any(|a| a.get_id() == id),
+                "config key targets unknown argument `{id}`"
+            );
+        }
+    }
+
+    /// flag > file > built-in default on the real command.
+    #[test]
+    fn flag_beats_file_beats_default() {
+        let path = tempfile::NamedTempFile::new().unwrap();
+        std::fs::write(
+            path.path(),
+            "[server]\nport = 9001\n\n[miden]\nstore_dir = \"/var/lib/miden\"\n",
+        )
+        .unwrap();
+        let file = ConfigFile::load(path.path()).unwrap();
+        let applied = file.apply(Command::command()).unwrap();
+
+        let parsed = |args: &[&str]| {
+            let m = applied.clone().try_get_matches_from(args).unwrap();
+            Command::from_arg_matches(&m).unwrap()
+        };
+        let c = parsed(&["prog"]);
+        assert_eq!(c.port, 9001);
+        assert_eq!(c.miden_store_dir, Some(PathBuf::from("/var/lib/miden")));
+        assert!(!c.read_only, "keys absent from the file keep their default");
+        assert_eq!(parsed(&["prog", "--port", "9100"]).port, 9100);
+        assert_eq!(Command::try_parse_from(["prog"]).unwrap().port, 8546);
+    }
+}
@@ -34,15 +34,16 @@ use miden_protocol::{Felt, Word};
 /// a reachable L1 RPC for the token's origin network.
 pub const METADATA_UNRECOVERABLE_METRIC: &str = "bridge_out_metadata_unrecoverable_total";
 
-/// Maps an `origin_network` id → the RPC URL that serves that network's token
-/// contracts (network 0 = L1, network 2 = a second rollup / L2B, …). Cantina #13
+/// Maps an `origin_network` id → the RPC URLs that serve that network's token
+/// contracts (network 0 = L1, network 2 = a second rollup / L2B, …), primary
+/// first; later URLs are failover endpoints (see [`crate::l1_rpc_pool`]). Cantina #13
 /// metadata recovery uses it to fetch ERC-20 `name()`/`symbol()`/`decimals()`
 /// from the token's ACTUAL origin chain instead of always dialing L1 — a token
 /// whose origin is L2B (origin_network=2) would otherwise be validated against
 /// the wrong chain and fail the keccak gate (finding #62). An empty map means "no
 /// RPC for any network" (recovery falls back to the all-Miden candidate only),
 /// which is exactly the pre-#62 behavior when no `l1_rpc_url` was configured.
-pub type NetworkRpcMap = std::collections::HashMap<u32, String>;
+pub type NetworkRpcMap = std::collections::HashMap<u32, Vec<String>>;
 
 /// Native-ETH sentinel: an all-zero origin token address. Native ETH legitimately
 /// carries empty metadata and must never be touched by recovery.
@@ -393,47 +394,34 @@ fn miden_faucet_candidate(
 /// source. If the L1 RPC points at the wrong origin network the calls return
 /// values that won't match the stored hash and the keccak gate rejects them.
 async fn fetch_l1_token_candidate(
-    l1_rpc_url: &str,
+    rpc_urls: &[String],
     origin_address: &[u8; 20],
 ) -> anyhow::Result<MetadataCandidate> {
-    use alloy::providers::{Provider, ProviderBuilder};
+    use alloy::providers::Provider;
     use alloy::sol_types::SolCall;
     use alloy_rpc_types_eth::TransactionRequest;
 
-    let provider = ProviderBuilder::new().connect_http(l1_rpc_url.parse()?);
+    let rpc = crate::l1_rpc_pool::L1RpcPool::connect(rpc_urls, 1)?;
     let addr = alloy::primitives::Address::from(*origin_address);
+    let call = |input: Vec<u8>| {
+        rpc.call("eth_call", move |p| {
+            let tx = TransactionRequest::default()
+                .to(addr)
+                .input(input.clone().into());
+            async move { p.call(tx).await }
+        })
+    };
 
     let name = {
-        let call = IERC20Metadata::nameCall {};
-        let res = provider
-            .call(
-                TransactionRequest::default()
-                    .to(addr)
-                    .input(call.abi_encode().into()),
-            )
-            .await?;
+        let res = call(IERC20Metadata::nameCall {}.abi_encode()).await?;
         IERC20Metadata::nameCall::abi_decode_returns(&res)?
     };
     let symbol = {
-        let call = IERC20Metadata::symbolCall {};
-        let res = provider
-            .call(
-                TransactionRequest::default()
-                    .to(addr)
-                    .input(call.abi_encode().into()),
-            )
-            .await?;
+        let res = call(IERC20Metadata::symbolCall {}.abi_encode()).await?;
         IERC20Metadata::symbolCall::abi_decode_returns(&res)?
     };
     let decimals = {
-        let call = IERC20Metadata::decimalsCall {};
-        let res = provider
-            .call(
-                TransactionRequest::default()
-                    .to(addr)
-                    .input(call.abi_encode().into()),
-            )
-            .await?;
+        let res = call(IERC20Metadata::decimalsCall {}.abi_encode()).await?;
         IERC20Metadata::decimalsCall::abi_decode_returns(&res)?
     };
 
@@ -464,7 +452,7 @@ pub async fn recover_bridge_out_metadata(
     faucet_id: AccountId,
     bridge_account: Option<&Account>,
     faucet_account: Option<&Account>,
-    l1_rpc_url: Option<&str>,
+    rpc_urls: &[String],
 ) -> EmitMetadata {
     // Fast paths: no recovery (and no account reads) needed.
     if !stored_metadata.is_empty() {
@@ -493,8 +481,8 @@ pub async fn recover_bridge_out_metadata(
     }
 
     // Candidate 2 — authoritative L1 ERC-20 metadata (only if an L1 RPC is wired).
-    if let Some(url) = l1_rpc_url {
-        match fetch_l1_token_candidate(url, origin_address).await {
+    if !rpc_urls.is_empty() {
+        match fetch_l1_token_candidate(rpc_urls, origin_address).await {
             Ok(c) => candidates.push(c),
             Err(e) => {
                 tracing::warn!(
@@ -683,19 +671,22 @@ mod tests {
         #[test]
         fn finding_62_network_rpc_map_selects_rpc_by_origin_network() {
             let mut rpcs = NetworkRpcMap::new();
-            rpcs.insert(0, "http://l1:8545".to_string());
-            rpcs.insert(2, "http://anvil-l2b:8545".to_string());
+            rpcs.insert(0, vec!["http://l1:8545".to_string()]);
+            rpcs.insert(2, vec!["http://anvil-l2b:8545".to_string()]);
 
-            assert_eq!(rpcs.get(&0).map(String::as_str), Some("http://l1:8545"));
             assert_eq!(
-                rpcs.get(&2).map(String::as_str),
-                Some("http://anvil-l2b:8545")
+                rpcs.get(&0).map(Vec::as_slice),
+                Some(&["http://l1:8545".to_string()][..])
+            );
+            assert_eq!(
+                rpcs.get(&2).map(Vec::as_slice),
+                Some(&["http://anvil-l2b:8545".to_string()][..])
             );
             // An unmapped network (e.g. a third rollup we weren't configured for)
             // selects no RPC — recovery falls back to the all-Miden candidate.
-            assert_eq!(rpcs.get(&3).map(String::as_str), None);
+            assert_eq!(rpcs.get(&3).map(Vec::as_slice), None);
             // Empty map == pre-#62 "no L1 RPC configured": every lookup is None.
-            assert_eq!(NetworkRpcMap::new().get(&0).map(String::as_str), None);
+            assert_eq!(NetworkRpcMap::new().get(&0).map(Vec::as_slice), None);
         }
 
         /// A restore rebuild for an L2B-origin faucet (origin_network=2) must carry
@@ -88,6 +88,30 @@ pub fn init_metrics() {
          crash in the receipt→nonce window is the recovery WORKING (the signer is NOT wedged); a \
          steady climb without restarts would signal a store that is losing nonce writes."
     );
+    describe_gauge!(
+        "rpc_future_nonce_held",
+        "Future-nonce eth_sendRawTransaction envelopes parked until their signer's nonce gap \
+         fills (answered -32007)."
+    );
+    describe_counter!(
+        "rpc_future_nonce_held_total",
+        "Future-nonce envelopes newly parked in the holding area."
+    );
+    describe_counter!(
+        "rpc_future_nonce_released_total",
+        "Held envelopes resubmitted once their gap filled, by outcome (admitted, held_again, \
+         refused)."
+    );
+    describe_counter!(
+        "rpc_future_nonce_dropped_total",
+        "Held envelopes discarded, by reason: stale (another tx consumed the nonce) or expired \
+         (the gap stayed open past the hold TTL)."
+    );
+    describe_counter!(
+        "rpc_signer_quota_rejections_total",
+        "Submissions refused with -32006 because the signer was at its in-flight writer quota \
+         (kind=inflight) or its future-nonce holding share (kind=held)."
+    );
     describe_counter!(
         "rpc_nonce_reservation_lost_total",
         "#55 BLOCKER 1 cross-replica guard: a submission LOST the atomic (signer, nonce) \
@@ -154,6 +178,15 @@ pub fn init_metrics() {
         "#156: transactions that have failed recovery repeatedly and need operator \
          attention (alert on any increase)."
     );
+    describe_counter!(
+        "block_timestamps_recorded_total",
+        "Miden block timestamps recorded for synthetic block headers"
+    );
+    describe_counter!(
+        "block_timestamps_skipped_total",
+        "Blocks left on the legacy deterministic timestamp because the catch-up gap \
+         exceeded the per-tick header budget"
+    );
     describe_gauge!(
         "projector_visibility_barrier_held_blocks",
         "#30 visibility barrier: blocks the projector is holding because the reconciler \
@@ -184,6 +217,18 @@ pub fn init_metrics() {
          consumption block. Detection only — getLogs immutability forbids late healing. MUST \
          stay 0; the soak gates on it. Alarmed once per note; the counter is cumulative."
     );
+    describe_gauge!(
+        "synthetic_latest_block",
+        "Synthetic tip served for the `latest` block tag (== the Miden committed frontier)"
+    );
+    describe_gauge!(
+        "synthetic_safe_block",
+        "Block served for the `safe` block tag: the tip minus --safe-block-lag"
+    );
+    describe_gauge!(
+        "synthetic_finalized_block",
+        "Block served for the `finalized` block tag: the tip minus --finalized-block-lag"
+    );
     describe_gauge!(
         "synthetic_projector_completeness_audit_lag",
         "in-proxy completeness auditor liveness beacon: the highest block audited so far \
@@ -208,6 +253,51 @@ pub fn init_metrics() {
     describe_counter!("bridge_outs_total", "Total bridge-out operations");
     describe_counter!("store_errors_total", "Total store operation errors");
     describe_histogram!("rpc_request_duration_seconds", "JSON-RPC request duration");
+    describe_gauge!(
+        "rpc_filters_installed",
+        "Installed eth_newFilter / eth_newBlockFilter filters"
+    );
+    describe_counter!(
+        "rpc_filters_expired_total",
+        "Installed filters dropped after inactivity"
+    );
+    describe_counter!(
+        "rpc_filter_limit_rejected_total",
+        "Filter installs refused by the per-client or global cap"
+    );
+    describe_gauge!(
+        "rpc_policy_version",
+        "Version of the active request policy (signer allow-list, CORS, rate \
+         limit); 1 at boot, +1 per successful reload"
+    );
+    describe_counter!(
+        "rpc_policy_reloads_total",
+        "Request policy reloads. Labels: trigger=sighup|admin, outcome=ok|error \
+         (an error leaves the previous policy active)"
+    );
+    describe_gauge!("rpc_ws_connections", "Open WebSocket JSON-RPC sessions");
+    describe_gauge!(
+        "rpc_ws_subscriptions",
+        "Live eth_subscribe subscriptions across all WebSocket sessions"
+    );
+    describe_counter!(
+        "rpc_ws_notifications_total",
+        "eth_subscription notifications sent. Labels: kind=newHeads|logs"
+    );
+    describe_counter!(
+        "rpc_ws_messages_rate_limited_total",
+        "WebSocket requests refused with -32005 by the per-IP rate limit"
+    );
+    describe_counter!(
+        "rpc_ws_rejected_total",
+        "WebSocket upgrades refused. Labels: reason=origin (browser Origin not \
+         on the CORS allow-list)"
+    );
+    describe_counter!(
+        "rpc_admin_audit_write_failures_total",
+        "Admin calls whose admin_audit_log row could not be written (the call \
+         itself was still answered)"
+    );
     describe_counter!(
         "miden_client_build_errors_total",
         "Failed attempts to build Miden client connection"
@@ -349,6 +439,19 @@ pub fn init_metrics() {
          quarantine table so operators have a concrete handle for a \
          future recovery flow."
     );
+    describe_counter!(
+        "bridge_out_quarantine_reprojected_total",
+        "admin_reprojectQuarantined attempts on a quarantined B2AGG by \
+         outcome: emitted (BridgeEvent written at the reserved LET index) \
+         or refused (still quarantined or failed; the row is kept)."
+    );
+    describe_histogram!(
+        "rpc_send_raw_txn_sync_wait_seconds",
+        "eth_sendRawTransactionSync: time from admission to the receipt \
+         becoming visible, or to the caller's timeout. Labels: \
+         outcome=receipt|timeout. A rising timeout share means the writer or \
+         prover is slower than callers' budgets."
+    );
     describe_counter!(
         "rpc_claim_ger_not_seen_total",
         "Claim submission rejected at the C6 pre-admission gate because \
@@ -461,12 +564,129 @@ pub fn init_metrics() {
          other call sites). Recorded on both success and error paths."
     );
 
+    // L1 RPC endpoint pool (indexer + L1 metadata recovery).
+    describe_counter!(
+        "l1_rpc_requests_total",
+        "L1 RPC calls per endpoint. Labels: endpoint=0 (primary)|1..N, \
+         outcome=ok|error. A steady error share on one endpoint means it \
+         is being routed around."
+    );
+    describe_gauge!(
+        "l1_rpc_endpoint_score",
+        "Health score (0-100) of each L1 RPC endpoint; failures cost 25, \
+         successes restore 5. Endpoints with three or more consecutive \
+         failures also cool down before being preferred again."
+    );
+    describe_counter!(
+        "l1_rpc_failovers_total",
+        "L1 RPC calls answered by an endpoint other than the first one \
+         tried. Alert: sustained rate >0 → warn (an endpoint is degraded)."
+    );
+    describe_counter!(
+        "l1_rpc_quorum_failures_total",
+        "Quorum reads that could not be decided, so the batch was retried. \
+         Labels: reason=unavailable (fewer than --l1-rpc-quorum endpoints \
+         answered) | disagreement (endpoints returned conflicting logs). \
+         Alert: rate >0 for 15 min → page (the indexer is stalled)."
+    );
+    describe_counter!(
+        "l1_rpc_quorum_dropped_logs_total",
+        "Logs returned by a minority of endpoints and outvoted by a quorum \
+         that did not return them. Any increase means an endpoint served a \
+         log the others do not have; investigate that endpoint."
+    );
+
+    describe_counter!(
+        "l1_info_tree_indexer_reorgs_total",
+        "L1 reorgs the indexer detected below its cursor (a block's parent \
+         hash differs from the recorded end of the previous scanned range). \
+         Labels: outcome=within_checkpoints|beyond_checkpoints. Each rewinds \
+         the cursor and un-verifies the GERs observed above the fork. Alert: \
+         any beyond_checkpoints → page."
+    );
+    describe_histogram!(
+        "l1_info_tree_indexer_reorg_depth_blocks",
+        "Depth of each detected L1 reorg: the old cursor minus the fork block \
+         the indexer rewound to."
+    );
+
+    // Built-in GER relayer (opt-in, --ger-relayer).
+    describe_counter!(
+        "ger_relayer_relayed_total",
+        "insertGlobalExitRoot transactions the built-in GER relayer admitted \
+         to the writer for an L1-observed GER."
+    );
+    describe_counter!(
+        "ger_relayer_skipped_total",
+        "Polls where the GER relayer had a GER to relay but held back. Labels: \
+         reason=in_writer|outstanding|rate_limited. A steady in_writer rate \
+         means an external aggoracle is injecting and the relayer is idle."
+    );
+    describe_counter!(
+        "ger_relayer_errors_total",
+        "GER relayer polls that failed (store read, signing or writer \
+         admission). Alert: sustained increase → the relayer is not injecting."
+    );
+
+    // GER removal (GER-remover role).
+    describe_counter!(
+        "ger_removal_submitted_total",
+        "RemoveGerNotes submitted to Miden. Labels: source=rpc|admin \
+         (removeGlobalExitRoots calldata or admin_removeGlobalExitRoot)."
+    );
+    describe_counter!(
+        "rpc_unauthorized_ger_remover_total",
+        "removeGlobalExitRoots submissions refused because the signer is not on \
+         --ger-remover-signers."
+    );
+    describe_counter!(
+        "ger_removals_total",
+        "GER removals the projector emitted from a consumed RemoveGerNote \
+         (RemoveLastGlobalExitRoot + UpdateRemovalHashChainValue, removal \
+         hash chain rolled)."
+    );
+    describe_counter!(
+        "restore_ger_removal_rejected_total",
+        "RemoveGerNote-shaped consumed notes refused by the MA#28 provenance \
+         check (missing metadata, wrong sender or wrong target). Alert: any \
+         increase → investigate who minted the note."
+    );
+
+    // Reconstructed L1 info tree (L1InfoTreeIndexer).
+    describe_gauge!(
+        "l1_info_tree_leaf_count",
+        "Leaves in the locally reconstructed L1 info tree."
+    );
+    describe_counter!(
+        "l1_info_tree_leaves_appended_total",
+        "UpdateL1InfoTree leaves appended to the reconstructed L1 info tree."
+    );
+    describe_counter!(
+        "l1_info_tree_root_checks_total",
+        "Cross-checks of the reconstructed tree against the GER manager's \
+         getRoot() at the scanned frontier. Labels: outcome=match|mismatch \
+         (mismatch also covers a re-scanned leaf that differs from the stored \
+         one). Alert: any mismatch → page; the tree stops verifying."
+    );
+    describe_counter!(
+        "l1_info_tree_gaps_total",
+        "Indexer batches whose first L1 info tree leaf lies past the stored \
+         tree, so no leaves were appended. Expected when the scan started \
+         mid-history; backfill from the GER manager's deployment block."
+    );
+    describe_counter!(
+        "l1_info_tree_errors_total",
+        "Indexer batches left unadvanced because the L1 info tree update \
+         failed (RPC or store error). Retried on the next poll."
+    );
+
     // RD-940 — single writer observability (Spec F §4).
     describe_gauge!(
         "agglayer_writer_queue_depth",
-        "RD-940: current number of WriteJobs sitting in the writer-worker \
-         mpsc channel (gauge). Alert: >0.8×cap for 10 min → warn; \
-         >0.95×cap for 2 min → page."
+        "RD-940: current number of WriteJobs sitting in a writer-worker \
+         lane (gauge). Labels: lane=ger_insert|claim; GER removals share the \
+         ger_insert lane. Alert: >0.8×lane cap for 10 min → warn; \
+         >0.95×lane cap for 2 min → page."
     );
     describe_gauge!(
         "agglayer_writer_inflight_jobs",
@@ -482,12 +702,37 @@ pub fn init_metrics() {
     describe_counter!(
         "agglayer_writer_queue_full_rejections_total",
         "RD-940: eth_sendRawTransaction requests rejected because the \
-         writer-worker mpsc channel was at capacity. Wire response is \
+         writer-worker lane for their kind was at capacity. Wire response is \
          JSON-RPC -32005 'writer queue saturated; retry' (geth's \
          LimitExceeded); aggkit's ethtxmanager retries transparently. \
          Labels: kind=claim|ger_insert. Alert: rate >0.1/s for 5 min → page."
     );
 
+    describe_histogram!(
+        "agglayer_writer_claim_batch_size",
+        "RD-940: claims published together as one Miden transaction, per \
+         batched dispatch (only batches of two or more are recorded). Capped \
+         by AGGLAYER_WRITER_CLAIM_BATCH_MAX. Informational."
+    );
+    describe_counter!(
+        "agglayer_writer_claim_batch_fallbacks_total",
+        "RD-940: batched claim transactions that failed, after which the \
+         claims without a durable note handoff were re-driven one by one. \
+         Alert: sustained rate >0 → warn (batching is not paying off)."
+    );
+    describe_gauge!(
+        "agglayer_writer_paused",
+        "RD-940: 1 while writer dispatch is paused by admin_pauseWriter, 0 \
+         otherwise. Admission stays open while paused, so the lanes fill. \
+         Alert: 1 for >30 min → warn (maintenance left on)."
+    );
+    describe_counter!(
+        "agglayer_writer_jobs_cancelled_total",
+        "RD-940: queued writer jobs cancelled by admin_cancelWriterJob; each \
+         gets a status:0x0 receipt. Labels: kind=claim|ger_insert. \
+         Informational."
+    );
+
     // RD-940 Phase 5 observability — the remaining 3 metrics from Spec F §4.
     describe_counter!(
         "agglayer_writer_job_failures_total",
@@ -497,22 +742,25 @@ pub fn init_metrics() {
     );
     describe_counter!(
         "agglayer_writer_dropped_on_restart_total",
-        "RD-940: queue-depth snapshot read on boot from the previous \
-         process's graceful shutdown. A non-zero value means the previous \
-         restart lost that many in-memory dispatches whose signed envelopes \
-         remain durable — those callers MUST re-submit the SAME hash. \
-         **Hard page on increase[1h]>0** — restart-pressure tripwire. \
-         The metric is silent under SIGKILL because the tmpfile is only \
-         written on graceful drain; combined with pre-kill queue-depth \
-         history this still pinpoints the loss window."
+        "RD-940: writer jobs the previous process's shutdown drain could not \
+         finish, counted on boot from the store marks. They are re-driven by \
+         orphan recovery without backoff; no caller action is needed. Alert \
+         on sustained increase (restart pressure). Silent under SIGKILL, \
+         where recovery still finds the pending rows on its normal schedule."
     );
     describe_counter!(
         "agglayer_writer_drain_outcome_total",
         "RD-940: graceful-shutdown drain outcomes. Labels: outcome=clean \
-         (queue empty within budget) | partial (budget elapsed, residual \
-         jobs left for dropped_on_restart accounting). Dashboard only — \
+         (queue empty within the deadline) | partial (deadline reached, \
+         residual jobs marked for immediate recovery). Dashboard only — \
          not paging."
     );
+    describe_counter!(
+        "agglayer_writer_drain_jobs_total",
+        "RD-940: jobs handled by the graceful-shutdown drain. Labels: \
+         outcome=dispatched (run by the worker after admission closed) | \
+         marked_for_recovery (left for the next boot's orphan recovery)."
+    );
 }
 
 // =====================================================================
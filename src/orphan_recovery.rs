@@ -178,6 +178,34 @@ async fn finalize_already_claimed(
     true
 }
 
+/// Finalise the original proxy hash with a terminal REVERTED
+/// (`GlobalExitRootNotFound()`) receipt for a GER removal whose GER is already
+/// gone from the bridge without this tx's RemoveGerNote being consumed. Unlike
+/// injection, removal is not idempotent on-chain: re-driving would build a
+/// note the bridge rejects. Same confirmed-duplicate path as
+/// `finalize_already_claimed`. Returns `true` only when durably written.
+#[must_use]
+async fn finalize_ger_already_removed(
+    service: &ServiceState,
+    tx: &RecoverablePendingTxn,
+    ger_bytes: &[u8; 32],
+) -> bool {
+    let block = service.store.get_latest_block_number().await.unwrap_or(0);
+    let msg = "execution reverted: GlobalExitRootNotFound()".to_string();
+    if let Err(e) = service
+        .store
+        .txn_commit_confirmed_duplicate(tx.tx_hash, Err(msg), block)
+        .await
+    {
+        tracing::warn!(target: "recovery", tx_hash = %tx.tx_hash, error = %e, "recovery: finalize_ger_already_removed commit failed; stopping signer, will retry next sweep");
+        return false;
+    }
+    let _ = service.store.clear_recovery_backoff(tx.tx_hash).await;
+    ::metrics::counter!("orphan_recovery_successes_total").increment(1);
+    tracing::warn!(target: "recovery", tx_hash = %tx.tx_hash, nonce = tx.nonce, signer = %tx.signer, ger = %hex::encode(ger_bytes), "recovery: GER already removed elsewhere — finalised original hash as GlobalExitRootNotFound (reverted), not resubmitted");
+    true
+}
+
 /// Finalise the original hash with a terminal FAILURE receipt (a deterministic,
 /// non-retryable error). Returns `true` only when durably written (see #6).
 #[must_use]
@@ -302,6 +330,15 @@ async fn recover_one(service: &ServiceState, signer: Address, tx: &RecoverablePe
             )
             .await
         }
+        DecodedWriteCall::GerRemoval { ger_bytes } => {
+            crate::applied_state::reconcile_ger_removal_recovery(
+                service,
+                *ger_bytes,
+                handoff_note_id,
+                fresh_has_handoff,
+            )
+            .await
+        }
         DecodedWriteCall::Claim { params } => {
             crate::applied_state::reconcile_claim_recovery(
                 service,
@@ -334,6 +371,7 @@ async fn recover_one(service: &ServiceState, signer: Address, tx: &RecoverablePe
             // block with no event. Leave it PENDING and poll — projection finalises it.
             let what = match &decoded {
                 DecodedWriteCall::Ger { .. } => "GER",
+                DecodedWriteCall::GerRemoval { .. } => "GER removal",
                 DecodedWriteCall::Claim { .. } => "claim",
             };
             poll_next_sweep(
@@ -356,6 +394,13 @@ async fn recover_one(service: &ServiceState, signer: Address, tx: &RecoverablePe
                         Step::StopSigner
                     }
                 }
+                DecodedWriteCall::GerRemoval { ger_bytes } => {
+                    if finalize_ger_already_removed(service, tx, ger_bytes).await {
+                        Step::Continue
+                    } else {
+                        Step::StopSigner
+                    }
+                }
                 DecodedWriteCall::Claim { params } => {
                     if finalize_already_claimed(service, tx, params.globalIndex).await {
                         Step::Continue
@@ -442,6 +487,13 @@ async fn recover_one(service: &ServiceState, signer: Address, tx: &RecoverablePe
         defer_with_backoff(service, tx, "no writer handle available").await;
         return Step::StopSigner;
     };
+    // A draining writer refuses the enqueue anyway; recording an attempt now
+    // would hand the row to the next boot with a backoff instead of the
+    // immediate re-drive the drain marks it for.
+    if handle.is_draining() {
+        poll_next_sweep(tx, "writer draining for shutdown");
+        return Step::StopSigner;
+    }
 
     // Reviewer #1 — persist the next backoff BEFORE the enqueue, and enqueue ONLY
     // after a CONFIRMED durable update. If the persist FAILS (DB error, or no row
@@ -49,7 +49,7 @@ use crate::miden_client::{
     MidenClient, MidenClientLib, ensure_complete_note_response, ordered_account_transactions,
 };
 use crate::store::Store;
-use miden_base_agglayer::UpdateGerNote;
+use miden_base_agglayer::{RemoveGerNote, UpdateGerNote};
 use miden_client::store::{InputNoteRecord, NoteFilter};
 use miden_protocol::account::AccountId;
 use miden_protocol::note::{NoteAttachments, NoteDetails, NoteId, NoteMetadata, Nullifier};
@@ -893,7 +893,8 @@ async fn restore_faucet_identities(
                         &conversion,
                         network_rpcs
                             .get(&conversion.origin_network)
-                            .map(String::as_str),
+                            .map(Vec::as_slice)
+                            .unwrap_or_default(),
                     )
                     .await
                     {
@@ -1271,7 +1272,10 @@ pub(crate) async fn project_b2agg_note(
             faucet_account.as_ref(),
             // Finding #62: dial the token's ACTUAL origin-network RPC (L1 for
             // network 0, L2B for network 2, …) so the keccak gate validates.
-            network_rpcs.get(&origin.origin_network).map(String::as_str),
+            network_rpcs
+                .get(&origin.origin_network)
+                .map(Vec::as_slice)
+                .unwrap_or_default(),
         )
         .await
     };
@@ -2290,6 +2294,109 @@ pub(crate) async fn project_ger_note(
     Ok(GerProjectOutcome::Emitted)
 }
 
+/// Project a single consumed note through the GER-removal derivation: for a
+/// sanctioned `RemoveGerNote` whose GER is currently injected, emit the
+/// `RemoveLastGlobalExitRoot` + `UpdateRemovalHashChainValue` logs, roll the
+/// removal hash chain and clear `is_injected` (`commit_ger_removal_atomic`).
+///
+/// Same MA#28 provenance gate as [`project_ger_note`]: `RemoveGerNote::create`
+/// sets the same sender (the GER-manager account, which holds the remover
+/// role) and the same bridge attachment. Shares its `GerProjectOutcome`.
+pub(crate) async fn project_ger_removal_note(
+    store: &Arc<dyn Store>,
+    note: &InputNoteRecord,
+    output_metadata: &std::collections::HashMap<[u8; 32], NoteMetadata>,
+    expected_sender: AccountId,
+    expected_target: AccountId,
+    block_number: u64,
+    block_hash: [u8; 32],
+) -> anyhow::Result<GerProjectOutcome> {
+    let details = note.details();
+    if details.script().root() != RemoveGerNote::script_root() {
+        return Ok(GerProjectOutcome::Skipped);
+    }
+
+    let effective_metadata = note
+        .metadata()
+        .or_else(|| output_metadata.get(&note.details_commitment().as_bytes()));
+    let verdict = classify_ger_note(
+        effective_metadata,
+        note.attachments(),
+        expected_sender,
+        expected_target,
+    );
+    if verdict != GerNoteVerdict::Accept {
+        ::metrics::counter!("restore_ger_removal_rejected_total").increment(1);
+        tracing::error!(
+            note_id = %hex::encode(note.details_commitment().as_bytes()),
+            ?verdict,
+            "MA#28: RemoveGerNote-shaped note failed provenance; refusing to project the removal"
+        );
+        return Ok(GerProjectOutcome::Skipped);
+    }
+
+    let note_commitment = hex::encode(note.details_commitment().as_bytes());
+    let observed_tx_hash = store
+        .confirm_note_handoff_by_commitment(&note_commitment)
+        .await?;
+
+    let storage = details.storage();
+    let items = storage.items();
+    if items.len() < RemoveGerNote::NUM_STORAGE_ITEMS {
+        tracing::warn!(
+            note_id = %note_commitment,
+            storage_len = items.len(),
+            "restore: RemoveGerNote has unexpected storage size, skipping"
+        );
+        return Ok(GerProjectOutcome::Skipped);
+    }
+    let ger_bytes = match ger_bytes_from_storage(items) {
+        Ok(g) => g,
+        Err(i) => {
+            tracing::error!(
+                note_id = %note_commitment,
+                limb_index = i,
+                "restore: RemoveGerNote limb exceeds u32::MAX, skipping (X6)"
+            );
+            return Ok(GerProjectOutcome::Skipped);
+        }
+    };
+
+    // The bridge only consumes a RemoveGerNote for a GER it holds, so a GER
+    // that is not injected here was already removed by an earlier projection.
+    if !store.is_ger_injected(&ger_bytes).await? {
+        return Ok(GerProjectOutcome::Skipped);
+    }
+
+    // Real `removeGlobalExitRoots` hash (signed by the caller or, for an
+    // admin removal, by the proxy) via the note link, else derived.
+    let tx_hash = match observed_tx_hash {
+        Some(real_tx) => real_tx,
+        None => match store.get_tx_for_note(&note_commitment).await? {
+            Some(real_tx) => real_tx,
+            None => {
+                let mut hasher = Keccak256::new();
+                hasher.update(b"restore-ger-removal-miden-");
+                hasher.update(note_commitment.as_bytes());
+                format!("0x{}", hex::encode(hasher.finalize()))
+            }
+        },
+    };
+
+    store
+        .commit_ger_removal_atomic(block_number, block_hash, &tx_hash, &ger_bytes)
+        .await?;
+    ::metrics::counter!("ger_removals_total").increment(1);
+    tracing::info!(
+        note_id = %note_commitment,
+        ger = %hex::encode(ger_bytes),
+        %tx_hash,
+        "projected GER removal from consumed RemoveGerNote"
+    );
+
+    Ok(GerProjectOutcome::Emitted)
+}
+
 /// Phase 3: scan consumed UpdateGerNote notes to rebuild GER state.
 ///
 /// Cantina MA#28 — also asserts that the consumed note was minted by the
@@ -2390,6 +2497,22 @@ async fn restore_gers(
                         ger_count += 1;
                         log_count += 1;
                     }
+                    // Removals fold into the same ordered replay: a GER removed
+                    // and later re-injected must come back injected.
+                    if project_ger_removal_note(
+                        &store_clone,
+                        note,
+                        &own_output_metadata,
+                        expected_sender,
+                        expected_target,
+                        blk,
+                        block_hash,
+                    )
+                    .await?
+                        == GerProjectOutcome::Emitted
+                    {
+                        log_count += 2;
+                    }
                 }
 
                 *result_inner.lock().unwrap() = (ger_count, log_count);
@@ -1,8 +1,18 @@
 use crate::COMPONENT;
+use crate::admin_auth::AdminKeyring;
 use crate::hex::hex_decode_u64;
+use crate::service_block_txns::{
+    block_json, service_get_block_receipts, service_get_block_transaction_count_by_hash,
+    service_get_block_transaction_count_by_number, service_get_transaction_by_block_hash_and_index,
+    service_get_transaction_by_block_number_and_index, transaction_index,
+};
 use crate::service_debug::service_debug_trace_transaction;
 use crate::service_estimate_gas::service_estimate_gas;
 use crate::service_eth_call::service_eth_call;
+use crate::service_filters::{
+    service_get_filter_changes, service_get_filter_logs, service_new_block_filter,
+    service_new_filter, service_uninstall_filter,
+};
 use crate::service_get_logs::service_get_logs;
 use crate::service_get_txn_receipt::service_get_txn_receipt;
 use crate::service_helpers::{
@@ -10,22 +20,30 @@ use crate::service_helpers::{
 };
 use crate::service_send_raw_txn::service_send_raw_txn;
 use crate::service_state::ServiceState;
-use crate::service_zkevm::{service_zkevm_get_exit_roots_by_ger, service_zkevm_get_latest_ger};
+use crate::service_zkevm::{
+    service_zkevm_get_exit_roots_by_ger, service_zkevm_get_l1_info_root,
+    service_zkevm_get_l1_info_tree_leaf, service_zkevm_get_l1_info_tree_leaf_by_ger,
+    service_zkevm_get_latest_ger,
+};
 use alloy::primitives::TxHash;
 use anyhow::Context;
+use axum::Extension;
 use axum::Router;
+use axum::extract::ConnectInfo;
 use axum::extract::State;
 use axum::response::IntoResponse;
 use axum::routing::{get, post};
+use axum_jrpc::batch::{BatchEntry, JsonRpcPayload, JsonRpcPayloadResponse};
 use axum_jrpc::error::{JsonRpcError, JsonRpcErrorReason};
 use axum_jrpc::{JrpcResult, JsonRpcExtractor, JsonRpcResponse};
 use http::HeaderValue;
+use std::net::{IpAddr, SocketAddr};
 use std::str::FromStr;
+use std::sync::Arc;
 use tokio::net::TcpListener;
 use tokio::signal::unix::SignalKind;
-use tower::ServiceBuilder;
+use tower::{ServiceBuilder, ServiceExt};
 use tower_governor::GovernorLayer;
-use tower_governor::governor::GovernorConfigBuilder;
 use tower_http::limit::RequestBodyLimitLayer;
 
 /// Default per-IP rate limit (R13). 500 req/sec sustained with a 500-request
@@ -66,11 +84,119 @@ use tower_http::trace::TraceLayer;
 pub const MAX_REQUEST_BODY_BYTES: usize = 256 * 1024;
 use url::Url;
 
+/// Default cap on the number of entries in one JSON-RPC batch. Sized for
+/// bridge-service / alloy batches of block and log lookups; each entry is also
+/// charged against the per-IP rate limit, so the cap bounds the work one HTTP
+/// request can queue rather than the sustained rate. Configurable via
+/// `--rpc-max-batch-size` / `RPC_MAX_BATCH_SIZE`.
+pub const DEFAULT_RPC_MAX_BATCH_SIZE: usize = 100;
+
+/// Per-IP admission check for batch entries, sharing the `GovernorLayer`'s
+/// keyed limiter. The layer charges each HTTP request once; a batch of `n`
+/// entries draws the remaining `n - 1` cells here so a batch cannot be used
+/// to multiply the configured rate. Installed by [`build_app`].
+#[derive(Clone)]
+pub struct RpcEntryLimiter(Arc<dyn Fn(IpAddr) -> bool + Send + Sync>);
+
+impl RpcEntryLimiter {
+    pub fn new(admit: impl Fn(IpAddr) -> bool + Send + Sync + 'static) -> Self {
+        Self(Arc::new(admit))
+    }
+
+    pub(crate) fn admit(&self, ip: IpAddr) -> bool {
+        (self.0)(ip)
+    }
+}
+
 async fn json_rpc_endpoint(
     State(service): State<ServiceState>,
     headers: axum::http::HeaderMap,
+    peer: Option<Extension<ConnectInfo<SocketAddr>>>,
+    payload: JsonRpcPayload,
+) -> JsonRpcPayloadResponse {
+    let peer_ip = peer.map(|Extension(ConnectInfo(addr))| addr.ip());
+    let entries = match payload {
+        JsonRpcPayload::Single(request) => {
+            return JsonRpcPayloadResponse::Single(
+                json_rpc_call(&service, &headers, peer_ip, request).await,
+            );
+        }
+        JsonRpcPayload::Batch(entries) => entries,
+    };
+
+    metrics::histogram!("rpc_batch_size").record(entries.len() as f64);
+    if entries.len() > service.rpc_max_batch_size {
+        metrics::counter!("rpc_batch_rejected_total", "reason" => "too_large").increment(1);
+        return JsonRpcPayloadResponse::Single(JsonRpcResponse::error(
+            (),
+            JsonRpcError::new(
+                JsonRpcErrorReason::InvalidRequest,
+                format!(
+                    "batch too large: {} entries exceeds the limit of {}",
+                    entries.len(),
+                    service.rpc_max_batch_size
+                ),
+                serde_json::Value::Null,
+            ),
+        ));
+    }
+
+    // Entries run sequentially, in request order: a batch may carry several
+    // `eth_sendRawTransaction`s from one signer whose nonces must be admitted
+    // in order, and sequential dispatch keeps a batch from fanning out past
+    // the writer's admission control.
+    let mut responses = Vec::with_capacity(entries.len());
+    for (index, entry) in entries.into_iter().enumerate() {
+        // Entry 0 was paid for by the GovernorLayer when the HTTP request was
+        // admitted. Without ConnectInfo (in-process tests) the layer could not
+        // key the caller either, so there is nothing to charge.
+        let admitted = index == 0
+            || match (&service.rpc_entry_limiter, peer_ip) {
+                (Some(limiter), Some(ip)) => limiter.admit(ip),
+                _ => true,
+            };
+        if !admitted {
+            metrics::counter!("rpc_batch_entries_rate_limited_total").increment(1);
+            match entry {
+                BatchEntry::Call(request) => responses.push(JsonRpcResponse::error(
+                    request.get_answer_id(),
+                    JsonRpcError::new(
+                        JsonRpcErrorReason::ServerError(-32005),
+                        "rate limit exceeded".to_string(),
+                        serde_json::Value::Null,
+                    ),
+                )),
+                BatchEntry::Notification(_) => {}
+                BatchEntry::Invalid(response) => responses.push(response),
+            }
+            continue;
+        }
+        match entry {
+            BatchEntry::Call(request) => {
+                responses.push(json_rpc_call(&service, &headers, peer_ip, request).await);
+            }
+            BatchEntry::Notification(request) => {
+                json_rpc_call(&service, &headers, peer_ip, request).await;
+            }
+            BatchEntry::Invalid(response) => {
+                metrics::counter!("rpc_requests_total", "method" => "other").increment(1);
+                responses.push(response);
+            }
+        }
+    }
+    JsonRpcPayloadResponse::from_batch(responses)
+}
+
+/// Serve one JSON-RPC request (a single-request body or one batch entry):
+/// admin gate, dispatch, and the per-method metrics. Also the dispatch path
+/// for ordinary methods sent over a WebSocket session. `caller` is the peer
+/// IP when known; it keys per-client state such as installed filters.
+pub(crate) async fn json_rpc_call(
+    service: &ServiceState,
+    headers: &axum::http::HeaderMap,
+    caller: Option<IpAddr>,
     request: JsonRpcExtractor,
-) -> JrpcResult {
+) -> JsonRpcResponse {
     let start = std::time::Instant::now();
     let method_name = request.method.clone();
     // Cardinality-safe label for metrics. A request.method comes from the
@@ -85,29 +211,89 @@ async fn json_rpc_endpoint(
     // Without this, admin endpoints (`admin_registerFaucet`, `admin_listFaucets`) are
     // reachable by anyone who can hit the JSON-RPC port — letting a malicious caller
     // poison the faucet registry with attacker-chosen `MetadataHash` for any token.
-    #[allow(clippy::collapsible_if)]
+    //
+    // Every call made with a known key, admitted or forbidden, is appended to
+    // the audit log. Calls without a valid key are only counted: anyone who
+    // can reach the port could otherwise grow the append-only table at will.
+    let mut admin_key = None;
     if method_name.starts_with("admin_") {
-        if let Err(reason) = check_admin_auth(service.admin_api_key.as_deref(), &headers) {
-            metrics::counter!("rpc_admin_auth_rejects_total", "method" => method_label)
-                .increment(1);
-            return Ok(JsonRpcResponse::error(
-                request.get_answer_id(),
-                JsonRpcError::new(
-                    JsonRpcErrorReason::ServerError(-32001),
-                    format!("admin auth: {reason}"),
-                    serde_json::Value::Null,
-                ),
-            ));
+        let params_digest = format!(
+            "{:#x}",
+            alloy::primitives::keccak256(request.parsed.to_string())
+        );
+        match check_admin_auth(&service.admin_keys, headers, &method_name) {
+            Ok(key) => admin_key = Some((key, params_digest)),
+            Err(reason) => {
+                metrics::counter!("rpc_admin_auth_rejects_total", "method" => method_label)
+                    .increment(1);
+                if let AdminAuthError::Forbidden { key } = &reason {
+                    record_admin_audit(
+                        service,
+                        Some(key.clone()),
+                        method_label,
+                        &params_digest,
+                        "forbidden",
+                    )
+                    .await;
+                }
+                return JsonRpcResponse::error(
+                    request.get_answer_id(),
+                    JsonRpcError::new(
+                        JsonRpcErrorReason::ServerError(-32001),
+                        format!("admin auth: {reason}"),
+                        serde_json::Value::Null,
+                    ),
+                );
+            }
         }
     }
 
-    let result = json_rpc_handler(service, request).await;
+    let result = json_rpc_handler(service.clone(), request, caller).await;
+
+    if let Some((key_name, params_digest)) = admin_key {
+        let outcome = if result.is_ok() { "ok" } else { "error" };
+        record_admin_audit(
+            service,
+            Some(key_name),
+            method_label,
+            &params_digest,
+            outcome,
+        )
+        .await;
+    }
 
     metrics::counter!("rpc_requests_total", "method" => method_label).increment(1);
     metrics::histogram!("rpc_request_duration_seconds", "method" => method_label)
         .record(start.elapsed().as_secs_f64());
 
-    result
+    result.unwrap_or_else(|error_response| error_response)
+}
+
+/// Append one admin call to the audit log. Best effort: the call has already
+/// been answered or refused, so a store failure is logged and counted rather
+/// than turned into an error for the caller.
+async fn record_admin_audit(
+    service: &ServiceState,
+    key_name: Option<String>,
+    method: &str,
+    params_digest: &str,
+    outcome: &str,
+) {
+    if let Err(err) = service
+        .store
+        .append_admin_audit(key_name.as_deref(), method, params_digest, outcome)
+        .await
+    {
+        metrics::counter!("rpc_admin_audit_write_failures_total").increment(1);
+        tracing::error!(
+            target: "rpc::admin_audit",
+            method,
+            outcome,
+            key = key_name.as_deref().unwrap_or("-"),
+            error = format!("{err:#}"),
+            "failed to append admin audit log entry"
+        );
+    }
 }
 
 /// Return a metric label for a JSON-RPC method name, restricted to a finite
@@ -130,28 +316,120 @@ fn bucket_method_label(method: &str) -> &'static str {
         "eth_getBalance" => "eth_getBalance",
         "eth_getStorageAt" => "eth_getStorageAt",
         "eth_getLogs" => "eth_getLogs",
+        "eth_newFilter" => "eth_newFilter",
+        "eth_newBlockFilter" => "eth_newBlockFilter",
+        "eth_getFilterChanges" => "eth_getFilterChanges",
+        "eth_getFilterLogs" => "eth_getFilterLogs",
+        "eth_uninstallFilter" => "eth_uninstallFilter",
         "eth_getTransactionCount" => "eth_getTransactionCount",
         "eth_getTransactionByHash" => "eth_getTransactionByHash",
         "eth_getTransactionReceipt" => "eth_getTransactionReceipt",
         "eth_getBlockTransactionCountByNumber" => "eth_getBlockTransactionCountByNumber",
+        "eth_getBlockTransactionCountByHash" => "eth_getBlockTransactionCountByHash",
+        "eth_getTransactionByBlockNumberAndIndex" => "eth_getTransactionByBlockNumberAndIndex",
+        "eth_getTransactionByBlockHashAndIndex" => "eth_getTransactionByBlockHashAndIndex",
+        "eth_getBlockReceipts" => "eth_getBlockReceipts",
         "eth_call" => "eth_call",
         "eth_estimateGas" => "eth_estimateGas",
         "eth_syncing" => "eth_syncing",
         "eth_gasPrice" => "eth_gasPrice",
         "eth_sendRawTransaction" => "eth_sendRawTransaction",
+        "eth_sendRawTransactionSync" => "eth_sendRawTransactionSync",
+        "eth_subscribe" => "eth_subscribe",
+        "eth_unsubscribe" => "eth_unsubscribe",
         "net_version" => "net_version",
         "debug_traceTransaction" => "debug_traceTransaction",
         "zkevm_getLatestGlobalExitRoot" => "zkevm_getLatestGlobalExitRoot",
         "zkevm_getExitRootsByGER" => "zkevm_getExitRootsByGER",
+        "zkevm_getL1InfoTreeLeaf" => "zkevm_getL1InfoTreeLeaf",
+        "zkevm_getL1InfoTreeLeafByGER" => "zkevm_getL1InfoTreeLeafByGER",
+        "zkevm_getL1InfoRoot" => "zkevm_getL1InfoRoot",
         "admin_registerFaucet" => "admin_registerFaucet",
         "admin_registerNativeFaucet" => "admin_registerNativeFaucet",
         "admin_listFaucets" => "admin_listFaucets",
+        "admin_listAuditLog" => "admin_listAuditLog",
+        "admin_getPolicy" => "admin_getPolicy",
+        "admin_reloadPolicy" => "admin_reloadPolicy",
+        "admin_listWriterJobs" => "admin_listWriterJobs",
+        "admin_getWriterJob" => "admin_getWriterJob",
+        "admin_cancelWriterJob" => "admin_cancelWriterJob",
+        "admin_pauseWriter" => "admin_pauseWriter",
+        "admin_resumeWriter" => "admin_resumeWriter",
+        "admin_listQuarantinedBridgeOuts" => "admin_listQuarantinedBridgeOuts",
+        "admin_getQuarantinedBridgeOut" => "admin_getQuarantinedBridgeOut",
+        "admin_reprojectQuarantined" => "admin_reprojectQuarantined",
+        "admin_removeGlobalExitRoot" => "admin_removeGlobalExitRoot",
         // Anything else → "other". Includes typos and method-name-fuzzing
         // attacks. We still log the actual method via tracing for debugging.
         _ => "other",
     }
 }
 
+/// RD-940 — promote writer-queue-saturation to JSON-RPC -32005 (geth's
+/// `LimitExceeded`). aggkit's ethtxmanager retries `-32005` transparently;
+/// without this mapping the default `ApplicationError(1) = SendRawTransaction`
+/// would conflate queue backpressure with all other tx-submission failures,
+/// and ethtxmanager would not classify it as transient. A draining writer and
+/// a full future-nonce holding area get the same code: the submitter retries.
+///
+/// The per-signer admission outcomes get their own codes so a submitter can
+/// tell them apart: `-32006` for a signer over its quota, `-32007` for a
+/// future-nonce envelope held until its gap fills (hash and nonces in `data`).
+fn writer_backpressure_response(
+    err: &anyhow::Error,
+    answer_id: &axum_jrpc::Id,
+) -> Option<JsonRpcResponse> {
+    use crate::future_nonce_pool::{
+        FutureNoncePoolFullError, NONCE_HELD_ERROR_CODE, NonceTooHighHeldError,
+        SIGNER_QUOTA_ERROR_CODE, SignerQuotaExceededError,
+    };
+    let (code, message, data) = if err
+        .downcast_ref::<crate::writer_worker::WriterQueueSaturatedError>()
+        .is_some()
+    {
+        (
+            -32005,
+            "writer queue saturated; retry".to_string(),
+            serde_json::Value::Null,
+        )
+    } else if err
+        .downcast_ref::<crate::writer_worker::WriterShuttingDownError>()
+        .is_some()
+    {
+        (
+            -32005,
+            "service shutting down; retry".to_string(),
+            serde_json::Value::Null,
+        )
+    } else if let Some(full) = err.downcast_ref::<FutureNoncePoolFullError>() {
+        (-32005, full.to_string(), serde_json::Value::Null)
+    } else if let Some(quota) = err.downcast_ref::<SignerQuotaExceededError>() {
+        (
+            SIGNER_QUOTA_ERROR_CODE,
+            quota.to_string(),
+            serde_json::json!({
+                "signer": format!("{:#x}", quota.signer),
+                "count": quota.count,
+                "quota": quota.quota,
+            }),
+        )
+    } else if let Some(held) = err.downcast_ref::<NonceTooHighHeldError>() {
+        (
+            NONCE_HELD_ERROR_CODE,
+            held.to_string(),
+            serde_json::json!({
+                "txHash": format!("{:#x}", held.tx_hash),
+                "nonce": held.nonce,
+                "expectedNonce": held.expected,
+            }),
+        )
+    } else {
+        return None;
+    };
+    let error = JsonRpcError::new(JsonRpcErrorReason::ServerError(code), message, data);
+    Some(JsonRpcResponse::error(answer_id.clone(), error))
+}
+
 /// Outcome of an admin auth check; private detail for documentation.
 #[derive(Debug, PartialEq)]
 enum AdminAuthError {
@@ -159,6 +437,11 @@ enum AdminAuthError {
     MissingHeader,
     MalformedHeader,
     BadToken,
+    /// The token is valid but its key is not scoped for the method. The key
+    /// name goes to the audit log, not to the caller.
+    Forbidden {
+        key: String,
+    },
 }
 
 impl std::fmt::Display for AdminAuthError {
@@ -172,24 +455,30 @@ impl std::fmt::Display for AdminAuthError {
                 f.write_str("malformed Authorization header (expected `Bearer <token>`)")
             }
             Self::BadToken => f.write_str("invalid bearer token"),
+            Self::Forbidden { .. } => f.write_str("admin key is not allowed to call this method"),
         }
     }
 }
 
-/// Verify the `Authorization: Bearer <token>` header against the configured admin
-/// API key. Returns `Ok(())` if the request is authorised; otherwise an
-/// `AdminAuthError` whose `Display` is safe to surface to the caller.
+/// Verify the `Authorization: Bearer <token>` header against the admin keyring
+/// and the matched key's method scope. Returns the key's name if the request
+/// is authorised; otherwise an `AdminAuthError` whose `Display` is safe to
+/// surface to the caller.
 ///
 /// Self-review R1 — pre-fix, every admin method was reachable by any caller who
 /// could hit the JSON-RPC port. We didn't even check `Authorization`. The fix
-/// requires a configured `admin_api_key` (CLI/env); when none is set, every
-/// `admin_*` request is rejected with `NotConfigured`. Constant-time comparison
-/// guards against timing oracles.
+/// requires at least one configured admin key (`--admin-api-key`,
+/// `--admin-keys-file` or the store); when none is set, every `admin_*`
+/// request is rejected with `NotConfigured`. Tokens are compared by hash in
+/// constant time (see [`crate::admin_auth`]).
 fn check_admin_auth(
-    configured: Option<&str>,
+    keyring: &AdminKeyring,
     headers: &axum::http::HeaderMap,
-) -> Result<(), AdminAuthError> {
-    let configured = configured.ok_or(AdminAuthError::NotConfigured)?;
+    method: &str,
+) -> Result<String, AdminAuthError> {
+    if keyring.is_empty() {
+        return Err(AdminAuthError::NotConfigured);
+    }
     let header = headers
         .get(http::header::AUTHORIZATION)
         .ok_or(AdminAuthError::MissingHeader)?;
@@ -201,11 +490,15 @@ fn check_admin_auth(
     // reject standards-compliant clients that lower-case the scheme.
     let token =
         strip_bearer_prefix_case_insensitive(header_str).ok_or(AdminAuthError::MalformedHeader)?;
-    if constant_time_eq(token.as_bytes(), configured.as_bytes()) {
-        Ok(())
-    } else {
-        Err(AdminAuthError::BadToken)
+    let key = keyring
+        .authenticate(token)
+        .ok_or(AdminAuthError::BadToken)?;
+    if !key.allows(method) {
+        return Err(AdminAuthError::Forbidden {
+            key: key.name.clone(),
+        });
     }
+    Ok(key.name.clone())
 }
 
 /// Strip a case-insensitive `Bearer ` (or `bearer `, `BEARER `, etc.) prefix.
@@ -225,28 +518,6 @@ fn strip_bearer_prefix_case_insensitive(s: &str) -> Option<&str> {
     }
 }
 
-/// Constant-time byte equality — prevents an attacker from learning prefix-match
-/// length OR length-mismatch from response timing.
-///
-/// Self-review of-the-fix follow-up: the previous implementation early-returned
-/// `false` on `a.len() != b.len()`, which leaks the configured token length via
-/// timing. Now we walk the longer slice in full, treating any out-of-bounds
-/// byte from the shorter slice as zero. Constant work irrespective of input
-/// shape; the only timing channel is "input length", which is already
-/// observable in the request's wire size.
-fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
-    let max_len = a.len().max(b.len());
-    let mut diff: u32 = 0;
-    // Length difference contributes to `diff` so unequal lengths reject.
-    diff |= (a.len() as u32) ^ (b.len() as u32);
-    for i in 0..max_len {
-        let x = *a.get(i).unwrap_or(&0);
-        let y = *b.get(i).unwrap_or(&0);
-        diff |= u32::from(x ^ y);
-    }
-    diff == 0
-}
-
 pub(crate) fn select_transaction_count(
     accepted_nonce: u64,
     tag: &str,
@@ -261,7 +532,11 @@ pub(crate) fn select_transaction_count(
     }
 }
 
-async fn json_rpc_handler(service: ServiceState, request: JsonRpcExtractor) -> JrpcResult {
+async fn json_rpc_handler(
+    service: ServiceState,
+    request: JsonRpcExtractor,
+    caller: Option<IpAddr>,
+) -> JrpcResult {
     let answer_id = request.get_answer_id();
     let method_name = request.method.clone();
     let method = method_name.as_str();
@@ -273,11 +548,18 @@ async fn json_rpc_handler(service: ServiceState, request: JsonRpcExtractor) -> J
         | "eth_getLogs"
         | "net_version"
         | "eth_getBlockTransactionCountByNumber"
+        | "eth_getBlockTransactionCountByHash"
+        | "eth_getTransactionByBlockNumberAndIndex"
+        | "eth_getTransactionByBlockHashAndIndex"
+        | "eth_getBlockReceipts"
         | "eth_getTransactionCount"
         | "eth_getTransactionByHash"
         | "eth_getTransactionReceipt"
         | "zkevm_getLatestGlobalExitRoot"
-        | "zkevm_getExitRootsByGER" => {
+        | "zkevm_getExitRootsByGER"
+        | "zkevm_getL1InfoTreeLeaf"
+        | "zkevm_getL1InfoTreeLeafByGER"
+        | "zkevm_getL1InfoRoot" => {
             tracing::debug!(target: concat!(module_path!(), "::debug"), "JSON-RPC {method}")
         }
         _ => tracing::debug!("JSON-RPC {method}"),
@@ -329,15 +611,14 @@ async fn json_rpc_handler(service: ServiceState, request: JsonRpcExtractor) -> J
 
         "eth_getBlockByNumber" => {
             let params: (String, bool) = request.parse_params()?;
-            let block_num = match params.0.as_str() {
-                "latest" | "pending" | "finalized" | "safe" => service
-                    .store
-                    .get_latest_block_number()
-                    .await
-                    .map_err(|e| store_error(answer_id.clone(), e))?,
-                "earliest" => 0,
-                any => {
-                    let Ok(num) = hex_decode_u64(any) else {
+            let frontiers = service
+                .frontiers()
+                .await
+                .map_err(|e| store_error(answer_id.clone(), e))?;
+            let block_num = match frontiers.resolve(&params.0) {
+                Some(num) => num,
+                None => {
+                    let Ok(num) = hex_decode_u64(&params.0) else {
                         let error = JsonRpcError::new(
                             JsonRpcErrorReason::InvalidParams,
                             String::from("bad block number"),
@@ -347,12 +628,7 @@ async fn json_rpc_handler(service: ServiceState, request: JsonRpcExtractor) -> J
                     };
                     // Return null for blocks beyond the chain tip to avoid
                     // ensure_block_exists iterating over billions of synthetic blocks.
-                    let latest = service
-                        .store
-                        .get_latest_block_number()
-                        .await
-                        .map_err(|e| store_error(answer_id.clone(), e))?;
-                    if num > latest {
+                    if num > frontiers.latest {
                         return Ok(JsonRpcResponse::success::<serde_json::Value, _>(
                             answer_id,
                             serde_json::Value::Null,
@@ -365,8 +641,10 @@ async fn json_rpc_handler(service: ServiceState, request: JsonRpcExtractor) -> J
             let block = service.block_state.get_block_by_number(block_num);
             match block {
                 Some(b) => Ok(JsonRpcResponse::success::<serde_json::Value, _>(
-                    answer_id,
-                    b.to_json(full_txns),
+                    answer_id.clone(),
+                    block_json(&service, &b, full_txns)
+                        .await
+                        .map_err(|e| store_error(answer_id, e))?,
                 )),
                 None => Ok(JsonRpcResponse::success::<serde_json::Value, _>(
                     answer_id,
@@ -386,8 +664,10 @@ async fn json_rpc_handler(service: ServiceState, request: JsonRpcExtractor) -> J
             let block = service.block_state.get_block_by_hash(&hash);
             match block {
                 Some(b) => Ok(JsonRpcResponse::success::<serde_json::Value, _>(
-                    answer_id,
-                    b.to_json(full_txns),
+                    answer_id.clone(),
+                    block_json(&service, &b, full_txns)
+                        .await
+                        .map_err(|e| store_error(answer_id, e))?,
                 )),
                 None => Ok(JsonRpcResponse::success::<serde_json::Value, _>(
                     answer_id,
@@ -454,7 +734,9 @@ async fn json_rpc_handler(service: ServiceState, request: JsonRpcExtractor) -> J
 
         // aggkit health-polls eth_syncing; the synthetic chain has no download
         // phase (the projector holds the tip at the Miden tip), so report
-        // "not syncing" per the Ethereum JSON-RPC spec (boolean false).
+        // "not syncing" per the Ethereum JSON-RPC spec (boolean false). A chain
+        // younger than the finalized lag is not syncing either: `finalized`
+        // just clamps to genesis until the tip passes the lag.
         "eth_syncing" => Ok(JsonRpcResponse::success(answer_id, false)),
 
         // Standard client-discovery stubs (block explorers / tooling probe
@@ -490,23 +772,57 @@ async fn json_rpc_handler(service: ServiceState, request: JsonRpcExtractor) -> J
                 Ok(hash) => tracing::info!("eth_sendRawTransaction: OK hash={hash}"),
                 Err(err) => tracing::info!("eth_sendRawTransaction: ERR {err:#}"),
             }
-            // RD-940 — promote writer-queue-saturation to JSON-RPC -32005
-            // (geth's `LimitExceeded`). aggkit's ethtxmanager retries
-            // `-32005` transparently; without this mapping the default
-            // `ApplicationError(1) = SendRawTransaction` would conflate
-            // queue backpressure with all other tx-submission failures,
-            // and ethtxmanager would not classify it as transient.
             if let Err(err) = &result
-                && err
-                    .downcast_ref::<crate::writer_worker::WriterQueueSaturatedError>()
-                    .is_some()
+                && let Some(response) = writer_backpressure_response(err, &answer_id)
             {
-                let error = JsonRpcError::new(
-                    JsonRpcErrorReason::ServerError(-32005),
-                    "writer queue saturated; retry".to_string(),
-                    serde_json::Value::Null,
-                );
-                return Err(JsonRpcResponse::error(answer_id, error));
+                return Err(response);
+            }
+            json_rpc_response_from_result(result, answer_id, ServiceErrorCode::SendRawTransaction)
+        }
+
+        "eth_sendRawTransactionSync" => {
+            let params: Vec<serde_json::Value> = request.parse_params()?;
+            let (input, timeout_ms) = crate::service_send_raw_txn_sync::parse_sync_params(&params)
+                .map_err(|reason| {
+                    JsonRpcResponse::error(
+                        answer_id.clone(),
+                        JsonRpcError::new(
+                            JsonRpcErrorReason::InvalidParams,
+                            reason,
+                            serde_json::Value::Null,
+                        ),
+                    )
+                })?;
+            let result = crate::service_send_raw_txn_sync::service_send_raw_txn_sync(
+                service, input, timeout_ms,
+            )
+            .await;
+            match &result {
+                Ok(receipt) => tracing::info!(
+                    "eth_sendRawTransactionSync: OK hash={} block={}",
+                    receipt.transaction_hash,
+                    receipt.block_number.unwrap_or(0)
+                ),
+                Err(err) => tracing::info!("eth_sendRawTransactionSync: ERR {err:#}"),
+            }
+            if let Err(err) = &result {
+                if let Some(response) = writer_backpressure_response(err, &answer_id) {
+                    return Err(response);
+                }
+                // EIP-7966: the tx is admitted; hand back its hash so the
+                // caller can keep polling `eth_getTransactionReceipt`.
+                if let Some(timeout) =
+                    err.downcast_ref::<crate::service_send_raw_txn_sync::SyncReceiptTimeout>()
+                {
+                    let error = JsonRpcError::new(
+                        JsonRpcErrorReason::ApplicationError(
+                            crate::service_send_raw_txn_sync::SYNC_TIMEOUT_ERROR_CODE,
+                        ),
+                        timeout.to_string(),
+                        serde_json::json!(format!("{:#x}", timeout.hash)),
+                    );
+                    return Err(JsonRpcResponse::error(answer_id, error));
+                }
             }
             json_rpc_response_from_result(result, answer_id, ServiceErrorCode::SendRawTransaction)
         }
@@ -565,7 +881,13 @@ async fn json_rpc_handler(service: ServiceState, request: JsonRpcExtractor) -> J
                     "eth_getTransactionByHash: served stored tx {} (input_len={input_len})",
                     format!("{txn_hash:#x}")
                 );
-                let txn = data.to_rpc_transaction(txn_hash, &service.block_state);
+                let mut txn = data.to_rpc_transaction(txn_hash, &service.block_state);
+                if data.result.is_some() {
+                    let index = transaction_index(&*service.store, data.block_num, txn_hash)
+                        .await
+                        .map_err(|e| store_error(answer_id.clone(), e))?;
+                    txn["transactionIndex"] = serde_json::Value::String(format!("{index:#x}"));
+                }
                 return Ok(JsonRpcResponse::success(answer_id, txn));
             }
 
@@ -623,7 +945,11 @@ async fn json_rpc_handler(service: ServiceState, request: JsonRpcExtractor) -> J
                          metadata"
                     );
                 }
-                let synthetic_tx = build_synthetic_tx_json(txn_hash, log, service.chain_id);
+                let mut synthetic_tx = build_synthetic_tx_json(txn_hash, log, service.chain_id);
+                let index = transaction_index(&*service.store, log.block_number, txn_hash)
+                    .await
+                    .map_err(|e| store_error(answer_id.clone(), e))?;
+                synthetic_tx["transactionIndex"] = serde_json::Value::String(format!("{index:#x}"));
                 return Ok(JsonRpcResponse::success(answer_id, synthetic_tx));
             }
 
@@ -638,26 +964,47 @@ async fn json_rpc_handler(service: ServiceState, request: JsonRpcExtractor) -> J
         }
 
         "eth_getLogs" => service_get_logs(service, request).await,
+        "eth_newFilter" => service_new_filter(service, request, caller).await,
+        "eth_newBlockFilter" => service_new_block_filter(service, request, caller).await,
+        "eth_getFilterChanges" => service_get_filter_changes(service, request).await,
+        "eth_getFilterLogs" => service_get_filter_logs(service, request).await,
+        "eth_uninstallFilter" => service_uninstall_filter(service, request).await,
 
         "eth_getBalance" => {
             let params: (String, String) = request.parse_params()?;
-            validate_eth_address(&params.0).map_err(|msg| {
-                JsonRpcResponse::error(
-                    answer_id.clone(),
-                    JsonRpcError::new(
-                        JsonRpcErrorReason::InvalidParams,
-                        format!("eth_getBalance: {msg}"),
-                        serde_json::Value::Null,
-                    ),
-                )
-            })?;
-            Ok(JsonRpcResponse::success(answer_id, "0x0"))
+            let holder = validate_eth_address(&params.0)
+                .and_then(|()| {
+                    alloy::primitives::Address::from_str(&params.0).map_err(|e| e.to_string())
+                })
+                .map_err(|msg| {
+                    JsonRpcResponse::error(
+                        answer_id.clone(),
+                        JsonRpcError::new(
+                            JsonRpcErrorReason::InvalidParams,
+                            format!("eth_getBalance: {msg}"),
+                            serde_json::Value::Null,
+                        ),
+                    )
+                })?;
+            let balance = crate::token_balances::eth_balance(&service, holder)
+                .await
+                .map_err(|e| crate::token_balances::token_read_error(answer_id.clone(), e))?;
+            Ok(JsonRpcResponse::success(answer_id, format!("{balance:#x}")))
         }
 
         "eth_getBlockTransactionCountByNumber" => {
-            let _params: (String,) = request.parse_params()?;
-            Ok(JsonRpcResponse::success(answer_id, "0x0"))
+            service_get_block_transaction_count_by_number(service, request).await
+        }
+        "eth_getBlockTransactionCountByHash" => {
+            service_get_block_transaction_count_by_hash(service, request).await
+        }
+        "eth_getTransactionByBlockNumberAndIndex" => {
+            service_get_transaction_by_block_number_and_index(service, request).await
         }
+        "eth_getTransactionByBlockHashAndIndex" => {
+            service_get_transaction_by_block_hash_and_index(service, request).await
+        }
+        "eth_getBlockReceipts" => service_get_block_receipts(service, request).await,
 
         "eth_getStorageAt" => {
             let params: (String, String, String) = request.parse_params()?;
@@ -683,6 +1030,14 @@ async fn json_rpc_handler(service: ServiceState, request: JsonRpcExtractor) -> J
 
         "zkevm_getExitRootsByGER" => service_zkevm_get_exit_roots_by_ger(service, request).await,
 
+        "zkevm_getL1InfoTreeLeaf" => service_zkevm_get_l1_info_tree_leaf(service, request).await,
+
+        "zkevm_getL1InfoTreeLeafByGER" => {
+            service_zkevm_get_l1_info_tree_leaf_by_ger(service, request).await
+        }
+
+        "zkevm_getL1InfoRoot" => service_zkevm_get_l1_info_root(service, request).await,
+
         "admin_registerFaucet" => {
             let params: (crate::service_admin::RegisterFaucetParams,) = request.parse_params()?;
             let result = crate::service_admin::admin_register_faucet(service, params.0).await;
@@ -731,6 +1086,100 @@ async fn json_rpc_handler(service: ServiceState, request: JsonRpcExtractor) -> J
             Ok(JsonRpcResponse::success(answer_id, serde_json::json!(list)))
         }
 
+        "admin_getPolicy" => {
+            let view = service.policy.view(&service.policy.current());
+            Ok(JsonRpcResponse::success(answer_id, view))
+        }
+
+        "admin_reloadPolicy" => {
+            let result = service
+                .policy
+                .reload("admin")
+                .map(|policy| service.policy.view(&policy));
+            json_rpc_response_from_result(result, answer_id, ServiceErrorCode::AdminPolicy)
+        }
+
+        "admin_listAuditLog" => {
+            let params: Option<Vec<crate::service_admin_audit::AuditLogQuery>> =
+                request.parse_params()?;
+            let query = params
+                .and_then(|p| p.into_iter().next())
+                .unwrap_or_default();
+            let result = crate::service_admin_audit::admin_list_audit_log(service, query).await;
+            json_rpc_response_from_result(result, answer_id, ServiceErrorCode::AdminAuditLog)
+        }
+
+        "admin_listQuarantinedBridgeOuts" => {
+            let params: Option<Vec<crate::service_admin_quarantine::QuarantineListQuery>> =
+                request.parse_params()?;
+            let query = params
+                .and_then(|p| p.into_iter().next())
+                .unwrap_or_default();
+            let result =
+                crate::service_admin_quarantine::admin_list_quarantined(service, query).await;
+            json_rpc_response_from_result(result, answer_id, ServiceErrorCode::AdminQuarantine)
+        }
+
+        "admin_getQuarantinedBridgeOut" => {
+            let params: (crate::service_admin_quarantine::QuarantineGetQuery,) =
+                request.parse_params()?;
+            let result =
+                crate::service_admin_quarantine::admin_get_quarantined(service, params.0).await;
+            json_rpc_response_from_result(result, answer_id, ServiceErrorCode::AdminQuarantine)
+        }
+
+        "admin_reprojectQuarantined" => {
+            let params: (String,) = request.parse_params()?;
+            let result =
+                crate::service_admin_quarantine::admin_reproject_quarantined(service, params.0)
+                    .await;
+            json_rpc_response_from_result(result, answer_id, ServiceErrorCode::AdminQuarantine)
+        }
+
+        "admin_removeGlobalExitRoot" => {
+            let params: (String,) = request.parse_params()?;
+            let result =
+                crate::service_admin_ger::admin_remove_global_exit_root(service, params.0).await;
+            json_rpc_response_from_result(result, answer_id, ServiceErrorCode::AdminGerRemoval)
+        }
+
+        "admin_listWriterJobs" => {
+            let result = crate::service_admin_writer::admin_list_writer_jobs(service).await;
+            json_rpc_response_from_result(result, answer_id, ServiceErrorCode::AdminWriterJob)
+        }
+
+        "admin_getWriterJob" => {
+            let params: (String,) = request.parse_params()?;
+            let result = crate::service_admin_writer::admin_get_writer_job(service, params.0).await;
+            json_rpc_response_from_result(result, answer_id, ServiceErrorCode::AdminWriterJob)
+        }
+
+        "admin_cancelWriterJob" => {
+            let params: (String,) = request.parse_params()?;
+            let result =
+                crate::service_admin_writer::admin_cancel_writer_job(service, params.0).await;
+            json_rpc_response_from_result(result, answer_id, ServiceErrorCode::AdminWriterJob)
+        }
+
+        "admin_pauseWriter" => {
+            let result = crate::service_admin_writer::admin_pause_writer(service).await;
+            json_rpc_response_from_result(result, answer_id, ServiceErrorCode::AdminWriterJob)
+        }
+
+        "admin_resumeWriter" => {
+            let result = crate::service_admin_writer::admin_resume_writer(service).await;
+            json_rpc_response_from_result(result, answer_id, ServiceErrorCode::AdminWriterJob)
+        }
+
+        "eth_subscribe" | "eth_unsubscribe" => Ok(JsonRpcResponse::error(
+            answer_id,
+            JsonRpcError::new(
+                JsonRpcErrorReason::MethodNotFound,
+                format!("{method} is only available over WebSocket (GET /)"),
+                serde_json::Value::Null,
+            ),
+        )),
+
         method => {
             // WARN, not ERROR: internet scanners and explorer capability
             // probes (debug_*, parity_*, trace_*) hit this constantly; an
@@ -857,6 +1306,7 @@ pub async fn serve(
     // Build the router (all routes, including `/metrics`, behind the shared
     // governor + body-limit layer chain — audit H5). See `build_app` for the
     // R13 per_second → per_millisecond rationale.
+    let writer = state.writer_handle.clone();
     let app = build_app(state, metrics_handle);
 
     let listener = url
@@ -873,13 +1323,25 @@ pub async fn serve(
         listener,
         app.into_make_service_with_connect_info::<std::net::SocketAddr>(),
     )
-    .with_graceful_shutdown(shutdown_signal())
+    .with_graceful_shutdown(async move {
+        shutdown_signal().await;
+        // Close writer admission before HTTP finishes winding down, so
+        // requests still in flight get "shutting down" instead of a hash
+        // for work the drain may not reach.
+        if let Some(writer) = writer {
+            writer.begin_drain();
+        }
+    })
     .await?;
     Ok(())
 }
 
 /// Build the application router: JSON-RPC routes + `/health` + `/metrics`, all
-/// behind the shared layer chain (rate limit, body limit, CORS, trace).
+/// behind the shared layer chain (rate limit, body limit, CORS, trace). The
+/// rate limit and CORS come from the active [`RuntimePolicy`] per request, so
+/// a policy reload takes effect without rebuilding the router.
+///
+/// [`RuntimePolicy`]: crate::runtime_policy::RuntimePolicy
 ///
 /// Audit H5 — previously `/metrics` was mounted AFTER `.with_state(...)`, which
 /// put it on a separate sub-router that escaped the `GovernorLayer` (per-IP
@@ -889,28 +1351,23 @@ pub async fn serve(
 /// routes are now mounted BEFORE `.with_state(...)` so the layer chain applies
 /// uniformly.
 pub(crate) fn build_app(
-    state: ServiceState,
+    mut state: ServiceState,
     metrics_handle: metrics_exporter_prometheus::PrometheusHandle,
 ) -> Router<()> {
-    // R13 — per-IP rate limit (500 req/sec, 500-token burst by default).
-    // `per_second(N)` is named misleadingly in tower_governor — it sets the
-    // replenish PERIOD, not the rate. We use `.per_millisecond(1000 / N)` so
-    // the config name maps to the actual sustained rate (clamped to ≥1 ms).
-    let replenish_period_ms = 1000_u64
-        .checked_div(state.rate_limit_per_second)
-        .unwrap_or(1)
-        .max(1);
-    let governor_conf = std::sync::Arc::new(
-        GovernorConfigBuilder::default()
-            .per_millisecond(replenish_period_ms)
-            .burst_size(state.rate_limit_burst)
-            .finish()
-            .expect("rate-limit config must produce a valid governor"),
-    );
-    let governor_layer = GovernorLayer::new(governor_conf);
+    // Batch entries beyond the first draw from the same per-IP buckets as
+    // the policy layer, looked up per check so a reload is followed.
+    let policy = state.policy.clone();
+    state.rpc_entry_limiter = Some(RpcEntryLimiter::new(move |ip| {
+        policy.current().rate_limit.limiter().check_key(&ip).is_ok()
+    }));
 
     Router::new()
-        .route("/", post(json_rpc_endpoint))
+        // GET upgrades to a WebSocket session (eth_subscribe); it sits behind
+        // the same governor / CORS layers as the POST endpoint.
+        .route(
+            "/",
+            post(json_rpc_endpoint).get(crate::subscriptions::ws_endpoint),
+        )
         .route("/health", get(health_check))
         // H5 — /metrics mounted here (before .with_state) so it inherits the
         // governor + body-limit layers below.
@@ -929,14 +1386,35 @@ pub(crate) fn build_app(
                 // than MAX_REQUEST_BODY_BYTES are rejected with HTTP 413 by tower-http
                 // without ever allocating the full payload.
                 .layer(RequestBodyLimitLayer::new(MAX_REQUEST_BODY_BYTES))
-                // R13 — per-IP rate limiting. Applied before the JSON-RPC handler so
-                // an attacker cannot exhaust the worker pool via a flood.
-                .layer(governor_layer)
-                .layer(build_cors_layer(state.cors_allowed_origins.as_deref())),
+                // R13 — per-IP rate limiting, then CORS (R11), both from the
+                // active policy. Applied before the JSON-RPC handler so an
+                // attacker cannot exhaust the worker pool via a flood.
+                .layer(axum::middleware::from_fn_with_state(
+                    state.policy.clone(),
+                    apply_runtime_policy,
+                )),
         )
         .with_state(state)
 }
 
+/// Run the request through the rate-limit and CORS layers of the policy
+/// active when it arrived.
+async fn apply_runtime_policy(
+    State(policy): State<crate::runtime_policy::PolicyHandle>,
+    request: axum::extract::Request,
+    next: axum::middleware::Next,
+) -> axum::response::Response {
+    let current = policy.current();
+    let service = ServiceBuilder::new()
+        .layer(GovernorLayer::new(current.rate_limit.clone()))
+        .layer(current.cors.clone())
+        .service(next);
+    match service.oneshot(request).await {
+        Ok(response) => response,
+        Err(never) => match never {},
+    }
+}
+
 /// Build the CORS layer for the JSON-RPC route.
 ///
 /// Self-review R11 — pre-fix, the CORS layer used `allow_origin(Any)` and
@@ -945,13 +1423,13 @@ pub(crate) fn build_app(
 /// POST to a private agglayer endpoint via fetch, including state-mutating methods
 /// like `admin_registerFaucet` and `eth_sendRawTransaction`.
 ///
-/// Now driven by `ServiceState::cors_allowed_origins` (CLI flag
-/// `--cors-allowed-origins` / env `CORS_ALLOWED_ORIGINS`):
+/// Now driven by the runtime policy's `cors_allowed_origins` (CLI flag
+/// `--cors-allowed-origins` / env `CORS_ALLOWED_ORIGINS`, or the policy file):
 /// - `None` → no `Access-Control-Allow-Origin` header is emitted; cross-origin
 ///   browser requests are blocked by the browser. Safest production default.
 /// - `Some(["*"])` → wildcard, dev-only convenience.
 /// - `Some([..])` → explicit allowlist.
-fn build_cors_layer(allowed_origins: Option<&[String]>) -> tower_http::cors::CorsLayer {
+pub(crate) fn build_cors_layer(allowed_origins: Option<&[String]>) -> tower_http::cors::CorsLayer {
     let layer = tower_http::cors::CorsLayer::new()
         .allow_methods(tower_http::cors::Any)
         .allow_headers([http::header::CONTENT_TYPE]);
@@ -1029,6 +1507,9 @@ fn validate_eth_address(addr: &str) -> Result<(), String> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::admin_auth::constant_time_eq;
+    use crate::runtime_policy::{PolicyHandle, PolicySettings};
+    use tower_governor::governor::GovernorConfigBuilder;
 
     /// Self-review R13 — repro+regression. Default rate-limit constants
     /// must produce a buildable governor config that delivers the expected
@@ -1077,6 +1558,56 @@ mod tests {
         );
     }
 
+    /// Saturation, signer quota and a held future nonce each reach the wire
+    /// with their own code; any other submission error keeps the default.
+    #[test]
+    fn admission_refusals_map_to_distinct_codes() {
+        use crate::future_nonce_pool::{NonceTooHighHeldError, SignerQuotaExceededError};
+        let id = axum_jrpc::Id::Num(7);
+        let code = |err: anyhow::Error| {
+            let response = writer_backpressure_response(&err, &id)?;
+            let value = serde_json::to_value(response).unwrap();
+            Some((
+                value["error"]["code"].clone(),
+                value["error"]["data"].clone(),
+            ))
+        };
+        let signer = alloy::primitives::Address::from([0x11; 20]);
+
+        let (saturated, _) = code(crate::writer_worker::WriterQueueSaturatedError.into()).unwrap();
+        assert_eq!(saturated, -32005);
+        let (full, _) = code(crate::future_nonce_pool::FutureNoncePoolFullError.into()).unwrap();
+        assert_eq!(full, -32005);
+        let (quota, data) = code(
+            SignerQuotaExceededError {
+                signer,
+                what: "in-flight",
+                count: 4,
+                quota: 4,
+            }
+            .into(),
+        )
+        .unwrap();
+        assert_eq!(quota, -32006);
+        assert_eq!(data["quota"], 4);
+        let (held, data) = code(
+            NonceTooHighHeldError {
+                tx_hash: alloy::primitives::TxHash::from([0x22; 32]),
+                signer,
+                nonce: 5,
+                expected: 3,
+            }
+            .into(),
+        )
+        .unwrap();
+        assert_eq!(held, -32007);
+        assert_eq!(
+            (data["nonce"].clone(), data["expectedNonce"].clone()),
+            (5.into(), 3.into())
+        );
+        assert!(code(anyhow::anyhow!("nonce mismatch")).is_none());
+    }
+
     /// Self-review (review-of-fix follow-up) — repro+regression. Pre-fix,
     /// `rpc_requests_total{method=...}` used the raw attacker-supplied method
     /// string. An unauthenticated caller posting `{"method":"admin_<uuid>"}`
@@ -1153,11 +1684,20 @@ mod tests {
     /// - `wrong_token` — `Bearer x` where x != configured key.
     /// - `correct_token` — accepted.
     /// - `constant_time_eq` — basic equality + length-mismatch coverage.
+    fn keyring(token: &str) -> AdminKeyring {
+        AdminKeyring::from_token(Some(token))
+    }
+
     #[test]
     fn r1_admin_auth_rejects_when_unconfigured() {
         let headers = axum::http::HeaderMap::new();
         assert_eq!(
-            check_admin_auth(None, &headers).unwrap_err(),
+            check_admin_auth(
+                &AdminKeyring::from_token(None),
+                &headers,
+                "admin_listFaucets"
+            )
+            .unwrap_err(),
             AdminAuthError::NotConfigured
         );
     }
@@ -1166,7 +1706,7 @@ mod tests {
     fn r1_admin_auth_rejects_missing_header() {
         let headers = axum::http::HeaderMap::new();
         assert_eq!(
-            check_admin_auth(Some("s3cret"), &headers).unwrap_err(),
+            check_admin_auth(&keyring("s3cret"), &headers, "admin_listFaucets").unwrap_err(),
             AdminAuthError::MissingHeader
         );
     }
@@ -1179,7 +1719,7 @@ mod tests {
             HeaderValue::from_static("Basic dXNlcjpwYXNz"),
         );
         assert_eq!(
-            check_admin_auth(Some("s3cret"), &headers).unwrap_err(),
+            check_admin_auth(&keyring("s3cret"), &headers, "admin_listFaucets").unwrap_err(),
             AdminAuthError::MalformedHeader
         );
     }
@@ -1192,7 +1732,7 @@ mod tests {
             HeaderValue::from_static("Bearer wrong"),
         );
         assert_eq!(
-            check_admin_auth(Some("s3cret"), &headers).unwrap_err(),
+            check_admin_auth(&keyring("s3cret"), &headers, "admin_listFaucets").unwrap_err(),
             AdminAuthError::BadToken
         );
     }
@@ -1204,7 +1744,7 @@ mod tests {
             http::header::AUTHORIZATION,
             HeaderValue::from_static("Bearer s3cret"),
         );
-        assert!(check_admin_auth(Some("s3cret"), &headers).is_ok());
+        assert!(check_admin_auth(&keyring("s3cret"), &headers, "admin_listFaucets").is_ok());
     }
 
     #[test]
@@ -1385,8 +1925,12 @@ mod tests {
         let recorder = metrics_exporter_prometheus::PrometheusBuilder::new().build_recorder();
         let metrics_handle = recorder.handle();
         let mut state = crate::test_helpers::create_test_service();
-        state.rate_limit_per_second = 1; // 1 req/sec sustained
-        state.rate_limit_burst = 1; // 1-token burst — second immediate hit throttles
+        state.policy = PolicyHandle::new(PolicySettings {
+            rate_limit_per_second: 1, // 1 req/sec sustained
+            rate_limit_burst: 1,      // 1-token burst — second immediate hit throttles
+            ..PolicySettings::default()
+        })
+        .unwrap();
 
         let app = build_app(state, metrics_handle);
         let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
@@ -1413,4 +1957,532 @@ mod tests {
             "second /metrics request must be rate-limited (governor must cover /metrics)"
         );
     }
+
+    /// Spawn the full router on an ephemeral port (with ConnectInfo, so the
+    /// governor can key the caller) and return the JSON-RPC URL.
+    async fn spawn_app(state: ServiceState) -> String {
+        use std::net::SocketAddr;
+
+        let recorder = metrics_exporter_prometheus::PrometheusBuilder::new().build_recorder();
+        let app = build_app(state, recorder.handle());
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr: SocketAddr = listener.local_addr().unwrap();
+        let server = axum::serve(
+            listener,
+            app.into_make_service_with_connect_info::<SocketAddr>(),
+        );
+        tokio::spawn(async move {
+            let _ = server.await;
+        });
+        format!("http://{addr}/")
+    }
+
+    /// POST one JSON-RPC call to `url` and return the reply body.
+    async fn rpc_call(
+        client: &reqwest::Client,
+        url: &str,
+        method: &str,
+        params: serde_json::Value,
+    ) -> serde_json::Value {
+        client
+            .post(url)
+            .json(&serde_json::json!({
+                "jsonrpc": "2.0", "id": 1, "method": method, "params": params,
+            }))
+            .send()
+            .await
+            .unwrap()
+            .json()
+            .await
+            .unwrap()
+    }
+
+    /// JSON-RPC batches: an array body gets an array reply in
+    /// request order, notifications are executed but omitted, and a malformed
+    /// entry gets its own -32600 without failing its neighbours. Pre-fix the
+    /// extractor rejected any array body with a single -32600.
+    #[tokio::test]
+    async fn batch_request_answers_each_entry() {
+        let url = spawn_app(crate::test_helpers::create_test_service()).await;
+        let body = serde_json::json!([
+            {"jsonrpc": "2.0", "id": 1, "method": "eth_chainId"},
+            {"jsonrpc": "2.0", "method": "eth_chainId"},
+            {"jsonrpc": "2.0", "id": 2, "method": "no_such_method"},
+            "garbage",
+        ]);
+        let reply: serde_json::Value = reqwest::Client::new()
+            .post(&url)
+            .json(&body)
+            .send()
+            .await
+            .unwrap()
+            .json()
+            .await
+            .unwrap();
+        let entries = reply.as_array().expect("batch reply must be an array");
+        assert_eq!(entries.len(), 3, "notification must be omitted: {reply}");
+        assert_eq!(entries[0]["id"], 1);
+        assert!(entries[0]["result"].is_string(), "{reply}");
+        assert_eq!(entries[1]["id"], 2);
+        assert_eq!(entries[1]["error"]["code"], -32601);
+        assert!(entries[2]["id"].is_null());
+        assert_eq!(entries[2]["error"]["code"], -32600);
+    }
+
+    /// A batch larger than `rpc_max_batch_size` is rejected whole
+    /// with one -32600 object, and each entry of an admitted batch draws its
+    /// own rate-limit cell: with a 2-token burst, the third entry of a batch
+    /// is refused with -32005 even though the HTTP request itself was admitted.
+    #[tokio::test]
+    async fn batch_size_cap_and_per_entry_rate_limit() {
+        let call =
+            |id: u64| serde_json::json!({"jsonrpc": "2.0", "id": id, "method": "eth_chainId"});
+        let client = reqwest::Client::new();
+
+        let mut state = crate::test_helpers::create_test_service();
+        state.rpc_max_batch_size = 2;
+        let url = spawn_app(state).await;
+        let reply: serde_json::Value = client
+            .post(&url)
+            .json(&serde_json::json!([call(1), call(2), call(3)]))
+            .send()
+            .await
+            .unwrap()
+            .json()
+            .await
+            .unwrap();
+        assert!(
+            reply.is_object(),
+            "oversized batch must get one error object: {reply}"
+        );
+        assert_eq!(reply["error"]["code"], -32600);
+
+        let mut state = crate::test_helpers::create_test_service();
+        state.policy = PolicyHandle::new(PolicySettings {
+            rate_limit_per_second: 1,
+            rate_limit_burst: 2,
+            ..PolicySettings::default()
+        })
+        .unwrap();
+        let url = spawn_app(state).await;
+        let reply: serde_json::Value = client
+            .post(&url)
+            .json(&serde_json::json!([call(1), call(2), call(3)]))
+            .send()
+            .await
+            .unwrap()
+            .json()
+            .await
+            .unwrap();
+        let entries = reply.as_array().expect("batch reply must be an array");
+        assert!(entries[0]["result"].is_string(), "{reply}");
+        assert!(entries[1]["result"].is_string(), "{reply}");
+        assert_eq!(entries[2]["id"], 3);
+        assert_eq!(entries[2]["error"]["code"], -32005, "{reply}");
+    }
+
+    /// Send one JSON-RPC request over a WebSocket and read the next message,
+    /// which must be its reply.
+    async fn ws_call<S>(
+        ws: &mut tokio_tungstenite::WebSocketStream<S>,
+        id: u64,
+        method: &str,
+        params: serde_json::Value,
+    ) -> serde_json::Value
+    where
+        S: tokio::io::AsyncRead + tokio::io::AsyncWrite + Unpin,
+    {
+        use futures_util::{SinkExt, StreamExt};
+        use tokio_tungstenite::tungstenite::Message;
+
+        let request =
+            serde_json::json!({"jsonrpc": "2.0", "id": id, "method": method, "params": params});
+        ws.send(Message::text(request.to_string())).await.unwrap();
+        let Some(Ok(Message::Text(reply))) = ws.next().await else {
+            panic!("expected a text reply to {method}");
+        };
+        serde_json::from_str(&reply).unwrap()
+    }
+
+    /// WebSocket `eth_subscribe("newHeads")` over the real router:
+    /// a tip advance announced on the shared `TipNotifier` yields one
+    /// notification per new block, in order, carrying the subscription id;
+    /// ordinary methods still work on the same socket, and a cancelled
+    /// subscription reports `true` once and `false` thereafter.
+    #[tokio::test]
+    async fn ws_new_heads_subscription_follows_tip() {
+        use futures_util::StreamExt;
+        use tokio_tungstenite::tungstenite::Message;
+
+        let state = crate::test_helpers::create_test_service();
+        let store = state.store.clone();
+        let notifier = state.tip_notifier.clone();
+        let url = spawn_app(state).await.replacen("http://", "ws://", 1);
+        let (mut ws, _) = tokio_tungstenite::connect_async(url).await.unwrap();
+
+        let reply = ws_call(&mut ws, 1, "eth_chainId", serde_json::json!([])).await;
+        assert!(reply["result"].is_string(), "{reply}");
+        let reply = ws_call(&mut ws, 2, "eth_subscribe", serde_json::json!(["newHeads"])).await;
+        let sub_id = reply["result"]
+            .as_str()
+            .expect("subscription id")
+            .to_string();
+
+        store.set_latest_block_number(2).await.unwrap();
+        notifier.notify(2);
+        for expected in [1_u64, 2] {
+            let Some(Ok(Message::Text(text))) = ws.next().await else {
+                panic!("expected a newHeads notification");
+            };
+            let note: serde_json::Value = serde_json::from_str(&text).unwrap();
+            assert_eq!(note["method"], "eth_subscription");
+            assert_eq!(note["params"]["subscription"], sub_id.as_str());
+            assert_eq!(
+                note["params"]["result"]["number"],
+                format!("0x{expected:x}"),
+                "{note}"
+            );
+        }
+
+        let reply = ws_call(
+            &mut ws,
+            3,
+            "eth_unsubscribe",
+            serde_json::json!([sub_id.as_str()]),
+        )
+        .await;
+        assert_eq!(reply["result"], true);
+        let reply = ws_call(
+            &mut ws,
+            4,
+            "eth_unsubscribe",
+            serde_json::json!([sub_id.as_str()]),
+        )
+        .await;
+        assert_eq!(reply["result"], false);
+    }
+
+    /// Installable filters over HTTP: a block filter reports each
+    /// block past its install point exactly once, a log filter answers
+    /// `eth_getFilterLogs`, and an uninstalled filter is gone (-32000).
+    #[tokio::test]
+    async fn polling_filters_round_trip() {
+        let state = crate::test_helpers::create_test_service();
+        let store = state.store.clone();
+        let url = spawn_app(state).await;
+        let client = reqwest::Client::new();
+        let rpc = |method, params| rpc_call(&client, &url, method, params);
+
+        let reply = rpc("eth_newBlockFilter", serde_json::json!([])).await;
+        let blocks = reply["result"].as_str().expect("filter id").to_string();
+        let reply = rpc("eth_newFilter", serde_json::json!([{"address": "0x01"}])).await;
+        let logs = reply["result"].as_str().expect("filter id").to_string();
+
+        store.set_latest_block_number(3).await.unwrap();
+        let reply = rpc("eth_getFilterChanges", serde_json::json!([blocks])).await;
+        assert_eq!(reply["result"].as_array().map(Vec::len), Some(3), "{reply}");
+        let reply = rpc("eth_getFilterChanges", serde_json::json!([blocks])).await;
+        assert_eq!(reply["result"], serde_json::json!([]), "{reply}");
+
+        let reply = rpc("eth_getFilterLogs", serde_json::json!([logs])).await;
+        assert!(reply["result"].is_array(), "{reply}");
+        let reply = rpc("eth_getFilterLogs", serde_json::json!([blocks])).await;
+        assert_eq!(reply["error"]["code"], -32602, "{reply}");
+
+        let reply = rpc("eth_uninstallFilter", serde_json::json!([blocks])).await;
+        assert_eq!(reply["result"], true);
+        let reply = rpc("eth_getFilterChanges", serde_json::json!([blocks])).await;
+        assert_eq!(reply["error"]["code"], -32000, "{reply}");
+    }
+
+    /// `safe` / `finalized` trail the tip by the configured lags,
+    /// consistently across eth_getBlockByNumber and eth_getLogs, and clamp to
+    /// genesis on a young chain without reporting it as syncing.
+    #[tokio::test]
+    async fn safe_and_finalized_tags_trail_the_tip() {
+        let mut state = crate::test_helpers::create_test_service();
+        state.finality = crate::finality::FinalityLags::new(2, 10).unwrap();
+        let store = state.store.clone();
+        let url = spawn_app(state).await;
+        let client = reqwest::Client::new();
+        let rpc = |method, params| rpc_call(&client, &url, method, params);
+
+        // Younger than the finalized lag: only genesis is finalized yet.
+        store.set_latest_block_number(5).await.unwrap();
+        let reply = rpc("eth_syncing", serde_json::json!([])).await;
+        assert_eq!(reply["result"], false, "{reply}");
+        let reply = rpc(
+            "eth_getBlockByNumber",
+            serde_json::json!(["finalized", false]),
+        )
+        .await;
+        assert_eq!(reply["result"]["number"], "0x0", "{reply}");
+
+        store.set_latest_block_number(20).await.unwrap();
+        let reply = rpc("eth_syncing", serde_json::json!([])).await;
+        assert_eq!(reply["result"], false, "{reply}");
+        for (tag, number) in [("latest", "0x14"), ("safe", "0x12"), ("finalized", "0xa")] {
+            let reply = rpc("eth_getBlockByNumber", serde_json::json!([tag, false])).await;
+            assert_eq!(reply["result"]["number"], number, "{tag}: {reply}");
+        }
+
+        for block in [10u64, 15] {
+            store
+                .add_log(crate::log_synthesis::SyntheticLog {
+                    address: "0x01".to_string(),
+                    topics: vec![],
+                    data: "0x".to_string(),
+                    block_number: block,
+                    block_hash: [0u8; 32],
+                    transaction_hash: format!("0x{block:x}"),
+                    transaction_index: 0,
+                    log_index: 0,
+                    removed: false,
+                })
+                .await
+                .unwrap();
+        }
+        let reply = rpc(
+            "eth_getLogs",
+            serde_json::json!([{"fromBlock": "0x0", "toBlock": "finalized"}]),
+        )
+        .await;
+        let logs = reply["result"].as_array().expect("logs");
+        assert_eq!(logs.len(), 1, "{reply}");
+        assert_eq!(logs[0]["blockNumber"], "0xa");
+    }
+
+    /// A block lists the transactions behind its logs: hashes (or
+    /// full objects) in `eth_getBlockByNumber`, the matching count, by-index
+    /// lookups by number and hash, and one receipt per transaction from
+    /// `eth_getBlockReceipts`, all agreeing on each transaction's index.
+    #[tokio::test]
+    async fn block_lists_its_transactions() {
+        let state = crate::test_helpers::create_test_service();
+        let store = state.store.clone();
+        let block_hash = format!("0x{}", hex::encode(state.block_state.get_block_hash(3)));
+        let url = spawn_app(state).await;
+        let client = reqwest::Client::new();
+        let rpc = |method, params| rpc_call(&client, &url, method, params);
+
+        store.set_latest_block_number(3).await.unwrap();
+        let (first, second) = (
+            format!("0x{}", "11".repeat(32)),
+            format!("0x{}", "22".repeat(32)),
+        );
+        for (tx, log_index) in [(&second, 2u64), (&first, 0), (&second, 1)] {
+            store
+                .add_log(crate::log_synthesis::SyntheticLog {
+                    address: crate::log_synthesis::L2_GLOBAL_EXIT_ROOT_ADDRESS.to_string(),
+                    topics: vec![crate::log_synthesis::UPDATE_HASH_CHAIN_VALUE_TOPIC.to_string()],
+                    data: "0x".to_string(),
+                    block_number: 3,
+                    block_hash: [0u8; 32],
+                    transaction_hash: tx.clone(),
+                    transaction_index: 0,
+                    log_index,
+                    removed: false,
+                })
+                .await
+                .unwrap();
+        }
+
+        let reply = rpc("eth_getBlockByNumber", serde_json::json!(["latest", false])).await;
+        assert_eq!(
+            reply["result"]["transactions"],
+            serde_json::json!([first, second]),
+            "{reply}"
+        );
+        let reply = rpc("eth_getBlockByNumber", serde_json::json!(["0x3", true])).await;
+        assert_eq!(
+            reply["result"]["transactions"][1]["hash"], second,
+            "{reply}"
+        );
+        assert_eq!(
+            reply["result"]["transactions"][1]["transactionIndex"],
+            "0x1"
+        );
+
+        let reply = rpc(
+            "eth_getBlockTransactionCountByNumber",
+            serde_json::json!(["0x3"]),
+        )
+        .await;
+        assert_eq!(reply["result"], "0x2", "{reply}");
+        let reply = rpc(
+            "eth_getBlockTransactionCountByHash",
+            serde_json::json!([block_hash]),
+        )
+        .await;
+        assert_eq!(reply["result"], "0x2", "{reply}");
+        let reply = rpc(
+            "eth_getBlockTransactionCountByNumber",
+            serde_json::json!(["0x2"]),
+        )
+        .await;
+        assert_eq!(reply["result"], "0x0", "{reply}");
+
+        let reply = rpc(
+            "eth_getTransactionByBlockNumberAndIndex",
+            serde_json::json!(["0x3", "0x1"]),
+        )
+        .await;
+        assert_eq!(reply["result"]["hash"], second, "{reply}");
+        let reply = rpc(
+            "eth_getTransactionByBlockHashAndIndex",
+            serde_json::json!([block_hash, "0x0"]),
+        )
+        .await;
+        assert_eq!(reply["result"]["hash"], first, "{reply}");
+        let reply = rpc(
+            "eth_getTransactionByBlockNumberAndIndex",
+            serde_json::json!(["0x3", "0x2"]),
+        )
+        .await;
+        assert!(reply["result"].is_null(), "{reply}");
+        let reply = rpc("eth_getTransactionByHash", serde_json::json!([second])).await;
+        assert_eq!(reply["result"]["transactionIndex"], "0x1", "{reply}");
+
+        let reply = rpc("eth_getBlockReceipts", serde_json::json!(["latest"])).await;
+        let receipts = reply["result"].as_array().expect("receipts");
+        assert_eq!(receipts.len(), 2, "{reply}");
+        assert_eq!(receipts[1]["transactionHash"], second);
+        assert_eq!(receipts[1]["transactionIndex"], "0x1");
+        assert_eq!(receipts[1]["logs"].as_array().unwrap().len(), 2);
+        let reply = rpc("eth_getBlockReceipts", serde_json::json!(["0x9"])).await;
+        assert!(reply["result"].is_null(), "{reply}");
+    }
+
+    /// A key scoped to `admin_list*` may list but not register,
+    /// and every call made with a known key, refused or not, lands in the
+    /// audit log with the key that made it. A call with an unknown token is
+    /// refused without an audit row.
+    #[tokio::test]
+    async fn scoped_admin_keys_are_enforced_and_audited() {
+        use crate::admin_auth::hash_admin_token;
+        use crate::store::AdminKeyRecord;
+
+        let mut state = crate::test_helpers::create_test_service();
+        state.admin_keys = Arc::new(
+            AdminKeyring::new(vec![
+                AdminKeyRecord {
+                    name: "readonly".to_string(),
+                    key_hash: hash_admin_token("t-read"),
+                    methods: vec!["admin_list*".to_string()],
+                },
+                AdminKeyRecord {
+                    name: "ops".to_string(),
+                    key_hash: hash_admin_token("t-ops"),
+                    methods: vec!["*".to_string()],
+                },
+            ])
+            .unwrap(),
+        );
+        let url = spawn_app(state).await;
+        let rpc = |token: &'static str, method: &'static str, params: serde_json::Value| {
+            let url = url.clone();
+            async move {
+                reqwest::Client::new()
+                    .post(&url)
+                    .bearer_auth(token)
+                    .json(&serde_json::json!({
+                        "jsonrpc": "2.0", "id": 1, "method": method, "params": params,
+                    }))
+                    .send()
+                    .await
+                    .unwrap()
+                    .json::<serde_json::Value>()
+                    .await
+                    .unwrap()
+            }
+        };
+
+        let reply = rpc("t-read", "admin_registerFaucet", serde_json::json!([{}])).await;
+        assert_eq!(reply["error"]["code"], -32001, "{reply}");
+        let reply = rpc("t-read", "admin_listFaucets", serde_json::json!([])).await;
+        assert!(reply["result"].is_array(), "{reply}");
+        let reply = rpc("t-wrong", "admin_listFaucets", serde_json::json!([])).await;
+        assert_eq!(reply["error"]["code"], -32001, "{reply}");
+
+        let reply = rpc(
+            "t-ops",
+            "admin_listAuditLog",
+            serde_json::json!([{"limit": 10}]),
+        )
+        .await;
+        let entries = reply["result"].as_array().expect("audit page");
+        let rows: Vec<(serde_json::Value, &str, &str)> = entries
+            .iter()
+            .map(|e| {
+                (
+                    e["key_name"].clone(),
+                    e["method"].as_str().unwrap(),
+                    e["outcome"].as_str().unwrap(),
+                )
+            })
+            .collect();
+        // Newest first; the listing call itself is recorded after it answers.
+        assert_eq!(
+            rows,
+            vec![
+                ("readonly".into(), "admin_listFaucets", "ok"),
+                ("readonly".into(), "admin_registerFaucet", "forbidden"),
+            ],
+            "{reply}"
+        );
+    }
+
+    /// `admin_reloadPolicy` swaps the CORS allow-list under the
+    /// running router: the next request is served under the new list and the
+    /// reported version advances.
+    #[tokio::test]
+    async fn policy_reload_applies_to_the_running_router() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("policy.toml");
+        std::fs::write(&path, "cors_allowed_origins = [\"https://a.example\"]\n").unwrap();
+        let mut state = crate::test_helpers::create_test_service();
+        state.admin_keys = Arc::new(keyring("t-ops"));
+        state.policy =
+            PolicyHandle::load(PolicySettings::default(), Some(path.clone()), false).unwrap();
+        let url = spawn_app(state).await;
+        let client = reqwest::Client::new();
+        let allow_origin = |origin: &'static str| {
+            let request = client
+                .post(&url)
+                .header("origin", origin)
+                .json(&serde_json::json!({"jsonrpc": "2.0", "id": 1, "method": "eth_chainId"}));
+            async move {
+                request
+                    .send()
+                    .await
+                    .unwrap()
+                    .headers()
+                    .get("access-control-allow-origin")
+                    .map(|v| v.to_str().unwrap().to_string())
+            }
+        };
+        assert_eq!(
+            allow_origin("https://a.example").await.as_deref(),
+            Some("https://a.example")
+        );
+        assert_eq!(allow_origin("https://b.example").await, None);
+
+        std::fs::write(&path, "cors_allowed_origins = [\"https://b.example\"]\n").unwrap();
+        let reply: serde_json::Value = client
+            .post(&url)
+            .bearer_auth("t-ops")
+            .json(&serde_json::json!({"jsonrpc": "2.0", "id": 1, "method": "admin_reloadPolicy"}))
+            .send()
+            .await
+            .unwrap()
+            .json()
+            .await
+            .unwrap();
+        assert_eq!(reply["result"]["version"], 2, "{reply}");
+        assert_eq!(allow_origin("https://a.example").await, None);
+        assert_eq!(
+            allow_origin("https://b.example").await.as_deref(),
+            Some("https://b.example")
+        );
+    }
 }
@@ -187,7 +187,7 @@ pub async fn admin_register_faucet(
                         &bridge_account,
                         existing_id,
                         &conversion,
-                        None,
+                        &[],
                     )
                     .await
                     {
@@ -0,0 +1,57 @@
+//! `admin_listAuditLog`: page through the append-only admin audit log.
+//!
+//! Every `admin_*` call is recorded by the JSON-RPC dispatcher (see
+//! `service::json_rpc_call`) with the key that authenticated it, the bucketed
+//! method name, a keccak256 digest of its params and its outcome. Pages are
+//! newest first; pass the last `id` seen as `beforeId` for the next page.
+
+use crate::service_state::ServiceState;
+use serde::{Deserialize, Serialize};
+
+/// Rows returned when the caller gives no `limit`.
+pub const DEFAULT_AUDIT_PAGE: usize = 100;
+/// Largest page served; bigger limits are clamped.
+pub const MAX_AUDIT_PAGE: usize = 1_000;
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct AuditLogQuery {
+    pub limit: Option<usize>,
+    pub before_id: Option<u64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AuditLogEntryView {
+    pub id: u64,
+    /// Unix seconds.
+    pub recorded_at: u64,
+    pub key_name: Option<String>,
+    pub method: String,
+    pub params_digest: String,
+    pub outcome: String,
+}
+
+pub async fn admin_list_audit_log(
+    service: ServiceState,
+    query: AuditLogQuery,
+) -> anyhow::Result<Vec<AuditLogEntryView>> {
+    let limit = query
+        .limit
+        .unwrap_or(DEFAULT_AUDIT_PAGE)
+        .min(MAX_AUDIT_PAGE);
+    let entries = service
+        .store
+        .list_admin_audit(query.before_id, limit)
+        .await?;
+    Ok(entries
+        .into_iter()
+        .map(|e| AuditLogEntryView {
+            id: e.id,
+            recorded_at: e.recorded_at,
+            key_name: e.key_name,
+            method: e.method,
+            params_digest: e.params_digest,
+            outcome: e.outcome,
+        })
+        .collect())
+}
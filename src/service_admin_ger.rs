@@ -0,0 +1,243 @@
+//! `admin_removeGlobalExitRoot`: remove one GER through the GER-remover role.
+//!
+//! The operator counterpart of the sovereign-chain `removeGlobalExitRoots`
+//! calldata accepted by `eth_sendRawTransaction`. The proxy signs that
+//! calldata with a process-local key and admits it into the writer like the
+//! GER relayer's injections, so both paths submit the same `RemoveGerNote`
+//! from the GER-manager account to the bridge, off the RPC request. The
+//! projector emits `RemoveLastGlobalExitRoot` + `UpdateRemovalHashChainValue`,
+//! clears the GER from `globalExitRootMap` and finalises the receipt when the
+//! bridge consumes the note.
+
+use crate::ger::removeGlobalExitRootsCall;
+use crate::service_state::ServiceState;
+use alloy::primitives::{Address, FixedBytes};
+use alloy::signers::local::PrivateKeySigner;
+use alloy_core::sol_types::SolCall;
+use anyhow::Context;
+use serde::Serialize;
+use std::collections::HashSet;
+use std::sync::{LazyLock, Mutex};
+
+/// Gas limit stamped on admin removals; nothing meters it.
+const ADMIN_REMOVAL_GAS_LIMIT: u64 = 100_000;
+
+/// Signs admin removals. Random per process: the key never leaves it, so no
+/// one else can submit as this signer.
+static ADMIN_REMOVAL_KEY: LazyLock<PrivateKeySigner> = LazyLock::new(PrivateKeySigner::random);
+
+/// GERs with an admin removal being admitted in this process. Keeps two
+/// concurrent calls for one GER from both being admitted; once one is, its
+/// writer job and then the writer's removal record refuse the other (see
+/// `validate_ger_removal`).
+static ADMIN_REMOVALS_INFLIGHT: LazyLock<Mutex<HashSet<[u8; 32]>>> =
+    LazyLock::new(|| Mutex::new(HashSet::new()));
+
+struct InflightGuard([u8; 32]);
+
+impl Drop for InflightGuard {
+    fn drop(&mut self) {
+        ADMIN_REMOVALS_INFLIGHT
+            .lock()
+            .expect("admin GER removal set poisoned")
+            .remove(&self.0);
+    }
+}
+
+/// The signer admin removals recover to.
+pub(crate) fn admin_removal_signer() -> Address {
+    ADMIN_REMOVAL_KEY.address()
+}
+
+/// Result of an admitted admin GER removal.
+#[derive(Debug, Serialize)]
+pub struct GerRemovalView {
+    pub global_exit_root: String,
+    /// Hash of the removal transaction. Its receipt is pending until the
+    /// projector emits the removal logs under it.
+    pub tx_hash: String,
+}
+
+fn parse_ger(ger: &str) -> anyhow::Result<[u8; 32]> {
+    let bytes = crate::hex::hex_decode_prefixed(ger).context("parsing globalExitRoot")?;
+    bytes
+        .try_into()
+        .map_err(|_| anyhow::anyhow!("globalExitRoot must be 32 bytes"))
+}
+
+pub async fn admin_remove_global_exit_root(
+    service: ServiceState,
+    ger: String,
+) -> anyhow::Result<GerRemovalView> {
+    let ger = parse_ger(&ger)?;
+    if !ADMIN_REMOVALS_INFLIGHT
+        .lock()
+        .expect("admin GER removal set poisoned")
+        .insert(ger)
+    {
+        anyhow::bail!(
+            "a removal of GER 0x{} is already in flight; wait for it to finish",
+            hex::encode(ger)
+        );
+    }
+    let _guard = InflightGuard(ger);
+
+    crate::service_send_raw_txn::validate_ger_removal(&service, &ger).await?;
+    let input = removeGlobalExitRootsCall {
+        gersToRemove: vec![FixedBytes::from(ger)],
+    }
+    .abi_encode();
+    let tx_hash = crate::service_send_raw_txn::admit_locally_signed(
+        &service,
+        &ADMIN_REMOVAL_KEY,
+        input,
+        ADMIN_REMOVAL_GAS_LIMIT,
+    )
+    .await?;
+    tracing::warn!(
+        ger = %hex::encode(ger),
+        %tx_hash,
+        "admin_removeGlobalExitRoot: removal admitted to the writer; removal logs follow on consumption"
+    );
+    Ok(GerRemovalView {
+        global_exit_root: format!("0x{}", hex::encode(ger)),
+        tx_hash: format!("{tx_hash:#x}"),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::create_test_service;
+    use crate::writer_worker::{DecodedWriteCall, WriterWorker};
+    use alloy::consensus::Transaction;
+    use alloy::primitives::TxHash;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    /// A test service whose writer admits jobs but never dispatches them.
+    fn service_with_paused_writer() -> ServiceState {
+        let mut service = create_test_service();
+        let (handle, shutdown) = WriterWorker::spawn(service.clone(), 64, Duration::from_secs(60));
+        // Leak the shutdown sender so the worker lives for the test.
+        std::mem::forget(shutdown);
+        handle.pause();
+        service.writer_handle = Some(Arc::new(handle));
+        service
+    }
+
+    async fn inject(service: &ServiceState, ger: &[u8; 32]) {
+        service
+            .store
+            .commit_ger_event_atomic(1, [0u8; 32], "0xinjected", ger, None, None, 0)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn refuses_a_ger_that_is_not_applied() {
+        let service = create_test_service();
+        let err = admin_remove_global_exit_root(service, format!("0x{}", "ab".repeat(32)))
+            .await
+            .unwrap_err();
+        assert!(
+            err.to_string().contains("GlobalExitRootNotFound()"),
+            "{err:#}"
+        );
+    }
+
+    /// The removal is a durable, proxy-signed `removeGlobalExitRoots` queued
+    /// in the writer; a second call for the same GER is refused while it is.
+    #[tokio::test]
+    async fn admits_the_removal_into_the_writer() {
+        let service = service_with_paused_writer();
+        let ger = [0xcdu8; 32];
+        inject(&service, &ger).await;
+
+        let view =
+            admin_remove_global_exit_root(service.clone(), format!("0x{}", hex::encode(ger)))
+                .await
+                .unwrap();
+        assert_eq!(view.global_exit_root, format!("0x{}", hex::encode(ger)));
+        let hash: TxHash = view.tx_hash.parse().unwrap();
+        let txn = service
+            .store
+            .txn_get(hash)
+            .await
+            .unwrap()
+            .expect("pending row");
+        assert!(txn.result.is_none());
+        assert_eq!(txn.signer, admin_removal_signer());
+        assert!(matches!(
+            crate::service_send_raw_txn::decode_write_call(txn.envelope.input()).unwrap(),
+            DecodedWriteCall::GerRemoval { ger_bytes } if ger_bytes == ger
+        ));
+        assert!(service.writer_handle.as_ref().unwrap().is_inflight(&hash));
+        assert!(
+            !ADMIN_REMOVALS_INFLIGHT.lock().unwrap().contains(&ger),
+            "guard must release the GER"
+        );
+
+        let err = admin_remove_global_exit_root(service, format!("0x{}", hex::encode(ger)))
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("already in flight"), "{err:#}");
+    }
+
+    /// A removal the writer recorded blocks another one for the same GER
+    /// without its writer entry, as after a restart, until its receipt is
+    /// final.
+    #[tokio::test]
+    async fn recorded_removal_blocks_another_until_its_receipt_is_final() {
+        let mut service = service_with_paused_writer();
+        let ger = [0xceu8; 32];
+        inject(&service, &ger).await;
+        let first: TxHash =
+            admin_remove_global_exit_root(service.clone(), format!("0x{}", hex::encode(ger)))
+                .await
+                .unwrap()
+                .tx_hash
+                .parse()
+                .unwrap();
+        service.store.record_ger_removal(first, &ger).await.unwrap();
+        service.writer_handle = None;
+
+        let err = crate::service_send_raw_txn::validate_ger_removal(&service, &ger)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("already in flight"), "{err:#}");
+
+        // The writer refuses a second removal job too, before any note.
+        let envelope = service
+            .store
+            .txn_get(first)
+            .await
+            .unwrap()
+            .unwrap()
+            .envelope;
+        let err = crate::service_send_raw_txn::worker_handle_ger_removal(
+            &service,
+            ger,
+            TxHash::repeat_byte(0x77),
+            envelope,
+            admin_removal_signer(),
+        )
+        .await
+        .unwrap_err();
+        assert!(err.to_string().contains("already in flight"), "{err:#}");
+
+        service
+            .store
+            .txn_commit(first, Err("reverted".into()), 1, [1; 32])
+            .await
+            .unwrap();
+        crate::service_send_raw_txn::validate_ger_removal(&service, &ger)
+            .await
+            .unwrap();
+    }
+
+    #[test]
+    fn rejects_a_short_ger() {
+        assert!(parse_ger("0x1234").is_err());
+    }
+}
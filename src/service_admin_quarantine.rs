@@ -0,0 +1,494 @@
+//! Quarantined bridge-outs (Cantina MA#18): `admin_listQuarantinedBridgeOuts`,
+//! `admin_getQuarantinedBridgeOut` and `admin_reprojectQuarantined`.
+//!
+//! A B2AGG the bridge consumed but the projector could not translate keeps its
+//! reserved LET index and gets an `unbridgeable_bridge_outs` row instead of a
+//! `BridgeEvent`; the projector then refuses to seal past it. Once the cause is
+//! fixed (faucet registered, parser patched), `admin_reprojectQuarantined`
+//! runs that one note through `restore::project_b2agg_note` again. The event
+//! is emitted only if every projection gate passes, and only into the index
+//! the note already reserved, so LET order never shifts.
+
+use crate::block_state::BlockState;
+use crate::miden_client::MidenClientLib;
+use crate::restore::{B2AggRestoreOutcome, project_b2agg_note};
+use crate::service_state::ServiceState;
+use crate::store::{Store, UnbridgeableBridgeOut};
+use anyhow::Context;
+use miden_client::store::InputNoteRecord;
+use miden_protocol::account::AccountId;
+use miden_protocol::note::NoteId;
+use serde::{Deserialize, Serialize};
+use std::sync::{Arc, Mutex};
+
+/// Rows returned when the caller gives no `limit`.
+pub const DEFAULT_QUARANTINE_PAGE: usize = 100;
+/// Largest page served; bigger limits are clamped.
+pub const MAX_QUARANTINE_PAGE: usize = 1_000;
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct QuarantineListQuery {
+    pub limit: Option<usize>,
+    pub after_note_id: Option<String>,
+    #[serde(default)]
+    pub include_note_dump: bool,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct QuarantineGetQuery {
+    pub note_id: String,
+    #[serde(default)]
+    pub include_note_dump: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct QuarantinedBridgeOutView {
+    pub note_id: String,
+    pub bridge_account: String,
+    pub reason: &'static str,
+    pub detail: String,
+    pub observed_block: u64,
+    /// The LET index the note reserved, if any.
+    pub deposit_count: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note_dump: Option<String>,
+}
+
+fn view(
+    entry: UnbridgeableBridgeOut,
+    deposit_count: Option<u32>,
+    include_note_dump: bool,
+) -> QuarantinedBridgeOutView {
+    QuarantinedBridgeOutView {
+        note_id: entry.note_id,
+        bridge_account: entry.bridge_account.to_hex(),
+        reason: entry.reason.as_str(),
+        detail: entry.detail,
+        observed_block: entry.observed_block,
+        deposit_count,
+        note_dump: include_note_dump.then_some(entry.note_dump),
+    }
+}
+
+pub async fn admin_list_quarantined(
+    service: ServiceState,
+    query: QuarantineListQuery,
+) -> anyhow::Result<Vec<QuarantinedBridgeOutView>> {
+    let limit = query
+        .limit
+        .unwrap_or(DEFAULT_QUARANTINE_PAGE)
+        .min(MAX_QUARANTINE_PAGE);
+    let entries = service
+        .store
+        .list_unbridgeable_bridge_outs(query.after_note_id.as_deref(), limit)
+        .await?;
+    let keys: Vec<String> = entries.iter().map(|e| e.note_id.clone()).collect();
+    let indices = service.store.get_deposit_indices(&keys).await?;
+    Ok(entries
+        .into_iter()
+        .map(|e| {
+            let deposit_count = indices.get(&e.note_id).copied();
+            view(e, deposit_count, query.include_note_dump)
+        })
+        .collect())
+}
+
+pub async fn admin_get_quarantined(
+    service: ServiceState,
+    query: QuarantineGetQuery,
+) -> anyhow::Result<QuarantinedBridgeOutView> {
+    let entry = service
+        .store
+        .get_unbridgeable_bridge_out(&query.note_id)
+        .await?
+        .with_context(|| format!("note {} is not quarantined", query.note_id))?;
+    let deposit_count = service
+        .store
+        .get_deposit_indices(std::slice::from_ref(&entry.note_id))
+        .await?
+        .get(&entry.note_id)
+        .copied();
+    Ok(view(entry, deposit_count, query.include_note_dump))
+}
+
+/// What `admin_reprojectQuarantined` did with the note.
+#[derive(Debug, PartialEq, Eq, Serialize)]
+#[serde(tag = "outcome", rename_all = "snake_case")]
+pub enum ReprojectOutcome {
+    /// The `BridgeEvent` was emitted at the note's reserved index.
+    Emitted { deposit_count: u32 },
+    /// Already emitted (by the projector after the fix landed); the stale
+    /// quarantine row was dropped.
+    AlreadyEmitted,
+    /// A projection gate still refuses the note. `reason` / `detail` are the
+    /// current quarantine row's.
+    StillQuarantined {
+        reason: &'static str,
+        detail: String,
+    },
+}
+
+pub async fn admin_reproject_quarantined(
+    service: ServiceState,
+    note_id: String,
+) -> anyhow::Result<ReprojectOutcome> {
+    let entry = service
+        .store
+        .get_unbridgeable_bridge_out(&note_id)
+        .await?
+        .with_context(|| format!("note {note_id} is not quarantined"))?;
+    let id = NoteId::try_from_hex(&note_id).context("parsing noteId")?;
+
+    let result: Arc<Mutex<Option<ReprojectOutcome>>> = Arc::new(Mutex::new(None));
+    let result_in = result.clone();
+    let bridge_id = service.accounts.0.bridge.0;
+    service
+        .miden_client
+        .clone()
+        .with(move |client| {
+            Box::new(async move {
+                let note = client
+                    .get_input_note(id)
+                    .await?
+                    .with_context(|| format!("note {note_id} is not in the Miden client store"))?;
+                let outcome = reproject_quarantined(
+                    &service.store,
+                    &service.block_state,
+                    bridge_id,
+                    service.network_id,
+                    &service.network_rpcs,
+                    entry,
+                    &note,
+                    id,
+                    Some(client),
+                )
+                .await?;
+                *result_in.lock().expect("reproject mutex poisoned") = Some(outcome);
+                Ok(())
+            })
+        })
+        .await?;
+
+    result
+        .lock()
+        .expect("reproject mutex poisoned")
+        .take()
+        .context("Miden client request completed without a re-projection outcome")
+}
+
+/// Re-run projection for one quarantined note.
+///
+/// Refuses a note with no LET reservation (projecting it would take the next
+/// free index, out of order) and a note whose observed block is already
+/// sealed (its logs are exposed and final). The row is cleared before the
+/// attempt so a still-failing gate records its current reason; if the attempt
+/// fails without recording one, the original row is put back.
+#[allow(clippy::too_many_arguments)]
+pub(crate) async fn reproject_quarantined(
+    store: &Arc<dyn Store>,
+    block_state: &BlockState,
+    bridge_id: AccountId,
+    local_network_id: u32,
+    network_rpcs: &crate::metadata_recovery::NetworkRpcMap,
+    entry: UnbridgeableBridgeOut,
+    note: &InputNoteRecord,
+    note_id: NoteId,
+    client: Option<&mut MidenClientLib>,
+) -> anyhow::Result<ReprojectOutcome> {
+    let key = entry.note_id.clone();
+    anyhow::ensure!(
+        note_id.to_hex() == key,
+        "note {} does not match quarantine row {key}",
+        note_id.to_hex()
+    );
+    if store.is_note_processed(&key).await? {
+        store.delete_unbridgeable_bridge_out(&key).await?;
+        return Ok(ReprojectOutcome::AlreadyEmitted);
+    }
+    let reserved = store
+        .get_deposit_indices(std::slice::from_ref(&key))
+        .await?
+        .get(&key)
+        .copied();
+    anyhow::ensure!(
+        reserved.is_some(),
+        "note {key} holds no LET reservation; re-projecting it would assign a new index out of order"
+    );
+    let sealed_through = block_state.sealed_through();
+    anyhow::ensure!(
+        entry.observed_block > sealed_through,
+        "note {key} was observed at block {}, already sealed (sealed through {sealed_through})",
+        entry.observed_block
+    );
+
+    store.delete_unbridgeable_bridge_out(&key).await?;
+    let attempt = project_b2agg_note(
+        store,
+        note,
+        note_id,
+        bridge_id,
+        local_network_id,
+        entry.observed_block,
+        block_state.get_block_hash(entry.observed_block),
+        crate::bridge_address::get_bridge_address(),
+        client,
+        network_rpcs,
+    )
+    .await;
+
+    if let Ok(B2AggRestoreOutcome::Emitted) = attempt {
+        let deposit_count = store
+            .get_deposit_indices(std::slice::from_ref(&key))
+            .await?
+            .get(&key)
+            .copied()
+            .context("emitted note lost its LET reservation")?;
+        ::metrics::counter!("bridge_out_quarantine_reprojected_total", "outcome" => "emitted")
+            .increment(1);
+        tracing::info!(note_id = %key, deposit_count, "quarantined bridge-out re-projected");
+        return Ok(ReprojectOutcome::Emitted { deposit_count });
+    }
+
+    // Not emitted: keep the note quarantined, under its fresh row if the
+    // projection recorded one.
+    store.record_unbridgeable_bridge_out(entry).await?;
+    ::metrics::counter!("bridge_out_quarantine_reprojected_total", "outcome" => "refused")
+        .increment(1);
+    attempt?;
+    let current = store
+        .get_unbridgeable_bridge_out(&key)
+        .await?
+        .context("quarantine row vanished during re-projection")?;
+    Ok(ReprojectOutcome::StillQuarantined {
+        reason: current.reason.as_str(),
+        detail: current.detail,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::store::memory::InMemoryStore;
+    use crate::store::{FaucetEntry, UnbridgeableBridgeOutReason};
+    use miden_client::store::InputNoteState;
+    use miden_client::store::input_note_states::ConsumedExternalNoteState;
+    use miden_protocol::asset::{Asset, FungibleAsset};
+    use miden_protocol::block::BlockNumber;
+    use miden_protocol::note::{
+        NoteAssets, NoteAttachments, NoteDetails, NoteMetadata, NoteRecipient, NoteStorage,
+        NoteType, PartialNoteMetadata,
+    };
+    use miden_protocol::{Felt, Word};
+
+    /// A well-formed bridge-out B2AGG from `faucet_id` to network 0, consumed
+    /// by `bridge_id`, with its deterministic NoteId.
+    fn bridge_out_note(faucet_id: AccountId, bridge_id: AccountId) -> (InputNoteRecord, NoteId) {
+        let storage = NoteStorage::new(vec![
+            Felt::from(0u32),
+            Felt::from(0x1111_1111u32),
+            Felt::from(0u32),
+            Felt::from(0u32),
+            Felt::from(0u32),
+            Felt::from(0u32),
+        ])
+        .unwrap();
+        let recipient = NoteRecipient::new(
+            Word::default(),
+            miden_base_agglayer::B2AggNote::script(),
+            storage,
+        );
+        let asset: Asset = FungibleAsset::new(faucet_id, 50).unwrap().into();
+        let details = NoteDetails::new(NoteAssets::new(vec![asset]).unwrap(), recipient);
+        let state = InputNoteState::ConsumedExternal(ConsumedExternalNoteState {
+            nullifier_block_height: BlockNumber::from(0u32),
+            consumer_account: Some(bridge_id),
+            consumed_tx_order: None,
+            metadata: None,
+        });
+        let note = InputNoteRecord::new(details, NoteAttachments::default(), None, state);
+        let metadata = NoteMetadata::new(
+            PartialNoteMetadata::new(bridge_id, NoteType::Public),
+            &NoteAttachments::default(),
+        );
+        let id = NoteId::new(note.details_commitment(), &metadata);
+        (note, id)
+    }
+
+    #[tokio::test]
+    async fn reproject_emits_at_the_reserved_index_once_the_faucet_is_known() {
+        let store: Arc<dyn Store> = Arc::new(InMemoryStore::new());
+        let block_state = BlockState::new();
+        let rpcs = crate::metadata_recovery::NetworkRpcMap::new();
+        let bridge_id = AccountId::from_hex("0xac0000000000dd110000ee000000fc").unwrap();
+        let faucet_id = AccountId::from_hex("0xaa0000000000bc310000bc000000de").unwrap();
+        let (note, id) = bridge_out_note(faucet_id, bridge_id);
+        let key = id.to_hex();
+
+        // The faucet is unknown: the note reserves index 0 and is quarantined.
+        let outcome = project_b2agg_note(
+            &store,
+            &note,
+            id,
+            bridge_id,
+            7,
+            100,
+            block_state.get_block_hash(100),
+            crate::bridge_address::get_bridge_address(),
+            None,
+            &rpcs,
+        )
+        .await
+        .unwrap();
+        assert_eq!(outcome, B2AggRestoreOutcome::Skipped);
+        // A later leaf takes index 1.
+        assert_eq!(store.reserve_deposit_index("later-leaf").await.unwrap(), 1);
+
+        let entry = store
+            .get_unbridgeable_bridge_out(&key)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(entry.reason, UnbridgeableBridgeOutReason::UnknownFaucet);
+        let listed = store.list_unbridgeable_bridge_outs(None, 10).await.unwrap();
+        assert_eq!(listed.len(), 1);
+        assert!(
+            store
+                .list_unbridgeable_bridge_outs(Some(&key), 10)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+
+        // Root cause not fixed yet: still quarantined, row kept.
+        let outcome = reproject_quarantined(
+            &store,
+            &block_state,
+            bridge_id,
+            7,
+            &rpcs,
+            entry.clone(),
+            &note,
+            id,
+            None,
+        )
+        .await
+        .unwrap();
+        assert!(matches!(
+            outcome,
+            ReprojectOutcome::StillQuarantined {
+                reason: "unknown_faucet",
+                ..
+            }
+        ));
+        assert!(!store.is_note_processed(&key).await.unwrap());
+
+        store
+            .register_faucet(FaucetEntry {
+                faucet_id,
+                origin_address: [0u8; 20],
+                origin_network: 0,
+                symbol: "ETH".into(),
+                origin_decimals: 18,
+                miden_decimals: 8,
+                scale: 10,
+                metadata: vec![],
+            })
+            .await
+            .unwrap();
+        let outcome = reproject_quarantined(
+            &store,
+            &block_state,
+            bridge_id,
+            7,
+            &rpcs,
+            entry.clone(),
+            &note,
+            id,
+            None,
+        )
+        .await
+        .unwrap();
+        assert_eq!(outcome, ReprojectOutcome::Emitted { deposit_count: 0 });
+        assert!(store.is_note_processed(&key).await.unwrap());
+        assert!(
+            store
+                .get_unbridgeable_bridge_out(&key)
+                .await
+                .unwrap()
+                .is_none()
+        );
+
+        // Once emitted, a repeat call only clears a stale row.
+        store.record_unbridgeable_bridge_out(entry).await.unwrap();
+        let outcome = reproject_quarantined(
+            &store,
+            &block_state,
+            bridge_id,
+            7,
+            &rpcs,
+            store
+                .get_unbridgeable_bridge_out(&key)
+                .await
+                .unwrap()
+                .unwrap(),
+            &note,
+            id,
+            None,
+        )
+        .await
+        .unwrap();
+        assert_eq!(outcome, ReprojectOutcome::AlreadyEmitted);
+        assert!(
+            store
+                .get_unbridgeable_bridge_out(&key)
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn reproject_refuses_a_note_without_a_reservation() {
+        let store: Arc<dyn Store> = Arc::new(InMemoryStore::new());
+        let bridge_id = AccountId::from_hex("0xac0000000000dd110000ee000000fc").unwrap();
+        let faucet_id = AccountId::from_hex("0xaa0000000000bc310000bc000000de").unwrap();
+        let (note, id) = bridge_out_note(faucet_id, bridge_id);
+        let entry = UnbridgeableBridgeOut {
+            note_id: id.to_hex(),
+            bridge_account: bridge_id,
+            reason: UnbridgeableBridgeOutReason::UnknownFaucet,
+            detail: "unknown faucet".into(),
+            note_dump: "{}".into(),
+            observed_block: 100,
+        };
+        store
+            .record_unbridgeable_bridge_out(entry.clone())
+            .await
+            .unwrap();
+
+        let err = reproject_quarantined(
+            &store,
+            &BlockState::new(),
+            bridge_id,
+            7,
+            &crate::metadata_recovery::NetworkRpcMap::new(),
+            entry,
+            &note,
+            id,
+            None,
+        )
+        .await
+        .unwrap_err();
+        assert!(err.to_string().contains("no LET reservation"), "{err:#}");
+        assert!(
+            store
+                .get_unbridgeable_bridge_out(&id.to_hex())
+                .await
+                .unwrap()
+                .is_some()
+        );
+        assert_eq!(store.reserve_deposit_index("next").await.unwrap(), 0);
+    }
+}
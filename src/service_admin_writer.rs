@@ -0,0 +1,169 @@
+//! Admin RPC endpoints for the async writer's job table.
+//!
+//! `admin_listWriterJobs` / `admin_getWriterJob` expose the in-flight map
+//! (queued, submitting and not-yet-evicted terminal entries);
+//! `admin_cancelWriterJob` cancels a job that is still queued (see
+//! [`crate::writer_worker::cancel_queued_job`]); `admin_pauseWriter` /
+//! `admin_resumeWriter` hold and release dispatch for maintenance while the
+//! HTTP listener keeps admitting. A job is addressed by its eth tx hash or by
+//! the ULID assigned at enqueue.
+
+use crate::service_state::ServiceState;
+use crate::writer_worker::{InFlightEntry, JobState, WriterWorkerHandle};
+use alloy::primitives::TxHash;
+use serde::Serialize;
+use std::sync::Arc;
+use ulid::Ulid;
+
+/// One writer job as reported to operators.
+#[derive(Debug, Serialize)]
+pub struct WriterJobView {
+    pub tx_hash: String,
+    pub job_id: String,
+    pub kind: &'static str,
+    pub state: &'static str,
+    /// Synthetic block of a committed job.
+    pub block_number: Option<u64>,
+    pub signer: String,
+    pub nonce: u64,
+    pub age_secs: u64,
+}
+
+impl From<&InFlightEntry> for WriterJobView {
+    fn from(entry: &InFlightEntry) -> Self {
+        let block_number = match entry.state {
+            JobState::Committed { block_number } => Some(block_number),
+            _ => None,
+        };
+        Self {
+            tx_hash: format!("{:#x}", entry.eth_tx_hash),
+            job_id: entry.job_id.to_string(),
+            kind: entry.kind.as_str(),
+            state: entry.state.as_str(),
+            block_number,
+            signer: format!("{:#x}", entry.signer),
+            nonce: crate::store::envelope_nonce(&entry.envelope),
+            age_secs: entry.created_at.elapsed().as_secs(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct WriterJobList {
+    pub paused: bool,
+    pub jobs: Vec<WriterJobView>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct WriterDispatchStatus {
+    pub paused: bool,
+    pub inflight: usize,
+}
+
+fn writer_handle(service: &ServiceState) -> anyhow::Result<&Arc<WriterWorkerHandle>> {
+    service
+        .writer_handle
+        .as_ref()
+        .ok_or_else(|| anyhow::anyhow!("writer worker is not running"))
+}
+
+/// Resolve a job reference: a `0x`-prefixed 32-byte tx hash or a ULID.
+/// `Ok(None)` for a well-formed ULID the writer does not track.
+fn resolve_job(handle: &WriterWorkerHandle, job: &str) -> anyhow::Result<Option<TxHash>> {
+    if job.starts_with("0x") {
+        let hash: TxHash = job
+            .parse()
+            .map_err(|e| anyhow::anyhow!("bad tx hash {job}: {e}"))?;
+        return Ok(Some(hash));
+    }
+    let job_id = Ulid::from_string(job).map_err(|e| anyhow::anyhow!("bad job id {job}: {e}"))?;
+    Ok(handle.find_job(job_id).map(|e| e.eth_tx_hash))
+}
+
+pub async fn admin_list_writer_jobs(service: ServiceState) -> anyhow::Result<WriterJobList> {
+    let handle = writer_handle(&service)?;
+    Ok(WriterJobList {
+        paused: handle.is_paused(),
+        jobs: handle.entries().iter().map(WriterJobView::from).collect(),
+    })
+}
+
+/// `null` when the job is unknown or already TTL-evicted.
+pub async fn admin_get_writer_job(
+    service: ServiceState,
+    job: String,
+) -> anyhow::Result<Option<WriterJobView>> {
+    let handle = writer_handle(&service)?;
+    let Some(hash) = resolve_job(handle, &job)? else {
+        return Ok(None);
+    };
+    Ok(handle.get_inflight(&hash).as_ref().map(WriterJobView::from))
+}
+
+pub async fn admin_cancel_writer_job(
+    service: ServiceState,
+    job: String,
+) -> anyhow::Result<WriterJobView> {
+    let handle = writer_handle(&service)?;
+    let hash = resolve_job(handle, &job)?.ok_or(crate::writer_worker::CancelJobError::NotFound)?;
+    let entry = crate::writer_worker::cancel_queued_job(&service, hash).await?;
+    Ok(WriterJobView::from(&entry))
+}
+
+pub async fn admin_pause_writer(service: ServiceState) -> anyhow::Result<WriterDispatchStatus> {
+    let handle = writer_handle(&service)?;
+    handle.pause();
+    tracing::warn!(target: "writer_worker", "writer dispatch paused by operator");
+    Ok(WriterDispatchStatus {
+        paused: true,
+        inflight: handle.inflight_len(),
+    })
+}
+
+pub async fn admin_resume_writer(service: ServiceState) -> anyhow::Result<WriterDispatchStatus> {
+    let handle = writer_handle(&service)?;
+    handle.resume();
+    tracing::info!(target: "writer_worker", "writer dispatch resumed by operator");
+    Ok(WriterDispatchStatus {
+        paused: false,
+        inflight: handle.inflight_len(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn writer_admin_reports_jobs_and_toggles_dispatch() {
+        let mut service = crate::test_helpers::create_test_service();
+        let (handle, _shutdown) =
+            crate::writer_worker::WriterWorker::spawn(service.clone(), 4, Duration::from_secs(60));
+        let handle = Arc::new(handle);
+        handle.pause();
+        service.writer_handle = Some(handle.clone());
+
+        let list = admin_list_writer_jobs(service.clone()).await.unwrap();
+        assert!(list.paused);
+        assert!(list.jobs.is_empty());
+        let unknown = admin_get_writer_job(service.clone(), Ulid::new().to_string())
+            .await
+            .unwrap();
+        assert!(unknown.is_none());
+        assert!(
+            admin_get_writer_job(service.clone(), "not-a-job".into())
+                .await
+                .is_err()
+        );
+        assert!(
+            admin_cancel_writer_job(service.clone(), Ulid::new().to_string())
+                .await
+                .is_err()
+        );
+
+        let status = admin_resume_writer(service).await.unwrap();
+        assert!(!status.paused);
+        assert!(!handle.is_paused());
+    }
+}
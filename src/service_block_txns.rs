@@ -0,0 +1,362 @@
+//! Per-block transaction listing for the synthetic chain.
+//!
+//! A synthetic block's transactions are not recorded anywhere as a list; they
+//! are derived from the store on demand:
+//!
+//! 1. every transaction that emitted a synthetic log in the block (claims, GER
+//!    updates, derived bridge-out hashes), ordered by its first log index —
+//!    the same order the block's `receiptsRoot` hashes (see
+//!    [`crate::block_state::logs_by_transaction`]) and the index the projector
+//!    stamps onto each log when it seals the block;
+//! 2. then every other transaction with a terminal receipt at the block
+//!    (failed or log-less), in hash order.
+//!
+//! Log-bearing positions are fixed once the block is sealed. Log-less entries
+//! can still be appended to an exposed block (an immediate local success lands
+//! at the current tip), which only ever affects the tail of the list.
+
+use crate::finality::Frontiers;
+use crate::hex::hex_decode_u64;
+use crate::log_synthesis::LogFilter;
+use crate::service_get_txn_receipt::receipt_with_index;
+use crate::service_helpers::{build_synthetic_tx_json, store_error};
+use crate::service_state::ServiceState;
+use crate::store::Store;
+use alloy::primitives::TxHash;
+use axum_jrpc::error::{JsonRpcError, JsonRpcErrorReason};
+use axum_jrpc::{JrpcResult, JsonRpcExtractor, JsonRpcResponse};
+use std::collections::HashSet;
+use std::str::FromStr;
+
+/// Every transaction in `block`, in block order (see the module docs).
+pub async fn block_transaction_hashes(
+    store: &dyn Store,
+    block: u64,
+) -> anyhow::Result<Vec<TxHash>> {
+    let filter = LogFilter {
+        from_block: Some(format!("0x{block:x}")),
+        to_block: Some(format!("0x{block:x}")),
+        ..Default::default()
+    };
+    let logs = store.get_logs(&filter, block).await?;
+    let mut hashes = Vec::new();
+    let mut seen = HashSet::new();
+    for (tx, _) in crate::block_state::logs_by_transaction(&logs) {
+        let Ok(hash) = TxHash::from_str(&tx) else {
+            tracing::warn!(block, %tx, "synthetic log with a malformed transaction hash");
+            continue;
+        };
+        if seen.insert(hash) {
+            hashes.push(hash);
+        }
+    }
+    for hash in store.txn_hashes_at_block(block).await? {
+        if seen.insert(hash) {
+            hashes.push(hash);
+        }
+    }
+    Ok(hashes)
+}
+
+/// `tx_hash`'s position in `block`; 0 when it is not listed there.
+pub async fn transaction_index(
+    store: &dyn Store,
+    block: u64,
+    tx_hash: TxHash,
+) -> anyhow::Result<u64> {
+    Ok(block_transaction_hashes(store, block)
+        .await?
+        .iter()
+        .position(|hash| *hash == tx_hash)
+        .unwrap_or(0) as u64)
+}
+
+/// The `eth_getTransactionByHash` object for a listed transaction: the stored
+/// envelope when there is one, else the synthetic shape built from its first
+/// log. `None` if the store knows neither.
+async fn transaction_json(
+    service: &ServiceState,
+    tx_hash: TxHash,
+    index: u64,
+) -> anyhow::Result<Option<serde_json::Value>> {
+    let mut json = if let Some(data) = service.store.txn_get(tx_hash).await? {
+        data.to_rpc_transaction(tx_hash, &service.block_state)
+    } else {
+        let logs = service
+            .store
+            .get_logs_for_tx(&format!("{tx_hash:#x}"))
+            .await?;
+        let Some(log) = logs.first() else {
+            return Ok(None);
+        };
+        build_synthetic_tx_json(tx_hash, log, service.chain_id)
+    };
+    json["transactionIndex"] = serde_json::Value::String(format!("{index:#x}"));
+    Ok(Some(json))
+}
+
+/// A block's JSON with its transaction list filled in: hashes, or full
+/// transaction objects when `full_transactions` is set.
+pub async fn block_json(
+    service: &ServiceState,
+    block: &crate::block_state::SyntheticBlock,
+    full_transactions: bool,
+) -> anyhow::Result<serde_json::Value> {
+    let hashes = block_transaction_hashes(&*service.store, block.number).await?;
+    let mut transactions = Vec::with_capacity(hashes.len());
+    for (index, hash) in hashes.into_iter().enumerate() {
+        if full_transactions {
+            if let Some(tx) = transaction_json(service, hash, index as u64).await? {
+                transactions.push(tx);
+            }
+        } else {
+            transactions.push(serde_json::Value::String(format!("{hash:#x}")));
+        }
+    }
+    let mut json = block.to_json(full_transactions);
+    json["transactions"] = serde_json::Value::Array(transactions);
+    Ok(json)
+}
+
+/// Resolve a block tag or hex number. `Ok(None)` for a block above the tip.
+fn resolve_block_number(param: &str, frontiers: &Frontiers) -> Result<Option<u64>, String> {
+    if let Some(number) = frontiers.resolve(param) {
+        return Ok(Some(number));
+    }
+    let number = hex_decode_u64(param).map_err(|_| String::from("bad block number"))?;
+    Ok((number <= frontiers.latest).then_some(number))
+}
+
+fn invalid_params(answer_id: axum_jrpc::Id, message: String) -> JsonRpcResponse {
+    JsonRpcResponse::error(
+        answer_id,
+        JsonRpcError::new(
+            JsonRpcErrorReason::InvalidParams,
+            message,
+            serde_json::Value::Null,
+        ),
+    )
+}
+
+fn null(answer_id: axum_jrpc::Id) -> JrpcResult {
+    Ok(JsonRpcResponse::success::<serde_json::Value, _>(
+        answer_id,
+        serde_json::Value::Null,
+    ))
+}
+
+/// Block number for a block-number param, `None` above the tip.
+async fn block_by_number_param(
+    service: &ServiceState,
+    answer_id: &axum_jrpc::Id,
+    param: &str,
+) -> Result<Option<u64>, JsonRpcResponse> {
+    let frontiers = service
+        .frontiers()
+        .await
+        .map_err(|e| store_error(answer_id.clone(), e))?;
+    resolve_block_number(param, &frontiers).map_err(|msg| invalid_params(answer_id.clone(), msg))
+}
+
+/// Block number for a block-hash param, `None` for an unknown hash.
+fn block_by_hash_param(
+    service: &ServiceState,
+    answer_id: &axum_jrpc::Id,
+    param: &str,
+) -> Result<Option<u64>, JsonRpcResponse> {
+    let hash =
+        crate::service_helpers::validate_hex_hash_param(param, "block hash", answer_id.clone())?;
+    Ok(service
+        .block_state
+        .get_block_by_hash(&hash)
+        .map(|block| block.number))
+}
+
+fn parse_index(answer_id: &axum_jrpc::Id, param: &str) -> Result<usize, JsonRpcResponse> {
+    hex_decode_u64(param)
+        .map(|index| index as usize)
+        .map_err(|_| invalid_params(answer_id.clone(), String::from("bad transaction index")))
+}
+
+async fn transaction_count(
+    service: &ServiceState,
+    answer_id: axum_jrpc::Id,
+    block: Option<u64>,
+) -> JrpcResult {
+    let Some(block) = block else {
+        return null(answer_id);
+    };
+    let count = block_transaction_hashes(&*service.store, block)
+        .await
+        .map_err(|e| store_error(answer_id.clone(), e))?
+        .len();
+    Ok(JsonRpcResponse::success(answer_id, format!("{count:#x}")))
+}
+
+async fn transaction_at(
+    service: &ServiceState,
+    answer_id: axum_jrpc::Id,
+    block: Option<u64>,
+    index: usize,
+) -> JrpcResult {
+    let Some(block) = block else {
+        return null(answer_id);
+    };
+    let hashes = block_transaction_hashes(&*service.store, block)
+        .await
+        .map_err(|e| store_error(answer_id.clone(), e))?;
+    let Some(hash) = hashes.get(index).copied() else {
+        return null(answer_id);
+    };
+    match transaction_json(service, hash, index as u64)
+        .await
+        .map_err(|e| store_error(answer_id.clone(), e))?
+    {
+        Some(tx) => Ok(JsonRpcResponse::success(answer_id, tx)),
+        None => null(answer_id),
+    }
+}
+
+/// `eth_getBlockTransactionCountByNumber(block)`.
+pub(crate) async fn service_get_block_transaction_count_by_number(
+    service: ServiceState,
+    request: JsonRpcExtractor,
+) -> JrpcResult {
+    let answer_id = request.get_answer_id();
+    let (block,): (String,) = request.parse_params()?;
+    let block = block_by_number_param(&service, &answer_id, &block).await?;
+    transaction_count(&service, answer_id, block).await
+}
+
+/// `eth_getBlockTransactionCountByHash(hash)`.
+pub(crate) async fn service_get_block_transaction_count_by_hash(
+    service: ServiceState,
+    request: JsonRpcExtractor,
+) -> JrpcResult {
+    let answer_id = request.get_answer_id();
+    let (hash,): (String,) = request.parse_params()?;
+    let block = block_by_hash_param(&service, &answer_id, &hash)?;
+    transaction_count(&service, answer_id, block).await
+}
+
+/// `eth_getTransactionByBlockNumberAndIndex(block, index)`.
+pub(crate) async fn service_get_transaction_by_block_number_and_index(
+    service: ServiceState,
+    request: JsonRpcExtractor,
+) -> JrpcResult {
+    let answer_id = request.get_answer_id();
+    let (block, index): (String, String) = request.parse_params()?;
+    let index = parse_index(&answer_id, &index)?;
+    let block = block_by_number_param(&service, &answer_id, &block).await?;
+    transaction_at(&service, answer_id, block, index).await
+}
+
+/// `eth_getTransactionByBlockHashAndIndex(hash, index)`.
+pub(crate) async fn service_get_transaction_by_block_hash_and_index(
+    service: ServiceState,
+    request: JsonRpcExtractor,
+) -> JrpcResult {
+    let answer_id = request.get_answer_id();
+    let (hash, index): (String, String) = request.parse_params()?;
+    let index = parse_index(&answer_id, &index)?;
+    let block = block_by_hash_param(&service, &answer_id, &hash)?;
+    transaction_at(&service, answer_id, block, index).await
+}
+
+/// `eth_getBlockReceipts(block)` — the receipt of every listed transaction,
+/// in block order. Accepts a block tag, number or hash.
+pub(crate) async fn service_get_block_receipts(
+    service: ServiceState,
+    request: JsonRpcExtractor,
+) -> JrpcResult {
+    let answer_id = request.get_answer_id();
+    let (param,): (String,) = request.parse_params()?;
+    let block = if param.len() == 66 {
+        block_by_hash_param(&service, &answer_id, &param)?
+    } else {
+        block_by_number_param(&service, &answer_id, &param).await?
+    };
+    let Some(block) = block else {
+        return null(answer_id);
+    };
+    let hashes = block_transaction_hashes(&*service.store, block)
+        .await
+        .map_err(|e| store_error(answer_id.clone(), e))?;
+    let mut receipts = Vec::with_capacity(hashes.len());
+    for (index, hash) in hashes.into_iter().enumerate() {
+        let receipt = receipt_with_index(service.clone(), hash, Some(index as u64))
+            .await
+            .map_err(|e| store_error(answer_id.clone(), e))?;
+        // A listed transaction always has a receipt; skip rather than serve a
+        // hole if one raced away (e.g. a failure healed back to pending).
+        if let Some(receipt) = receipt {
+            receipts.push(receipt);
+        }
+    }
+    Ok(JsonRpcResponse::success(answer_id, receipts))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::log_synthesis::SyntheticLog;
+    use crate::test_helpers::{begin_test_txn, create_test_service};
+
+    fn log(tx: TxHash, block: u64, log_index: u64) -> SyntheticLog {
+        SyntheticLog {
+            address: crate::log_synthesis::L2_GLOBAL_EXIT_ROOT_ADDRESS.to_string(),
+            topics: vec![crate::log_synthesis::UPDATE_HASH_CHAIN_VALUE_TOPIC.to_string()],
+            data: "0x".to_string(),
+            block_number: block,
+            block_hash: [0u8; 32],
+            transaction_hash: format!("{tx:#x}"),
+            transaction_index: 0,
+            log_index,
+            removed: false,
+        }
+    }
+
+    async fn commit(service: &ServiceState, tx: TxHash, block: u64, result: Result<(), String>) {
+        begin_test_txn(service, tx).await;
+        service
+            .store
+            .txn_commit(tx, result, block, service.block_state.get_block_hash(block))
+            .await
+            .unwrap();
+    }
+
+    /// Log-bearing transactions come first in first-log-index order, then
+    /// every other terminal transaction at the block in hash order.
+    #[tokio::test]
+    async fn lists_log_bearing_then_terminal_transactions() {
+        let service = create_test_service();
+        let (synthetic, real, failed, other_block) = (
+            TxHash::from([0xbb; 32]),
+            TxHash::from([0xcc; 32]),
+            TxHash::from([0x01; 32]),
+            TxHash::from([0xdd; 32]),
+        );
+        service.store.add_log(log(real, 4, 3)).await.unwrap();
+        service.store.add_log(log(synthetic, 4, 1)).await.unwrap();
+        service.store.add_log(log(other_block, 5, 0)).await.unwrap();
+        commit(&service, real, 4, Ok(())).await;
+        commit(&service, failed, 4, Err("reverted".to_string())).await;
+
+        let hashes = block_transaction_hashes(&*service.store, 4).await.unwrap();
+        assert_eq!(hashes, vec![synthetic, real, failed]);
+        assert_eq!(
+            transaction_index(&*service.store, 4, failed).await.unwrap(),
+            2
+        );
+
+        let block = service.block_state.get_block_by_number(4).unwrap();
+        let json = block_json(&service, &block, true).await.unwrap();
+        let txs = json["transactions"].as_array().unwrap();
+        assert_eq!(txs.len(), 3);
+        assert_eq!(txs[0]["hash"], format!("{synthetic:#x}"));
+        assert_eq!(txs[1]["transactionIndex"], "0x1");
+        assert_eq!(txs[2]["hash"], format!("{failed:#x}"));
+        let json = block_json(&service, &block, false).await.unwrap();
+        assert_eq!(json["transactions"][1], format!("{real:#x}"));
+    }
+}
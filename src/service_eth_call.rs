@@ -1,16 +1,224 @@
+//! `eth_call` for the bridge and GER-manager view functions aggkit and
+//! tooling read, answered from the store and the synchronized bridge state.
+//! Nothing is executed: a selector without an answer here reverts the way
+//! geth reports a revert without data (`-32000 execution reverted`), so a
+//! caller never mistakes a stub for contract state.
+
+use crate::exit::{BridgeEvent, leaf_value};
 use crate::hex::hex_decode_prefixed;
+use crate::l1_info_tree::Frontier;
+use crate::log_synthesis::{BRIDGE_EVENT_TOPIC, LogFilter, TopicFilter};
 use crate::service_helpers::{networkIDCall, store_error};
 use crate::service_state::ServiceState;
-use alloy_core::sol_types::SolCall;
+use crate::store::ExitTreeState;
+use alloy::primitives::{Address, B256, Bytes, U256};
+use alloy_core::sol_types::{SolCall, SolEvent, SolValue};
 use axum_jrpc::error::{JsonRpcError, JsonRpcErrorReason};
 use axum_jrpc::{JrpcResult, JsonRpcExtractor, JsonRpcResponse};
 use serde::Deserialize;
+use std::str::FromStr;
 
 const ABI_FALSE: &str = "0x0000000000000000000000000000000000000000000000000000000000000000";
 const ABI_TRUE: &str = "0x0000000000000000000000000000000000000000000000000000000000000001";
 const GLOBAL_EXIT_ROOT_MAP_SELECTOR: [u8; 4] = [0x25, 0x7b, 0x36, 0x32];
 const IS_CLAIMED_SELECTOR: [u8; 4] = [0xcc, 0x46, 0x16, 0x32];
 
+alloy_core::sol! {
+    // AgglayerBridge (PolygonZkEVMBridgeV2 / DepositContractV2) and the L2
+    // sovereign GER manager view functions.
+    #[derive(Debug)]
+    interface IBridgeViews {
+        function depositCount() external view returns (uint256);
+        function getRoot() external view returns (bytes32);
+        function lastUpdatedDepositCount() external view returns (uint32);
+        function getTokenWrappedAddress(uint32 originNetwork, address originTokenAddress)
+            external view returns (address);
+        function precalculatedWrapperAddress(
+            uint32 originNetwork,
+            address originTokenAddress,
+            string name,
+            string symbol,
+            uint8 decimals
+        ) external view returns (address);
+        function wrappedTokenToTokenInfo(address wrappedToken)
+            external view returns (uint32 originNetwork, address originTokenAddress);
+        function insertedGERHashChain() external view returns (bytes32);
+        function removedGERHashChain() external view returns (bytes32);
+    }
+}
+
+/// Outcome of a bridge view call.
+enum ViewReply {
+    /// ABI-encoded return data.
+    Data(Vec<u8>),
+    /// The call reverts, with a reason for the `execution reverted: ...` message.
+    Revert(String),
+}
+
+const MALFORMED_CALLDATA: &str = "malformed calldata";
+
+/// geth's error for a revert without revert data.
+fn execution_reverted(reason: Option<&str>) -> JsonRpcError {
+    JsonRpcError::new(
+        JsonRpcErrorReason::ServerError(-32000),
+        match reason {
+            Some(reason) => format!("execution reverted: {reason}"),
+            None => "execution reverted".to_string(),
+        },
+        serde_json::Value::Null,
+    )
+}
+
+/// The local exit tree as exposed by the synthetic chain: the `BridgeEvent`s
+/// up to the tip, folded by `depositCount` into the stored frontier. Only the
+/// blocks sealed since the last call are read. `Err` when they do not continue
+/// the contiguous prefix `0..n` (a legacy LET offset or a quarantined
+/// reservation leaves leaves without an event), so no root can be derived;
+/// the frontier then stays where it was.
+async fn exposed_exit_tree(service: &ServiceState) -> anyhow::Result<Result<Frontier, String>> {
+    let latest = service.store.get_latest_block_number().await?;
+    let stored = service.store.get_exit_tree_state().await?;
+    if stored.next_block > latest {
+        return Ok(Ok(stored.frontier));
+    }
+    let filter = LogFilter {
+        from_block: Some(format!("{:#x}", stored.next_block)),
+        to_block: Some(format!("{latest:#x}")),
+        topics: Some(vec![Some(TopicFilter::Single(
+            BRIDGE_EVENT_TOPIC.to_string(),
+        ))]),
+        ..Default::default()
+    };
+    let mut leaves = Vec::new();
+    for log in service.store.get_logs(&filter, latest).await? {
+        let data = Bytes::from_str(&log.data)?;
+        let (
+            leaf_type,
+            origin_network,
+            origin_address,
+            destination_network,
+            destination_address,
+            amount,
+            metadata,
+            deposit_count,
+        ) = BridgeEvent::abi_decode_data(&data)?;
+        leaves.push((
+            deposit_count,
+            leaf_value(
+                leaf_type,
+                origin_network,
+                origin_address,
+                destination_network,
+                destination_address,
+                amount,
+                &metadata,
+            ),
+        ));
+    }
+    leaves.sort_by_key(|(deposit_count, _)| *deposit_count);
+    let mut frontier = stored.frontier.clone();
+    for (deposit_count, leaf) in leaves {
+        if deposit_count != frontier.leaf_count() {
+            return Ok(Err(format!(
+                "local exit tree not derivable: no BridgeEvent for depositCount {}",
+                frontier.leaf_count()
+            )));
+        }
+        frontier.push(leaf.0)?;
+    }
+    let next = ExitTreeState {
+        frontier,
+        next_block: latest + 1,
+    };
+    // A concurrent call folded the same blocks; either result is the same tree.
+    service
+        .store
+        .advance_exit_tree_state(&stored, &next)
+        .await?;
+    Ok(Ok(next.frontier))
+}
+
+/// Answer a bridge / GER-manager view call. `Ok(None)` for a selector this
+/// proxy does not serve.
+async fn bridge_view(service: &ServiceState, data: &[u8]) -> anyhow::Result<Option<ViewReply>> {
+    use IBridgeViews::*;
+
+    let reply = if data.starts_with(&depositCountCall::SELECTOR) {
+        // The count the exposed root covers, so `depositCount()` and
+        // `getRoot()` always describe the same tree.
+        match exposed_exit_tree(service).await? {
+            Ok(tree) => ViewReply::Data(depositCountCall::abi_encode_returns(&U256::from(
+                tree.leaf_count(),
+            ))),
+            Err(reason) => ViewReply::Revert(reason),
+        }
+    } else if data.starts_with(&getRootCall::SELECTOR) {
+        match exposed_exit_tree(service).await? {
+            Ok(tree) => ViewReply::Data(getRootCall::abi_encode_returns(&B256::from(tree.root()))),
+            Err(reason) => ViewReply::Revert(reason),
+        }
+    } else if data.starts_with(&lastUpdatedDepositCountCall::SELECTOR) {
+        // What `getRoot()` was last updated with on an EVM bridge.
+        match exposed_exit_tree(service).await? {
+            Ok(tree) => ViewReply::Data(lastUpdatedDepositCountCall::abi_encode_returns(
+                &tree.leaf_count(),
+            )),
+            Err(reason) => ViewReply::Revert(reason),
+        }
+    } else if data.starts_with(&getTokenWrappedAddressCall::SELECTOR) {
+        let Ok(call) = getTokenWrappedAddressCall::abi_decode(data) else {
+            return Ok(Some(ViewReply::Revert(MALFORMED_CALLDATA.to_string())));
+        };
+        let address = service
+            .store
+            .get_faucet_by_origin(&call.originTokenAddress.0.0, call.originNetwork)
+            .await?
+            .map(|faucet| crate::token_balances::token_address(faucet.faucet_id))
+            .unwrap_or(Address::ZERO);
+        ViewReply::Data(getTokenWrappedAddressCall::abi_encode_returns(&address))
+    } else if data.starts_with(&precalculatedWrapperAddressCall::SELECTOR) {
+        // Miden faucet ids are not CREATE2-derived, so only an already
+        // deployed wrapper has a known address.
+        let Ok(call) = precalculatedWrapperAddressCall::abi_decode(data) else {
+            return Ok(Some(ViewReply::Revert(MALFORMED_CALLDATA.to_string())));
+        };
+        match service
+            .store
+            .get_faucet_by_origin(&call.originTokenAddress.0.0, call.originNetwork)
+            .await?
+        {
+            Some(faucet) => ViewReply::Data(precalculatedWrapperAddressCall::abi_encode_returns(
+                &crate::token_balances::token_address(faucet.faucet_id),
+            )),
+            None => ViewReply::Revert(
+                "wrapper address is only known once its faucet is deployed".to_string(),
+            ),
+        }
+    } else if data.starts_with(&wrappedTokenToTokenInfoCall::SELECTOR) {
+        let Ok(call) = wrappedTokenToTokenInfoCall::abi_decode(data) else {
+            return Ok(Some(ViewReply::Revert(MALFORMED_CALLDATA.to_string())));
+        };
+        let info = crate::token_balances::faucet_at(service, call.wrappedToken)
+            .await?
+            .map(|faucet| (faucet.origin_network, Address::from(faucet.origin_address)))
+            .unwrap_or((0, Address::ZERO));
+        ViewReply::Data(info.abi_encode_params())
+    } else if data.starts_with(&insertedGERHashChainCall::SELECTOR) {
+        let chain = service.store.get_ger_hash_chain().await?;
+        ViewReply::Data(insertedGERHashChainCall::abi_encode_returns(&B256::from(
+            chain,
+        )))
+    } else if data.starts_with(&removedGERHashChainCall::SELECTOR) {
+        let chain = service.store.get_ger_removal_hash_chain().await?;
+        ViewReply::Data(removedGERHashChainCall::abi_encode_returns(&B256::from(
+            chain,
+        )))
+    } else {
+        return Ok(None);
+    };
+    Ok(Some(reply))
+}
+
 fn abi_u32(word: &[u8]) -> Option<u32> {
     if word.len() != 32 || word[..28].iter().any(|byte| *byte != 0) {
         return None;
@@ -53,6 +261,23 @@ pub(crate) async fn service_eth_call(
             );
         }
 
+        if let Some(to) = to_addr.as_deref()
+            && let Ok(to) = alloy::primitives::Address::from_str(to)
+            && let Some(faucet) = crate::token_balances::faucet_at(&service, to)
+                .await
+                .map_err(|error| store_error(answer_id.clone(), error))?
+            && let Some(reply) = crate::token_balances::erc20_call(&service, &faucet, &data)
+                .await
+                .map_err(|error| {
+                    crate::token_balances::token_read_error(answer_id.clone(), error)
+                })?
+        {
+            return Ok(JsonRpcResponse::success(
+                answer_id,
+                format!("0x{}", alloy::hex::encode(reply)),
+            ));
+        }
+
         if data.starts_with(&networkIDCall::SELECTOR) {
             return Ok(JsonRpcResponse::success(
                 answer_id,
@@ -103,14 +328,37 @@ pub(crate) async fn service_eth_call(
                 if applied { ABI_TRUE } else { ABI_FALSE },
             ));
         }
+
+        match bridge_view(&service, &data).await {
+            Ok(Some(ViewReply::Data(reply))) => {
+                return Ok(JsonRpcResponse::success(
+                    answer_id,
+                    format!("0x{}", alloy::hex::encode(reply)),
+                ));
+            }
+            Ok(Some(ViewReply::Revert(reason))) => {
+                return Err(JsonRpcResponse::error(
+                    answer_id,
+                    execution_reverted(Some(&reason)),
+                ));
+            }
+            Ok(None) => {}
+            Err(error) => return Err(store_error(answer_id, error)),
+        }
+
+        if !data.is_empty() {
+            return Err(JsonRpcResponse::error(answer_id, execution_reverted(None)));
+        }
     }
 
-    Ok(JsonRpcResponse::success(answer_id, ABI_FALSE))
+    // No calldata: a plain value-transfer simulation, which has no return data.
+    Ok(JsonRpcResponse::success(answer_id, "0x"))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::exit::exit_tree_root;
     use crate::test_helpers::create_test_service;
     use axum_jrpc::Id;
 
@@ -188,11 +436,296 @@ mod tests {
         calldata.extend(GLOBAL_EXIT_ROOT_MAP_SELECTOR);
         calldata.extend(ger);
 
-        let response = service_eth_call(service, eth_call_request(calldata))
+        let response = service_eth_call(service.clone(), eth_call_request(calldata.clone()))
             .await
             .unwrap();
         let json = serde_json::to_value(response).unwrap();
         assert_eq!(json["result"], ABI_TRUE);
+
+        // A projected removal drops the GER from the map.
+        service
+            .store
+            .commit_ger_removal_atomic(2, [0u8; 32], "0xger-removed", &ger)
+            .await
+            .unwrap();
+        let response = service_eth_call(service, eth_call_request(calldata))
+            .await
+            .unwrap();
+        let json = serde_json::to_value(response).unwrap();
+        assert_eq!(json["result"], ABI_FALSE);
+    }
+
+    /// `eth_call` against a registered faucet's wrapped-token
+    /// address is answered by the ERC-20 shim, not the bridge selectors.
+    #[tokio::test]
+    async fn erc20_reads_route_to_the_wrapped_token_shim() {
+        let mut service = create_test_service();
+        crate::test_helpers::seed_test_faucets(&*service.store).await;
+        let faucet = service
+            .store
+            .get_faucet_by_origin(&[0u8; 20], 0)
+            .await
+            .unwrap()
+            .unwrap();
+        let mut source = crate::token_balances::StaticTokenSource::default();
+        source.issuance.insert(faucet.faucet_id, 2);
+        service.token_source = std::sync::Arc::new(source);
+
+        // totalSupply()
+        let mut request = eth_call_request(vec![0x18, 0x16, 0x0d, 0xdd]);
+        request.parsed[0]["to"] = serde_json::Value::String(format!(
+            "{:#x}",
+            crate::token_balances::token_address(faucet.faucet_id)
+        ));
+        let response = service_eth_call(service, request).await.unwrap();
+        let json = serde_json::to_value(response).unwrap();
+        assert_eq!(json["result"], format!("{:#066x}", 20_000_000_000u64));
+    }
+
+    /// A holder whose Miden account is private has no readable balance: a
+    /// distinct `-32000`, not an internal error. Undecodable `balanceOf`
+    /// calldata reverts like an unknown selector.
+    #[tokio::test]
+    async fn erc20_private_holder_and_malformed_calldata() {
+        let mut service = create_test_service();
+        crate::test_helpers::seed_test_faucets(&*service.store).await;
+        let faucet = service
+            .store
+            .get_faucet_by_origin(&[0u8; 20], 0)
+            .await
+            .unwrap()
+            .unwrap();
+        let holder = Address::from([0x77u8; 20]);
+        service
+            .store
+            .set_address_mapping(holder, faucet.faucet_id)
+            .await
+            .unwrap();
+        let mut source = crate::token_balances::StaticTokenSource::default();
+        source.private.insert(faucet.faucet_id);
+        service.token_source = std::sync::Arc::new(source);
+        let to = serde_json::Value::String(format!(
+            "{:#x}",
+            crate::token_balances::token_address(faucet.faucet_id)
+        ));
+
+        let mut request = eth_call_request(
+            crate::token_balances::IERC20Shim::balanceOfCall { account: holder }.abi_encode(),
+        );
+        request.parsed[0]["to"] = to.clone();
+        let response = match service_eth_call(service.clone(), request).await {
+            Ok(response) | Err(response) => response,
+        };
+        let json = serde_json::to_value(response).unwrap();
+        assert_eq!(json["error"]["code"], -32000, "{json}");
+        assert!(
+            json["error"]["message"]
+                .as_str()
+                .unwrap()
+                .contains("no public state"),
+            "{json}"
+        );
+
+        let mut request = eth_call_request(vec![0x70, 0xa0, 0x82, 0x31, 0x01]);
+        request.parsed[0]["to"] = to;
+        let response = match service_eth_call(service, request).await {
+            Ok(response) | Err(response) => response,
+        };
+        let json = serde_json::to_value(response).unwrap();
+        assert_eq!(json["error"]["code"], -32000, "{json}");
+        assert_eq!(json["error"]["message"], "execution reverted");
+    }
+
+    async fn call_result(service: &ServiceState, data: Vec<u8>) -> serde_json::Value {
+        let response = match service_eth_call(service.clone(), eth_call_request(data)).await {
+            Ok(response) | Err(response) => response,
+        };
+        serde_json::to_value(response).unwrap()
+    }
+
+    /// Bridge and GER-manager views are answered from the store;
+    /// anything else reverts instead of returning a generic zero word.
+    #[tokio::test]
+    async fn bridge_views_read_the_store_and_unknown_selectors_revert() {
+        use IBridgeViews::*;
+
+        let service = create_test_service();
+        crate::test_helpers::seed_test_faucets(&*service.store).await;
+        service.store.reserve_deposit_index("note-0").await.unwrap();
+        let event = BridgeEvent {
+            leafType: 0,
+            originNetwork: 0,
+            originAddress: Address::ZERO,
+            destinationNetwork: 0,
+            destinationAddress: Address::from([0x11u8; 20]),
+            amount: U256::from(7u64),
+            metadata: Bytes::new(),
+            depositCount: 0,
+        };
+        service
+            .store
+            .add_log(crate::log_synthesis::SyntheticLog {
+                address: "0x0000000000000000000000000000000000000001".to_string(),
+                topics: vec![BRIDGE_EVENT_TOPIC.to_string()],
+                data: format!("0x{}", alloy::hex::encode(event.encode_data())),
+                block_number: 2,
+                block_hash: [0u8; 32],
+                transaction_hash: format!("0x{}", "ab".repeat(32)),
+                transaction_index: 0,
+                log_index: 0,
+                removed: false,
+            })
+            .await
+            .unwrap();
+        service.store.set_latest_block_number(2).await.unwrap();
+
+        let json = call_result(&service, depositCountCall {}.abi_encode()).await;
+        assert_eq!(json["result"], format!("{:#066x}", 1), "{json}");
+        let leaf = leaf_value(
+            0,
+            0,
+            Address::ZERO,
+            0,
+            Address::from([0x11u8; 20]),
+            U256::from(7u64),
+            &[],
+        );
+        let json = call_result(&service, getRootCall {}.abi_encode()).await;
+        assert_eq!(
+            json["result"],
+            format!("{}", exit_tree_root(&[leaf])),
+            "{json}"
+        );
+        let json = call_result(&service, lastUpdatedDepositCountCall {}.abi_encode()).await;
+        assert_eq!(json["result"], format!("{:#066x}", 1), "{json}");
+
+        // Later blocks are folded onto the stored frontier; the earlier leaf
+        // is not read again.
+        let second = BridgeEvent {
+            amount: U256::from(9u64),
+            depositCount: 1,
+            ..event
+        };
+        service
+            .store
+            .add_log(crate::log_synthesis::SyntheticLog {
+                address: "0x0000000000000000000000000000000000000001".to_string(),
+                topics: vec![BRIDGE_EVENT_TOPIC.to_string()],
+                data: format!("0x{}", alloy::hex::encode(second.encode_data())),
+                block_number: 4,
+                block_hash: [0u8; 32],
+                transaction_hash: format!("0x{}", "cd".repeat(32)),
+                transaction_index: 0,
+                log_index: 1,
+                removed: false,
+            })
+            .await
+            .unwrap();
+        service.store.set_latest_block_number(4).await.unwrap();
+        let second_leaf = leaf_value(
+            0,
+            0,
+            Address::ZERO,
+            0,
+            Address::from([0x11u8; 20]),
+            U256::from(9u64),
+            &[],
+        );
+        let json = call_result(&service, getRootCall {}.abi_encode()).await;
+        assert_eq!(
+            json["result"],
+            format!("{}", exit_tree_root(&[leaf, second_leaf])),
+            "{json}"
+        );
+        let json = call_result(&service, depositCountCall {}.abi_encode()).await;
+        assert_eq!(json["result"], format!("{:#066x}", 2), "{json}");
+        assert_eq!(
+            service
+                .store
+                .get_exit_tree_state()
+                .await
+                .unwrap()
+                .next_block,
+            5
+        );
+
+        let faucet = service
+            .store
+            .get_faucet_by_origin(&[0u8; 20], 0)
+            .await
+            .unwrap()
+            .unwrap();
+        let wrapped = crate::token_balances::token_address(faucet.faucet_id);
+        let json = call_result(
+            &service,
+            getTokenWrappedAddressCall {
+                originNetwork: 0,
+                originTokenAddress: Address::ZERO,
+            }
+            .abi_encode(),
+        )
+        .await;
+        let reply = hex_decode_prefixed(json["result"].as_str().unwrap()).unwrap();
+        assert_eq!(
+            getTokenWrappedAddressCall::abi_decode_returns(&reply).unwrap(),
+            wrapped
+        );
+        let json = call_result(
+            &service,
+            wrappedTokenToTokenInfoCall {
+                wrappedToken: wrapped,
+            }
+            .abi_encode(),
+        )
+        .await;
+        let reply = hex_decode_prefixed(json["result"].as_str().unwrap()).unwrap();
+        let info = wrappedTokenToTokenInfoCall::abi_decode_returns(&reply).unwrap();
+        assert_eq!(
+            (info.originNetwork, info.originTokenAddress),
+            (0, Address::ZERO)
+        );
+
+        let ger = [0xcdu8; 32];
+        service
+            .store
+            .commit_ger_event_atomic(2, [0u8; 32], "0xger-chain", &ger, None, None, 0)
+            .await
+            .unwrap();
+        let json = call_result(&service, insertedGERHashChainCall {}.abi_encode()).await;
+        let expected = alloy::primitives::keccak256([[0u8; 32], ger].concat());
+        assert_eq!(json["result"], format!("{expected}"), "{json}");
+        let json = call_result(&service, removedGERHashChainCall {}.abi_encode()).await;
+        assert_eq!(json["result"], format!("{}", B256::ZERO), "{json}");
+        service
+            .store
+            .commit_ger_removal_atomic(2, [0u8; 32], "0xger-removal-chain", &ger)
+            .await
+            .unwrap();
+        let json = call_result(&service, removedGERHashChainCall {}.abi_encode()).await;
+        assert_eq!(json["result"], format!("{expected}"), "{json}");
+
+        let json = call_result(&service, vec![0xde, 0xad, 0xbe, 0xef]).await;
+        assert_eq!(json["error"]["code"], -32000, "{json}");
+        assert_eq!(json["error"]["message"], "execution reverted");
+        let json = call_result(
+            &service,
+            precalculatedWrapperAddressCall {
+                originNetwork: 9,
+                originTokenAddress: Address::from([0x22u8; 20]),
+                name: "Token".to_string(),
+                symbol: "TKN".to_string(),
+                decimals: 18,
+            }
+            .abi_encode(),
+        )
+        .await;
+        assert!(
+            json["error"]["message"]
+                .as_str()
+                .unwrap()
+                .starts_with("execution reverted: "),
+            "{json}"
+        );
     }
 
     #[test]
@@ -0,0 +1,504 @@
+//! Installable polling filters: `eth_newFilter`, `eth_newBlockFilter`,
+//! `eth_getFilterChanges`, `eth_getFilterLogs`, `eth_uninstallFilter`.
+//!
+//! Filters live in memory only — a restart drops them and clients reinstall,
+//! exactly as against geth. Each filter carries a cursor (the first synthetic
+//! block it has not yet reported) that starts just past the tip at install
+//! time and only ever advances up to the synthetic tip, which the projector
+//! moves after a block's logs are committed (write-before-advance); a poll can
+//! therefore never report a block and later miss one of its logs.
+//!
+//! Bounds: filters untouched for [`FILTER_TIMEOUT`] are dropped, each client
+//! (peer IP) may hold at most [`MAX_FILTERS_PER_CLIENT`], and one
+//! `eth_getFilterChanges` reports at most [`MAX_GETLOGS_BLOCK_RANGE`] blocks of
+//! logs or [`MAX_FILTER_BLOCK_HASHES`] block hashes — the cursor stops at the
+//! end of that window and the next poll continues from there.
+
+use crate::finality::Frontiers;
+use crate::log_synthesis::LogFilter;
+use crate::service_get_logs::{MAX_GETLOGS_BLOCK_RANGE, validate_getlogs_filter};
+use crate::service_helpers::store_error;
+use crate::service_state::ServiceState;
+use axum_jrpc::error::{JsonRpcError, JsonRpcErrorReason};
+use axum_jrpc::{Id, JrpcResult, JsonRpcExtractor, JsonRpcResponse};
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::time::{Duration, Instant};
+
+/// Inactivity after which an installed filter is dropped (geth's default).
+/// Any call naming the filter resets the clock.
+pub const FILTER_TIMEOUT: Duration = Duration::from_secs(5 * 60);
+
+/// Installed filters per client, keyed by peer IP. Legitimate indexers hold a
+/// handful; the cap stops one caller from growing the registry without bound.
+pub const MAX_FILTERS_PER_CLIENT: usize = 64;
+
+/// Installed filters across all clients — the memory bound when many peer IPs
+/// each stay under [`MAX_FILTERS_PER_CLIENT`].
+pub const MAX_FILTERS_TOTAL: usize = 10_000;
+
+/// Block hashes reported by one `eth_getFilterChanges` on a block filter.
+pub const MAX_FILTER_BLOCK_HASHES: u64 = 1_000;
+
+#[derive(Debug, Clone)]
+enum FilterKind {
+    Blocks,
+    Logs(LogFilter),
+}
+
+struct InstalledFilter {
+    owner: Option<IpAddr>,
+    kind: FilterKind,
+    /// First block not yet reported by `eth_getFilterChanges`.
+    next_block: u64,
+    last_used: Instant,
+}
+
+/// Why a filter could not be installed.
+#[derive(Debug, PartialEq)]
+enum InstallError {
+    ClientLimit,
+    GlobalLimit,
+}
+
+/// In-memory registry of installed filters, shared by every request.
+#[derive(Default)]
+pub struct FilterRegistry {
+    filters: std::sync::Mutex<HashMap<String, InstalledFilter>>,
+}
+
+impl FilterRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<String, InstalledFilter>> {
+        self.filters.lock().expect("FilterRegistry mutex poisoned")
+    }
+
+    /// Drop filters idle for longer than [`FILTER_TIMEOUT`]. Run lazily on
+    /// every registry call, so no background task is needed.
+    fn expire(filters: &mut HashMap<String, InstalledFilter>, now: Instant) {
+        let before = filters.len();
+        filters.retain(|_, f| now.duration_since(f.last_used) < FILTER_TIMEOUT);
+        let expired = before - filters.len();
+        if expired > 0 {
+            metrics::counter!("rpc_filters_expired_total").increment(expired as u64);
+            metrics::gauge!("rpc_filters_installed").set(filters.len() as f64);
+        }
+    }
+
+    fn install(
+        &self,
+        owner: Option<IpAddr>,
+        kind: FilterKind,
+        next_block: u64,
+        now: Instant,
+    ) -> Result<String, InstallError> {
+        let mut filters = self.lock();
+        Self::expire(&mut filters, now);
+        if filters.len() >= MAX_FILTERS_TOTAL {
+            return Err(InstallError::GlobalLimit);
+        }
+        if filters.values().filter(|f| f.owner == owner).count() >= MAX_FILTERS_PER_CLIENT {
+            return Err(InstallError::ClientLimit);
+        }
+        let id = format!("0x{:032x}", ulid::Ulid::new().0);
+        filters.insert(
+            id.clone(),
+            InstalledFilter {
+                owner,
+                kind,
+                next_block,
+                last_used: now,
+            },
+        );
+        metrics::gauge!("rpc_filters_installed").set(filters.len() as f64);
+        Ok(id)
+    }
+
+    fn uninstall(&self, id: &str, now: Instant) -> bool {
+        let mut filters = self.lock();
+        Self::expire(&mut filters, now);
+        let removed = filters.remove(id).is_some();
+        metrics::gauge!("rpc_filters_installed").set(filters.len() as f64);
+        removed
+    }
+
+    /// Look a filter up and reset its idle clock.
+    fn touch(&self, id: &str, now: Instant) -> Option<FilterKind> {
+        let mut filters = self.lock();
+        Self::expire(&mut filters, now);
+        let filter = filters.get_mut(id)?;
+        filter.last_used = now;
+        Some(filter.kind.clone())
+    }
+
+    /// Claim the next window of blocks for `eth_getFilterChanges`, advancing
+    /// the cursor under the lock so two concurrent polls of one filter never
+    /// report the same block. Returns the filter and the claimed inclusive
+    /// range (empty when `start > end`).
+    fn claim_window(
+        &self,
+        id: &str,
+        frontiers: &Frontiers,
+        now: Instant,
+    ) -> Option<(FilterKind, u64, u64)> {
+        let tip = frontiers.latest;
+        let mut filters = self.lock();
+        Self::expire(&mut filters, now);
+        let filter = filters.get_mut(id)?;
+        filter.last_used = now;
+        let (start, end) = match &filter.kind {
+            FilterKind::Blocks => {
+                let start = filter.next_block;
+                (
+                    start,
+                    tip.min(start.saturating_add(MAX_FILTER_BLOCK_HASHES - 1)),
+                )
+            }
+            FilterKind::Logs(log_filter) => {
+                // `safe` / `finalized` bounds follow their frontier on every
+                // poll. Resolving the rest against 0 keeps only explicit block
+                // numbers: `latest`/`pending`/absent bounds follow the live tip.
+                let mut log_filter = log_filter.clone();
+                frontiers.resolve_log_filter(&mut log_filter);
+                let start = filter.next_block.max(log_filter.from_block_number(0));
+                let upper = tip.min(log_filter.to_block_number(tip));
+                (
+                    start,
+                    upper.min(start.saturating_add(MAX_GETLOGS_BLOCK_RANGE - 1)),
+                )
+            }
+        };
+        if start <= end {
+            filter.next_block = end + 1;
+        }
+        Some((filter.kind.clone(), start, end))
+    }
+
+    /// Undo [`Self::claim_window`] after the store failed, unless another
+    /// poll has advanced the cursor since.
+    fn release_window(&self, id: &str, start: u64, end: u64) {
+        if let Some(filter) = self.lock().get_mut(id)
+            && filter.next_block == end + 1
+        {
+            filter.next_block = start;
+        }
+    }
+}
+
+fn filter_not_found(answer_id: Id) -> JsonRpcResponse {
+    JsonRpcResponse::error(
+        answer_id,
+        JsonRpcError::new(
+            JsonRpcErrorReason::ServerError(-32000),
+            "filter not found".to_string(),
+            serde_json::Value::Null,
+        ),
+    )
+}
+
+fn invalid_params(answer_id: Id, message: String) -> JsonRpcResponse {
+    JsonRpcResponse::error(
+        answer_id,
+        JsonRpcError::new(
+            JsonRpcErrorReason::InvalidParams,
+            message,
+            serde_json::Value::Null,
+        ),
+    )
+}
+
+async fn install(
+    service: &ServiceState,
+    caller: Option<IpAddr>,
+    answer_id: Id,
+    kind: FilterKind,
+) -> JrpcResult {
+    let tip = service
+        .store
+        .get_latest_block_number()
+        .await
+        .map_err(|e| store_error(answer_id.clone(), e))?;
+    match service
+        .filters
+        .install(caller, kind, tip + 1, Instant::now())
+    {
+        Ok(id) => Ok(JsonRpcResponse::success(answer_id, id)),
+        Err(reason) => {
+            metrics::counter!("rpc_filter_limit_rejected_total").increment(1);
+            let message = match reason {
+                InstallError::ClientLimit => format!(
+                    "filter limit reached: at most {MAX_FILTERS_PER_CLIENT} installed filters per client; uninstall unused filters"
+                ),
+                InstallError::GlobalLimit => {
+                    "filter limit reached: too many installed filters, retry later".to_string()
+                }
+            };
+            Ok(JsonRpcResponse::error(
+                answer_id,
+                JsonRpcError::new(
+                    JsonRpcErrorReason::ServerError(-32005),
+                    message,
+                    serde_json::Value::Null,
+                ),
+            ))
+        }
+    }
+}
+
+/// `eth_newFilter(filter)` — install a log filter. Block bounds are honoured
+/// (a numeric `toBlock` stops the feed); `blockHash` is rejected because a
+/// single-block filter has no "changes".
+pub(crate) async fn service_new_filter(
+    service: ServiceState,
+    request: JsonRpcExtractor,
+    caller: Option<IpAddr>,
+) -> JrpcResult {
+    let answer_id = request.get_answer_id();
+    let (raw,): (serde_json::Value,) = request.parse_params()?;
+    let filter: LogFilter = serde_json::from_value(raw)
+        .map_err(|e| invalid_params(answer_id.clone(), format!("invalid filter: {e}")))?;
+    if filter.block_hash.is_some() {
+        return Ok(invalid_params(
+            answer_id,
+            "eth_newFilter does not accept blockHash; use eth_getLogs".to_string(),
+        ));
+    }
+    // Only the address/topic caps apply at install time; the block span is
+    // checked when eth_getFilterLogs actually runs the range query.
+    let sizes_only = LogFilter {
+        from_block: None,
+        to_block: None,
+        ..filter.clone()
+    };
+    if let Err(msg) = validate_getlogs_filter(&sizes_only, 0) {
+        return Ok(invalid_params(answer_id, msg));
+    }
+    install(&service, caller, answer_id, FilterKind::Logs(filter)).await
+}
+
+/// `eth_newBlockFilter()` — install a filter reporting new block hashes.
+pub(crate) async fn service_new_block_filter(
+    service: ServiceState,
+    request: JsonRpcExtractor,
+    caller: Option<IpAddr>,
+) -> JrpcResult {
+    install(
+        &service,
+        caller,
+        request.get_answer_id(),
+        FilterKind::Blocks,
+    )
+    .await
+}
+
+/// `eth_getFilterChanges(id)` — blocks hashes or logs since the last poll.
+pub(crate) async fn service_get_filter_changes(
+    service: ServiceState,
+    request: JsonRpcExtractor,
+) -> JrpcResult {
+    let answer_id = request.get_answer_id();
+    let (id,): (String,) = request.parse_params()?;
+    let frontiers = service
+        .frontiers()
+        .await
+        .map_err(|e| store_error(answer_id.clone(), e))?;
+    let tip = frontiers.latest;
+    let Some((kind, start, end)) = service
+        .filters
+        .claim_window(&id, &frontiers, Instant::now())
+    else {
+        return Ok(filter_not_found(answer_id));
+    };
+    if start > end {
+        return Ok(JsonRpcResponse::success(answer_id, Vec::<String>::new()));
+    }
+    match kind {
+        FilterKind::Blocks => {
+            let hashes: Vec<String> = (start..=end)
+                .map(|n| format!("0x{}", hex::encode(service.block_state.get_block_hash(n))))
+                .collect();
+            Ok(JsonRpcResponse::success(answer_id, hashes))
+        }
+        FilterKind::Logs(filter) => {
+            let window = LogFilter {
+                from_block: Some(format!("0x{start:x}")),
+                to_block: Some(format!("0x{end:x}")),
+                ..filter
+            };
+            let logs = match service.store.get_logs(&window, tip).await {
+                Ok(logs) => logs,
+                Err(e) => {
+                    // Hand the window back so the next poll retries it.
+                    service.filters.release_window(&id, start, end);
+                    return Err(store_error(answer_id, e));
+                }
+            };
+            let json_logs: Vec<serde_json::Value> = logs.iter().map(|l| l.to_json()).collect();
+            Ok(JsonRpcResponse::success(answer_id, json_logs))
+        }
+    }
+}
+
+/// `eth_getFilterLogs(id)` — every log matching a log filter's full range,
+/// under the same caps as `eth_getLogs`. Does not move the changes cursor.
+pub(crate) async fn service_get_filter_logs(
+    service: ServiceState,
+    request: JsonRpcExtractor,
+) -> JrpcResult {
+    let answer_id = request.get_answer_id();
+    let (id,): (String,) = request.parse_params()?;
+    let mut filter = match service.filters.touch(&id, Instant::now()) {
+        Some(FilterKind::Logs(filter)) => filter,
+        Some(FilterKind::Blocks) => {
+            return Ok(invalid_params(
+                answer_id,
+                "eth_getFilterLogs requires a log filter, not a block filter".to_string(),
+            ));
+        }
+        None => return Ok(filter_not_found(answer_id)),
+    };
+    let frontiers = service
+        .frontiers()
+        .await
+        .map_err(|e| store_error(answer_id.clone(), e))?;
+    frontiers.resolve_log_filter(&mut filter);
+    let tip = frontiers.latest;
+    if let Err(msg) = validate_getlogs_filter(&filter, tip) {
+        return Ok(invalid_params(answer_id, msg));
+    }
+    let logs = service
+        .store
+        .get_logs(&filter, tip)
+        .await
+        .map_err(|e| store_error(answer_id.clone(), e))?;
+    let json_logs: Vec<serde_json::Value> = logs.iter().map(|l| l.to_json()).collect();
+    Ok(JsonRpcResponse::success(answer_id, json_logs))
+}
+
+/// `eth_uninstallFilter(id)` — `true` if the filter existed.
+pub(crate) async fn service_uninstall_filter(
+    service: ServiceState,
+    request: JsonRpcExtractor,
+) -> JrpcResult {
+    let answer_id = request.get_answer_id();
+    let (id,): (String,) = request.parse_params()?;
+    let removed = service.filters.uninstall(&id, Instant::now());
+    Ok(JsonRpcResponse::success(answer_id, removed))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::finality::FinalityLags;
+
+    /// Frontiers at `tip` with `safe` / `finalized` two and ten blocks behind.
+    fn at(tip: u64) -> Frontiers {
+        FinalityLags::new(2, 10).unwrap().frontiers(tip)
+    }
+
+    fn logs_filter(from: Option<&str>, to: Option<&str>) -> FilterKind {
+        FilterKind::Logs(LogFilter {
+            from_block: from.map(str::to_string),
+            to_block: to.map(str::to_string),
+            ..Default::default()
+        })
+    }
+
+    /// The cursor reports each block exactly once, never past the tip, and
+    /// never more than one window per poll.
+    #[test]
+    fn filter_cursor_advances_by_bounded_windows() {
+        let registry = FilterRegistry::new();
+        let now = Instant::now();
+        let id = registry.install(None, FilterKind::Blocks, 11, now).unwrap();
+
+        // Tip has not moved since install: nothing to report.
+        let (_, start, end) = registry.claim_window(&id, &at(10), now).unwrap();
+        assert!(start > end);
+
+        let (_, start, end) = registry.claim_window(&id, &at(15), now).unwrap();
+        assert_eq!((start, end), (11, 15));
+        let (_, start, end) = registry.claim_window(&id, &at(15), now).unwrap();
+        assert!(start > end, "a block must not be reported twice");
+
+        let far = 15 + 3 * MAX_FILTER_BLOCK_HASHES;
+        let (_, start, end) = registry.claim_window(&id, &at(far), now).unwrap();
+        assert_eq!((start, end), (16, 15 + MAX_FILTER_BLOCK_HASHES));
+
+        // A failed store read hands the window back.
+        registry.release_window(&id, start, end);
+        let (_, again, _) = registry.claim_window(&id, &at(far), now).unwrap();
+        assert_eq!(again, start);
+    }
+
+    /// Explicit numeric bounds clamp the feed; tags follow the tip.
+    #[test]
+    fn log_filter_window_honours_numeric_bounds() {
+        let registry = FilterRegistry::new();
+        let now = Instant::now();
+
+        let bounded = registry
+            .install(None, logs_filter(Some("0x14"), Some("0x1e")), 6, now)
+            .unwrap();
+        let (_, start, end) = registry.claim_window(&bounded, &at(100), now).unwrap();
+        assert_eq!((start, end), (20, 30));
+        let (_, start, end) = registry.claim_window(&bounded, &at(200), now).unwrap();
+        assert!(start > end, "toBlock reached: feed is exhausted");
+
+        let live = registry
+            .install(None, logs_filter(Some("latest"), None), 6, now)
+            .unwrap();
+        let (_, start, end) = registry.claim_window(&live, &at(9), now).unwrap();
+        assert_eq!((start, end), (6, 9));
+
+        let finalized = registry
+            .install(None, logs_filter(Some("latest"), Some("finalized")), 6, now)
+            .unwrap();
+        let (_, start, end) = registry.claim_window(&finalized, &at(20), now).unwrap();
+        assert_eq!((start, end), (6, 10), "toBlock `finalized` trails the tip");
+        let (_, start, end) = registry.claim_window(&finalized, &at(25), now).unwrap();
+        assert_eq!((start, end), (11, 15));
+    }
+
+    #[test]
+    fn filters_expire_after_inactivity() {
+        let registry = FilterRegistry::new();
+        let now = Instant::now();
+        let idle = registry.install(None, FilterKind::Blocks, 1, now).unwrap();
+        let busy = registry.install(None, FilterKind::Blocks, 1, now).unwrap();
+
+        let later = now + FILTER_TIMEOUT / 2;
+        assert!(registry.touch(&busy, later).is_some());
+        let expired_at = now + FILTER_TIMEOUT;
+        assert!(registry.touch(&idle, expired_at).is_none());
+        assert!(registry.touch(&busy, expired_at).is_some());
+        assert!(!registry.uninstall(&idle, expired_at));
+        assert!(registry.uninstall(&busy, expired_at));
+    }
+
+    #[test]
+    fn installed_filters_are_bounded_per_client() {
+        let registry = FilterRegistry::new();
+        let now = Instant::now();
+        let a: IpAddr = "10.0.0.1".parse().unwrap();
+        let b: IpAddr = "10.0.0.2".parse().unwrap();
+        for _ in 0..MAX_FILTERS_PER_CLIENT {
+            registry
+                .install(Some(a), FilterKind::Blocks, 1, now)
+                .unwrap();
+        }
+        assert_eq!(
+            registry.install(Some(a), FilterKind::Blocks, 1, now),
+            Err(InstallError::ClientLimit)
+        );
+        // Another client is unaffected.
+        assert!(
+            registry
+                .install(Some(b), FilterKind::Blocks, 1, now)
+                .is_ok()
+        );
+    }
+}
@@ -37,7 +37,7 @@ pub(crate) async fn service_get_logs(
     // unrecognised hex string) by returning an empty result for the wrong reason,
     // which downstream consumers would misinterpret as "no claims yet" and fail to
     // retry. JSON-RPC contract is to fail loud on bad input.
-    let log_filter: LogFilter = serde_json::from_value(raw_params.0.clone()).map_err(|e| {
+    let mut log_filter: LogFilter = serde_json::from_value(raw_params.0.clone()).map_err(|e| {
         tracing::warn!("eth_getLogs: rejecting malformed filter params: {e}");
         JsonRpcResponse::error(
             answer_id.clone(),
@@ -48,11 +48,12 @@ pub(crate) async fn service_get_logs(
             ),
         )
     })?;
-    let current_block = service
-        .store
-        .get_latest_block_number()
+    let frontiers = service
+        .frontiers()
         .await
         .map_err(|e| store_error(answer_id.clone(), e))?;
+    frontiers.resolve_log_filter(&mut log_filter);
+    let current_block = frontiers.latest;
 
     // R5 — bound the block range and filter array sizes before hitting the store.
     if let Err(msg) = validate_getlogs_filter(&log_filter, current_block) {
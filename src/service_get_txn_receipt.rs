@@ -11,7 +11,17 @@ pub async fn service_get_txn_receipt(
     service: ServiceState,
     txn_hash: String,
 ) -> anyhow::Result<Option<TransactionReceipt<ReceiptEnvelope<Log>>>> {
-    let txn_hash = TxHash::from_str(&txn_hash)?;
+    receipt_with_index(service, TxHash::from_str(&txn_hash)?, None).await
+}
+
+/// The receipt of `txn_hash`. `transaction_index` is its position in the
+/// block when the caller already listed the block; otherwise the index sealed
+/// onto its logs is used, and only a log-less receipt lists its block.
+pub(crate) async fn receipt_with_index(
+    service: ServiceState,
+    txn_hash: TxHash,
+    transaction_index: Option<u64>,
+) -> anyhow::Result<Option<TransactionReceipt<ReceiptEnvelope<Log>>>> {
     let (status, block_num) = match service.store.txn_receipt(txn_hash).await? {
         Some((result, block_num)) => (result.is_ok(), block_num),
         None => {
@@ -56,6 +66,36 @@ pub async fn service_get_txn_receipt(
     // Go's types.Receipt unmarshaling fails silently and the EthTxManager
     // treats the tx as "not mined".
     let block_hash = service.block_state.get_block_hash(block_num);
+
+    // Serve the same logs and bloom that were hashed into the block's
+    // `receiptsRoot` / `logsBloom` (see `block_state::compute_block_roots`).
+    let mut tx_logs = service
+        .store
+        .get_logs_for_tx(&format!("{txn_hash:#x}"))
+        .await?;
+    tx_logs.retain(|log| log.block_number == block_num);
+    // Logs above the tip sit in a block the projector has not sealed yet: its
+    // hash and roots are provisional, so the receipt is still pending.
+    if !tx_logs.is_empty() && block_num > service.store.get_latest_block_number().await? {
+        return Ok(None);
+    }
+    tx_logs.sort_by_key(|log| log.log_index);
+    let consensus = crate::block_state::synthetic_receipt(&tx_logs)?;
+    receipt_inner.logs_bloom = consensus.logs_bloom;
+    receipt_inner.receipt.logs = tx_logs
+        .iter()
+        .zip(consensus.receipt.logs)
+        .map(|(log, inner)| Log {
+            inner,
+            block_hash: Some(alloy::primitives::B256::from(block_hash)),
+            block_number: Some(block_num),
+            block_timestamp: None,
+            transaction_hash: Some(txn_hash),
+            transaction_index: Some(log.transaction_index),
+            log_index: Some(log.log_index),
+            removed: false,
+        })
+        .collect();
     // RD-940 latent-bug co-fix (originally scoped for the Phase 3 BlockMonitor
     // PR; folded here because the BlockMonitor unification is deferred to a
     // follow-up). The pre-fix path returned `from: Default::default()` (the
@@ -74,10 +114,18 @@ pub async fn service_get_txn_receipt(
         .flatten()
         .map(|t| t.signer)
         .unwrap_or_default();
+    let transaction_index = match (transaction_index, tx_logs.first()) {
+        (Some(index), _) => index,
+        (None, Some(log)) => log.transaction_index,
+        (None, None) => {
+            crate::service_block_txns::transaction_index(&*service.store, block_num, txn_hash)
+                .await?
+        }
+    };
     let receipt: TransactionReceipt<ReceiptEnvelope<Log>> = TransactionReceipt {
         inner: ReceiptEnvelope::Eip1559(receipt_inner),
         transaction_hash: txn_hash,
-        transaction_index: Some(0),
+        transaction_index: Some(transaction_index),
         block_hash: Some(alloy::primitives::B256::from(block_hash)),
         block_number: Some(block_num),
         gas_used: 0,
@@ -385,18 +433,42 @@ mod tests {
                 block_number: 39,
                 block_hash: [0u8; 32],
                 transaction_hash: tx.to_string(),
-                transaction_index: 0,
+                transaction_index: 2,
                 log_index: 0,
                 removed: false,
             })
             .await
             .unwrap();
+        assert!(
+            service_get_txn_receipt(service.clone(), tx.to_string())
+                .await
+                .unwrap()
+                .is_none(),
+            "a receipt in a block above the tip is still pending"
+        );
+        service.store.set_latest_block_number(39).await.unwrap();
 
         let receipt = service_get_txn_receipt(service, tx.to_string())
             .await
             .unwrap()
             .expect("a tx that emitted a synthetic log MUST have a receipt, not null");
         assert_eq!(receipt.block_number, Some(39), "receipt at the log's block");
+        assert_eq!(
+            receipt.transaction_index,
+            Some(2),
+            "the index sealed onto the tx's logs, without listing the block"
+        );
+        let inner = receipt.inner.as_receipt_with_bloom().unwrap();
+        assert_eq!(inner.receipt.logs.len(), 1, "receipt carries the tx's logs");
+        assert_eq!(
+            inner.receipt.logs[0].transaction_hash,
+            Some(receipt.transaction_hash)
+        );
+        assert_ne!(
+            inner.logs_bloom,
+            alloy::primitives::Bloom::ZERO,
+            "receipt bloom covers its logs"
+        );
         assert!(
             matches!(
                 receipt.inner.as_receipt().unwrap().status,
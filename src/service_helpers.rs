@@ -27,6 +27,11 @@ pub(crate) enum ServiceErrorCode {
     GetTransactionReceipt,
     AdminRegisterFaucet,
     AdminRegisterNativeFaucet,
+    AdminWriterJob,
+    AdminAuditLog,
+    AdminPolicy,
+    AdminQuarantine,
+    AdminGerRemoval,
 }
 
 impl From<ServiceErrorCode> for JsonRpcErrorReason {
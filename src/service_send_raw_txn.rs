@@ -1,5 +1,6 @@
 use crate::claim::claimAssetCall;
-use crate::ger::{insertGlobalExitRootCall, updateExitRootCall};
+use crate::future_nonce_pool::SignerQuotaExceededError;
+use crate::ger::{insertGlobalExitRootCall, removeGlobalExitRootsCall, updateExitRootCall};
 use crate::hex::hex_decode_prefixed;
 use crate::service_state::ServiceState;
 use crate::store::TxnEntry;
@@ -54,6 +55,19 @@ pub(crate) fn decode_write_call(
         Ok(crate::writer_worker::DecodedWriteCall::Ger {
             ger_bytes: ger::combined_ger(&params.newMainnetExitRoot.0, &params.newRollupExitRoot.0),
         })
+    } else if params_encoded.starts_with(&removeGlobalExitRootsCall::SELECTOR) {
+        tracing::debug!("removeGlobalExitRoots call");
+        let params = removeGlobalExitRootsCall::abi_decode(params_encoded)?;
+        tracing::debug!(target: concat!(module_path!(), "::debug"), "removeGlobalExitRoots call params: {params:?}");
+        // One RemoveGerNote per eth transaction: the note link, the receipt
+        // and the projected removal logs are all keyed by the tx hash.
+        let [ger] = params.gersToRemove.as_slice() else {
+            anyhow::bail!(
+                "removeGlobalExitRoots must remove exactly one GER per transaction (got {}); submit one transaction per GER",
+                params.gersToRemove.len()
+            );
+        };
+        Ok(crate::writer_worker::DecodedWriteCall::GerRemoval { ger_bytes: ger.0 })
     } else {
         anyhow::bail!("unhandled txn method {params_encoded:?}")
     }
@@ -212,8 +226,18 @@ async fn record_local_immediate_success(
     signer: Address,
     logs: Vec<LogData>,
 ) -> anyhow::Result<()> {
-    let block_num = service.store.get_latest_block_number().await?;
-    record_local_success_at_block(service, tx_hash, txn_envelope, signer, block_num, logs).await
+    if logs.is_empty() {
+        let latest = service.store.get_latest_block_number().await?;
+        return record_local_success_at_block(service, tx_hash, txn_envelope, signer, latest, logs)
+            .await;
+    }
+    // The exposed tip is sealed: its `logsBloom` / `receiptsRoot` are final.
+    // Logs join the next block instead, which the projector seals with them.
+    // Holding the seal lock keeps that block from sealing between reading the
+    // tip and writing the logs; the receipt stays hidden until it does.
+    let _seal = service.block_state.lock_seal().await;
+    let latest = service.store.get_latest_block_number().await?;
+    record_local_success_at_block(service, tx_hash, txn_envelope, signer, latest + 1, logs).await
 }
 
 async fn record_local_success_at_block(
@@ -349,6 +373,153 @@ pub(crate) async fn worker_handle_claim_asset(
     txn_envelope: TxEnvelope,
     signer: Address,
 ) -> anyhow::Result<()> {
+    let guard =
+        match begin_claim_dispatch(service, &params, txn_hash, &txn_envelope, signer).await? {
+            ClaimDispatch::Done => return Ok(()),
+            ClaimDispatch::Publish(guard) => guard,
+        };
+    let result =
+        publish_and_record_claim(service, params, txn_hash, txn_envelope, signer, &guard).await;
+    finish_claim_dispatch(guard, result).await
+}
+
+/// A `claimAsset` the writer dequeued, as handed to
+/// [`worker_handle_claim_batch`].
+pub(crate) struct QueuedClaim {
+    pub(crate) params: claimAssetCall,
+    pub(crate) txn_hash: TxHash,
+    pub(crate) txn_envelope: TxEnvelope,
+    pub(crate) signer: Address,
+}
+
+/// Handle several queued `claimAsset` transactions with one Miden
+/// transaction. Returns one result per claim, in input order, with the same
+/// meaning as [`worker_handle_claim_asset`]'s.
+///
+/// Every claim goes through the single-claim classification first (network,
+/// zero amount, landed/in-flight lock, unresolvable destination), so a claim
+/// that fails or short-circuits there never joins the transaction. The rest
+/// are published together by [`claim::publish_claim_batch`]. When that
+/// transaction fails, each claim with a durable note handoff stays pending
+/// exactly like a single claim would, and the others are re-driven one by
+/// one — which isolates a claim that broke the shared transaction.
+pub(crate) async fn worker_handle_claim_batch(
+    service: &ServiceState,
+    claims: Vec<QueuedClaim>,
+) -> Vec<anyhow::Result<()>> {
+    let mut results: Vec<Option<anyhow::Result<()>>> = claims.iter().map(|_| None).collect();
+    let mut ready = Vec::with_capacity(claims.len());
+    for (index, claim) in claims.into_iter().enumerate() {
+        match begin_claim_dispatch(
+            service,
+            &claim.params,
+            claim.txn_hash,
+            &claim.txn_envelope,
+            claim.signer,
+        )
+        .await
+        {
+            Ok(ClaimDispatch::Done) => results[index] = Some(Ok(())),
+            Ok(ClaimDispatch::Publish(guard)) => ready.push((index, claim, guard)),
+            Err(err) => results[index] = Some(Err(err)),
+        }
+    }
+
+    if ready.len() > 1 {
+        ::metrics::histogram!("agglayer_writer_claim_batch_size").record(ready.len() as f64);
+        let batch = ready
+            .iter()
+            .map(|(_, claim, guard)| claim::BatchedClaim {
+                claim: claim::ClaimToPublish {
+                    params: claim.params.clone(),
+                    submission_fence: guard.submission_fence(),
+                },
+                txn_hash: claim.txn_hash,
+                txn_envelope: claim.txn_envelope.clone(),
+                signer: claim.signer,
+            })
+            .collect();
+        let published = match service.store.get_latest_block_number().await {
+            Ok(latest_block) => {
+                claim::publish_claim_batch(
+                    batch,
+                    &service.miden_client,
+                    service.accounts.clone(),
+                    service.store.clone(),
+                    latest_block,
+                    service.reject_zero_padding_addresses,
+                    Some(service.expected_mints.clone()),
+                )
+                .await
+            }
+            Err(err) => Err(err),
+        };
+        match published {
+            Ok(published) => {
+                for ((index, claim, guard), claim_result) in ready.drain(..).zip(published) {
+                    let result = settle_claim_publish(service, claim.txn_hash, claim_result).await;
+                    results[index] = Some(finish_claim_dispatch(guard, result).await);
+                }
+            }
+            Err(err) => {
+                ::metrics::counter!("agglayer_writer_claim_batch_fallbacks_total").increment(1);
+                tracing::warn!(
+                    claims = ready.len(),
+                    error = format!("{err:#}"),
+                    "batched claim transaction failed; re-driving claims without a note \
+                     handoff one by one"
+                );
+                let mut retry = Vec::with_capacity(ready.len());
+                for (index, claim, guard) in ready.drain(..) {
+                    let batch_err = anyhow::anyhow!("batched claim transaction failed: {err:#}");
+                    match settle_claim_publish(service, claim.txn_hash, Err(batch_err)).await {
+                        Ok(()) => results[index] = Some(finish_claim_dispatch(guard, Ok(())).await),
+                        Err(_) => retry.push((index, claim, guard)),
+                    }
+                }
+                ready = retry;
+            }
+        }
+    }
+
+    for (index, claim, guard) in ready {
+        let result = publish_and_record_claim(
+            service,
+            claim.params,
+            claim.txn_hash,
+            claim.txn_envelope,
+            claim.signer,
+            &guard,
+        )
+        .await;
+        results[index] = Some(finish_claim_dispatch(guard, result).await);
+    }
+
+    results
+        .into_iter()
+        .map(|result| result.unwrap_or_else(|| Err(anyhow::anyhow!("claim left out of its batch"))))
+        .collect()
+}
+
+/// Outcome of the pre-publish half of a claim dispatch.
+enum ClaimDispatch {
+    /// Settled without a Miden submission: skipped, reverted as already
+    /// landed, or recorded unclaimable.
+    Done,
+    /// The claim holds its `global_index` fence and must be published.
+    Publish(ClaimGuard),
+}
+
+/// Everything [`worker_handle_claim_asset`] does before publishing: network
+/// and zero-amount checks, the authoritative landed/in-flight lock and the
+/// unresolvable-destination short circuit.
+async fn begin_claim_dispatch(
+    service: &ServiceState,
+    params: &claimAssetCall,
+    txn_hash: TxHash,
+    txn_envelope: &TxEnvelope,
+    signer: Address,
+) -> anyhow::Result<ClaimDispatch> {
     // Only claims where destinationNetwork matches our network_id are processed.
     //
     // RD-703 — `service.network_id` is `u32` (validated at startup in
@@ -369,8 +540,9 @@ pub(crate) async fn worker_handle_claim_asset(
     // CLAIM notes that crash the NTX builder's faucet actor.
     if params.amount.is_zero() {
         tracing::info!("skipping zero-amount claim (genesis batch)");
-        record_local_immediate_success(service, txn_hash, txn_envelope, signer, vec![]).await?;
-        return Ok(());
+        record_local_immediate_success(service, txn_hash, txn_envelope.clone(), signer, vec![])
+            .await?;
+        return Ok(ClaimDispatch::Done);
     }
 
     // #55 BLOCKER A — the AUTHORITATIVE landed classification runs FIRST, before
@@ -383,7 +555,7 @@ pub(crate) async fn worker_handle_claim_asset(
     // `acquire_claim_lock` is the ONE atomic classification (BLOCKER B): a gi that
     // landed at any point in the try_claim window classifies `Landed`, never
     // `InFlight`, so no interleaving hard-rejects a landed gi.
-    let tx_nonce = envelope_nonce(&txn_envelope);
+    let tx_nonce = envelope_nonce(txn_envelope);
     let signer_str = format!("{signer:#x}");
     let claim_fence = match acquire_claim_lock(
         &service.store,
@@ -401,15 +573,15 @@ pub(crate) async fn worker_handle_claim_asset(
         ClaimLockOutcome::Landed => {
             accept_and_revert_landed_claim(
                 service,
-                &params,
+                params,
                 txn_hash,
-                txn_envelope,
+                txn_envelope.clone(),
                 signer,
                 &signer_str,
                 tx_nonce,
             )
             .await?;
-            return Ok(());
+            return Ok(ClaimDispatch::Done);
         }
         // A genuine concurrent submission for this gi is in flight (locked, no
         // ClaimEvent yet, within TTL). Do not double-publish. Because this dispatcher
@@ -491,22 +663,23 @@ pub(crate) async fn worker_handle_claim_asset(
         // counts against `unclaimable_claims` to see how many funds are truly on L1.
         let event = crate::claim::ClaimEvent::from(params.clone());
         let log = <crate::claim::ClaimEvent as alloy::sol_types::SolEvent>::encode_log_data(&event);
-        record_local_immediate_success(service, txn_hash, txn_envelope, signer, vec![log]).await?;
+        record_local_immediate_success(service, txn_hash, txn_envelope.clone(), signer, vec![log])
+            .await?;
         // The gi is now handled (unclaimable record + ClaimEvent); drop the lock so
         // a resubmit classifies `Landed` (the ClaimEvent exists) → accept-and-revert.
         guard.release_explicitly().await;
-        return Ok(());
+        return Ok(ClaimDispatch::Done);
     }
 
-    let result = publish_and_record_claim(
-        service,
-        params.clone(),
-        txn_hash,
-        txn_envelope,
-        signer,
-        &guard,
-    )
-    .await;
+    Ok(ClaimDispatch::Publish(guard))
+}
+
+/// Settle a claim's fence once its publish finished: keep it on success (the
+/// claim is committed), release it on failure.
+async fn finish_claim_dispatch(
+    guard: ClaimGuard,
+    result: anyhow::Result<()>,
+) -> anyhow::Result<()> {
     if let Err(err) = result {
         // Explicit release: the guard would also fire on drop, but doing it
         // here avoids the tokio::spawn round-trip on the error path.
@@ -550,6 +723,140 @@ pub(crate) fn reservation_lease() -> std::time::Duration {
     std::time::Duration::from_secs(secs.max(3))
 }
 
+/// How long a request waits, lock released, for a missing lower nonce from
+/// concurrent HTTP delivery before its envelope is held instead.
+#[cfg(not(test))]
+const FUTURE_NONCE_REORDER_WAIT: std::time::Duration = std::time::Duration::from_secs(30);
+// Hold-path tests should not sit out the production reorder window.
+#[cfg(test)]
+const FUTURE_NONCE_REORDER_WAIT: std::time::Duration = std::time::Duration::from_millis(500);
+
+/// Park a future-nonce envelope and answer `-32007`; a refusal maps to the
+/// signer quota (`-32006`), a full area (`-32005`), or falls through to the
+/// R4 mismatch when the gap is too wide to be reordering.
+fn hold_future_nonce(
+    service: &ServiceState,
+    signer: Address,
+    tx_nonce: u64,
+    expected_nonce: u64,
+    txn_hash: TxHash,
+    input: &str,
+) -> anyhow::Result<()> {
+    use crate::future_nonce_pool::{FutureNoncePoolFullError, HoldRefusal, NonceTooHighHeldError};
+    let refusal = match service.future_nonces.hold(
+        signer,
+        tx_nonce,
+        expected_nonce,
+        txn_hash,
+        input.to_string(),
+    ) {
+        Ok(()) => {
+            ::metrics::counter!("rpc_future_nonce_held_total").increment(1);
+            return Err(NonceTooHighHeldError {
+                tx_hash: txn_hash,
+                signer,
+                nonce: tx_nonce,
+                expected: expected_nonce,
+            }
+            .into());
+        }
+        Err(refusal) => refusal,
+    };
+    match refusal {
+        HoldRefusal::GapTooLarge => Ok(()),
+        HoldRefusal::SignerFull { held } => {
+            ::metrics::counter!("rpc_signer_quota_rejections_total", "kind" => "held").increment(1);
+            Err(SignerQuotaExceededError {
+                signer,
+                what: "held future-nonce",
+                count: held,
+                quota: service.future_nonces.per_signer(),
+            }
+            .into())
+        }
+        HoldRefusal::PoolFull => Err(FutureNoncePoolFullError.into()),
+        HoldRefusal::NonceTaken(other) => anyhow::bail!(
+            "nonce {tx_nonce} for {signer:#x} is already held for a different tx {other:#x}"
+        ),
+    }
+}
+
+/// Resubmit held future-nonce envelopes once their signer's nonce reaches
+/// them. Woken after every admission and on a 1 s tick, which also expires
+/// envelopes held past [`crate::future_nonce_pool::HELD_TX_TTL`]. A released
+/// envelope runs the full `service_send_raw_txn` path again.
+pub fn spawn_future_nonce_release(service: ServiceState) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        let pool = service.future_nonces.clone();
+        loop {
+            tokio::select! {
+                _ = pool.notified() => {}
+                _ = tokio::time::sleep(std::time::Duration::from_secs(1)) => {}
+            }
+            pool.expire(crate::future_nonce_pool::HELD_TX_TTL);
+            for signer in pool.signers() {
+                let expected = match service.store.nonce_get(&format!("{signer:#x}")).await {
+                    Ok(nonce) => nonce,
+                    Err(err) => {
+                        tracing::warn!(%signer, error = %err, "future-nonce release: nonce read failed");
+                        continue;
+                    }
+                };
+                let Some(held) = pool.take_ready(signer, expected) else {
+                    continue;
+                };
+                let outcome = match service_send_raw_txn(service.clone(), held.raw.clone()).await {
+                    Ok(_) => "admitted",
+                    Err(err)
+                        if err
+                            .downcast_ref::<crate::future_nonce_pool::NonceTooHighHeldError>()
+                            .is_some() =>
+                    {
+                        "held_again"
+                    }
+                    // Quota or queue pressure clears on its own: keep the
+                    // envelope for the next pass instead of dropping it.
+                    Err(err) if is_transient_release_refusal(&err) => {
+                        if pool.restore(signer, held) {
+                            "requeued"
+                        } else {
+                            "refused"
+                        }
+                    }
+                    Err(err) => {
+                        tracing::warn!(
+                            %signer,
+                            tx_hash = %held.tx_hash,
+                            nonce = held.nonce,
+                            error = %err,
+                            "held future-nonce transaction was refused on release"
+                        );
+                        "refused"
+                    }
+                };
+                ::metrics::counter!("rpc_future_nonce_released_total", "outcome" => outcome)
+                    .increment(1);
+            }
+        }
+    })
+}
+
+/// Whether a released envelope was refused only for load: the signer's
+/// in-flight quota, a saturated writer or holding area, or a writer shutting
+/// down. Such an envelope goes back to the holding area.
+fn is_transient_release_refusal(err: &anyhow::Error) -> bool {
+    err.downcast_ref::<SignerQuotaExceededError>().is_some()
+        || err
+            .downcast_ref::<crate::writer_worker::WriterQueueSaturatedError>()
+            .is_some()
+        || err
+            .downcast_ref::<crate::writer_worker::WriterShuttingDownError>()
+            .is_some()
+        || err
+            .downcast_ref::<crate::future_nonce_pool::FutureNoncePoolFullError>()
+            .is_some()
+}
+
 /// A spawned lease-renewal task must never outlive the request that owns it.
 /// Tokio detaches a `JoinHandle` on drop, so an unguarded handle would keep an
 /// abandoned reservation alive forever after request cancellation.
@@ -573,12 +880,24 @@ impl Drop for AbortTaskOnDrop {
     }
 }
 
+/// Map a writer admission refusal onto the sentinel `service.rs` turns into
+/// JSON-RPC `-32005`.
+pub(crate) fn writer_admission_error(err: crate::writer_worker::TryEnqueueError) -> anyhow::Error {
+    use crate::writer_worker::TryEnqueueError;
+    match err {
+        TryEnqueueError::QueueFull => crate::writer_worker::WriterQueueSaturatedError.into(),
+        TryEnqueueError::ShuttingDown | TryEnqueueError::ShutDown => {
+            crate::writer_worker::WriterShuttingDownError.into()
+        }
+    }
+}
+
 /// #55 BLOCKER 1 — execute a WON admission through the writer worker and return
 /// the tx hash. Extracted so `service_send_raw_txn` can
 /// wrap it with the reservation-lease RELEASE (success → future same-hash dedups;
 /// failure → the same tx may retry via lease takeover). Only ever called after the
 /// caller won the `(signer, nonce)` reservation.
-async fn durably_admit_and_advance_nonce(
+pub(crate) async fn durably_admit_and_advance_nonce(
     service: &ServiceState,
     txn_hash: TxHash,
     txn_envelope: &TxEnvelope,
@@ -618,6 +937,86 @@ async fn durably_admit_and_advance_nonce(
     Ok(())
 }
 
+/// Sign `input` as a legacy transaction to the bridge at `key`'s next nonce and
+/// admit it into the writer the way `eth_sendRawTransaction` admits a
+/// submitted one: per-signer lock, fenced `(signer, nonce)` reservation,
+/// pending row before the nonce advance. Used for the transactions the proxy
+/// originates itself (GER relayer injections, admin GER removals); the
+/// request-path checks that only apply to a foreign signer are skipped.
+pub(crate) async fn admit_locally_signed(
+    service: &ServiceState,
+    key: &alloy::signers::local::PrivateKeySigner,
+    input: Vec<u8>,
+    gas_limit: u64,
+) -> anyhow::Result<TxHash> {
+    use alloy::consensus::{SignableTransaction, TxLegacy};
+    use alloy::signers::SignerSync;
+    use anyhow::Context;
+
+    let decoded = decode_write_call(&input)?;
+    let handle = service
+        .writer_handle
+        .as_ref()
+        .ok_or_else(|| anyhow::anyhow!("locally signed transactions require the writer worker"))?;
+    handle
+        .check_admission(decoded.kind())
+        .map_err(writer_admission_error)?;
+    let bridge = crate::bridge_address::get_bridge_address()
+        .parse::<Address>()
+        .context("invalid bridge address")?;
+
+    let signer = key.address();
+    let signer_str = format!("{signer:#x}");
+    let _lock = service.per_signer_locks.lock(signer).await;
+    let nonce = service.store.nonce_get(&signer_str).await?;
+    let txn = TxLegacy {
+        chain_id: Some(service.chain_id),
+        nonce,
+        gas_limit,
+        to: alloy::primitives::TxKind::Call(bridge),
+        input: input.into(),
+        ..Default::default()
+    };
+    let signature = key.sign_hash_sync(&txn.signature_hash())?;
+    let signed = txn.into_signed(signature);
+    let hash = *signed.hash();
+    let envelope = TxEnvelope::from(signed);
+
+    let fence = match service
+        .store
+        .reserve_nonce(&signer_str, nonce, hash, reservation_lease())
+        .await?
+    {
+        crate::store::NonceReservation::Won { fence } => fence,
+        other => anyhow::bail!(
+            "nonce {nonce} for {signer_str} is not free ({other:?}); another replica may be \
+             signing with the same key"
+        ),
+    };
+    let admission = async {
+        durably_admit_and_advance_nonce(service, hash, &envelope, signer, &signer_str, nonce)
+            .await?;
+        let job = decoded.into_job(envelope, signer, hash);
+        // A refusal here leaves the durable pending row for orphan recovery,
+        // exactly like a submitted transaction.
+        handle.try_enqueue(job).map_err(writer_admission_error)
+    }
+    .await;
+    if let Err(e) = service
+        .store
+        .release_reservation(&signer_str, nonce, hash, fence, admission.is_ok())
+        .await
+    {
+        tracing::warn!(
+            tx = %hash,
+            nonce,
+            error = %e,
+            "failed to release the nonce reservation of a locally signed transaction; it will expire"
+        );
+    }
+    admission.map(|()| hash)
+}
+
 /// State-only compatibility gate for `claimAsset`. One bridge snapshot answers
 /// both questions in EVM order: already-claimed wins; otherwise a missing GER
 /// fails before nonce consumption. Returns true only for an applied claim.
@@ -649,10 +1048,73 @@ async fn claim_state_gate(service: &ServiceState, params: &claimAssetCall) -> an
     Ok(false)
 }
 
+/// Reject a GER removal the bridge would refuse: the GER must currently be
+/// applied (same read as `globalExitRootMap`) and no other removal of it may
+/// still be outstanding. Shared by `removeGlobalExitRoots` admission and
+/// `admin_removeGlobalExitRoot`.
+pub(crate) async fn validate_ger_removal(
+    service: &ServiceState,
+    ger_bytes: &[u8; 32],
+) -> anyhow::Result<()> {
+    if !crate::applied_state::ger_applied(service, ger_bytes).await? {
+        anyhow::bail!("execution reverted: GlobalExitRootNotFound()");
+    }
+    if ger_removal_outstanding(service, ger_bytes).await? {
+        anyhow::bail!(
+            "a removal of GER 0x{} is already in flight; wait for its receipt",
+            ::hex::encode(ger_bytes)
+        );
+    }
+    Ok(())
+}
+
+/// Whether a removal of `ger_bytes` is still outstanding: a
+/// `removeGlobalExitRoots` writer job still queued or submitting, or a removal
+/// the writer recorded whose receipt is still pending (it is finalised only
+/// when the bridge consumes the RemoveGerNote). The record survives restarts
+/// and writer-entry eviction.
+async fn ger_removal_outstanding(
+    service: &ServiceState,
+    ger_bytes: &[u8; 32],
+) -> anyhow::Result<bool> {
+    if service
+        .store
+        .pending_ger_removal(ger_bytes)
+        .await?
+        .is_some()
+    {
+        return Ok(true);
+    }
+    let Some(handle) = service.writer_handle.as_ref() else {
+        return Ok(false);
+    };
+    Ok(handle.entries().iter().any(|entry| {
+        entry.kind == crate::writer_worker::WriteJobKind::GerRemove
+            && !entry.state.is_terminal()
+            && matches!(
+                decode_envelope_write_call(&entry.envelope),
+                Ok(crate::writer_worker::DecodedWriteCall::GerRemoval { ger_bytes: g }) if g == *ger_bytes
+            )
+    }))
+}
+
 async fn validate_before_nonce_reservation(
     service: &ServiceState,
     decoded: &crate::writer_worker::DecodedWriteCall,
+    signer: &Address,
 ) -> anyhow::Result<()> {
+    if let crate::writer_worker::DecodedWriteCall::GerRemoval { ger_bytes } = decoded {
+        // L1 lets only the `globalExitRootRemover` role remove a GER; the
+        // shared allow-list also admits aggoracle and claim sponsors.
+        if !service.ger_remover_signers.contains(signer) {
+            ::metrics::counter!("rpc_unauthorized_ger_remover_total").increment(1);
+            anyhow::bail!(
+                "signer {signer:#x} may not remove GERs; configure --ger-remover-signers \
+                 (or GER_REMOVER_SIGNERS)"
+            );
+        }
+        return validate_ger_removal(service, ger_bytes).await;
+    }
     let crate::writer_worker::DecodedWriteCall::Claim { params } = decoded else {
         return Ok(());
     };
@@ -721,9 +1183,9 @@ async fn dispatch_after_reservation(
     }
 
     if let Some(handle) = service.writer_handle.as_ref() {
-        if handle.available_capacity() == 0 {
-            return Err(crate::writer_worker::WriterQueueSaturatedError.into());
-        }
+        handle
+            .check_admission(decoded.kind())
+            .map_err(writer_admission_error)?;
         durably_admit_and_advance_nonce(
             service,
             txn_hash,
@@ -734,15 +1196,8 @@ async fn dispatch_after_reservation(
         )
         .await?;
         let job = decoded.into_job(txn_envelope, signer, txn_hash);
-        match handle.try_enqueue(job) {
-            Ok(()) => Ok(txn_hash),
-            Err(crate::writer_worker::TryEnqueueError::QueueFull) => {
-                Err(crate::writer_worker::WriterQueueSaturatedError.into())
-            }
-            Err(crate::writer_worker::TryEnqueueError::ShutDown) => {
-                anyhow::bail!("writer worker has shut down; retry the same signed transaction")
-            }
-        }
+        handle.try_enqueue(job).map_err(writer_admission_error)?;
+        Ok(txn_hash)
     } else {
         #[cfg(not(test))]
         anyhow::bail!("single writer handle missing from production ServiceState");
@@ -770,6 +1225,10 @@ async fn dispatch_after_reservation(
                     worker_handle_ger_insert(service, ger_bytes, txn_hash, txn_envelope, signer)
                         .await
                 }
+                crate::writer_worker::DecodedWriteCall::GerRemoval { ger_bytes } => {
+                    worker_handle_ger_removal(service, ger_bytes, txn_hash, txn_envelope, signer)
+                        .await
+                }
             };
             if let Err(err) = dispatch {
                 // Once the durable row and nonce CAS commit, the transaction is accepted.
@@ -1043,6 +1502,18 @@ async fn publish_and_record_claim(
         guard.submission_fence(),
     )
     .await;
+    settle_claim_publish(service, txn_hash, claim_result).await
+}
+
+/// Interpret one claim's publish result. A failure after the claim's durable
+/// note handoff is outcome-ambiguous (the note may still land), so the
+/// receipt stays pending for commit/observation reconciliation instead of
+/// surfacing the error.
+async fn settle_claim_publish(
+    service: &ServiceState,
+    txn_hash: TxHash,
+    claim_result: anyhow::Result<claim::PublishClaimTxn>,
+) -> anyhow::Result<()> {
     match claim_result {
         Ok(claim_result) => {
             tracing::info!(
@@ -1114,6 +1585,89 @@ pub(crate) async fn worker_handle_ger_insert(
     .await
 }
 
+/// `removeGlobalExitRoots` dispatcher used by the mandatory writer worker.
+/// Like `worker_handle_ger_insert` it does not advance the nonce. The receipt
+/// stays pending until the projector observes the consumed RemoveGerNote and
+/// emits the removal logs under this hash.
+pub(crate) async fn worker_handle_ger_removal(
+    service: &ServiceState,
+    ger_bytes: [u8; 32],
+    txn_hash: TxHash,
+    txn_envelope: TxEnvelope,
+    signer: Address,
+) -> anyhow::Result<()> {
+    // The GER may have been removed while this job was queued. Failing here
+    // writes the status-0 receipt the contract would have produced.
+    if !crate::applied_state::ger_applied(service, &ger_bytes).await? {
+        anyhow::bail!("execution reverted: GlobalExitRootNotFound()");
+    }
+    // The writer runs one job at a time, so this check and the record below
+    // cannot interleave with another removal's. A re-drive of the same
+    // transaction finds its own record and proceeds.
+    if let Some(other) = service.store.pending_ger_removal(&ger_bytes).await?
+        && other != txn_hash
+    {
+        anyhow::bail!(
+            "a removal of GER 0x{} is already in flight ({other:#x})",
+            ::hex::encode(ger_bytes)
+        );
+    }
+    service
+        .store
+        .record_ger_removal(txn_hash, &ger_bytes)
+        .await?;
+    let result = ger::remove_ger(
+        ger_bytes,
+        &service.miden_client,
+        service.accounts.clone(),
+        &service.store,
+        txn_hash,
+        txn_envelope.clone(),
+        signer,
+    )
+    .await;
+    match result {
+        Ok(note_id) => {
+            let source = if signer == crate::service_admin_ger::admin_removal_signer() {
+                "admin"
+            } else {
+                "rpc"
+            };
+            ::metrics::counter!("ger_removal_submitted_total", "source" => source).increment(1);
+            tracing::info!(
+                %txn_hash,
+                ger = %::hex::encode(ger_bytes),
+                note_id = note_id.as_deref().unwrap_or("-"),
+                "GER removal submitted; receipt pending until the projector observes consumption"
+            );
+            // Same synchronous dedup safety net as the GER-insert path.
+            if service.store.txn_get(txn_hash).await?.is_none() {
+                record_local_pending_tx(service, txn_hash, txn_envelope, signer, None, vec![])
+                    .await?;
+            }
+            Ok(())
+        }
+        Err(err) => {
+            let tx_key = format!("{txn_hash:#x}");
+            if service
+                .store
+                .get_note_handoff_for_tx(&tx_key)
+                .await?
+                .is_some()
+            {
+                tracing::warn!(
+                    %txn_hash,
+                    error = %err,
+                    "GER removal outcome is ambiguous after durable note handoff; leaving receipt pending"
+                );
+                return Ok(());
+            }
+            tracing::error!("remove_ger failed: {err:#?}");
+            Err(err)
+        }
+    }
+}
+
 /// Check whether the recovered signer is permitted to submit transactions.
 ///
 /// `None` = open mode (legacy default). `Some(list)` = explicit allow-list — every
@@ -1179,7 +1733,11 @@ pub async fn service_send_raw_txn(service: ServiceState, input: String) -> anyho
     // Reject unauthorized signers before any store read or per-signer lock
     // allocation. In fail-closed mode this keeps untrusted addresses from
     // growing the lock registry or consuming database capacity.
-    if !service.allow_any_signer && !is_signer_allowed(service.allowed_signers.as_deref(), &signer)
+    if !service.allow_any_signer
+        && !is_signer_allowed(
+            service.policy.current().settings.allowed_signers.as_deref(),
+            &signer,
+        )
     {
         ::metrics::counter!("rpc_unauthorized_signer_total").increment(1);
         anyhow::bail!(
@@ -1289,11 +1847,11 @@ pub async fn service_send_raw_txn(service: ServiceState, input: String) -> anyho
     let decoded = decode_write_call(&txn.input)?;
     // Deterministic and side-effect-free rejection belongs before the signer
     // lock. Stateful checks repeat after reservation to close landing races.
-    validate_before_nonce_reservation(&service, &decoded).await?;
-    if let Some(handle) = service.writer_handle.as_ref()
-        && handle.available_capacity() == 0
-    {
-        return Err(crate::writer_worker::WriterQueueSaturatedError.into());
+    validate_before_nonce_reservation(&service, &decoded, &signer).await?;
+    if let Some(handle) = service.writer_handle.as_ref() {
+        handle
+            .check_admission(decoded.kind())
+            .map_err(writer_admission_error)?;
     }
     #[cfg(not(test))]
     if service.writer_handle.is_none() {
@@ -1340,9 +1898,10 @@ pub async fn service_send_raw_txn(service: ServiceState, input: String) -> anyho
     // The mandatory writer also tolerates bounded future-nonce
     // reordering from concurrent HTTP delivery: if nonce N+1 reaches us before
     // nonce N, release the lock, wait briefly for N to be accepted, then
-    // re-check. This is a small in-process txpool behavior; stale/replay nonces
-    // still fail immediately, and missing gaps still fail after the bound.
-    let future_nonce_wait_max = std::time::Duration::from_secs(30);
+    // re-check. A gap still open after the wait parks the envelope in the
+    // future-nonce holding area (`-32007`), which resubmits it once N lands;
+    // stale/replay nonces still fail immediately.
+    let future_nonce_wait_max = FUTURE_NONCE_REORDER_WAIT;
     let future_nonce_poll = std::time::Duration::from_millis(50);
     let future_nonce_wait_started = tokio::time::Instant::now();
     let mut logged_future_nonce_wait = false;
@@ -1433,12 +1992,37 @@ pub async fn service_send_raw_txn(service: ServiceState, input: String) -> anyho
             continue;
         }
 
+        if service.writer_handle.is_some() && tx_nonce > expected_nonce {
+            hold_future_nonce(&service, signer, tx_nonce, expected_nonce, txn_hash, &input)?;
+        }
+
         ::metrics::counter!("rpc_nonce_mismatch_total").increment(1);
         anyhow::bail!(
             "nonce mismatch for {signer_str}: tx.nonce = {tx_nonce}, expected {expected_nonce}; this guards against replay and out-of-order submission (R4)"
         );
     };
 
+    // Per-signer quota: one allowed signer must not be able to fill the whole
+    // writer queue. A durable intent being resumed already owns its nonce and
+    // is exempt, so recovery is never refused.
+    if !known_durable_intent
+        && service.signer_inflight_quota > 0
+        && let Some(handle) = service.writer_handle.as_ref()
+    {
+        let inflight = handle.count_non_terminal_for_signer(&signer);
+        if inflight >= service.signer_inflight_quota {
+            ::metrics::counter!("rpc_signer_quota_rejections_total", "kind" => "inflight")
+                .increment(1);
+            return Err(SignerQuotaExceededError {
+                signer,
+                what: "in-flight",
+                count: inflight,
+                quota: service.signer_inflight_quota,
+            }
+            .into());
+        }
+    }
+
     // ── #55 BLOCKER 1 — atomic (signer, nonce) reservation ──────────────
     //
     // Reserve the (signer, nonce) slot ATOMICALLY, BEFORE any queue/dispatch/receipt
@@ -1526,6 +2110,10 @@ pub async fn service_send_raw_txn(service: ServiceState, input: String) -> anyho
     )
     .await;
     reservation_heartbeat.abort();
+    if admission.is_ok() {
+        // The signer's nonce advanced: a held envelope may now be next.
+        service.future_nonces.notify();
+    }
     if let Err(release_err) = service
         .store
         .release_reservation(
@@ -1555,6 +2143,15 @@ mod tests {
     use alloy::primitives::{FixedBytes, Signature, TxHash, U256};
     use alloy_core::sol_types::SolCall;
 
+    /// A fixed request policy with `signers` as the allow-list.
+    fn signer_policy(signers: Option<Vec<Address>>) -> crate::runtime_policy::PolicyHandle {
+        crate::runtime_policy::PolicyHandle::new(crate::runtime_policy::PolicySettings {
+            allowed_signers: signers,
+            ..Default::default()
+        })
+        .unwrap()
+    }
+
     /// Encode a legacy transaction with the given calldata into a hex string
     /// suitable for `service_send_raw_txn`.
     ///
@@ -1644,6 +2241,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn remove_global_exit_roots_decodes_a_single_ger() {
+        let calldata = removeGlobalExitRootsCall {
+            gersToRemove: vec![FixedBytes::from([0x11; 32])],
+        }
+        .abi_encode();
+        assert!(matches!(
+            decode_write_call(&calldata.into()).unwrap(),
+            crate::writer_worker::DecodedWriteCall::GerRemoval { ger_bytes } if ger_bytes == [0x11; 32]
+        ));
+
+        for gers in [vec![], vec![FixedBytes::from([0x11; 32]); 2]] {
+            let calldata = removeGlobalExitRootsCall { gersToRemove: gers }.abi_encode();
+            let err = decode_write_call(&calldata.into()).unwrap_err();
+            assert!(err.to_string().contains("exactly one GER"), "{err:#}");
+        }
+    }
+
     // ── Happy-path tests ────────────────────────────────────────────
 
     #[tokio::test]
@@ -2694,6 +3309,115 @@ mod tests {
         let _ = shutdown.send(());
     }
 
+    /// A gap still open after the reorder wait parks the envelope (`-32007`,
+    /// idempotent for a re-send) instead of failing it; once the missing nonce
+    /// is accepted the release loop admits the held one.
+    #[tokio::test]
+    async fn future_nonce_is_held_then_released_when_the_gap_fills() {
+        let mut service = create_test_service();
+        let store = service.store.clone();
+        let (handle, shutdown) = crate::writer_worker::WriterWorker::spawn(
+            service.clone(),
+            64,
+            std::time::Duration::from_secs(60),
+        );
+        service.writer_handle = Some(std::sync::Arc::new(handle));
+
+        let key = alloy::signers::local::PrivateKeySigner::random();
+        let signer_str = format!("{:#x}", key.address());
+        let ger = |b: u8| {
+            insertGlobalExitRootCall {
+                root: FixedBytes::from([b; 32]),
+            }
+            .abi_encode()
+        };
+        let (input_1, hash_1) = encode_tx_signed_with_nonce(&key, ger(0xC1), 1);
+        for _ in 0..2 {
+            let err = service_send_raw_txn(service.clone(), input_1.clone())
+                .await
+                .expect_err("nonce 1 before nonce 0 is held, not admitted");
+            let held = err
+                .downcast_ref::<crate::future_nonce_pool::NonceTooHighHeldError>()
+                .unwrap_or_else(|| panic!("expected the held error, got {err:#}"));
+            assert_eq!((held.tx_hash, held.nonce, held.expected), (hash_1, 1, 0));
+        }
+        assert_eq!(service.future_nonces.len(), 1);
+
+        // Too wide a gap is still a plain R4 mismatch.
+        let (far, _) = encode_tx_signed_with_nonce(&key, ger(0xC2), 100);
+        let err = service_send_raw_txn(service.clone(), far)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("nonce mismatch"), "{err:#}");
+
+        let release = spawn_future_nonce_release(service.clone());
+        let (input_0, _) = encode_tx_signed_with_nonce(&key, ger(0xC0), 0);
+        service_send_raw_txn(service.clone(), input_0)
+            .await
+            .expect("nonce 0 admits");
+        tokio::time::timeout(std::time::Duration::from_secs(5), async {
+            while store.nonce_get(&signer_str).await.unwrap() < 2 {
+                tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+            }
+        })
+        .await
+        .expect("the held nonce-1 envelope must be admitted once nonce 0 lands");
+        assert!(service.future_nonces.is_empty());
+        assert!(store.txn_get(hash_1).await.unwrap().is_some());
+
+        release.abort();
+        let _ = shutdown.send(());
+    }
+
+    /// One signer cannot take more than its quota of writer jobs; others are
+    /// unaffected, and the refusal is the distinct quota error.
+    #[tokio::test]
+    async fn signer_inflight_quota_refuses_only_the_busy_signer() {
+        let mut service = create_test_service();
+        let (handle, shutdown) = crate::writer_worker::WriterWorker::spawn(
+            service.clone(),
+            64,
+            std::time::Duration::from_secs(60),
+        );
+        handle.pause();
+        service.writer_handle = Some(std::sync::Arc::new(handle));
+        service.signer_inflight_quota = 1;
+
+        let ger = |b: u8| {
+            insertGlobalExitRootCall {
+                root: FixedBytes::from([b; 32]),
+            }
+            .abi_encode()
+        };
+        let busy = alloy::signers::local::PrivateKeySigner::random();
+        let other = alloy::signers::local::PrivateKeySigner::random();
+        let (first, _) = encode_tx_signed_with_nonce(&busy, ger(0xD0), 0);
+        service_send_raw_txn(service.clone(), first).await.unwrap();
+        let (second, _) = encode_tx_signed_with_nonce(&busy, ger(0xD1), 1);
+        let err = service_send_raw_txn(service.clone(), second)
+            .await
+            .unwrap_err();
+        let quota = err
+            .downcast_ref::<SignerQuotaExceededError>()
+            .unwrap_or_else(|| panic!("expected the quota error, got {err:#}"));
+        assert_eq!((quota.count, quota.quota), (1, 1));
+        assert_eq!(
+            service
+                .store
+                .nonce_get(&format!("{:#x}", busy.address()))
+                .await
+                .unwrap(),
+            1,
+            "a refused submission must not consume its nonce"
+        );
+
+        let (unrelated, _) = encode_tx_signed_with_nonce(&other, ger(0xD2), 0);
+        service_send_raw_txn(service.clone(), unrelated)
+            .await
+            .expect("another signer keeps its own quota");
+        let _ = shutdown.send(());
+    }
+
     /// Self-review R2 + audit C2 — repro+regression. Pre-fix, every recovered
     /// signer was accepted unconditionally; the allow-list then additionally
     /// failed OPEN (None => true). Post-C2 the predicate must:
@@ -2824,7 +3548,7 @@ mod tests {
         let foreign: Address = "0xdeAddeaDdEadDeaDDEaDDeadDEADDeaDDEAdDEaD"
             .parse()
             .unwrap();
-        service.allowed_signers = Some(vec![foreign]);
+        service.policy = signer_policy(Some(vec![foreign]));
 
         let calldata = insertGlobalExitRootCall {
             root: FixedBytes::from([0xAAu8; 32]),
@@ -2845,6 +3569,39 @@ mod tests {
         );
     }
 
+    /// `removeGlobalExitRoots` from a signer on the allow-list but not on
+    /// `--ger-remover-signers` is refused before its nonce is reserved.
+    #[tokio::test]
+    async fn ger_removal_needs_a_remover_signer() {
+        let mut service = create_test_service();
+        service.allow_any_signer = false;
+        let calldata = removeGlobalExitRootsCall {
+            gersToRemove: vec![FixedBytes::from([0x11u8; 32])],
+        }
+        .abi_encode();
+        let (input_hex, signer) = encode_legacy_tx(calldata);
+        service.policy = signer_policy(Some(vec![signer]));
+        let foreign: Address = "0xdeAddeaDdEadDeaDDEaDDeadDEADDeaDDEAdDEaD"
+            .parse()
+            .unwrap();
+        service.ger_remover_signers = vec![foreign];
+
+        let err = service_send_raw_txn(service.clone(), input_hex.clone())
+            .await
+            .expect_err("an allowed non-remover signer must be rejected");
+        assert!(err.to_string().contains("may not remove GERs"), "{err}");
+        let signer_str = format!("{signer:#x}");
+        assert_eq!(service.store.nonce_get(&signer_str).await.unwrap(), 0);
+
+        // A remover gets past the role check to the bridge-state check.
+        service.ger_remover_signers = vec![signer];
+        let err = service_send_raw_txn(service, input_hex).await.unwrap_err();
+        assert!(
+            err.to_string().contains("GlobalExitRootNotFound()"),
+            "{err}"
+        );
+    }
+
     /// Audit C2 — the fail-closed default. With NO allow-list configured AND
     /// `allow_any_signer = false` (the production default), a well-formed signed
     /// tx MUST be rejected. Pre-fix, `None` meant open and this tx would be
@@ -2854,7 +3611,7 @@ mod tests {
         let mut service = create_test_service();
         // Production default: no allow-list, no open-mode opt-in.
         service.allow_any_signer = false;
-        service.allowed_signers = None;
+        service.policy = signer_policy(None);
 
         let calldata = insertGlobalExitRootCall {
             root: FixedBytes::from([0xBBu8; 32]),
@@ -3045,6 +3802,220 @@ mod tests {
             .count()
     }
 
+    /// A claim batch settles every claim on its own: one that fails
+    /// classification or short-circuits never joins the shared Miden
+    /// transaction, and when that transaction fails the remaining claims are
+    /// re-driven one by one and release their locks on failure.
+    #[tokio::test]
+    async fn claim_batch_isolates_invalid_claims_and_falls_back_per_claim() {
+        use alloy::consensus::SignableTransaction;
+        use alloy::signers::SignerSync;
+        let service = create_test_service();
+        seed_zero_ger(&service.store).await;
+        let key = alloy::signers::local::PrivateKeySigner::random();
+        let queued = |nonce: u64, mut params: claimAssetCall| {
+            params.globalIndex = U256::from(100 + nonce);
+            let txn = TxLegacy {
+                nonce,
+                input: params.abi_encode().into(),
+                chain_id: Some(1),
+                ..Default::default()
+            };
+            let signature = key.sign_hash_sync(&txn.signature_hash()).unwrap();
+            let envelope: TxEnvelope = txn.into_signed(signature).into();
+            let txn_hash = match &envelope {
+                TxEnvelope::Legacy(s) => *s.hash(),
+                _ => unreachable!("constructed as legacy"),
+            };
+            QueuedClaim {
+                params,
+                txn_hash,
+                txn_envelope: envelope,
+                signer: key.address(),
+            }
+        };
+        let valid = claimAssetCall::abi_decode(&claim_calldata(
+            U256::ZERO,
+            resolvable_dest(),
+            U256::from(1_000_000u64),
+        ))
+        .unwrap();
+        let mut wrong_network = valid.clone();
+        wrong_network.destinationNetwork = 7;
+        let mut zero_amount = valid.clone();
+        zero_amount.amount = U256::ZERO;
+        let claims = vec![
+            queued(0, valid.clone()),
+            queued(1, wrong_network),
+            queued(2, zero_amount),
+            queued(3, valid),
+        ];
+        let zero_hash = claims[2].txn_hash;
+
+        let results = worker_handle_claim_batch(&service, claims).await;
+
+        assert_eq!(results.len(), 4);
+        assert!(results[1].is_err(), "wrong network fails alone");
+        assert!(results[2].is_ok(), "zero amount is skipped as success");
+        let (receipt, _) = service.store.txn_receipt(zero_hash).await.unwrap().unwrap();
+        assert!(receipt.is_ok());
+        // The two publishable claims shared one Miden call; the stub never
+        // runs the closure, so each was then re-driven alone and failed.
+        assert!(results[0].is_err() && results[3].is_err());
+        assert_eq!(service.miden_client.test_call_count(), 3);
+        for gi in [100u64, 103] {
+            assert!(
+                !service.store.is_claimed(&U256::from(gi)).await.unwrap(),
+                "a failed claim releases its lock"
+            );
+        }
+    }
+
+    /// Two claims published together get a note, a note handoff and a
+    /// pending receipt each. Drives every batch stage around the Miden
+    /// transaction with a real (offline) client; the execute/prove/submit
+    /// step itself needs a node and is covered by the e2e suite.
+    #[tokio::test]
+    async fn claim_batch_records_a_handoff_and_receipt_per_claim() {
+        use crate::store::NoteHandoffState;
+        use alloy::consensus::SignableTransaction;
+        use alloy::signers::SignerSync;
+        let service = create_test_service();
+        seed_zero_ger(&service.store).await;
+        crate::test_helpers::seed_test_faucets(&*service.store).await;
+        let key = alloy::signers::local::PrivateKeySigner::random();
+        let mut claims = Vec::new();
+        for nonce in 0..2u64 {
+            let params = claimAssetCall::abi_decode(&claim_calldata(
+                U256::from(200 + nonce),
+                resolvable_dest(),
+                U256::from(1_000_000u64 * (nonce + 1)),
+            ))
+            .unwrap();
+            let txn = TxLegacy {
+                nonce,
+                input: params.abi_encode().into(),
+                chain_id: Some(1),
+                ..Default::default()
+            };
+            let signature = key.sign_hash_sync(&txn.signature_hash()).unwrap();
+            let envelope: TxEnvelope = txn.into_signed(signature).into();
+            claims.push(QueuedClaim {
+                txn_hash: *envelope.tx_hash(),
+                params,
+                txn_envelope: envelope,
+                signer: key.address(),
+            });
+        }
+
+        let mut guards = Vec::new();
+        let mut to_publish = Vec::new();
+        for claim in &claims {
+            let dispatch = begin_claim_dispatch(
+                &service,
+                &claim.params,
+                claim.txn_hash,
+                &claim.txn_envelope,
+                claim.signer,
+            )
+            .await
+            .unwrap();
+            let ClaimDispatch::Publish(guard) = dispatch else {
+                panic!("a fresh resolvable claim must be published");
+            };
+            to_publish.push(claim::ClaimToPublish {
+                params: claim.params.clone(),
+                submission_fence: guard.submission_fence(),
+            });
+            guards.push(guard);
+        }
+
+        let mut client = crate::test_helpers::offline_miden_client_lib().await;
+        let built = claim::build_claim_notes(
+            to_publish,
+            &mut client,
+            &service.accounts.0,
+            &*service.store,
+            false,
+        )
+        .await;
+        assert!(built.results.iter().all(Option::is_none));
+        assert_eq!(built.notes.len(), 2);
+        assert_eq!(built.included.len(), 2);
+        assert_ne!(
+            built.included[0].note_commitment,
+            built.included[1].note_commitment
+        );
+
+        claim::prepare_claim_handoffs(&built.included, 50)
+            .await
+            .unwrap();
+        for (claim, included) in claims.iter().zip(&built.included) {
+            let handoff = service
+                .store
+                .get_note_handoff_for_tx(&format!("{:#x}", claim.txn_hash))
+                .await
+                .unwrap()
+                .expect("each claim has its own handoff");
+            assert_eq!(handoff.note_commitment, included.note_commitment);
+            assert_eq!(handoff.note_id.as_deref(), Some(included.note_id.as_str()));
+            assert_eq!(handoff.state, NoteHandoffState::Prepared);
+        }
+
+        let txn_id = miden_protocol::transaction::TransactionId::new(
+            miden_protocol::Word::default(),
+            miden_protocol::Word::default(),
+            miden_protocol::Word::default(),
+            miden_protocol::Word::default(),
+        );
+        let commitments: Vec<String> = built
+            .included
+            .iter()
+            .map(|claim| claim.note_commitment.clone())
+            .collect();
+        let published =
+            claim::settle_committed_claims(built.results, built.included, txn_id, 60, None).await;
+        let receipts = claims
+            .iter()
+            .map(|claim| (claim.txn_hash, claim.txn_envelope.clone(), claim.signer))
+            .collect();
+        let recorded = claim::record_batch_receipts(&*service.store, published, receipts).await;
+
+        assert_eq!(recorded.len(), 2);
+        for ((claim, result), commitment) in claims.iter().zip(recorded).zip(commitments) {
+            let published = result.expect("each claim settles on its own");
+            assert_eq!(published.note_commitment, commitment);
+            let handoff = service
+                .store
+                .get_note_handoff_for_tx(&format!("{:#x}", claim.txn_hash))
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(handoff.state, NoteHandoffState::Submitted);
+            assert!(
+                service
+                    .store
+                    .txn_get(claim.txn_hash)
+                    .await
+                    .unwrap()
+                    .is_some(),
+                "each claim has its own transaction row"
+            );
+            assert!(
+                service
+                    .store
+                    .txn_receipt(claim.txn_hash)
+                    .await
+                    .unwrap()
+                    .is_none(),
+                "the receipt stays pending until the projector sees the note consumed"
+            );
+        }
+        for guard in guards {
+            guard.commit();
+        }
+    }
+
     /// MANUAL USER CLAIM happy path — an ordinary, explicitly allow-listed
     /// USER key (NOT open mode, NOT any sponsor identity) submits a valid
     /// `claimAsset` and is accepted end-to-end: ClaimEvent emitted, receipt
@@ -3068,7 +4039,7 @@ mod tests {
         // A real allow-list containing ONLY the user — the manual claim must
         // pass on allow-list membership alone.
         service.allow_any_signer = false;
-        service.allowed_signers = Some(vec![user_addr]);
+        service.policy = signer_policy(Some(vec![user_addr]));
         let store = service.store.clone();
 
         let gi = U256::from(0x1001u64);
@@ -3292,7 +4263,7 @@ mod tests {
         let foreign: Address = "0xdeAddeaDdEadDeaDDEaDDeadDEADDeaDDEAdDEaD"
             .parse()
             .unwrap();
-        service.allowed_signers = Some(vec![foreign]);
+        service.policy = signer_policy(Some(vec![foreign]));
         let store = service.store.clone();
         let miden = service.miden_client.clone();
         seed_zero_ger(&store).await;
@@ -3332,7 +4303,7 @@ mod tests {
         // the same valid claimAsset is rejected identically.
         let mut service = create_test_service();
         service.allow_any_signer = false;
-        service.allowed_signers = None;
+        service.policy = signer_policy(None);
         let store = service.store.clone();
         seed_zero_ger(&store).await;
 
@@ -3360,7 +4331,7 @@ mod tests {
 
         let mut service = create_test_service();
         service.allow_any_signer = false;
-        service.allowed_signers = Some(vec![addr_c]);
+        service.policy = signer_policy(Some(vec![addr_c]));
         let store = service.store.clone();
         let miden = service.miden_client.clone();
         seed_zero_ger(&store).await;
@@ -3402,7 +4373,7 @@ mod tests {
         // retry leg 1's SAME tx, not submit a different claim at the same nonce).
         let mut service2 = create_test_service();
         service2.allow_any_signer = false;
-        service2.allowed_signers = Some(vec![addr_c]);
+        service2.policy = signer_policy(Some(vec![addr_c]));
         let store = service2.store.clone();
         seed_zero_ger(&store).await;
         let gi_swallow = U256::from(0x5006u64);
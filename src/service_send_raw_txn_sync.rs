@@ -0,0 +1,188 @@
+//! `eth_sendRawTransactionSync` (EIP-7966).
+//!
+//! Admission is exactly `eth_sendRawTransaction`'s ([`service_send_raw_txn`]):
+//! the tx is validated, durably recorded and handed to the writer queue. Only
+//! then does the request wait, on the HTTP task, for the receipt that
+//! `eth_getTransactionReceipt` would serve. The wait holds nothing of the
+//! writer's: the job occupies its lane slot only until the worker dequeues it,
+//! as with the async method. Wake-ups come from the synthetic tip notifier with
+//! a short poll as a backstop, since a receipt can be committed without a tip
+//! advance.
+//!
+//! A timeout is not a failure of the transaction: the caller gets
+//! [`SyncReceiptTimeout`] carrying the hash and keeps polling
+//! `eth_getTransactionReceipt` as it would after `eth_sendRawTransaction`.
+
+use crate::service_get_txn_receipt::service_get_txn_receipt;
+use crate::service_send_raw_txn::service_send_raw_txn;
+use crate::service_state::ServiceState;
+use alloy::primitives::TxHash;
+use alloy_rpc_types_eth::{Log, ReceiptEnvelope, TransactionReceipt};
+use std::time::Duration;
+use tokio::sync::broadcast::error::RecvError;
+use tokio::time::Instant;
+
+/// Wait applied when the caller passes no timeout.
+pub const DEFAULT_SYNC_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Upper bound on a caller-supplied timeout; longer requests are clamped.
+pub const MAX_SYNC_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// Receipt re-check interval between tip notifications.
+const RECEIPT_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// JSON-RPC error code EIP-7966 assigns to "admitted but not processed in
+/// time"; the error's `data` is the tx hash.
+pub const SYNC_TIMEOUT_ERROR_CODE: i32 = 4;
+
+/// The tx was admitted but no receipt was linked before the deadline.
+#[derive(Debug, thiserror::Error)]
+#[error(
+    "transaction {hash:#x} was admitted but no receipt was available within {}ms",
+    .timeout.as_millis()
+)]
+pub struct SyncReceiptTimeout {
+    pub hash: TxHash,
+    pub timeout: Duration,
+}
+
+/// Admit `input` like `eth_sendRawTransaction`, then wait up to `timeout_ms`
+/// (default [`DEFAULT_SYNC_TIMEOUT`], clamped to [`MAX_SYNC_TIMEOUT`]) for its
+/// receipt. The receipt may be `status:0x0`.
+pub async fn service_send_raw_txn_sync(
+    service: ServiceState,
+    input: String,
+    timeout_ms: Option<u64>,
+) -> anyhow::Result<TransactionReceipt<ReceiptEnvelope<Log>>> {
+    let timeout = timeout_ms
+        .map(Duration::from_millis)
+        .unwrap_or(DEFAULT_SYNC_TIMEOUT)
+        .min(MAX_SYNC_TIMEOUT);
+    let hash = service_send_raw_txn(service.clone(), input).await?;
+    wait_for_receipt(&service, hash, timeout).await
+}
+
+/// `[rawTx]` or `[rawTx, timeoutMs]`; the timeout is a JSON number or a hex
+/// quantity.
+pub fn parse_sync_params(params: &[serde_json::Value]) -> Result<(String, Option<u64>), String> {
+    let (raw, timeout) = match params {
+        [raw] => (raw, None),
+        [raw, timeout] => (raw, Some(timeout)),
+        _ => return Err("expected [rawTransaction, timeoutMs?]".to_string()),
+    };
+    let raw = raw
+        .as_str()
+        .ok_or_else(|| "rawTransaction must be a hex string".to_string())?
+        .to_string();
+    let timeout = match timeout {
+        None | Some(serde_json::Value::Null) => None,
+        Some(serde_json::Value::Number(n)) => Some(
+            n.as_u64()
+                .ok_or_else(|| "timeout must be a non-negative integer".to_string())?,
+        ),
+        Some(serde_json::Value::String(s)) => Some(
+            s.strip_prefix("0x")
+                .and_then(|h| u64::from_str_radix(h, 16).ok())
+                .ok_or_else(|| "timeout must be a hex quantity".to_string())?,
+        ),
+        Some(_) => return Err("timeout must be a number of milliseconds".to_string()),
+    };
+    Ok((raw, timeout))
+}
+
+async fn wait_for_receipt(
+    service: &ServiceState,
+    hash: TxHash,
+    timeout: Duration,
+) -> anyhow::Result<TransactionReceipt<ReceiptEnvelope<Log>>> {
+    let started = Instant::now();
+    let deadline = started + timeout;
+    let mut tips = service.tip_notifier.subscribe();
+    let mut tips_closed = false;
+    loop {
+        if let Some(receipt) =
+            service_get_txn_receipt(service.clone(), format!("{hash:#x}")).await?
+        {
+            ::metrics::histogram!("rpc_send_raw_txn_sync_wait_seconds", "outcome" => "receipt")
+                .record(started.elapsed().as_secs_f64());
+            return Ok(receipt);
+        }
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            ::metrics::histogram!("rpc_send_raw_txn_sync_wait_seconds", "outcome" => "timeout")
+                .record(started.elapsed().as_secs_f64());
+            return Err(SyncReceiptTimeout { hash, timeout }.into());
+        }
+        let poll = tokio::time::sleep(remaining.min(RECEIPT_POLL_INTERVAL));
+        tokio::select! {
+            tip = tips.recv(), if !tips_closed => {
+                if matches!(tip, Err(RecvError::Closed)) {
+                    tips_closed = true;
+                }
+            }
+            _ = poll => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::begin_test_txn;
+
+    /// The wait ends on the tip advance that follows the receipt commit, and
+    /// a pending tx that never gets one times out carrying its hash.
+    #[tokio::test]
+    async fn waits_for_the_receipt_or_times_out_with_the_hash() {
+        let service = crate::test_helpers::create_test_service();
+        let hash = TxHash::from([7u8; 32]);
+        begin_test_txn(&service, hash).await;
+
+        let committer = service.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            let block_hash = committer.block_state.get_block_hash(9);
+            committer
+                .store
+                .txn_commit(hash, Ok(()), 9, block_hash)
+                .await
+                .unwrap();
+            committer.tip_notifier.notify(9);
+        });
+        let receipt = wait_for_receipt(&service, hash, Duration::from_secs(5))
+            .await
+            .unwrap();
+        assert_eq!(receipt.transaction_hash, hash);
+        assert_eq!(receipt.block_number, Some(9));
+
+        let pending = TxHash::from([8u8; 32]);
+        begin_test_txn(&service, pending).await;
+        let err = wait_for_receipt(&service, pending, Duration::from_millis(100))
+            .await
+            .unwrap_err();
+        let timeout = err.downcast_ref::<SyncReceiptTimeout>().expect("timeout");
+        assert_eq!(timeout.hash, pending);
+    }
+
+    #[test]
+    fn timeout_param_is_optional_number_or_quantity() {
+        use serde_json::json;
+        assert_eq!(
+            parse_sync_params(&[json!("0x01")]).unwrap(),
+            ("0x01".to_string(), None)
+        );
+        assert_eq!(
+            parse_sync_params(&[json!("0x01"), json!(1500)]).unwrap().1,
+            Some(1500)
+        );
+        assert_eq!(
+            parse_sync_params(&[json!("0x01"), json!("0x5dc")])
+                .unwrap()
+                .1,
+            Some(1500)
+        );
+        assert!(parse_sync_params(&[]).is_err());
+        assert!(parse_sync_params(&[json!("0x01"), json!(-1)]).is_err());
+        assert!(parse_sync_params(&[json!(1)]).is_err());
+    }
+}
@@ -76,29 +76,33 @@ pub struct ServiceState {
     pub l1_rpc_url: Option<String>,
     /// L1 GER contract address
     pub ger_l1_address: Option<String>,
+    /// Per-origin-network RPC endpoints for ERC-20 metadata recovery, the same
+    /// map the projector uses. Read by `admin_reprojectQuarantined`.
+    pub network_rpcs: crate::metadata_recovery::NetworkRpcMap,
     /// Miden client store directory (used by recovery commands; the long-lived
     /// `MidenClient` owns the sqlite handle so production submission paths do
     /// not need to re-derive this).
     pub miden_store_dir: PathBuf,
-    /// CORS-allowed origins (R11). `None` = no cross-origin requests permitted (the
-    /// safe default in production); `Some(list)` = explicit allowlist; the special
-    /// single-entry `vec!["*"]` is reserved for dev-only wildcards.
-    pub cors_allowed_origins: Option<Vec<String>>,
-    /// Admin API key (R1). `None` = `admin_*` JSON-RPC methods are disabled
-    /// entirely (the safe production default — fail closed). `Some(token)` =
-    /// admin requests must carry `Authorization: Bearer <token>`.
-    pub admin_api_key: Option<String>,
-    /// Allow-list of EVM signer addresses (R2). Audit C2 — `None` now means
-    /// CLOSED (fail-closed: no signer is accepted). `Some(list)` = inbound txs
-    /// must be signed by an address in the list. To explicitly enable legacy
-    /// open mode, set `allow_any_signer = true` via `--insecure-allow-any-signer`
-    /// (refused by `--require-hardening`).
-    pub allowed_signers: Option<Vec<alloy::primitives::Address>>,
+    /// Signer allow-list (R2), CORS origins (R11) and per-IP rate limit (R13),
+    /// swappable at runtime by SIGHUP or `admin_reloadPolicy` (see
+    /// [`crate::runtime_policy`]). Audit C2 — an unset signer allow-list means
+    /// CLOSED (no signer is accepted) unless `allow_any_signer` is set.
+    pub policy: crate::runtime_policy::PolicyHandle,
+    /// Admin keys (R1). Empty = `admin_*` JSON-RPC methods are disabled
+    /// entirely (the safe production default — fail closed). Otherwise admin
+    /// requests must carry `Authorization: Bearer <token>` for a key scoped to
+    /// the method (see [`crate::admin_auth`]).
+    pub admin_keys: Arc<crate::admin_auth::AdminKeyring>,
     /// Audit C2 — explicit opt-in for legacy "accept any signer" mode. When
     /// true, `eth_sendRawTransaction` accepts any well-formed signer regardless
-    /// of `allowed_signers`. ONLY safe behind a loopback bind / network
+    /// of the policy's `allowed_signers`. ONLY safe behind a loopback bind / network
     /// boundary. Refused by `--require-hardening`.
     pub allow_any_signer: bool,
+    /// Signers allowed to submit `removeGlobalExitRoots` (`--ger-remover-signers`),
+    /// the counterpart of L1's `globalExitRootRemover` role. Checked on top of
+    /// the allow-list, and not waived by `allow_any_signer`. Empty = no
+    /// signed removal is accepted.
+    pub ger_remover_signers: Vec<alloy::primitives::Address>,
     /// Audit H6 — refuse to inject a GER whose `(mainnet, rollup)`
     /// decomposition was NOT corroborated by the independent L1 InfoTree
     /// indexer. Defends against a compromised aggoracle key injecting a forged
@@ -115,10 +119,26 @@ pub struct ServiceState {
     /// nonce-check critical section so two concurrent same-nonce txs from one
     /// signer cannot both pass the equality check before either increments.
     pub per_signer_locks: PerSignerLocks,
-    /// Per-IP rate limit (R13) — sustained rate (per second).
-    pub rate_limit_per_second: u64,
-    /// Per-IP rate limit burst (R13).
-    pub rate_limit_burst: u32,
+    /// Maximum entries accepted in one JSON-RPC batch; larger batches are
+    /// rejected whole with `-32600` before any entry runs.
+    pub rpc_max_batch_size: usize,
+    /// Per-entry rate-limit check for batches (R13), backed by the same keyed
+    /// limiter as the HTTP `GovernorLayer`. Installed by `service::build_app`;
+    /// `None` only for states that never pass through the router.
+    pub rpc_entry_limiter: Option<crate::service::RpcEntryLimiter>,
+    /// Synthetic tip fan-out for WebSocket `eth_subscribe` sessions. The
+    /// `SyntheticProjector` holds a clone and fires it after each tip write;
+    /// `main.rs` installs the shared instance.
+    pub tip_notifier: crate::subscriptions::TipNotifier,
+    /// How far the `safe` / `finalized` block tags trail the synthetic tip.
+    pub finality: crate::finality::FinalityLags,
+    /// Installed `eth_newFilter` / `eth_newBlockFilter` filters. In memory
+    /// only; bounded per client and expired after inactivity.
+    pub filters: Arc<crate::service_filters::FilterRegistry>,
+    /// Miden vault / faucet-issuance reads behind `eth_getBalance` and the
+    /// ERC-20 `eth_call` shim. Unconfigured until `main` points it at the
+    /// node's account RPC.
+    pub token_source: Arc<dyn crate::token_balances::TokenBalanceSource>,
     /// Reject the address-mapper zero-padding fallback (C5). When `true`,
     /// claims targeting an EVM address with no explicit store mapping are
     /// rejected immediately instead of falling through to the structural
@@ -139,6 +159,12 @@ pub struct ServiceState {
     /// unit tests can construct a state without starting a background runtime.
     /// The `Arc` shares the bounded channel and in-flight map across requests.
     pub writer_handle: Option<Arc<crate::writer_worker::WriterWorkerHandle>>,
+    /// Most non-terminal writer jobs one signer may have; further submissions
+    /// get `-32006`. 0 disables the cap.
+    pub signer_inflight_quota: usize,
+    /// Future-nonce envelopes waiting for their gap to fill. Drained by
+    /// `service_send_raw_txn::spawn_future_nonce_release`.
+    pub future_nonces: Arc<crate::future_nonce_pool::FutureNoncePool>,
 }
 
 const fn assert_sync<T: Send + Sync>() {}
@@ -173,20 +199,35 @@ impl ServiceState {
             block_monitor,
             l1_rpc_url: None,
             ger_l1_address: None,
+            network_rpcs: Default::default(),
             miden_store_dir: PathBuf::new(),
-            cors_allowed_origins: None,
-            admin_api_key: None,
-            allowed_signers: None,
+            policy: crate::runtime_policy::PolicyHandle::new(Default::default())
+                .expect("the default request policy is valid"),
+            admin_keys: Arc::new(crate::admin_auth::AdminKeyring::default()),
             allow_any_signer: false,
+            ger_remover_signers: Vec::new(),
             reject_unverified_ger: false,
             l1_evidence_tag: crate::ger::EvidenceTag::default(),
             per_signer_locks: PerSignerLocks::new(),
-            rate_limit_per_second: crate::service::DEFAULT_RATE_LIMIT_PER_SECOND,
-            rate_limit_burst: crate::service::DEFAULT_RATE_LIMIT_BURST,
+            rpc_max_batch_size: crate::service::DEFAULT_RPC_MAX_BATCH_SIZE,
+            rpc_entry_limiter: None,
+            tip_notifier: crate::subscriptions::TipNotifier::new(),
+            finality: crate::finality::FinalityLags::default(),
+            filters: Arc::new(crate::service_filters::FilterRegistry::new()),
+            token_source: Arc::new(crate::token_balances::UnconfiguredTokenSource),
             reject_zero_padding_addresses: false,
             expected_mints,
             miden_api_key: None,
             writer_handle: None,
+            signer_inflight_quota: crate::future_nonce_pool::DEFAULT_SIGNER_INFLIGHT_QUOTA,
+            future_nonces: Arc::new(crate::future_nonce_pool::FutureNoncePool::default()),
         }
     }
+
+    /// `latest` / `safe` / `finalized` from one read of the synthetic tip.
+    /// The frontier gauges are published by the projector when the tip moves.
+    pub async fn frontiers(&self) -> anyhow::Result<crate::finality::Frontiers> {
+        let latest = self.store.get_latest_block_number().await?;
+        Ok(self.finality.frontiers(latest))
+    }
 }
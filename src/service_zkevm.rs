@@ -1,5 +1,7 @@
 use crate::service_helpers::store_error;
 use crate::service_state::ServiceState;
+use crate::store::L1InfoTreeLeaf;
+use axum_jrpc::error::{JsonRpcError, JsonRpcErrorReason};
 use axum_jrpc::{JrpcResult, JsonRpcExtractor, JsonRpcResponse};
 
 pub(crate) async fn service_zkevm_get_latest_ger(
@@ -61,6 +63,102 @@ pub(crate) async fn service_zkevm_get_exit_roots_by_ger(
     }
 }
 
+/// JSON view of a reconstructed L1 info tree leaf. `verified` is true once a
+/// root covering the leaf matched the GER manager's `getRoot()`.
+fn l1_info_tree_leaf_json(leaf: &L1InfoTreeLeaf, verified_leaf_count: u32) -> serde_json::Value {
+    serde_json::json!({
+        "leafIndex": format!("0x{:x}", leaf.leaf_index),
+        "globalExitRoot": format!("0x{}", hex::encode(leaf.ger)),
+        "previousBlockHash": format!("0x{}", hex::encode(leaf.previous_block_hash)),
+        "timestamp": format!("0x{:x}", leaf.timestamp),
+        "blockNumber": format!("0x{:x}", leaf.block_number),
+        "l1InfoRoot": format!("0x{}", hex::encode(leaf.root)),
+        "verified": leaf.leaf_index < verified_leaf_count,
+    })
+}
+
+async fn l1_info_tree_leaf_response(
+    service: &ServiceState,
+    answer_id: axum_jrpc::Id,
+    leaf: anyhow::Result<Option<L1InfoTreeLeaf>>,
+) -> JrpcResult {
+    let Some(leaf) = leaf.map_err(|e| store_error(answer_id.clone(), e))? else {
+        return Ok(JsonRpcResponse::success::<serde_json::Value, _>(
+            answer_id,
+            serde_json::Value::Null,
+        ));
+    };
+    let state = service
+        .store
+        .get_l1_info_tree_state()
+        .await
+        .map_err(|e| store_error(answer_id.clone(), e))?;
+    Ok(JsonRpcResponse::success(
+        answer_id,
+        l1_info_tree_leaf_json(&leaf, state.verified_leaf_count),
+    ))
+}
+
+/// `zkevm_getL1InfoTreeLeaf(index)` — the reconstructed leaf at `index`, or
+/// null if the indexer has not appended it.
+pub(crate) async fn service_zkevm_get_l1_info_tree_leaf(
+    service: ServiceState,
+    request: JsonRpcExtractor,
+) -> JrpcResult {
+    let answer_id = request.get_answer_id();
+    let (index,): (String,) = request.parse_params()?;
+    let index = crate::hex::hex_decode_u64(&index)
+        .ok()
+        .and_then(|index| u32::try_from(index).ok())
+        .ok_or_else(|| {
+            JsonRpcResponse::error(
+                answer_id.clone(),
+                JsonRpcError::new(
+                    JsonRpcErrorReason::InvalidParams,
+                    "bad leaf index".to_string(),
+                    serde_json::Value::Null,
+                ),
+            )
+        })?;
+    let leaf = service.store.get_l1_info_tree_leaf(index).await;
+    l1_info_tree_leaf_response(&service, answer_id, leaf).await
+}
+
+/// `zkevm_getL1InfoTreeLeafByGER(ger)` — the reconstructed leaf that
+/// appended `ger`, or null.
+pub(crate) async fn service_zkevm_get_l1_info_tree_leaf_by_ger(
+    service: ServiceState,
+    request: JsonRpcExtractor,
+) -> JrpcResult {
+    let answer_id = request.get_answer_id();
+    let (ger,): (String,) = request.parse_params()?;
+    let ger = crate::service_helpers::validate_hex_hash_param(&ger, "GER hash", answer_id.clone())?;
+    let leaf = service.store.get_l1_info_tree_leaf_by_ger(&ger).await;
+    l1_info_tree_leaf_response(&service, answer_id, leaf).await
+}
+
+/// `zkevm_getL1InfoRoot()` — leaf count and root of the reconstructed tree,
+/// and how many of its leaves are covered by a verified root.
+pub(crate) async fn service_zkevm_get_l1_info_root(
+    service: ServiceState,
+    request: JsonRpcExtractor,
+) -> JrpcResult {
+    let answer_id = request.get_answer_id();
+    let state = service
+        .store
+        .get_l1_info_tree_state()
+        .await
+        .map_err(|e| store_error(answer_id.clone(), e))?;
+    Ok(JsonRpcResponse::success(
+        answer_id,
+        serde_json::json!({
+            "leafCount": format!("0x{:x}", state.frontier.leaf_count()),
+            "l1InfoRoot": format!("0x{}", hex::encode(state.frontier.root())),
+            "verifiedLeafCount": format!("0x{:x}", state.verified_leaf_count),
+        }),
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -165,4 +263,89 @@ mod tests {
         );
         assert_eq!(result["blockNumber"], "0x5");
     }
+
+    // ── L1 info tree lookups ───────────────────────────────────────────
+
+    #[tokio::test]
+    async fn test_l1_info_tree_lookups_report_leaf_root_and_verification() {
+        use crate::l1_info_tree::{Frontier, leaf_value};
+
+        let service = make_service();
+        let ger = [0x33u8; 32];
+        let mut frontier = Frontier::default();
+        frontier.push(leaf_value(&ger, &[0x44; 32], 77)).unwrap();
+        let leaf = L1InfoTreeLeaf {
+            leaf_index: 0,
+            ger,
+            previous_block_hash: [0x44; 32],
+            timestamp: 77,
+            block_number: 9,
+            root: frontier.root(),
+        };
+        service
+            .store
+            .append_l1_info_tree_leaves(std::slice::from_ref(&leaf), &frontier)
+            .await
+            .unwrap();
+
+        let request = |method: &str, params: serde_json::Value| JsonRpcExtractor {
+            parsed: params,
+            method: method.to_string(),
+            id: Id::Num(1),
+        };
+        let by_ger = extract_result(
+            service_zkevm_get_l1_info_tree_leaf_by_ger(
+                service.clone(),
+                request(
+                    "zkevm_getL1InfoTreeLeafByGER",
+                    serde_json::json!([format!("0x{}", hex::encode(ger))]),
+                ),
+            )
+            .await
+            .unwrap(),
+        );
+        assert_eq!(by_ger["leafIndex"], "0x0");
+        assert_eq!(
+            by_ger["l1InfoRoot"],
+            format!("0x{}", hex::encode(frontier.root()))
+        );
+        assert_eq!(by_ger["verified"], false);
+
+        service.store.set_l1_info_tree_verified(1).await.unwrap();
+        let by_index = extract_result(
+            service_zkevm_get_l1_info_tree_leaf(
+                service.clone(),
+                request("zkevm_getL1InfoTreeLeaf", serde_json::json!(["0x0"])),
+            )
+            .await
+            .unwrap(),
+        );
+        assert_eq!(by_index, {
+            let mut expected = by_ger.clone();
+            expected["verified"] = true.into();
+            expected
+        });
+        assert_eq!(
+            extract_result(
+                service_zkevm_get_l1_info_tree_leaf(
+                    service.clone(),
+                    request("zkevm_getL1InfoTreeLeaf", serde_json::json!(["0x1"])),
+                )
+                .await
+                .unwrap()
+            ),
+            serde_json::Value::Null
+        );
+
+        let root = extract_result(
+            service_zkevm_get_l1_info_root(
+                service,
+                request("zkevm_getL1InfoRoot", serde_json::json!([])),
+            )
+            .await
+            .unwrap(),
+        );
+        assert_eq!(root["leafCount"], "0x1");
+        assert_eq!(root["verifiedLeafCount"], "0x1");
+    }
 }
@@ -1,11 +1,14 @@
 //! In-memory Store implementation — wraps HashMap/RwLock data structures.
 
 use super::{
-    ClaimFence, FaucetEntry, NoteHandoff, NoteHandoffState, PendingNonceFrontier,
-    RecoverablePendingTxn, Store, TxnData, TxnEntry, UnbridgeableBridgeOut, UnclaimableClaim,
+    AdminAuditEntry, AdminKeyRecord, ClaimFence, ExitTreeState, FaucetEntry, L1EvidenceRewind,
+    L1InfoTreeLeaf, L1InfoTreeState, L1ScannedRange, NoteHandoff, NoteHandoffState,
+    PendingNonceFrontier, RecoverablePendingTxn, Store, TxnData, TxnEntry, UnbridgeableBridgeOut,
+    UnclaimableClaim,
 };
 use crate::log_synthesis::{
-    GerEntry, L2_GLOBAL_EXIT_ROOT_ADDRESS, LogFilter, SyntheticLog, UPDATE_HASH_CHAIN_VALUE_TOPIC,
+    GerEntry, L2_GLOBAL_EXIT_ROOT_ADDRESS, LogFilter, REMOVE_LAST_GLOBAL_EXIT_ROOT_TOPIC,
+    SyntheticLog, UPDATE_HASH_CHAIN_VALUE_TOPIC, UPDATE_REMOVAL_HASH_CHAIN_VALUE_TOPIC,
 };
 use alloy::primitives::{Address, LogData, TxHash, U256};
 use lru::LruCache;
@@ -14,7 +17,7 @@ use miden_protocol::note::{NoteId, Nullifier};
 use miden_protocol::transaction::TransactionId;
 use parking_lot::{Mutex, RwLock};
 use sha3::{Digest, Keccak256};
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::num::NonZeroUsize;
 
 fn bridge_event_deposit_count(data: &str) -> Option<u32> {
@@ -33,6 +36,7 @@ struct TxnReceipt {
     // #156 durable recovery backoff (0 / None until an orphan re-drive is recorded).
     recovery_attempts: u32,
     next_recovery_at: Option<u64>,
+    undrained_at_shutdown: bool,
 }
 
 /// #55 BLOCKER 1 — in-memory fenced admission-lease reservation row.
@@ -90,10 +94,15 @@ pub struct InMemoryStore {
     seen_gers: RwLock<HashMap<[u8; 32], GerEntry>>,
     latest_ger: RwLock<Option<[u8; 32]>>,
     hash_chain_value: RwLock<[u8; 32]>,
+    removal_hash_chain_value: RwLock<[u8; 32]>,
+    /// Removal transaction → the GER it removes.
+    ger_removals: RwLock<HashMap<TxHash, [u8; 32]>>,
     injected_gers: RwLock<HashSet<[u8; 32]>>,
 
     #[cfg(test)]
     test_fail_next_ger_evidence_write: std::sync::atomic::AtomicBool,
+    #[cfg(test)]
+    test_fail_next_block_timestamps_write: std::sync::atomic::AtomicBool,
 
     // Transactions
     transactions: Mutex<LruCache<TxHash, TxnReceipt>>,
@@ -125,6 +134,10 @@ pub struct InMemoryStore {
     // Unclaimable claims — first-write wins per global_index (RD-860).
     unclaimable: RwLock<HashMap<U256, UnclaimableClaim>>,
 
+    // Admin keys by name, and the append-only admin audit log (oldest first).
+    admin_keys: RwLock<BTreeMap<String, AdminKeyRecord>>,
+    admin_audit: RwLock<Vec<AdminAuditEntry>>,
+
     // Unbridgeable bridge-outs — first-write wins per note_id (Cantina MA#18).
     unbridgeable_bridge_outs: RwLock<HashMap<String, UnbridgeableBridgeOut>>,
 
@@ -185,6 +198,13 @@ pub struct InMemoryStore {
     // Store::get_reconcile_cursor.
     reconcile_cursor: RwLock<u64>,
 
+    // Real Miden block timestamps, write-once. Field-backed mirror of the
+    // PgStore `synthetic_blocks` table (migration 022). See
+    // Store::put_block_timestamps.
+    block_timestamps: RwLock<BTreeMap<u64, u64>>,
+    // Sealed header roots (migration 023). See Store::put_block_roots.
+    block_roots: RwLock<BTreeMap<u64, crate::block_state::BlockRoots>>,
+
     // Cursor of the one configured L1 evidence scan. PostgreSQL stores this in
     // the legacy `finalized_scan_cursor` column for upgrade-safe provenance.
     l1_evidence_cursor: RwLock<u64>,
@@ -192,6 +212,16 @@ pub struct InMemoryStore {
     // Canonical EvidenceTag that produced the persisted selected-scan state.
     l1_evidence_policy: RwLock<Option<String>>,
 
+    // Scanned L1 ranges by end block, for reorg detection.
+    l1_scanned_ranges: RwLock<BTreeMap<u64, L1ScannedRange>>,
+
+    // Reconstructed L1 info tree: frontier/verification mark and the leaves
+    // by index, under one lock so appends are atomic.
+    l1_info_tree: RwLock<(L1InfoTreeState, Vec<L1InfoTreeLeaf>)>,
+
+    // Local exit tree folded from exposed BridgeEvents.
+    exit_tree: RwLock<ExitTreeState>,
+
     // Receipts map (synthetic-indexer redesign, Phase 2b substrate) —
     // first-write-wins evm_tx_hash -> note_commitment, with the reverse index
     // mirrored alongside it. UNUSED in Phase 2a. See Store::record_tx_note_link.
@@ -238,10 +268,14 @@ impl InMemoryStore {
             seen_gers: RwLock::new(HashMap::new()),
             latest_ger: RwLock::new(None),
             hash_chain_value: RwLock::new([0u8; 32]),
+            removal_hash_chain_value: RwLock::new([0u8; 32]),
+            ger_removals: RwLock::new(HashMap::new()),
             injected_gers: RwLock::new(HashSet::new()),
             claim_calldata_pending: RwLock::new(HashSet::new()),
             #[cfg(test)]
             test_fail_next_ger_evidence_write: std::sync::atomic::AtomicBool::new(false),
+            #[cfg(test)]
+            test_fail_next_block_timestamps_write: std::sync::atomic::AtomicBool::new(false),
             transactions: Mutex::new(LruCache::new(NonZeroUsize::new(10_000).unwrap())),
             nonces: RwLock::new(HashMap::new()),
             nonce_reservations: RwLock::new(HashMap::new()),
@@ -250,6 +284,8 @@ impl InMemoryStore {
             #[cfg(test)]
             claim_clock_skew: RwLock::new(std::time::Duration::ZERO),
             unclaimable: RwLock::new(HashMap::new()),
+            admin_keys: RwLock::new(BTreeMap::new()),
+            admin_audit: RwLock::new(Vec::new()),
             unbridgeable_bridge_outs: RwLock::new(HashMap::new()),
             address_mappings: RwLock::new(HashMap::new()),
             processed_notes: RwLock::new(HashMap::new()),
@@ -268,8 +304,13 @@ impl InMemoryStore {
             fail_list_faucets: std::sync::atomic::AtomicBool::new(false),
             projector_cursor: RwLock::new(0),
             reconcile_cursor: RwLock::new(0),
+            block_timestamps: RwLock::new(BTreeMap::new()),
+            block_roots: RwLock::new(BTreeMap::new()),
             l1_evidence_cursor: RwLock::new(0),
             l1_evidence_policy: RwLock::new(None),
+            l1_scanned_ranges: RwLock::new(BTreeMap::new()),
+            l1_info_tree: RwLock::new((L1InfoTreeState::default(), Vec::new())),
+            exit_tree: RwLock::new(ExitTreeState::default()),
             tx_note_links: RwLock::new(HashMap::new()),
             note_tx_links: RwLock::new(HashMap::new()),
         }
@@ -325,6 +366,13 @@ impl InMemoryStore {
             .store(true, std::sync::atomic::Ordering::SeqCst);
     }
 
+    /// Test hook: the NEXT `put_block_timestamps` fails without writing.
+    #[cfg(test)]
+    pub fn test_fail_next_block_timestamps_write(&self) {
+        self.test_fail_next_block_timestamps_write
+            .store(true, std::sync::atomic::Ordering::SeqCst);
+    }
+
     /// Test hook (#55 BLOCKER B): arm the store so the NEXT `nonce_advance_cas`
     /// returns a store error, simulating a DB failure on the nonce CAS.
     #[cfg(test)]
@@ -458,6 +506,50 @@ impl Store for InMemoryStore {
         Ok(())
     }
 
+    // ── Synthetic block timestamps ───────────────────────────────
+
+    async fn put_block_timestamps(&self, entries: &[(u64, u64)]) -> anyhow::Result<()> {
+        #[cfg(test)]
+        if self
+            .test_fail_next_block_timestamps_write
+            .swap(false, std::sync::atomic::Ordering::SeqCst)
+        {
+            anyhow::bail!("injected fault: block timestamp write failed");
+        }
+        let mut timestamps = self.block_timestamps.write();
+        for &(block, timestamp) in entries {
+            timestamps.entry(block).or_insert(timestamp);
+        }
+        Ok(())
+    }
+
+    async fn get_block_timestamps(&self) -> anyhow::Result<Vec<(u64, u64)>> {
+        Ok(self
+            .block_timestamps
+            .read()
+            .iter()
+            .map(|(&block, &timestamp)| (block, timestamp))
+            .collect())
+    }
+
+    async fn put_block_roots(
+        &self,
+        block: u64,
+        roots: crate::block_state::BlockRoots,
+    ) -> anyhow::Result<()> {
+        self.block_roots.write().insert(block, roots);
+        Ok(())
+    }
+
+    async fn get_block_roots(&self) -> anyhow::Result<Vec<(u64, crate::block_state::BlockRoots)>> {
+        Ok(self
+            .block_roots
+            .read()
+            .iter()
+            .map(|(&block, &roots)| (block, roots))
+            .collect())
+    }
+
     async fn count_claim_events_awaiting_calldata(&self) -> anyhow::Result<u64> {
         // O(1) read of the durable repair-backlog set — NOT a scan of
         // `logs_by_tx`/`transactions` (review blocker 3). The `transactions`
@@ -538,6 +630,139 @@ impl Store for InMemoryStore {
         Ok(())
     }
 
+    async fn record_l1_scanned_range(
+        &self,
+        range: L1ScannedRange,
+        retain: usize,
+    ) -> anyhow::Result<()> {
+        let mut ranges = self.l1_scanned_ranges.write();
+        ranges.insert(range.end, range);
+        while ranges.len() > retain {
+            ranges.pop_first();
+        }
+        Ok(())
+    }
+
+    async fn list_l1_scanned_ranges(&self) -> anyhow::Result<Vec<L1ScannedRange>> {
+        Ok(self
+            .l1_scanned_ranges
+            .read()
+            .values()
+            .rev()
+            .cloned()
+            .collect())
+    }
+
+    async fn rewind_l1_evidence(&self, fork_block: u64) -> anyhow::Result<L1EvidenceRewind> {
+        // Lock order matches the individual writers; all four change together.
+        let mut ranges = self.l1_scanned_ranges.write();
+        let mut seen = self.seen_gers.write();
+        let mut tree = self.l1_info_tree.write();
+        let mut cursor = self.l1_evidence_cursor.write();
+
+        ranges.retain(|end, _| *end <= fork_block);
+        let mut unverified_gers = 0;
+        for entry in seen.values_mut() {
+            if entry.evidence_verified && entry.block_number > fork_block {
+                entry.evidence_verified = false;
+                unverified_gers += 1;
+            }
+        }
+        let (state, leaves) = &mut *tree;
+        let keep = leaves
+            .iter()
+            .position(|leaf| leaf.block_number > fork_block)
+            .unwrap_or(leaves.len());
+        let removed_leaves = (leaves.len() - keep) as u64;
+        if removed_leaves > 0 {
+            leaves.truncate(keep);
+            let mut frontier = crate::l1_info_tree::Frontier::default();
+            for leaf in leaves.iter() {
+                frontier.push(crate::l1_info_tree::leaf_value(
+                    &leaf.ger,
+                    &leaf.previous_block_hash,
+                    leaf.timestamp,
+                ))?;
+            }
+            state.verified_leaf_count = state.verified_leaf_count.min(frontier.leaf_count());
+            state.frontier = frontier;
+        }
+        *cursor = fork_block;
+        Ok(L1EvidenceRewind {
+            unverified_gers,
+            removed_leaves,
+        })
+    }
+
+    async fn get_l1_info_tree_state(&self) -> anyhow::Result<L1InfoTreeState> {
+        Ok(self.l1_info_tree.read().0.clone())
+    }
+
+    async fn append_l1_info_tree_leaves(
+        &self,
+        leaves: &[L1InfoTreeLeaf],
+        frontier: &crate::l1_info_tree::Frontier,
+    ) -> anyhow::Result<()> {
+        let mut tree = self.l1_info_tree.write();
+        let (state, stored) = &mut *tree;
+        let count = state.frontier.leaf_count();
+        anyhow::ensure!(
+            leaves
+                .iter()
+                .zip(count..)
+                .all(|(leaf, index)| leaf.leaf_index == index)
+                && u64::from(frontier.leaf_count()) == u64::from(count) + leaves.len() as u64,
+            "L1 info tree append of {} leaves does not continue the stored {count}",
+            leaves.len()
+        );
+        stored.extend_from_slice(leaves);
+        state.frontier = frontier.clone();
+        Ok(())
+    }
+
+    async fn set_l1_info_tree_verified(&self, leaf_count: u32) -> anyhow::Result<()> {
+        let mut tree = self.l1_info_tree.write();
+        tree.0.verified_leaf_count = tree.0.verified_leaf_count.max(leaf_count);
+        Ok(())
+    }
+
+    async fn get_l1_info_tree_leaf(
+        &self,
+        leaf_index: u32,
+    ) -> anyhow::Result<Option<L1InfoTreeLeaf>> {
+        Ok(self.l1_info_tree.read().1.get(leaf_index as usize).cloned())
+    }
+
+    async fn get_l1_info_tree_leaf_by_ger(
+        &self,
+        ger: &[u8; 32],
+    ) -> anyhow::Result<Option<L1InfoTreeLeaf>> {
+        Ok(self
+            .l1_info_tree
+            .read()
+            .1
+            .iter()
+            .find(|leaf| &leaf.ger == ger)
+            .cloned())
+    }
+
+    async fn get_exit_tree_state(&self) -> anyhow::Result<ExitTreeState> {
+        Ok(self.exit_tree.read().clone())
+    }
+
+    async fn advance_exit_tree_state(
+        &self,
+        expected: &ExitTreeState,
+        next: &ExitTreeState,
+    ) -> anyhow::Result<bool> {
+        let mut state = self.exit_tree.write();
+        if *state != *expected {
+            return Ok(false);
+        }
+        *state = next.clone();
+        Ok(true)
+    }
+
     // ── Receipts map (Phase 2b substrate; unused in 2a) ──────────
 
     async fn record_tx_note_link(
@@ -697,6 +922,81 @@ impl Store for InMemoryStore {
         Ok(())
     }
 
+    async fn mark_undrained_at_shutdown(&self, tx_hashes: &[TxHash]) -> anyhow::Result<u64> {
+        let mut txns = self.transactions.lock();
+        let mut marked = 0;
+        for hash in tx_hashes {
+            if let Some(r) = txns.get_mut(hash)
+                && r.result.is_none()
+            {
+                r.next_recovery_at = None;
+                r.undrained_at_shutdown = true;
+                marked += 1;
+            }
+        }
+        Ok(marked)
+    }
+
+    async fn take_undrained_at_shutdown(&self) -> anyhow::Result<u64> {
+        let mut txns = self.transactions.lock();
+        let mut taken = 0;
+        for (_, r) in txns.iter_mut() {
+            if std::mem::take(&mut r.undrained_at_shutdown) {
+                taken += 1;
+            }
+        }
+        Ok(taken)
+    }
+
+    async fn list_admin_keys(&self) -> anyhow::Result<Vec<AdminKeyRecord>> {
+        Ok(self.admin_keys.read().values().cloned().collect())
+    }
+
+    async fn put_admin_key(&self, record: AdminKeyRecord) -> anyhow::Result<()> {
+        self.admin_keys.write().insert(record.name.clone(), record);
+        Ok(())
+    }
+
+    async fn append_admin_audit(
+        &self,
+        key_name: Option<&str>,
+        method: &str,
+        params_digest: &str,
+        outcome: &str,
+    ) -> anyhow::Result<()> {
+        let recorded_at = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or_default();
+        let mut log = self.admin_audit.write();
+        let id = log.len() as u64 + 1;
+        log.push(AdminAuditEntry {
+            id,
+            recorded_at,
+            key_name: key_name.map(str::to_string),
+            method: method.to_string(),
+            params_digest: params_digest.to_string(),
+            outcome: outcome.to_string(),
+        });
+        Ok(())
+    }
+
+    async fn list_admin_audit(
+        &self,
+        before_id: Option<u64>,
+        limit: usize,
+    ) -> anyhow::Result<Vec<AdminAuditEntry>> {
+        Ok(self
+            .admin_audit
+            .read()
+            .iter()
+            .rev()
+            .filter(|e| before_id.is_none_or(|before| e.id < before))
+            .take(limit)
+            .cloned()
+            .collect())
+    }
+
     async fn prepare_note_handoff(
         &self,
         tx_hash: &str,
@@ -941,6 +1241,35 @@ impl Store for InMemoryStore {
         Ok(result)
     }
 
+    async fn seal_logs(
+        &self,
+        block: u64,
+        block_hash: [u8; 32],
+        tx_order: &[String],
+    ) -> anyhow::Result<()> {
+        let seal = |log: &mut SyntheticLog| {
+            log.block_hash = block_hash;
+            let tx = log.transaction_hash.to_lowercase();
+            if let Some(index) = tx_order.iter().position(|hash| *hash == tx) {
+                log.transaction_index = index as u64;
+            }
+        };
+        if let Some(logs) = self.logs_by_block.write().get_mut(&block) {
+            logs.iter_mut().for_each(seal);
+        }
+        for log in self.logs_by_tx.write().values_mut().flatten() {
+            if log.block_number == block {
+                seal(log);
+            }
+        }
+        for log in self.pending_events.write().iter_mut() {
+            if log.block_number == block {
+                seal(log);
+            }
+        }
+        Ok(())
+    }
+
     // ── GER ──────────────────────────────────────────────────────
 
     async fn has_seen_ger(&self, ger: &[u8; 32]) -> anyhow::Result<bool> {
@@ -966,6 +1295,22 @@ impl Store for InMemoryStore {
         Ok(self.seen_gers.read().get(ger).cloned())
     }
 
+    async fn get_latest_l1_observed_gers(&self) -> anyhow::Result<Vec<[u8; 32]>> {
+        let seen = self.seen_gers.read();
+        let observed = || {
+            seen.iter().filter(|(_, e)| {
+                e.evidence_verified && e.mainnet_exit_root.is_some() && e.rollup_exit_root.is_some()
+            })
+        };
+        let Some(top) = observed().map(|(_, e)| e.block_number).max() else {
+            return Ok(Vec::new());
+        };
+        Ok(observed()
+            .filter(|(_, e)| e.block_number == top)
+            .map(|(ger, _)| *ger)
+            .collect())
+    }
+
     async fn set_ger_exit_roots(
         &self,
         ger: &[u8; 32],
@@ -1005,6 +1350,10 @@ impl Store for InMemoryStore {
         Ok(self.injected_gers.read().contains(ger))
     }
 
+    async fn get_ger_hash_chain(&self) -> anyhow::Result<[u8; 32]> {
+        Ok(*self.hash_chain_value.read())
+    }
+
     /// Atomic GER commit (audit H2). Folds the idempotent chain roll + log
     /// emission with `is_injected = TRUE` into one operation, so a retry can
     /// never roll the hash chain / emit the synthetic log a second time (the
@@ -1110,6 +1459,103 @@ impl Store for InMemoryStore {
         Ok(())
     }
 
+    async fn get_ger_removal_hash_chain(&self) -> anyhow::Result<[u8; 32]> {
+        Ok(*self.removal_hash_chain_value.read())
+    }
+
+    /// Atomic GER removal commit. Same shape as `commit_ger_event_atomic`:
+    /// the removal chain roll + both removal logs are gated on `tx_hash`
+    /// having no logs yet, and `is_injected` is cleared unconditionally.
+    async fn commit_ger_removal_atomic(
+        &self,
+        block_number: u64,
+        block_hash: [u8; 32],
+        tx_hash: &str,
+        global_exit_root: &[u8; 32],
+    ) -> anyhow::Result<()> {
+        let tx_hash_key = tx_hash.to_lowercase();
+        let mut links = self.tx_note_links.write();
+        if let Some(link) = links.get_mut(&tx_hash_key) {
+            link.state = NoteHandoffState::Submitted;
+            link.expiration_block = None;
+        }
+
+        let already_emitted = self.logs_by_tx.read().contains_key(&tx_hash_key);
+        if !already_emitted {
+            let new_removal_chain = {
+                let mut chain = self.removal_hash_chain_value.write();
+                let mut hasher = Keccak256::new();
+                hasher.update(*chain);
+                hasher.update(global_exit_root);
+                let result: [u8; 32] = hasher.finalize().into();
+                *chain = result;
+                result
+            };
+
+            let ger_topic = format!("0x{}", hex::encode(global_exit_root));
+            let topic_sets = [
+                vec![
+                    REMOVE_LAST_GLOBAL_EXIT_ROOT_TOPIC.to_string(),
+                    ger_topic.clone(),
+                ],
+                vec![
+                    UPDATE_REMOVAL_HASH_CHAIN_VALUE_TOPIC.to_string(),
+                    ger_topic,
+                    format!("0x{}", hex::encode(new_removal_chain)),
+                ],
+            ];
+            for topics in topic_sets {
+                self.insert_log(SyntheticLog {
+                    address: L2_GLOBAL_EXIT_ROOT_ADDRESS.to_string(),
+                    topics,
+                    data: "0x".to_string(),
+                    block_number,
+                    block_hash,
+                    transaction_hash: tx_hash_key.clone(),
+                    transaction_index: 0,
+                    log_index: 0,
+                    removed: false,
+                });
+            }
+        }
+
+        // Always clear is_injected (idempotent); the GER's roots stay known.
+        self.injected_gers.write().remove(global_exit_root);
+        if let Ok(hash) = tx_hash.parse::<TxHash>()
+            && let Some(receipt) = self.transactions.lock().get_mut(&hash)
+        {
+            receipt.result = Some(Ok(()));
+            receipt.block_num = block_number;
+        }
+        drop(links);
+        Ok(())
+    }
+
+    async fn record_ger_removal(
+        &self,
+        tx_hash: TxHash,
+        global_exit_root: &[u8; 32],
+    ) -> anyhow::Result<()> {
+        self.ger_removals
+            .write()
+            .entry(tx_hash)
+            .or_insert(*global_exit_root);
+        Ok(())
+    }
+
+    async fn pending_ger_removal(
+        &self,
+        global_exit_root: &[u8; 32],
+    ) -> anyhow::Result<Option<TxHash>> {
+        let removals = self.ger_removals.read();
+        let txns = self.transactions.lock();
+        Ok(removals
+            .iter()
+            .filter(|(_, ger)| *ger == global_exit_root)
+            .map(|(hash, _)| *hash)
+            .find(|hash| txns.peek(hash).is_some_and(|r| r.result.is_none())))
+    }
+
     // ── Transactions ─────────────────────────────────────────────
 
     async fn txn_begin(&self, tx_hash: TxHash, entry: TxnEntry) -> anyhow::Result<()> {
@@ -1127,6 +1573,7 @@ impl Store for InMemoryStore {
             logs: entry.logs,
             recovery_attempts: 0,
             next_recovery_at: None,
+            undrained_at_shutdown: false,
         };
         let _ = txns.put(tx_hash, receipt);
         Ok(())
@@ -1158,6 +1605,7 @@ impl Store for InMemoryStore {
             logs: entry.logs,
             recovery_attempts: 0,
             next_recovery_at: None,
+            undrained_at_shutdown: false,
         };
         let _ = txns.put(tx_hash, receipt);
         Ok(true)
@@ -1301,6 +1749,17 @@ impl Store for InMemoryStore {
         }))
     }
 
+    async fn txn_hashes_at_block(&self, block: u64) -> anyhow::Result<Vec<TxHash>> {
+        let txns = self.transactions.lock();
+        let mut hashes: Vec<TxHash> = txns
+            .iter()
+            .filter(|(_, receipt)| receipt.result.is_some() && receipt.block_num == block)
+            .map(|(hash, _)| *hash)
+            .collect();
+        hashes.sort();
+        Ok(hashes)
+    }
+
     async fn pending_nonce_frontier(&self, addr: &str) -> anyhow::Result<PendingNonceFrontier> {
         let addr = addr.to_lowercase();
         let links = self.tx_note_links.read();
@@ -1584,6 +2043,7 @@ impl Store for InMemoryStore {
                 logs: vec![],
                 recovery_attempts: 0,
                 next_recovery_at: None,
+                undrained_at_shutdown: false,
             };
             let _ = txns.put(tx_hash, receipt);
         }
@@ -1816,6 +2276,30 @@ impl Store for InMemoryStore {
         Ok(self.unbridgeable_bridge_outs.read().get(note_id).cloned())
     }
 
+    async fn list_unbridgeable_bridge_outs(
+        &self,
+        after_note_id: Option<&str>,
+        limit: usize,
+    ) -> anyhow::Result<Vec<UnbridgeableBridgeOut>> {
+        let map = self.unbridgeable_bridge_outs.read();
+        let mut rows: Vec<UnbridgeableBridgeOut> = map
+            .values()
+            .filter(|e| after_note_id.is_none_or(|after| e.note_id.as_str() > after))
+            .cloned()
+            .collect();
+        rows.sort_by(|a, b| a.note_id.cmp(&b.note_id));
+        rows.truncate(limit);
+        Ok(rows)
+    }
+
+    async fn delete_unbridgeable_bridge_out(&self, note_id: &str) -> anyhow::Result<bool> {
+        Ok(self
+            .unbridgeable_bridge_outs
+            .write()
+            .remove(note_id)
+            .is_some())
+    }
+
     // ── Address mappings ─────────────────────────────────────────
 
     async fn get_address_mapping(&self, eth: &Address) -> anyhow::Result<Option<AccountId>> {
@@ -2437,6 +2921,81 @@ mod tests {
         assert_eq!(store.get_projector_cursor().await.unwrap(), 42);
     }
 
+    #[tokio::test]
+    async fn test_block_timestamps_are_write_once() {
+        // The timestamp is hashed into the synthetic header, so a second write
+        // for the same block must not replace what may already have been served.
+        let store = InMemoryStore::new();
+        assert!(store.get_block_timestamps().await.unwrap().is_empty());
+        store
+            .put_block_timestamps(&[(11, 1_750_000_010), (10, 1_750_000_000)])
+            .await
+            .unwrap();
+        store
+            .put_block_timestamps(&[(10, 1_999_999_999), (12, 1_750_000_020)])
+            .await
+            .unwrap();
+        assert_eq!(
+            store.get_block_timestamps().await.unwrap(),
+            vec![
+                (10, 1_750_000_000),
+                (11, 1_750_000_010),
+                (12, 1_750_000_020)
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_seal_rewrites_log_block_hash_and_index() {
+        let store = InMemoryStore::new();
+        for (block, tx) in [(7u64, "0xaa"), (7, "0xAB"), (8, "0xbb")] {
+            store
+                .add_log(SyntheticLog {
+                    log_index: 0,
+                    address: "0xdead".to_string(),
+                    topics: vec![],
+                    data: "0x".to_string(),
+                    block_number: block,
+                    block_hash: [1u8; 32],
+                    transaction_hash: tx.to_string(),
+                    transaction_index: 0,
+                    removed: false,
+                })
+                .await
+                .unwrap();
+        }
+        let roots = crate::block_state::BlockRoots::default();
+        store.put_block_roots(7, roots).await.unwrap();
+        store
+            .seal_logs(7, [9u8; 32], &["0xab".to_string(), "0xaa".to_string()])
+            .await
+            .unwrap();
+
+        assert_eq!(store.get_block_roots().await.unwrap(), vec![(7, roots)]);
+        let sealed = &store.get_logs_for_tx("0xaa").await.unwrap()[0];
+        assert_eq!(
+            (sealed.block_hash, sealed.transaction_index),
+            ([9u8; 32], 1)
+        );
+        assert_eq!(
+            store.get_logs_for_tx("0xAB").await.unwrap()[0].transaction_index,
+            0
+        );
+        assert_eq!(
+            store.get_logs_for_tx("0xbb").await.unwrap()[0].block_hash,
+            [1u8; 32]
+        );
+        let filter = LogFilter {
+            from_block: Some("0x7".to_string()),
+            to_block: Some("0x7".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            store.get_logs(&filter, 8).await.unwrap()[0].block_hash,
+            [9u8; 32]
+        );
+    }
+
     #[tokio::test]
     async fn test_reconcile_cursor_round_trip() {
         // Note-reconciler sweep cursor persistence (prod incident: the cursor
@@ -3692,6 +4251,62 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn commit_ger_removal_atomic_rolls_removal_chain_once() {
+        let store = InMemoryStore::new();
+        let ger = [0x77u8; 32];
+        store
+            .commit_ger_event_atomic(10, [0xaa; 32], "0xger-inject", &ger, None, None, 1000)
+            .await
+            .unwrap();
+        let inserted_chain = store.get_ger_hash_chain().await.unwrap();
+
+        for _ in 0..2 {
+            store
+                .commit_ger_removal_atomic(11, [0xbb; 32], "0xGER-remove", &ger)
+                .await
+                .unwrap();
+        }
+
+        assert!(!store.is_ger_injected(&ger).await.unwrap());
+        assert_eq!(store.get_ger_hash_chain().await.unwrap(), inserted_chain);
+        let expected: [u8; 32] = Keccak256::new()
+            .chain_update([0u8; 32])
+            .chain_update(ger)
+            .finalize()
+            .into();
+        assert_eq!(store.get_ger_removal_hash_chain().await.unwrap(), expected);
+
+        let logs = store.get_logs_for_tx("0xger-remove").await.unwrap();
+        let ger_topic = format!("0x{}", hex::encode(ger));
+        assert_eq!(
+            logs.iter().map(|l| l.topics.clone()).collect::<Vec<_>>(),
+            vec![
+                vec![
+                    REMOVE_LAST_GLOBAL_EXIT_ROOT_TOPIC.to_string(),
+                    ger_topic.clone()
+                ],
+                vec![
+                    UPDATE_REMOVAL_HASH_CHAIN_VALUE_TOPIC.to_string(),
+                    ger_topic,
+                    format!("0x{}", hex::encode(expected)),
+                ],
+            ],
+            "one removal pair, not repeated by the retry"
+        );
+        assert!(
+            logs.iter()
+                .all(|l| l.address == L2_GLOBAL_EXIT_ROOT_ADDRESS)
+        );
+
+        // The GER can be injected again after its removal.
+        store
+            .commit_ger_event_atomic(12, [0xcc; 32], "0xger-reinject", &ger, None, None, 1001)
+            .await
+            .unwrap();
+        assert!(store.is_ger_injected(&ger).await.unwrap());
+    }
+
     #[tokio::test]
     async fn test_faucet_registry() {
         let store = InMemoryStore::new();
@@ -34,14 +34,15 @@
 //!
 //! ## Caller contract
 //!
-//! `main.rs` calls `run_migrations(&db_url).await?` immediately after
+//! `main.rs` calls `run_migrations(&db_url, &tls).await?` immediately after
 //! parsing `--database-url` and before constructing `PgStore`. Errors
 //! propagate up and abort startup. The InMemoryStore code path (when
 //! `--database-url` is unset) does not need migrations.
 
+use super::pg_tls::PgTlsOptions;
 use anyhow::{Context, Result};
 use sha3::{Digest, Keccak256};
-use tokio_postgres::{Client, NoTls};
+use tokio_postgres::Client;
 
 /// Compile-time embedded list of `(filename, sql)`. KEEP THIS IN
 /// LEXICOGRAPHIC ORDER. New migrations are added at the end.
@@ -129,6 +130,50 @@ const MIGRATIONS: &[(&str, &str)] = &[
         "021_orphan_recovery_backoff.sql",
         include_str!("../../migrations/021_orphan_recovery_backoff.sql"),
     ),
+    (
+        "022_synthetic_block_timestamps.sql",
+        include_str!("../../migrations/022_synthetic_block_timestamps.sql"),
+    ),
+    (
+        "023_synthetic_block_roots.sql",
+        include_str!("../../migrations/023_synthetic_block_roots.sql"),
+    ),
+    (
+        "024_transactions_by_block.sql",
+        include_str!("../../migrations/024_transactions_by_block.sql"),
+    ),
+    (
+        "025_shutdown_undrained.sql",
+        include_str!("../../migrations/025_shutdown_undrained.sql"),
+    ),
+    (
+        "026_admin_keys_audit.sql",
+        include_str!("../../migrations/026_admin_keys_audit.sql"),
+    ),
+    (
+        "027_l1_info_tree.sql",
+        include_str!("../../migrations/027_l1_info_tree.sql"),
+    ),
+    (
+        "028_l1_scanned_ranges.sql",
+        include_str!("../../migrations/028_l1_scanned_ranges.sql"),
+    ),
+    (
+        "029_ger_removal_hash_chain.sql",
+        include_str!("../../migrations/029_ger_removal_hash_chain.sql"),
+    ),
+    (
+        "030_exit_tree_frontier.sql",
+        include_str!("../../migrations/030_exit_tree_frontier.sql"),
+    ),
+    (
+        "031_admin_audit_retention.sql",
+        include_str!("../../migrations/031_admin_audit_retention.sql"),
+    ),
+    (
+        "032_ger_removals.sql",
+        include_str!("../../migrations/032_ger_removals.sql"),
+    ),
 ];
 
 /// Postgres advisory-lock key. Arbitrary 64-bit int; just needs to be
@@ -143,9 +188,13 @@ pub struct MigrationReport {
 }
 
 /// Connect to `db_url`, apply any pending migrations from the embedded
-/// list, return a report. Errors abort proxy startup.
-pub async fn run_migrations(db_url: &str) -> Result<MigrationReport> {
-    let (client, connection) = tokio_postgres::connect(db_url, NoTls)
+/// list, return a report. Errors abort proxy startup. Negotiates TLS exactly
+/// like the `PgStore` pool (same `sslmode` and certificate material).
+pub async fn run_migrations(db_url: &str, tls: &PgTlsOptions) -> Result<MigrationReport> {
+    let conn = super::pg_tls::resolve_connection(db_url, tls)?;
+    let (client, connection) = conn
+        .config
+        .connect(conn.tls)
         .await
         .context("connecting to Postgres for migrations")?;
     tokio::spawn(async move {
@@ -298,8 +347,12 @@ mod tests {
     #[ignore = "requires live Postgres"]
     async fn live_run_migrations_is_idempotent() {
         let db_url = std::env::var("DATABASE_URL").expect("set DATABASE_URL to run this test");
-        let r1 = run_migrations(&db_url).await.expect("first run");
-        let r2 = run_migrations(&db_url).await.expect("second run");
+        let r1 = run_migrations(&db_url, &PgTlsOptions::default())
+            .await
+            .expect("first run");
+        let r2 = run_migrations(&db_url, &PgTlsOptions::default())
+            .await
+            .expect("second run");
         // Second run must apply nothing.
         assert!(
             r2.applied.is_empty(),
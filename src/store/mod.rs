@@ -8,12 +8,13 @@
 pub mod memory;
 #[cfg(feature = "postgres")]
 pub mod migrator;
+pub mod pg_tls;
 #[cfg(feature = "postgres")]
 pub mod postgres;
 #[cfg(all(test, feature = "postgres"))]
 mod postgres_tests;
 
-use crate::block_state::BlockState;
+use crate::block_state::{BlockRoots, BlockState};
 use crate::log_synthesis::{GerEntry, LogFilter, SyntheticLog};
 use crate::miden_client::{MidenClientLib, SyncListener};
 use alloy::consensus::TxEnvelope;
@@ -110,6 +111,33 @@ pub struct FaucetEntry {
     pub metadata: Vec<u8>,
 }
 
+/// A named admin API key provisioned in the store (`admin_keys`). Only the
+/// keccak256 of the bearer token is kept; `methods` scopes the key (see
+/// [`crate::admin_auth`]).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AdminKeyRecord {
+    pub name: String,
+    pub key_hash: [u8; 32],
+    pub methods: Vec<String>,
+}
+
+/// One row of the append-only `admin_audit_log`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AdminAuditEntry {
+    pub id: u64,
+    /// Unix seconds.
+    pub recorded_at: u64,
+    /// Key that made the call. `None` only on rows recorded before calls
+    /// without a valid key stopped being audited.
+    pub key_name: Option<String>,
+    /// Bucketed method label (`other` for unknown names).
+    pub method: String,
+    /// keccak256 of the JSON-encoded params.
+    pub params_digest: String,
+    /// `ok`, `error` or `forbidden`.
+    pub outcome: String,
+}
+
 /// The canonical MINT content derivable from a consumed CLAIM (Cantina #4).
 /// Decoded from the CLAIM's on-chain
 /// `ClaimNoteStorage`. Persisted keyed by the expected MINT serial
@@ -216,6 +244,57 @@ impl UnclaimableReason {
     }
 }
 
+/// One leaf of the reconstructed L1 info tree (see [`crate::l1_info_tree`]).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct L1InfoTreeLeaf {
+    pub leaf_index: u32,
+    /// Combined global exit root the leaf commits to.
+    pub ger: [u8; 32],
+    /// `blockhash(block.number - 1)` of the L1 block that appended the leaf.
+    pub previous_block_hash: [u8; 32],
+    /// Timestamp of that L1 block.
+    pub timestamp: u64,
+    pub block_number: u64,
+    /// `l1InfoRoot` once this leaf was appended.
+    pub root: [u8; 32],
+}
+
+/// One L1 block range the evidence scan processed, with the hash of its last
+/// block as seen at scan time. The next poll checks the following block's
+/// parent hash against `end_hash` to detect a reorg.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct L1ScannedRange {
+    pub start: u64,
+    pub end: u64,
+    pub end_hash: [u8; 32],
+}
+
+/// What [`Store::rewind_l1_evidence`] undid.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct L1EvidenceRewind {
+    /// `ger_entries` rows whose L1 evidence was marked unverified again.
+    pub unverified_gers: u64,
+    /// Reconstructed L1 info tree leaves removed.
+    pub removed_leaves: u64,
+}
+
+/// The reconstructed L1 info tree's frontier and how much of it has been
+/// checked against the contract's `getRoot()`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct L1InfoTreeState {
+    pub frontier: crate::l1_info_tree::Frontier,
+    /// Leaves `0..verified_leaf_count` are covered by a matching on-chain root.
+    pub verified_leaf_count: u32,
+}
+
+/// The local exit tree folded from exposed `BridgeEvent`s: its frontier and
+/// the first synthetic block not folded in yet.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ExitTreeState {
+    pub frontier: crate::l1_info_tree::Frontier,
+    pub next_block: u64,
+}
+
 /// Record of a B2AGG bridge-out that aggkit observed consumed by the bridge
 /// but could NOT translate into a synthetic `BridgeEvent` (Cantina MA#18).
 ///
@@ -456,6 +535,59 @@ pub trait Store: Send + Sync + 'static {
         anyhow::bail!("store does not support persistent L1 evidence-policy binding")
     }
 
+    // === L1 reorg handling ===
+    /// Record a processed L1 range, replacing any range with the same end,
+    /// and keep only the `retain` newest ranges.
+    async fn record_l1_scanned_range(
+        &self,
+        range: L1ScannedRange,
+        retain: usize,
+    ) -> anyhow::Result<()>;
+    /// Retained scanned ranges, newest (highest end) first.
+    async fn list_l1_scanned_ranges(&self) -> anyhow::Result<Vec<L1ScannedRange>>;
+    /// Undo all L1 evidence above `fork_block` in one step: forget scanned
+    /// ranges ending after it, mark `ger_entries` whose evidence came from a
+    /// later L1 block unverified, drop L1 info tree leaves appended by a later
+    /// block (rebuilding the frontier, and clamping the verified mark), and
+    /// set the evidence cursor to `fork_block`.
+    async fn rewind_l1_evidence(&self, fork_block: u64) -> anyhow::Result<L1EvidenceRewind>;
+
+    // === Reconstructed L1 info tree ===
+    /// Frontier and verification mark; the empty tree when nothing is stored.
+    async fn get_l1_info_tree_state(&self) -> anyhow::Result<L1InfoTreeState>;
+    /// Append `leaves` (consecutive, starting at the stored leaf count) and
+    /// replace the frontier with `frontier`, atomically. Errors without
+    /// writing anything if the first index is not the stored leaf count or
+    /// `frontier` does not account for exactly the appended leaves.
+    async fn append_l1_info_tree_leaves(
+        &self,
+        leaves: &[L1InfoTreeLeaf],
+        frontier: &crate::l1_info_tree::Frontier,
+    ) -> anyhow::Result<()>;
+    /// Record that the root over the first `leaf_count` leaves matched the
+    /// contract. Never lowers an existing mark.
+    async fn set_l1_info_tree_verified(&self, leaf_count: u32) -> anyhow::Result<()>;
+    async fn get_l1_info_tree_leaf(
+        &self,
+        leaf_index: u32,
+    ) -> anyhow::Result<Option<L1InfoTreeLeaf>>;
+    /// The leaf that committed `ger` (the contract appends each GER once).
+    async fn get_l1_info_tree_leaf_by_ger(
+        &self,
+        ger: &[u8; 32],
+    ) -> anyhow::Result<Option<L1InfoTreeLeaf>>;
+
+    // === Local exit tree ===
+    /// Folded exit tree; the empty tree at block 0 when nothing is stored.
+    async fn get_exit_tree_state(&self) -> anyhow::Result<ExitTreeState>;
+    /// Replace the stored state with `next` iff it still equals `expected`.
+    /// `false` when another caller advanced it first.
+    async fn advance_exit_tree_state(
+        &self,
+        expected: &ExitTreeState,
+        next: &ExitTreeState,
+    ) -> anyhow::Result<bool>;
+
     // === Synthetic projector cursor (synthetic-indexer redesign, Phase 2a) ===
     /// Last fully-projected Miden block height owned by the `SyntheticProjector`
     /// (`docs/SYNTHETIC-INDEXER-REDESIGN.md`). Returns 0 if the projector has
@@ -492,6 +624,32 @@ pub trait Store: Send + Sync + 'static {
         Ok(())
     }
 
+    // === Synthetic block timestamps (migration 022) ===
+    /// Persist real Miden block timestamps as `(block_number, unix_seconds)`.
+    /// Write-once: an existing row is never overwritten, because the timestamp
+    /// is part of the synthetic header hash and that hash may already have
+    /// been served. Written by `BlockTimestampRecorder` before anything hashes
+    /// the block.
+    async fn put_block_timestamps(&self, _entries: &[(u64, u64)]) -> anyhow::Result<()> {
+        Ok(())
+    }
+    /// Every persisted block timestamp, loaded into `BlockState` at startup.
+    /// Blocks without a row keep the legacy deterministic timestamp.
+    async fn get_block_timestamps(&self) -> anyhow::Result<Vec<(u64, u64)>> {
+        Ok(Vec::new())
+    }
+    /// Persist the `logsBloom` / `receiptsRoot` the projector sealed into
+    /// `block`. Only called for blocks above the exposed tip, so a re-seal
+    /// after a crash (same logs, same roots) may overwrite the row.
+    async fn put_block_roots(&self, _block: u64, _roots: BlockRoots) -> anyhow::Result<()> {
+        Ok(())
+    }
+    /// Every persisted set of block roots, loaded into `BlockState` at
+    /// startup. Blocks without a row keep the zero bloom / empty root.
+    async fn get_block_roots(&self) -> anyhow::Result<Vec<(u64, BlockRoots)>> {
+        Ok(Vec::new())
+    }
+
     /// #148 — readiness backlog: how many ClaimEvent-bearing synthetic
     /// transactions still have NO persisted `claimAsset` calldata envelope in
     /// the `transactions` table. In steady state this is 0 — a claim's envelope
@@ -601,6 +759,45 @@ pub trait Store: Send + Sync + 'static {
     /// transaction reaches a durable handoff or a terminal receipt (#156).
     async fn clear_recovery_backoff(&self, tx_hash: TxHash) -> anyhow::Result<()>;
 
+    /// Hand pending transactions whose writer job did not finish within the
+    /// shutdown drain to the next boot: clear their recovery backoff so the
+    /// first sweep re-drives them immediately, and flag them for the restart
+    /// accounting. Rows that are no longer pending are skipped. Returns the
+    /// number marked.
+    async fn mark_undrained_at_shutdown(&self, tx_hashes: &[TxHash]) -> anyhow::Result<u64>;
+
+    /// Count and clear the flags left by
+    /// [`Store::mark_undrained_at_shutdown`]. Called once at boot.
+    async fn take_undrained_at_shutdown(&self) -> anyhow::Result<u64>;
+
+    // === Admin keys and audit log ===
+
+    /// Admin keys provisioned in the store, merged at startup with
+    /// `--admin-api-key` and `--admin-keys-file`.
+    async fn list_admin_keys(&self) -> anyhow::Result<Vec<AdminKeyRecord>>;
+
+    /// Insert the key named `record.name`, replacing its hash and scope if it
+    /// already exists.
+    async fn put_admin_key(&self, record: AdminKeyRecord) -> anyhow::Result<()>;
+
+    /// Append one admin call to the audit log. Rows are never updated, and
+    /// the Postgres table only lets the retention role delete old ones.
+    async fn append_admin_audit(
+        &self,
+        key_name: Option<&str>,
+        method: &str,
+        params_digest: &str,
+        outcome: &str,
+    ) -> anyhow::Result<()>;
+
+    /// Newest-first page of the audit log: up to `limit` rows with an id
+    /// below `before_id` (all rows when `None`).
+    async fn list_admin_audit(
+        &self,
+        before_id: Option<u64>,
+        limit: usize,
+    ) -> anyhow::Result<Vec<AdminAuditEntry>>;
+
     /// Persist an exact note identity immediately before the external submit.
     async fn prepare_note_handoff(
         &self,
@@ -640,6 +837,18 @@ pub trait Store: Send + Sync + 'static {
         current_block: u64,
     ) -> anyhow::Result<Vec<SyntheticLog>>;
     async fn get_logs_for_tx(&self, tx_hash: &str) -> anyhow::Result<Vec<SyntheticLog>>;
+    /// Rewrite `blockHash` on every log of `block`, and set each log's
+    /// `transactionIndex` to its transaction's position in `tx_order`
+    /// (lowercase hashes; logs of other transactions keep theirs). Logs are
+    /// written with the block's provisional hash and index 0; the projector
+    /// stamps the final values (the hash covers the block's `logsBloom` /
+    /// `receiptsRoot`) just before the tip advance exposes the block.
+    async fn seal_logs(
+        &self,
+        block: u64,
+        block_hash: [u8; 32],
+        tx_order: &[String],
+    ) -> anyhow::Result<()>;
 
     // === GER state ===
     async fn has_seen_ger(&self, ger: &[u8; 32]) -> anyhow::Result<bool>;
@@ -664,6 +873,13 @@ pub trait Store: Send + Sync + 'static {
         l1_timestamp: u64,
     ) -> anyhow::Result<()>;
     async fn is_ger_injected(&self, ger: &[u8; 32]) -> anyhow::Result<bool>;
+    /// GERs the selected L1 scan recorded (both roots and the provenance
+    /// marker set) in the highest L1 block it recorded any in. Injected or
+    /// not; the built-in GER relayer filters on `is_ger_injected`.
+    async fn get_latest_l1_observed_gers(&self) -> anyhow::Result<Vec<[u8; 32]>>;
+    /// Current `UpdateHashChainValue` chain head (the GER manager's
+    /// `insertedGERHashChain()`); zero before the first injection.
+    async fn get_ger_hash_chain(&self) -> anyhow::Result<[u8; 32]>;
     /// Atomically, in a single all-or-nothing operation: mark the GER seen,
     /// idempotently roll the hash chain + emit the `UpdateHashChainValue`
     /// synthetic log, and set `is_injected = TRUE`.
@@ -694,6 +910,39 @@ pub trait Store: Send + Sync + 'static {
         rollup_exit_root: Option<[u8; 32]>,
         timestamp: u64,
     ) -> anyhow::Result<()>;
+    /// Current `UpdateRemovalHashChainValue` chain head (the GER manager's
+    /// `removedGERHashChain()`); zero before the first removal.
+    async fn get_ger_removal_hash_chain(&self) -> anyhow::Result<[u8; 32]>;
+    /// Removal counterpart of `commit_ger_event_atomic`, for a consumed
+    /// `RemoveGerNote`. Atomically: confirm the note handoff, idempotently
+    /// roll the removal hash chain + emit the `RemoveLastGlobalExitRoot` and
+    /// `UpdateRemovalHashChainValue` synthetic logs, set `is_injected = FALSE`
+    /// and finalise a linked transaction row as successful.
+    ///
+    /// Same idempotency contract as the injection path: the roll is gated on
+    /// whether a synthetic log with `tx_hash` already exists, so a replay
+    /// never rolls the removal chain twice.
+    async fn commit_ger_removal_atomic(
+        &self,
+        block_number: u64,
+        block_hash: [u8; 32],
+        tx_hash: &str,
+        global_exit_root: &[u8; 32],
+    ) -> anyhow::Result<()>;
+    /// Record that transaction `tx_hash` removes `global_exit_root`. The
+    /// writer calls it before building the `RemoveGerNote`; idempotent per
+    /// hash.
+    async fn record_ger_removal(
+        &self,
+        tx_hash: TxHash,
+        global_exit_root: &[u8; 32],
+    ) -> anyhow::Result<()>;
+    /// A recorded removal of `global_exit_root` whose transaction is still
+    /// pending — neither projected nor failed — if any.
+    async fn pending_ger_removal(
+        &self,
+        global_exit_root: &[u8; 32],
+    ) -> anyhow::Result<Option<TxHash>>;
 
     // === Transactions ===
     async fn txn_begin(&self, tx_hash: TxHash, entry: TxnEntry) -> anyhow::Result<()>;
@@ -721,6 +970,9 @@ pub trait Store: Send + Sync + 'static {
         tx_hash: TxHash,
     ) -> anyhow::Result<Option<(Result<(), String>, u64)>>;
     async fn txn_get(&self, tx_hash: TxHash) -> anyhow::Result<Option<TxnData>>;
+    /// Hashes of every transaction with a terminal receipt (success or
+    /// failure) at `block`, in hash order. Pending transactions are excluded.
+    async fn txn_hashes_at_block(&self, block: u64) -> anyhow::Result<Vec<TxHash>>;
     /// Return the durable pending-nonce boundary for `addr`. Unlike the writer
     /// DashMap, this survives restart and covers both sync and async admission.
     async fn pending_nonce_frontier(&self, addr: &str) -> anyhow::Result<PendingNonceFrontier>;
@@ -1101,6 +1353,22 @@ pub trait Store: Send + Sync + 'static {
         Ok(None)
     }
 
+    /// Up to `limit` quarantined B2AGGs ordered by `note_id`, starting after
+    /// `after_note_id` (from the first row when `None`).
+    async fn list_unbridgeable_bridge_outs(
+        &self,
+        _after_note_id: Option<&str>,
+        _limit: usize,
+    ) -> anyhow::Result<Vec<UnbridgeableBridgeOut>> {
+        Ok(Vec::new())
+    }
+
+    /// Drop a quarantine row once its note has been re-projected. Returns
+    /// `true` if a row was removed.
+    async fn delete_unbridgeable_bridge_out(&self, _note_id: &str) -> anyhow::Result<bool> {
+        Ok(false)
+    }
+
     // === Claim watcher ===
     //
     // Tracks consumed CLAIM notes the `claim_watcher` SyncListener has already
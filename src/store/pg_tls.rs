@@ -0,0 +1,498 @@
+//! TLS for the Postgres store and migrator.
+//!
+//! `tokio-postgres` only understands `sslmode=disable|prefer|require` and
+//! rejects the libpq certificate parameters (`sslrootcert`, `sslcert`,
+//! `sslkey`) and the `verify-ca` / `verify-full` modes outright, so a managed
+//! Postgres connection string copied from a cloud console fails to parse. This
+//! module accepts the libpq vocabulary in `DATABASE_URL`, strips it before the
+//! string reaches `tokio_postgres::Config`, and builds a rustls connector that
+//! both `PgStore::new` and `migrator::run_migrations` hand to the driver — so
+//! the pool and the migration connection negotiate TLS identically.
+//!
+//! ## Verification posture
+//!
+//! Deliberately stricter than libpq: rustls never completes a handshake with an
+//! unverified chain, so `require` and `verify-ca` both verify the server chain
+//! against the configured CA bundle (or the system roots when none is set) and
+//! skip only the hostname check; `verify-full` also checks the hostname. An
+//! encrypted-but-unauthenticated connection is not a security boundary, so there
+//! is no "encrypt without verifying" mode. `prefer` (the driver default when
+//! `sslmode` is absent) negotiates TLS when the server offers it — with the same
+//! chain verification — and falls back to plaintext only when the server does not
+//! offer TLS at all. `--require-hardening` therefore refuses `disable` and
+//! `prefer` (see [`PgSslMode::refuses_plaintext`]).
+//!
+//! The parameter parsing is feature-independent so `main.rs` can evaluate the
+//! hardening invariant in any build; only the connector needs the `postgres`
+//! feature.
+
+use std::path::PathBuf;
+
+/// Operator-supplied TLS material (`--database-ca-cert`,
+/// `--database-client-cert`, `--database-client-key`). Each field overrides the
+/// matching libpq parameter in `DATABASE_URL` when both are present.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PgTlsOptions {
+    /// PEM bundle of CA certificates trusted for the server chain. `None` =
+    /// the platform's native root store.
+    pub ca_cert: Option<PathBuf>,
+    /// PEM client certificate chain for mutual TLS.
+    pub client_cert: Option<PathBuf>,
+    /// PEM private key (PKCS#8, PKCS#1 or SEC1) matching `client_cert`.
+    pub client_key: Option<PathBuf>,
+}
+
+impl PgTlsOptions {
+    /// Fill any unset field from the libpq parameters found in the URL.
+    fn merged_with(&self, url_params: &UrlTlsParams) -> Self {
+        Self {
+            ca_cert: self
+                .ca_cert
+                .clone()
+                .or_else(|| url_params.root_cert.clone().map(PathBuf::from)),
+            client_cert: self
+                .client_cert
+                .clone()
+                .or_else(|| url_params.cert.clone().map(PathBuf::from)),
+            client_key: self
+                .client_key
+                .clone()
+                .or_else(|| url_params.key.clone().map(PathBuf::from)),
+        }
+    }
+}
+
+/// libpq `sslmode` values understood by the store. `allow` is not accepted: it
+/// prefers plaintext, which no deployment of this service should want.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PgSslMode {
+    Disable,
+    /// The libpq / tokio-postgres default when `sslmode` is absent.
+    #[default]
+    Prefer,
+    Require,
+    VerifyCa,
+    VerifyFull,
+}
+
+impl PgSslMode {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "disable" => Some(Self::Disable),
+            "prefer" => Some(Self::Prefer),
+            "require" => Some(Self::Require),
+            "verify-ca" => Some(Self::VerifyCa),
+            "verify-full" => Some(Self::VerifyFull),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Disable => "disable",
+            Self::Prefer => "prefer",
+            Self::Require => "require",
+            Self::VerifyCa => "verify-ca",
+            Self::VerifyFull => "verify-full",
+        }
+    }
+
+    /// True when the driver can never fall back to a plaintext session.
+    pub fn refuses_plaintext(&self) -> bool {
+        matches!(self, Self::Require | Self::VerifyCa | Self::VerifyFull)
+    }
+
+    /// Whether the server certificate must match the connection host.
+    fn verifies_hostname(&self) -> bool {
+        matches!(self, Self::VerifyFull)
+    }
+}
+
+/// libpq TLS parameters lifted out of a connection string.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UrlTlsParams {
+    pub ssl_mode: Option<PgSslMode>,
+    pub root_cert: Option<String>,
+    pub cert: Option<String>,
+    pub key: Option<String>,
+}
+
+impl UrlTlsParams {
+    /// Absorb one parameter. Returns `Ok(false)` for a non-TLS key the caller
+    /// must keep in the sanitised connection string.
+    fn absorb(&mut self, key: &str, value: &str) -> anyhow::Result<bool> {
+        match key {
+            "sslmode" => {
+                let mode = PgSslMode::parse(value).ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "DATABASE_URL sslmode `{value}` is not supported (expected one of \
+                         `disable`, `prefer`, `require`, `verify-ca`, `verify-full`)"
+                    )
+                })?;
+                self.ssl_mode = Some(mode);
+            }
+            "sslrootcert" => self.root_cert = Some(value.to_string()),
+            "sslcert" => self.cert = Some(value.to_string()),
+            "sslkey" => self.key = Some(value.to_string()),
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+}
+
+/// The effective `sslmode` of a `DATABASE_URL`. Used by the `--require-hardening`
+/// gate, which must run before any connection is attempted.
+pub fn database_ssl_mode(database_url: &str) -> anyhow::Result<PgSslMode> {
+    let (_, params) = split_tls_params(database_url)?;
+    Ok(params.ssl_mode.unwrap_or_default())
+}
+
+/// Split a connection string (URL or libpq key/value form) into the string
+/// `tokio_postgres::Config` can parse and the TLS parameters it would reject.
+pub fn split_tls_params(database_url: &str) -> anyhow::Result<(String, UrlTlsParams)> {
+    if database_url.starts_with("postgres://") || database_url.starts_with("postgresql://") {
+        split_url_form(database_url)
+    } else {
+        split_key_value_form(database_url)
+    }
+}
+
+/// URL form. Only the query string is touched: `url::Url` cannot parse the
+/// multi-host authority (`h1:5432,h2:5433`) Postgres URLs allow, so the
+/// authority is passed through verbatim.
+fn split_url_form(database_url: &str) -> anyhow::Result<(String, UrlTlsParams)> {
+    let mut params = UrlTlsParams::default();
+    let Some((base, query)) = database_url.split_once('?') else {
+        return Ok((database_url.to_string(), params));
+    };
+    let mut kept = Vec::new();
+    for segment in query.split('&').filter(|s| !s.is_empty()) {
+        let (key, value) = url::form_urlencoded::parse(segment.as_bytes())
+            .next()
+            .unwrap_or_default();
+        if !params.absorb(&key, &value)? {
+            kept.push(segment);
+        }
+    }
+    let sanitised = if kept.is_empty() {
+        base.to_string()
+    } else {
+        format!("{base}?{}", kept.join("&"))
+    };
+    Ok((sanitised, params))
+}
+
+/// libpq key/value form (`host=db user=x sslmode=require`). Values may be
+/// single-quoted with backslash escapes; kept pairs are re-emitted with their
+/// original quoting so passwords containing spaces survive.
+fn split_key_value_form(database_url: &str) -> anyhow::Result<(String, UrlTlsParams)> {
+    let mut params = UrlTlsParams::default();
+    let mut kept = Vec::new();
+    let mut rest = database_url.trim_start();
+    while !rest.is_empty() {
+        let eq = rest
+            .find('=')
+            .ok_or_else(|| anyhow::anyhow!("malformed DATABASE_URL: expected `key=value` pairs"))?;
+        let key = rest[..eq].trim();
+        let after = rest[eq + 1..].trim_start();
+        let (value, raw_len) = if let Some(quoted) = after.strip_prefix('\'') {
+            let mut value = String::new();
+            let mut end = None;
+            let mut chars = quoted.char_indices();
+            while let Some((i, c)) = chars.next() {
+                match c {
+                    '\\' => {
+                        if let Some((_, escaped)) = chars.next() {
+                            value.push(escaped);
+                        }
+                    }
+                    '\'' => {
+                        end = Some(i);
+                        break;
+                    }
+                    c => value.push(c),
+                }
+            }
+            let end = end.ok_or_else(|| {
+                anyhow::anyhow!("malformed DATABASE_URL: unterminated quoted value for `{key}`")
+            })?;
+            (value, end + 2)
+        } else {
+            let end = after.find(char::is_whitespace).unwrap_or(after.len());
+            (after[..end].to_string(), end)
+        };
+        if !params.absorb(key, &value)? {
+            kept.push(format!("{key}={}", &after[..raw_len]));
+        }
+        rest = after[raw_len..].trim_start();
+    }
+    Ok((kept.join(" "), params))
+}
+
+#[cfg(feature = "postgres")]
+pub use connector::{PgConnectionConfig, resolve_connection};
+
+#[cfg(feature = "postgres")]
+mod connector {
+    use super::{PgSslMode, PgTlsOptions, split_tls_params};
+    use anyhow::Context;
+    use rustls::client::WebPkiServerVerifier;
+    use rustls::client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier};
+    use rustls::pki_types::pem::PemObject;
+    use rustls::pki_types::{CertificateDer, PrivateKeyDer, ServerName, UnixTime};
+    use rustls::{CertificateError, DigitallySignedStruct, RootCertStore, SignatureScheme};
+    use std::sync::Arc;
+    use tokio_postgres_rustls::MakeRustlsConnect;
+
+    /// Everything needed to open a connection: the driver config with the
+    /// effective `sslmode` applied, and the connector to hand to
+    /// `deadpool_postgres::Manager::new` / `tokio_postgres::Config::connect`.
+    pub struct PgConnectionConfig {
+        pub config: tokio_postgres::Config,
+        pub ssl_mode: PgSslMode,
+        pub tls: MakeRustlsConnect,
+    }
+
+    /// Resolve `DATABASE_URL` + the operator's TLS flags into a connection
+    /// config. Certificate files are read here, so a missing or malformed CA
+    /// bundle / client key fails startup instead of the first query.
+    pub fn resolve_connection(
+        database_url: &str,
+        tls: &PgTlsOptions,
+    ) -> anyhow::Result<PgConnectionConfig> {
+        let (sanitised, url_params) = split_tls_params(database_url)?;
+        let ssl_mode = url_params.ssl_mode.unwrap_or_default();
+        let tls = tls.merged_with(&url_params);
+
+        let mut config: tokio_postgres::Config = sanitised
+            .parse()
+            .context("parsing DATABASE_URL (TLS parameters removed)")?;
+        config.ssl_mode(match ssl_mode {
+            PgSslMode::Disable => tokio_postgres::config::SslMode::Disable,
+            PgSslMode::Prefer => tokio_postgres::config::SslMode::Prefer,
+            PgSslMode::Require | PgSslMode::VerifyCa | PgSslMode::VerifyFull => {
+                tokio_postgres::config::SslMode::Require
+            }
+        });
+
+        let client_config = build_client_config(ssl_mode, &tls)?;
+        Ok(PgConnectionConfig {
+            config,
+            ssl_mode,
+            tls: MakeRustlsConnect::new(client_config),
+        })
+    }
+
+    fn build_client_config(
+        ssl_mode: PgSslMode,
+        tls: &PgTlsOptions,
+    ) -> anyhow::Result<rustls::ClientConfig> {
+        let provider = Arc::new(rustls::crypto::ring::default_provider());
+        let roots = Arc::new(load_roots(ssl_mode, tls)?);
+        let builder = rustls::ClientConfig::builder_with_provider(provider.clone())
+            .with_safe_default_protocol_versions()
+            .context("building rustls protocol versions for Postgres TLS")?;
+        let webpki = WebPkiServerVerifier::builder_with_provider(roots, provider)
+            .build()
+            .context("building Postgres server-certificate verifier")?;
+        let builder = if ssl_mode.verifies_hostname() {
+            builder.with_webpki_verifier(webpki)
+        } else {
+            builder
+                .dangerous()
+                .with_custom_certificate_verifier(Arc::new(ChainOnlyVerifier { inner: webpki }))
+        };
+
+        match (&tls.client_cert, &tls.client_key) {
+            (Some(cert_path), Some(key_path)) => {
+                let chain = CertificateDer::pem_file_iter(cert_path)
+                    .with_context(|| format!("reading client cert {}", cert_path.display()))?
+                    .collect::<Result<Vec<_>, _>>()
+                    .with_context(|| format!("parsing client cert {}", cert_path.display()))?;
+                let key = PrivateKeyDer::from_pem_file(key_path)
+                    .with_context(|| format!("reading client key {}", key_path.display()))?;
+                builder
+                    .with_client_auth_cert(chain, key)
+                    .context("client certificate does not match its private key")
+            }
+            (None, None) => Ok(builder.with_no_client_auth()),
+            _ => anyhow::bail!(
+                "Postgres mutual TLS needs BOTH a client certificate and a client key \
+                 (--database-client-cert / --database-client-key, or sslcert / sslkey)"
+            ),
+        }
+    }
+
+    fn load_roots(ssl_mode: PgSslMode, tls: &PgTlsOptions) -> anyhow::Result<RootCertStore> {
+        let mut roots = RootCertStore::empty();
+        if ssl_mode == PgSslMode::Disable {
+            // The connector is never invoked; do not touch the filesystem.
+            return Ok(roots);
+        }
+        if let Some(ca_path) = &tls.ca_cert {
+            for cert in CertificateDer::pem_file_iter(ca_path)
+                .with_context(|| format!("reading CA bundle {}", ca_path.display()))?
+            {
+                let cert =
+                    cert.with_context(|| format!("parsing CA bundle {}", ca_path.display()))?;
+                roots
+                    .add(cert)
+                    .with_context(|| format!("adding CA from {}", ca_path.display()))?;
+            }
+            if roots.is_empty() {
+                anyhow::bail!("CA bundle {} contains no certificates", ca_path.display());
+            }
+        } else {
+            let native = rustls_native_certs::load_native_certs();
+            for err in &native.errors {
+                tracing::warn!(error = %err, "Postgres TLS: skipping unreadable native root");
+            }
+            let (added, ignored) = roots.add_parsable_certificates(native.certs);
+            tracing::debug!(added, ignored, "Postgres TLS: loaded native root store");
+        }
+        Ok(roots)
+    }
+
+    /// libpq `verify-ca` / `require` semantics on top of rustls: the full chain
+    /// is verified against the trust anchors, only the hostname mismatch is
+    /// forgiven. `WebPkiServerVerifier` checks the name AFTER the chain, so a
+    /// name error implies the chain already verified.
+    #[derive(Debug)]
+    struct ChainOnlyVerifier {
+        inner: Arc<WebPkiServerVerifier>,
+    }
+
+    impl ServerCertVerifier for ChainOnlyVerifier {
+        fn verify_server_cert(
+            &self,
+            end_entity: &CertificateDer<'_>,
+            intermediates: &[CertificateDer<'_>],
+            server_name: &ServerName<'_>,
+            ocsp_response: &[u8],
+            now: UnixTime,
+        ) -> Result<ServerCertVerified, rustls::Error> {
+            match self.inner.verify_server_cert(
+                end_entity,
+                intermediates,
+                server_name,
+                ocsp_response,
+                now,
+            ) {
+                Err(rustls::Error::InvalidCertificate(
+                    CertificateError::NotValidForName
+                    | CertificateError::NotValidForNameContext { .. },
+                )) => Ok(ServerCertVerified::assertion()),
+                other => other,
+            }
+        }
+
+        fn verify_tls12_signature(
+            &self,
+            message: &[u8],
+            cert: &CertificateDer<'_>,
+            dss: &DigitallySignedStruct,
+        ) -> Result<HandshakeSignatureValid, rustls::Error> {
+            self.inner.verify_tls12_signature(message, cert, dss)
+        }
+
+        fn verify_tls13_signature(
+            &self,
+            message: &[u8],
+            cert: &CertificateDer<'_>,
+            dss: &DigitallySignedStruct,
+        ) -> Result<HandshakeSignatureValid, rustls::Error> {
+            self.inner.verify_tls13_signature(message, cert, dss)
+        }
+
+        fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+            self.inner.supported_verify_schemes()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn url_form_strips_libpq_tls_params_and_keeps_the_rest() {
+        let (clean, p) = split_tls_params(
+            "postgres://u:p@db.example.com:5432/store?sslmode=verify-full\
+             &application_name=agglayer&sslrootcert=%2Fetc%2Fca.pem",
+        )
+        .unwrap();
+        assert_eq!(
+            clean,
+            "postgres://u:p@db.example.com:5432/store?application_name=agglayer"
+        );
+        assert_eq!(p.ssl_mode, Some(PgSslMode::VerifyFull));
+        assert_eq!(p.root_cert.as_deref(), Some("/etc/ca.pem"));
+    }
+
+    #[test]
+    fn url_form_multi_host_authority_is_untouched() {
+        let (clean, p) =
+            split_tls_params("postgresql://u@h1:5432,h2:5433/db?sslmode=require").unwrap();
+        assert_eq!(clean, "postgresql://u@h1:5432,h2:5433/db");
+        assert_eq!(p.ssl_mode, Some(PgSslMode::Require));
+    }
+
+    #[test]
+    fn key_value_form_preserves_quoted_values() {
+        let (clean, p) = split_tls_params(
+            "host=agglayer-postgres password='pa ss\\'wd' sslmode=verify-ca sslcert=/c.pem \
+             sslkey = /k.pem dbname=agglayer_store",
+        )
+        .unwrap();
+        assert_eq!(
+            clean,
+            "host=agglayer-postgres password='pa ss\\'wd' dbname=agglayer_store"
+        );
+        assert_eq!(p.ssl_mode, Some(PgSslMode::VerifyCa));
+        assert_eq!(p.cert.as_deref(), Some("/c.pem"));
+        assert_eq!(p.key.as_deref(), Some("/k.pem"));
+    }
+
+    #[test]
+    fn missing_sslmode_defaults_to_prefer_and_unknown_mode_is_rejected() {
+        assert_eq!(
+            database_ssl_mode("host=db user=x").unwrap(),
+            PgSslMode::Prefer
+        );
+        assert!(database_ssl_mode("postgres://db/x?sslmode=allow").is_err());
+        assert!(database_ssl_mode("host=db password='unterminated").is_err());
+    }
+
+    /// `--require-hardening` contract: only the modes that can never fall back
+    /// to a plaintext session pass.
+    #[test]
+    fn only_encrypting_modes_refuse_plaintext() {
+        for (mode, refuses) in [
+            (PgSslMode::Disable, false),
+            (PgSslMode::Prefer, false),
+            (PgSslMode::Require, true),
+            (PgSslMode::VerifyCa, true),
+            (PgSslMode::VerifyFull, true),
+        ] {
+            assert_eq!(mode.refuses_plaintext(), refuses, "{}", mode.as_str());
+            assert_eq!(PgSslMode::parse(mode.as_str()), Some(mode));
+        }
+    }
+
+    #[test]
+    fn flags_override_url_cert_params() {
+        let flags = PgTlsOptions {
+            ca_cert: Some("/flag/ca.pem".into()),
+            ..Default::default()
+        };
+        let url = UrlTlsParams {
+            root_cert: Some("/url/ca.pem".into()),
+            cert: Some("/url/c.pem".into()),
+            ..Default::default()
+        };
+        let merged = flags.merged_with(&url);
+        assert_eq!(merged.ca_cert, Some("/flag/ca.pem".into()));
+        assert_eq!(merged.client_cert, Some("/url/c.pem".into()));
+        assert_eq!(merged.client_key, None);
+    }
+}
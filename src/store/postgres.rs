@@ -4,13 +4,15 @@
 //! with the schema from `migrations/001_initial.sql` applied.
 
 use super::{
-    ClaimFence, FaucetEntry, NoteHandoff, NoteHandoffState, PendingNonceFrontier,
-    RecoverablePendingTxn, Store, TxnData, TxnEntry, UnbridgeableBridgeOut,
+    AdminAuditEntry, AdminKeyRecord, ClaimFence, ExitTreeState, FaucetEntry, L1EvidenceRewind,
+    L1InfoTreeLeaf, L1InfoTreeState, L1ScannedRange, NoteHandoff, NoteHandoffState,
+    PendingNonceFrontier, RecoverablePendingTxn, Store, TxnData, TxnEntry, UnbridgeableBridgeOut,
     UnbridgeableBridgeOutReason, UnclaimableClaim, UnclaimableReason,
 };
 use crate::bridge_address::get_bridge_address;
 use crate::log_synthesis::{
-    GerEntry, L2_GLOBAL_EXIT_ROOT_ADDRESS, LogFilter, SyntheticLog, UPDATE_HASH_CHAIN_VALUE_TOPIC,
+    GerEntry, L2_GLOBAL_EXIT_ROOT_ADDRESS, LogFilter, REMOVE_LAST_GLOBAL_EXIT_ROOT_TOPIC,
+    SyntheticLog, UPDATE_HASH_CHAIN_VALUE_TOPIC, UPDATE_REMOVAL_HASH_CHAIN_VALUE_TOPIC,
 };
 use alloy::consensus::TxEnvelope;
 use alloy::eips::Encodable2718;
@@ -37,14 +39,23 @@ fn bytes_to_array_32(bytes: &[u8]) -> [u8; 32] {
 }
 
 impl PgStore {
-    pub async fn new(database_url: &str) -> anyhow::Result<Self> {
-        let config: tokio_postgres::Config = database_url.parse()?;
-        let manager = Manager::new(config, tokio_postgres::NoTls);
+    /// Connect the pool. `tls` carries the operator's CA / client-cert flags;
+    /// the libpq `sslmode` / `ssl*` parameters in `database_url` are honoured
+    /// too (see `store::pg_tls`).
+    pub async fn new(
+        database_url: &str,
+        tls: &super::pg_tls::PgTlsOptions,
+    ) -> anyhow::Result<Self> {
+        let conn = super::pg_tls::resolve_connection(database_url, tls)?;
+        let manager = Manager::new(conn.config, conn.tls);
         let pool = Pool::builder(manager).max_size(16).build()?;
 
-        // Verify connectivity
+        // Verify connectivity (and, with TLS, the handshake + certificate).
         let _client = pool.get().await?;
-        tracing::info!("PgStore: connected to PostgreSQL");
+        tracing::info!(
+            sslmode = conn.ssl_mode.as_str(),
+            "PgStore: connected to PostgreSQL"
+        );
 
         Ok(Self { pool })
     }
@@ -180,6 +191,295 @@ impl Store for PgStore {
         Ok(())
     }
 
+    // ── L1 reorg handling (migration 028) ────────────────────────
+
+    async fn record_l1_scanned_range(
+        &self,
+        range: L1ScannedRange,
+        retain: usize,
+    ) -> anyhow::Result<()> {
+        let mut client = self.pool.get().await?;
+        let tx = client.transaction().await?;
+        tx.execute(
+            "INSERT INTO l1_scanned_ranges (range_end, range_start, end_hash)
+             VALUES ($1, $2, $3)
+             ON CONFLICT (range_end) DO UPDATE
+                SET range_start = EXCLUDED.range_start, end_hash = EXCLUDED.end_hash,
+                    recorded_at = now()",
+            &[
+                &i64::try_from(range.end)?,
+                &i64::try_from(range.start)?,
+                &range.end_hash.as_slice(),
+            ],
+        )
+        .await?;
+        tx.execute(
+            "DELETE FROM l1_scanned_ranges
+              WHERE range_end NOT IN (
+                    SELECT range_end FROM l1_scanned_ranges ORDER BY range_end DESC LIMIT $1)",
+            &[&i64::try_from(retain)?],
+        )
+        .await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn list_l1_scanned_ranges(&self) -> anyhow::Result<Vec<L1ScannedRange>> {
+        let client = self.pool.get().await?;
+        let rows = client
+            .query(
+                "SELECT range_start, range_end, end_hash FROM l1_scanned_ranges
+                  ORDER BY range_end DESC",
+                &[],
+            )
+            .await?;
+        rows.into_iter()
+            .map(|row| {
+                Ok(L1ScannedRange {
+                    start: u64::try_from(row.get::<_, i64>(0))?,
+                    end: u64::try_from(row.get::<_, i64>(1))?,
+                    end_hash: bytes_to_array_32(row.get(2)),
+                })
+            })
+            .collect()
+    }
+
+    async fn rewind_l1_evidence(&self, fork_block: u64) -> anyhow::Result<L1EvidenceRewind> {
+        let fork = i64::try_from(fork_block)?;
+        let mut client = self.pool.get().await?;
+        let tx = client.transaction().await?;
+        tx.execute(
+            "DELETE FROM l1_scanned_ranges WHERE range_end > $1",
+            &[&fork],
+        )
+        .await?;
+        let unverified_gers = tx
+            .execute(
+                "UPDATE ger_entries SET finalized_verified = FALSE
+                  WHERE finalized_verified AND block_number > $1",
+                &[&fork],
+            )
+            .await?;
+
+        let tree = tx
+            .query_one(
+                "SELECT leaf_count, verified_leaf_count FROM l1_info_tree_state WHERE id = 1 FOR UPDATE",
+                &[],
+            )
+            .await?;
+        let removed_leaves = tx
+            .execute(
+                "DELETE FROM l1_info_tree_leaves WHERE block_number > $1",
+                &[&fork],
+            )
+            .await?;
+        if removed_leaves > 0 {
+            let rows = tx
+                .query(
+                    "SELECT ger, previous_block_hash, timestamp FROM l1_info_tree_leaves
+                      ORDER BY leaf_index",
+                    &[],
+                )
+                .await?;
+            let mut frontier = crate::l1_info_tree::Frontier::default();
+            for row in &rows {
+                frontier.push(crate::l1_info_tree::leaf_value(
+                    &bytes_to_array_32(row.get(0)),
+                    &bytes_to_array_32(row.get(1)),
+                    u64::try_from(row.get::<_, i64>(2))?,
+                ))?;
+            }
+            let stored: i64 = tree.get(0);
+            anyhow::ensure!(
+                i64::from(frontier.leaf_count()) + removed_leaves as i64 == stored,
+                "L1 info tree rewind left a non-contiguous tree"
+            );
+            let verified: i64 = tree.get(1);
+            tx.execute(
+                "UPDATE l1_info_tree_state
+                    SET leaf_count = $1, frontier = $2, verified_leaf_count = $3, updated_at = now()
+                  WHERE id = 1",
+                &[
+                    &i64::from(frontier.leaf_count()),
+                    &frontier.branch_bytes(),
+                    &verified.min(i64::from(frontier.leaf_count())),
+                ],
+            )
+            .await?;
+        }
+        tx.execute(
+            "UPDATE l1_indexer_state SET finalized_scan_cursor = $1, updated_at = now() WHERE id = 1",
+            &[&fork],
+        )
+        .await?;
+        tx.commit().await?;
+        Ok(L1EvidenceRewind {
+            unverified_gers,
+            removed_leaves,
+        })
+    }
+
+    // ── Reconstructed L1 info tree (migration 027) ───────────────
+
+    async fn get_l1_info_tree_state(&self) -> anyhow::Result<L1InfoTreeState> {
+        let client = self.pool.get().await?;
+        let row = client
+            .query_opt(
+                "SELECT leaf_count, frontier, verified_leaf_count FROM l1_info_tree_state WHERE id = 1",
+                &[],
+            )
+            .await?;
+        let Some(row) = row else {
+            return Ok(L1InfoTreeState::default());
+        };
+        let leaf_count = u32::try_from(row.get::<_, i64>(0))?;
+        Ok(L1InfoTreeState {
+            frontier: crate::l1_info_tree::Frontier::from_branch_bytes(leaf_count, row.get(1))?,
+            verified_leaf_count: u32::try_from(row.get::<_, i64>(2))?,
+        })
+    }
+
+    async fn append_l1_info_tree_leaves(
+        &self,
+        leaves: &[L1InfoTreeLeaf],
+        frontier: &crate::l1_info_tree::Frontier,
+    ) -> anyhow::Result<()> {
+        let mut client = self.pool.get().await?;
+        let tx = client.transaction().await?;
+        let count: i64 = tx
+            .query_one(
+                "SELECT leaf_count FROM l1_info_tree_state WHERE id = 1 FOR UPDATE",
+                &[],
+            )
+            .await?
+            .get(0);
+        let continues = leaves
+            .iter()
+            .zip(count..)
+            .all(|(leaf, index)| i64::from(leaf.leaf_index) == index)
+            && i64::from(frontier.leaf_count()) == count + leaves.len() as i64;
+        if !continues {
+            tx.rollback().await?;
+            anyhow::bail!(
+                "L1 info tree append of {} leaves does not continue the stored {count}",
+                leaves.len()
+            );
+        }
+        for leaf in leaves {
+            tx.execute(
+                "INSERT INTO l1_info_tree_leaves
+                    (leaf_index, ger, previous_block_hash, timestamp, block_number, root)
+                 VALUES ($1, $2, $3, $4, $5, $6)",
+                &[
+                    &i64::from(leaf.leaf_index),
+                    &leaf.ger.as_slice(),
+                    &leaf.previous_block_hash.as_slice(),
+                    &i64::try_from(leaf.timestamp)?,
+                    &i64::try_from(leaf.block_number)?,
+                    &leaf.root.as_slice(),
+                ],
+            )
+            .await?;
+        }
+        tx.execute(
+            "UPDATE l1_info_tree_state SET leaf_count = $1, frontier = $2, updated_at = now() WHERE id = 1",
+            &[&i64::from(frontier.leaf_count()), &frontier.branch_bytes()],
+        )
+        .await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn set_l1_info_tree_verified(&self, leaf_count: u32) -> anyhow::Result<()> {
+        let client = self.pool.get().await?;
+        client
+            .execute(
+                "UPDATE l1_info_tree_state
+                    SET verified_leaf_count = GREATEST(verified_leaf_count, $1), updated_at = now()
+                  WHERE id = 1",
+                &[&i64::from(leaf_count)],
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn get_l1_info_tree_leaf(
+        &self,
+        leaf_index: u32,
+    ) -> anyhow::Result<Option<L1InfoTreeLeaf>> {
+        let client = self.pool.get().await?;
+        client
+            .query_opt(
+                "SELECT leaf_index, ger, previous_block_hash, timestamp, block_number, root
+                   FROM l1_info_tree_leaves WHERE leaf_index = $1",
+                &[&i64::from(leaf_index)],
+            )
+            .await?
+            .as_ref()
+            .map(l1_info_tree_leaf_from_row)
+            .transpose()
+    }
+
+    async fn get_l1_info_tree_leaf_by_ger(
+        &self,
+        ger: &[u8; 32],
+    ) -> anyhow::Result<Option<L1InfoTreeLeaf>> {
+        let client = self.pool.get().await?;
+        client
+            .query_opt(
+                "SELECT leaf_index, ger, previous_block_hash, timestamp, block_number, root
+                   FROM l1_info_tree_leaves WHERE ger = $1
+                  ORDER BY leaf_index LIMIT 1",
+                &[&ger.as_slice()],
+            )
+            .await?
+            .as_ref()
+            .map(l1_info_tree_leaf_from_row)
+            .transpose()
+    }
+
+    async fn get_exit_tree_state(&self) -> anyhow::Result<ExitTreeState> {
+        let client = self.pool.get().await?;
+        let row = client
+            .query_opt(
+                "SELECT leaf_count, frontier, next_block FROM exit_tree_state WHERE id = 1",
+                &[],
+            )
+            .await?;
+        let Some(row) = row else {
+            return Ok(ExitTreeState::default());
+        };
+        let leaf_count = u32::try_from(row.get::<_, i64>(0))?;
+        Ok(ExitTreeState {
+            frontier: crate::l1_info_tree::Frontier::from_branch_bytes(leaf_count, row.get(1))?,
+            next_block: u64::try_from(row.get::<_, i64>(2))?,
+        })
+    }
+
+    async fn advance_exit_tree_state(
+        &self,
+        expected: &ExitTreeState,
+        next: &ExitTreeState,
+    ) -> anyhow::Result<bool> {
+        let client = self.pool.get().await?;
+        let updated = client
+            .execute(
+                "UPDATE exit_tree_state
+                    SET leaf_count = $1, frontier = $2, next_block = $3, updated_at = now()
+                  WHERE id = 1 AND leaf_count = $4 AND frontier = $5 AND next_block = $6",
+                &[
+                    &i64::from(next.frontier.leaf_count()),
+                    &next.frontier.branch_bytes(),
+                    &i64::try_from(next.next_block)?,
+                    &i64::from(expected.frontier.leaf_count()),
+                    &expected.frontier.branch_bytes(),
+                    &i64::try_from(expected.next_block)?,
+                ],
+            )
+            .await?;
+        Ok(updated == 1)
+    }
+
     // ── Synthetic projector cursor (Phase 2a) ────────────────────
     //
     // Persisted as a column on the single-row service_state table, mirroring
@@ -238,6 +538,100 @@ impl Store for PgStore {
         Ok(())
     }
 
+    // ── Synthetic block timestamps ───────────────────────────────
+    //
+    // One write-once row per recorded Miden block (migration 022). The batch
+    // goes in one transaction so a partial failure never leaves a hole that a
+    // later retry would fill with a different hash-chain history.
+
+    async fn put_block_timestamps(&self, entries: &[(u64, u64)]) -> anyhow::Result<()> {
+        if entries.is_empty() {
+            return Ok(());
+        }
+        let mut client = self.pool.get().await?;
+        let tx = client.transaction().await?;
+        for &(block, timestamp) in entries {
+            tx.execute(
+                "INSERT INTO synthetic_blocks (block_number, timestamp) VALUES ($1, $2) \
+                 ON CONFLICT (block_number) DO NOTHING",
+                &[&(block as i64), &(timestamp as i64)],
+            )
+            .await?;
+        }
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn get_block_timestamps(&self) -> anyhow::Result<Vec<(u64, u64)>> {
+        let client = self.pool.get().await?;
+        let rows = client
+            .query(
+                "SELECT block_number, timestamp FROM synthetic_blocks \
+                 WHERE timestamp IS NOT NULL ORDER BY block_number",
+                &[],
+            )
+            .await?;
+        Ok(rows
+            .iter()
+            .map(|row| {
+                let block: i64 = row.get(0);
+                let timestamp: i64 = row.get(1);
+                (block as u64, timestamp as u64)
+            })
+            .collect())
+    }
+
+    async fn put_block_roots(
+        &self,
+        block: u64,
+        roots: crate::block_state::BlockRoots,
+    ) -> anyhow::Result<()> {
+        let client = self.pool.get().await?;
+        client
+            .execute(
+                "INSERT INTO synthetic_blocks (block_number, logs_bloom, receipts_root) \
+                 VALUES ($1, $2, $3) \
+                 ON CONFLICT (block_number) DO UPDATE \
+                 SET logs_bloom = EXCLUDED.logs_bloom, receipts_root = EXCLUDED.receipts_root",
+                &[
+                    &(block as i64),
+                    &roots.logs_bloom.as_slice(),
+                    &roots.receipts_root.as_slice(),
+                ],
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn get_block_roots(&self) -> anyhow::Result<Vec<(u64, crate::block_state::BlockRoots)>> {
+        let client = self.pool.get().await?;
+        let rows = client
+            .query(
+                "SELECT block_number, logs_bloom, receipts_root FROM synthetic_blocks \
+                 WHERE logs_bloom IS NOT NULL ORDER BY block_number",
+                &[],
+            )
+            .await?;
+        rows.iter()
+            .map(|row| {
+                let block: i64 = row.get(0);
+                let bloom: &[u8] = row.get(1);
+                let logs_bloom = alloy::primitives::Bloom::try_from(bloom).map_err(|_| {
+                    anyhow::anyhow!(
+                        "synthetic_blocks.logs_bloom for block {block} is not 256 bytes"
+                    )
+                })?;
+                Ok((
+                    block as u64,
+                    crate::block_state::BlockRoots {
+                        logs_bloom,
+                        receipts_root: bytes_to_array_32(row.get(2)),
+                    },
+                ))
+            })
+            .collect()
+    }
+
     async fn count_claim_events_awaiting_calldata(&self) -> anyhow::Result<u64> {
         let client = self.pool.get().await?;
         // O(1) read of the durable repair-backlog set (migration 019), seeded once
@@ -530,6 +924,129 @@ impl Store for PgStore {
         Ok(())
     }
 
+    async fn mark_undrained_at_shutdown(&self, tx_hashes: &[TxHash]) -> anyhow::Result<u64> {
+        if tx_hashes.is_empty() {
+            return Ok(0);
+        }
+        let client = self.pool.get().await?;
+        let hashes: Vec<String> = tx_hashes.iter().map(|h| format!("{h:#x}")).collect();
+        let marked = client
+            .execute(
+                "UPDATE transactions
+                    SET undrained_at_shutdown = TRUE, next_recovery_at = NULL,
+                        updated_at = now()
+                  WHERE tx_hash = ANY($1) AND status = 'pending'",
+                &[&hashes],
+            )
+            .await?;
+        Ok(marked)
+    }
+
+    async fn take_undrained_at_shutdown(&self) -> anyhow::Result<u64> {
+        let client = self.pool.get().await?;
+        let taken = client
+            .execute(
+                "UPDATE transactions
+                    SET undrained_at_shutdown = FALSE
+                  WHERE undrained_at_shutdown",
+                &[],
+            )
+            .await?;
+        Ok(taken)
+    }
+
+    async fn list_admin_keys(&self) -> anyhow::Result<Vec<AdminKeyRecord>> {
+        let client = self.pool.get().await?;
+        let rows = client
+            .query(
+                "SELECT name, key_hash, methods FROM admin_keys ORDER BY name",
+                &[],
+            )
+            .await?;
+        rows.into_iter()
+            .map(|row| {
+                let name: String = row.get(0);
+                let key_hash: Vec<u8> = row.get(1);
+                let key_hash: [u8; 32] = key_hash.try_into().map_err(|bytes: Vec<u8>| {
+                    anyhow::anyhow!("admin_keys.key_hash for {name} is {} bytes", bytes.len())
+                })?;
+                Ok(AdminKeyRecord {
+                    name,
+                    key_hash,
+                    methods: row.get(2),
+                })
+            })
+            .collect()
+    }
+
+    async fn put_admin_key(&self, record: AdminKeyRecord) -> anyhow::Result<()> {
+        let client = self.pool.get().await?;
+        client
+            .execute(
+                "INSERT INTO admin_keys (name, key_hash, methods)
+                 VALUES ($1, $2, $3)
+                 ON CONFLICT (name) DO UPDATE
+                    SET key_hash = EXCLUDED.key_hash, methods = EXCLUDED.methods,
+                        updated_at = now()",
+                &[&record.name, &record.key_hash.as_slice(), &record.methods],
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn append_admin_audit(
+        &self,
+        key_name: Option<&str>,
+        method: &str,
+        params_digest: &str,
+        outcome: &str,
+    ) -> anyhow::Result<()> {
+        let client = self.pool.get().await?;
+        client
+            .execute(
+                "INSERT INTO admin_audit_log (key_name, method, params_digest, outcome)
+                 VALUES ($1, $2, $3, $4)",
+                &[&key_name, &method, &params_digest, &outcome],
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn list_admin_audit(
+        &self,
+        before_id: Option<u64>,
+        limit: usize,
+    ) -> anyhow::Result<Vec<AdminAuditEntry>> {
+        let client = self.pool.get().await?;
+        let before_id = before_id.map(i64::try_from).transpose()?;
+        let limit = i64::try_from(limit)?;
+        let rows = client
+            .query(
+                "SELECT id, EXTRACT(EPOCH FROM recorded_at)::BIGINT, key_name, method,
+                        params_digest, outcome
+                   FROM admin_audit_log
+                  WHERE $1::BIGINT IS NULL OR id < $1
+                  ORDER BY id DESC
+                  LIMIT $2",
+                &[&before_id, &limit],
+            )
+            .await?;
+        rows.into_iter()
+            .map(|row| {
+                let id: i64 = row.get(0);
+                let recorded_at: i64 = row.get(1);
+                Ok(AdminAuditEntry {
+                    id: u64::try_from(id)?,
+                    recorded_at: u64::try_from(recorded_at)?,
+                    key_name: row.get(2),
+                    method: row.get(3),
+                    params_digest: row.get(4),
+                    outcome: row.get(5),
+                })
+            })
+            .collect()
+    }
+
     async fn prepare_note_handoff(
         &self,
         tx_hash: &str,
@@ -954,6 +1471,26 @@ impl Store for PgStore {
             .collect())
     }
 
+    async fn seal_logs(
+        &self,
+        block: u64,
+        block_hash: [u8; 32],
+        tx_order: &[String],
+    ) -> anyhow::Result<()> {
+        let client = self.pool.get().await?;
+        client
+            .execute(
+                "UPDATE synthetic_logs SET block_hash = $2, \
+                 transaction_index = COALESCE( \
+                     array_position($3::text[], lower(transaction_hash)) - 1, \
+                     transaction_index) \
+                 WHERE block_number = $1",
+                &[&(block as i64), &block_hash.as_slice(), &tx_order],
+            )
+            .await?;
+        Ok(())
+    }
+
     // ── GER ──────────────────────────────────────────────────────
 
     async fn has_seen_ger(&self, ger: &[u8; 32]) -> anyhow::Result<bool> {
@@ -1008,6 +1545,25 @@ impl Store for PgStore {
         }))
     }
 
+    async fn get_latest_l1_observed_gers(&self) -> anyhow::Result<Vec<[u8; 32]>> {
+        let client = self.pool.get().await?;
+        let rows = client
+            .query(
+                "WITH observed AS (
+                     SELECT ger_hash, block_number FROM ger_entries
+                      WHERE finalized_verified
+                        AND mainnet_exit_root IS NOT NULL AND rollup_exit_root IS NOT NULL)
+                 SELECT ger_hash FROM observed
+                  WHERE block_number = (SELECT MAX(block_number) FROM observed)",
+                &[],
+            )
+            .await?;
+        Ok(rows
+            .iter()
+            .map(|row| bytes_to_array_32(row.get(0)))
+            .collect())
+    }
+
     async fn get_ger_entry(&self, ger: &[u8; 32]) -> anyhow::Result<Option<GerEntry>> {
         let client = self.pool.get().await?;
         let rows = client
@@ -1075,6 +1631,17 @@ impl Store for PgStore {
         Ok(!rows.is_empty())
     }
 
+    async fn get_ger_hash_chain(&self) -> anyhow::Result<[u8; 32]> {
+        let client = self.pool.get().await?;
+        let row = client
+            .query_one(
+                "SELECT hash_chain_value FROM service_state WHERE id = 1",
+                &[],
+            )
+            .await?;
+        Ok(bytes_to_array_32(row.get(0)))
+    }
+
     /// Atomic GER commit (audit H2). Single postgres txn folding the
     /// idempotent chain roll + log emission with `is_injected = TRUE`, so a
     /// crash can never leave the chain rolled without the injected flag set
@@ -1213,6 +1780,163 @@ impl Store for PgStore {
         Ok(())
     }
 
+    async fn get_ger_removal_hash_chain(&self) -> anyhow::Result<[u8; 32]> {
+        let client = self.pool.get().await?;
+        let row = client
+            .query_one(
+                "SELECT removal_hash_chain_value FROM service_state WHERE id = 1",
+                &[],
+            )
+            .await?;
+        Ok(bytes_to_array_32(row.get(0)))
+    }
+
+    /// Atomic GER removal commit. Single postgres txn, same idempotency gate
+    /// as `commit_ger_event_atomic`: the removal chain roll and both removal
+    /// logs are skipped when `tx_hash` already has synthetic logs.
+    async fn commit_ger_removal_atomic(
+        &self,
+        block_number: u64,
+        block_hash: [u8; 32],
+        tx_hash: &str,
+        global_exit_root: &[u8; 32],
+    ) -> anyhow::Result<()> {
+        let mut client = self.pool.get().await?;
+        let txn = client.transaction().await?;
+        let tx_hash_key = tx_hash.to_lowercase();
+
+        txn.execute(
+            "UPDATE tx_note_links
+             SET handoff_state = 'submitted', prepared_expiration_block = NULL
+             WHERE lower(tx_hash) = $1",
+            &[&tx_hash_key],
+        )
+        .await?;
+
+        let already_emitted = txn
+            .query_opt(
+                "SELECT 1 FROM synthetic_logs WHERE lower(transaction_hash) = $1 LIMIT 1",
+                &[&tx_hash_key],
+            )
+            .await?
+            .is_some();
+        if !already_emitted {
+            let row = txn
+                .query_one(
+                    "SELECT removal_hash_chain_value FROM service_state WHERE id = 1 FOR UPDATE",
+                    &[],
+                )
+                .await?;
+            let old_chain = bytes_to_array_32(row.get(0));
+
+            let mut hasher = Keccak256::new();
+            hasher.update(old_chain);
+            hasher.update(global_exit_root);
+            let new_chain: [u8; 32] = hasher.finalize().into();
+
+            txn.execute(
+                "UPDATE service_state SET removal_hash_chain_value = $1, updated_at = now() WHERE id = 1",
+                &[&new_chain.as_slice()],
+            )
+            .await?;
+
+            let ger_topic = format!("0x{}", hex::encode(global_exit_root));
+            let topic_sets = [
+                vec![
+                    REMOVE_LAST_GLOBAL_EXIT_ROOT_TOPIC.to_string(),
+                    ger_topic.clone(),
+                ],
+                vec![
+                    UPDATE_REMOVAL_HASH_CHAIN_VALUE_TOPIC.to_string(),
+                    ger_topic,
+                    format!("0x{}", hex::encode(new_chain)),
+                ],
+            ];
+            for topics in &topic_sets {
+                let row = txn
+                    .query_one(
+                        "UPDATE service_state
+                         SET log_counter = log_counter + 1, updated_at = now()
+                         WHERE id = 1
+                         RETURNING log_counter - 1",
+                        &[],
+                    )
+                    .await?;
+                let log_index: i64 = row.get(0);
+                let topic_refs: Vec<&str> = topics.iter().map(|topic| topic.as_str()).collect();
+                txn.execute(
+                    "INSERT INTO synthetic_logs (log_index, address, topics, data, block_number, block_hash, transaction_hash, transaction_index, removed)
+                     VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)",
+                    &[
+                        &log_index,
+                        &L2_GLOBAL_EXIT_ROOT_ADDRESS,
+                        &topic_refs,
+                        &"0x",
+                        &(block_number as i64),
+                        &block_hash.as_slice(),
+                        &tx_hash_key,
+                        &0_i64,
+                        &false,
+                    ],
+                )
+                .await?;
+            }
+        }
+
+        // Always clear is_injected (idempotent). Roots and provenance stay.
+        txn.execute(
+            "UPDATE ger_entries SET is_injected = FALSE WHERE ger_hash = $1",
+            &[&global_exit_root.as_slice()],
+        )
+        .await?;
+
+        txn.execute(
+            "UPDATE transactions SET status = 'success', error_message = NULL,
+                    block_number = $1, updated_at = now()
+             WHERE lower(tx_hash) = $2",
+            &[&(block_number as i64), &tx_hash_key],
+        )
+        .await?;
+
+        txn.commit().await?;
+        Ok(())
+    }
+
+    async fn record_ger_removal(
+        &self,
+        tx_hash: TxHash,
+        global_exit_root: &[u8; 32],
+    ) -> anyhow::Result<()> {
+        let client = self.pool.get().await?;
+        client
+            .execute(
+                "INSERT INTO ger_removals (tx_hash, global_exit_root) VALUES ($1, $2)
+                 ON CONFLICT (tx_hash) DO NOTHING",
+                &[&format!("{tx_hash:#x}"), &global_exit_root.as_slice()],
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn pending_ger_removal(
+        &self,
+        global_exit_root: &[u8; 32],
+    ) -> anyhow::Result<Option<TxHash>> {
+        let client = self.pool.get().await?;
+        let row = client
+            .query_opt(
+                "SELECT r.tx_hash FROM ger_removals r
+                 JOIN transactions t ON t.tx_hash = r.tx_hash
+                 WHERE r.global_exit_root = $1 AND t.status = 'pending'
+                 LIMIT 1",
+                &[&global_exit_root.as_slice()],
+            )
+            .await?;
+        row.map(|row| row.get::<_, &str>(0).parse::<TxHash>())
+            .transpose()
+            .map_err(Into::into)
+    }
+
     // ── Transactions ─────────────────────────────────────────────
 
     async fn txn_begin(&self, tx_hash: TxHash, entry: TxnEntry) -> anyhow::Result<()> {
@@ -1688,6 +2412,25 @@ impl Store for PgStore {
         }))
     }
 
+    async fn txn_hashes_at_block(&self, block: u64) -> anyhow::Result<Vec<TxHash>> {
+        let client = self.pool.get().await?;
+        let rows = client
+            .query(
+                "SELECT tx_hash FROM transactions \
+                 WHERE block_number = $1 AND status IN ('success', 'failed') \
+                 ORDER BY tx_hash",
+                &[&(block as i64)],
+            )
+            .await?;
+        rows.iter()
+            .map(|row| {
+                let hash: &str = row.get(0);
+                <TxHash as std::str::FromStr>::from_str(hash)
+                    .map_err(|e| anyhow::anyhow!("transactions.tx_hash {hash:?}: {e}"))
+            })
+            .collect()
+    }
+
     async fn pending_nonce_frontier(&self, addr: &str) -> anyhow::Result<PendingNonceFrontier> {
         use alloy::eips::Decodable2718;
 
@@ -2431,36 +3174,40 @@ impl Store for PgStore {
                 &[&note_id],
             )
             .await?;
-        let Some(row) = rows.into_iter().next() else {
-            return Ok(None);
-        };
-        let note_id_col: String = row.get(0);
-        let bridge_account_hex: String = row.get(1);
-        let reason_str: String = row.get(2);
-        let detail: String = row.get(3);
-        let note_dump: String = row.get(4);
-        let observed_block: i64 = row.get(5);
+        rows.first()
+            .map(unbridgeable_bridge_out_from_row)
+            .transpose()
+    }
 
-        let reason = match reason_str.as_str() {
-            "storage_parse_failed" => UnbridgeableBridgeOutReason::StorageParseFailed,
-            "no_fungible_asset" => UnbridgeableBridgeOutReason::NoFungibleAsset,
-            "unknown_faucet" => UnbridgeableBridgeOutReason::UnknownFaucet,
-            "amount_overflow" => UnbridgeableBridgeOutReason::AmountOverflow,
-            "atomic_commit_failed" => UnbridgeableBridgeOutReason::AtomicCommitFailed,
-            "metadata_too_large" => UnbridgeableBridgeOutReason::MetadataTooLarge,
-            "same_details_multiplicity" => UnbridgeableBridgeOutReason::SameDetailsMultiplicity,
-            other => anyhow::bail!("unknown unbridgeable_bridge_outs.reason value: {other}"),
-        };
+    async fn list_unbridgeable_bridge_outs(
+        &self,
+        after_note_id: Option<&str>,
+        limit: usize,
+    ) -> anyhow::Result<Vec<UnbridgeableBridgeOut>> {
+        let client = self.pool.get().await?;
+        let limit = i64::try_from(limit)?;
+        let rows = client
+            .query(
+                "SELECT note_id, bridge_account, reason, detail, note_dump, observed_block \
+                 FROM unbridgeable_bridge_outs \
+                 WHERE $1::TEXT IS NULL OR note_id > $1 \
+                 ORDER BY note_id \
+                 LIMIT $2",
+                &[&after_note_id, &limit],
+            )
+            .await?;
+        rows.iter().map(unbridgeable_bridge_out_from_row).collect()
+    }
 
-        Ok(Some(UnbridgeableBridgeOut {
-            note_id: note_id_col,
-            bridge_account: AccountId::from_hex(&bridge_account_hex)
-                .map_err(|e| anyhow::anyhow!("decoding bridge_account from db row: {e}"))?,
-            reason,
-            detail,
-            note_dump,
-            observed_block: u64::try_from(observed_block)?,
-        }))
+    async fn delete_unbridgeable_bridge_out(&self, note_id: &str) -> anyhow::Result<bool> {
+        let client = self.pool.get().await?;
+        let deleted = client
+            .execute(
+                "DELETE FROM unbridgeable_bridge_outs WHERE note_id = $1",
+                &[&note_id],
+            )
+            .await?;
+        Ok(deleted > 0)
     }
 
     // ── Address mappings ─────────────────────────────────────────
@@ -3294,3 +4041,50 @@ fn pg_row_to_faucet_entry(row: &tokio_postgres::Row) -> Option<FaucetEntry> {
         },
     })
 }
+
+/// Decode an `unbridgeable_bridge_outs` row selected as `note_id,
+/// bridge_account, reason, detail, note_dump, observed_block`.
+fn unbridgeable_bridge_out_from_row(
+    row: &tokio_postgres::Row,
+) -> anyhow::Result<UnbridgeableBridgeOut> {
+    let note_id_col: String = row.get(0);
+    let bridge_account_hex: String = row.get(1);
+    let reason_str: String = row.get(2);
+    let detail: String = row.get(3);
+    let note_dump: String = row.get(4);
+    let observed_block: i64 = row.get(5);
+
+    let reason = match reason_str.as_str() {
+        "storage_parse_failed" => UnbridgeableBridgeOutReason::StorageParseFailed,
+        "no_fungible_asset" => UnbridgeableBridgeOutReason::NoFungibleAsset,
+        "unknown_faucet" => UnbridgeableBridgeOutReason::UnknownFaucet,
+        "amount_overflow" => UnbridgeableBridgeOutReason::AmountOverflow,
+        "atomic_commit_failed" => UnbridgeableBridgeOutReason::AtomicCommitFailed,
+        "metadata_too_large" => UnbridgeableBridgeOutReason::MetadataTooLarge,
+        "same_details_multiplicity" => UnbridgeableBridgeOutReason::SameDetailsMultiplicity,
+        other => anyhow::bail!("unknown unbridgeable_bridge_outs.reason value: {other}"),
+    };
+
+    Ok(UnbridgeableBridgeOut {
+        note_id: note_id_col,
+        bridge_account: AccountId::from_hex(&bridge_account_hex)
+            .map_err(|e| anyhow::anyhow!("decoding bridge_account from db row: {e}"))?,
+        reason,
+        detail,
+        note_dump,
+        observed_block: u64::try_from(observed_block)?,
+    })
+}
+
+/// Decode an `l1_info_tree_leaves` row selected as `leaf_index, ger,
+/// previous_block_hash, timestamp, block_number, root`.
+fn l1_info_tree_leaf_from_row(row: &tokio_postgres::Row) -> anyhow::Result<L1InfoTreeLeaf> {
+    Ok(L1InfoTreeLeaf {
+        leaf_index: u32::try_from(row.get::<_, i64>(0))?,
+        ger: bytes_to_array_32(row.get(1)),
+        previous_block_hash: bytes_to_array_32(row.get(2)),
+        timestamp: u64::try_from(row.get::<_, i64>(3))?,
+        block_number: u64::try_from(row.get::<_, i64>(4))?,
+        root: bytes_to_array_32(row.get(5)),
+    })
+}
@@ -0,0 +1,498 @@
+//! WebSocket JSON-RPC with `eth_subscribe` / `eth_unsubscribe`.
+//!
+//! The WebSocket listener shares the HTTP router (`GET /` upgrades, `POST /`
+//! stays plain JSON-RPC), so it inherits the same per-IP governor, CORS
+//! allow-list and body cap. Every ordinary method is dispatched through the
+//! same `json_rpc_call` as HTTP, including the admin gate — the upgrade
+//! request's headers stand in for per-request headers.
+//!
+//! Two subscription kinds are served:
+//! - `newHeads` — one notification per synthetic block, in order.
+//! - `logs` — one notification per synthetic log matching an `address` /
+//!   `topics` filter, in block order.
+//!
+//! Both are driven by [`TipNotifier`], which the `SyntheticProjector` fires
+//! only AFTER `set_latest_block_number` — i.e. after every log of the block is
+//! committed (write-before-advance). A notification for block `N` therefore
+//! never precedes the data an `eth_getLogs` for `N` would return. Each
+//! subscription keeps its own cursor, so a slow session that lags the
+//! broadcast channel catches up from the store instead of skipping blocks.
+//! The synthetic chain is immutable, so `removed` is always `false`.
+
+use crate::log_synthesis::LogFilter;
+use crate::service::{MAX_REQUEST_BODY_BYTES, json_rpc_call};
+use crate::service_get_logs::{MAX_GETLOGS_BLOCK_RANGE, validate_getlogs_filter};
+use crate::service_helpers::store_error;
+use crate::service_state::ServiceState;
+use axum::Extension;
+use axum::extract::ws::{Message, WebSocket, WebSocketUpgrade};
+use axum::extract::{ConnectInfo, State};
+use axum::http::{HeaderMap, HeaderValue, StatusCode};
+use axum::response::{IntoResponse, Response};
+use axum_jrpc::error::{JsonRpcError, JsonRpcErrorReason};
+use axum_jrpc::{Id, JsonRpcExtractor, JsonRpcResponse};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::net::{IpAddr, SocketAddr};
+use tokio::sync::broadcast;
+use tokio::sync::broadcast::error::RecvError;
+
+/// Capacity of the tip broadcast channel. A session that falls further behind
+/// than this sees `Lagged` and re-reads the tip from the store; no block is
+/// skipped because delivery is driven by per-subscription cursors.
+const TIP_CHANNEL_CAPACITY: usize = 64;
+
+/// Maximum live subscriptions on one WebSocket connection. Each subscription
+/// costs a store query per tip advance, so the cap bounds the work one
+/// connection can attach to the projector's cadence.
+pub const MAX_SUBSCRIPTIONS_PER_CONNECTION: usize = 32;
+
+/// Maximum `newHeads` notifications emitted for one tip advance. Only a
+/// session that stalled for a long time (or a projector catching up after a
+/// restart) can exceed it; such a subscriber receives the most recent heads
+/// and can backfill older ones with `eth_getBlockByNumber`. `logs`
+/// subscriptions are never truncated.
+pub const MAX_NEW_HEADS_BACKFILL: u64 = 128;
+
+/// Fan-out of synthetic tip advances to WebSocket subscribers.
+///
+/// Cheap to clone; every clone feeds the same channel. `notify` never blocks
+/// and is a no-op when nobody is subscribed, so the projector pays nothing
+/// when the WebSocket endpoint is unused.
+#[derive(Clone)]
+pub struct TipNotifier {
+    tx: broadcast::Sender<u64>,
+}
+
+impl Default for TipNotifier {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TipNotifier {
+    pub fn new() -> Self {
+        let (tx, _) = broadcast::channel(TIP_CHANNEL_CAPACITY);
+        Self { tx }
+    }
+
+    /// Announce that the synthetic tip is now `block`. Callers MUST have
+    /// persisted the tip (and every log at or below it) first.
+    pub fn notify(&self, block: u64) {
+        // Err only means there are no receivers right now.
+        let _ = self.tx.send(block);
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<u64> {
+        self.tx.subscribe()
+    }
+}
+
+/// `GET /` — upgrade to a WebSocket JSON-RPC session.
+///
+/// Browsers do not apply CORS to WebSocket handshakes, so the `Origin` header
+/// is checked here against the same allow-list the CORS layer uses; without
+/// it any page a victim visits could open a session to a private endpoint.
+pub(crate) async fn ws_endpoint(
+    State(service): State<ServiceState>,
+    headers: HeaderMap,
+    peer: Option<Extension<ConnectInfo<SocketAddr>>>,
+    ws: WebSocketUpgrade,
+) -> Response {
+    if !origin_allowed(
+        headers.get(axum::http::header::ORIGIN),
+        service
+            .policy
+            .current()
+            .settings
+            .cors_allowed_origins
+            .as_deref(),
+    ) {
+        metrics::counter!("rpc_ws_rejected_total", "reason" => "origin").increment(1);
+        return StatusCode::FORBIDDEN.into_response();
+    }
+    let peer_ip = peer.map(|Extension(ConnectInfo(addr))| addr.ip());
+    ws.max_message_size(MAX_REQUEST_BODY_BYTES)
+        .on_upgrade(move |socket| Session::new(service, headers, peer_ip).run(socket))
+}
+
+/// Non-browser clients send no `Origin` and are always admitted; a browser
+/// origin must be on the allow-list (or the dev-only `*` wildcard).
+fn origin_allowed(origin: Option<&HeaderValue>, allowed: Option<&[String]>) -> bool {
+    let Some(origin) = origin else {
+        return true;
+    };
+    allowed.is_some_and(|list| {
+        list.iter()
+            .any(|o| o == "*" || o.as_bytes() == origin.as_bytes())
+    })
+}
+
+/// One WebSocket message. Unlike the HTTP path, batches are not accepted —
+/// a WebSocket client can simply pipeline requests.
+#[derive(Deserialize)]
+struct WsRequest {
+    jsonrpc: String,
+    #[serde(default)]
+    id: Option<Id>,
+    method: String,
+    #[serde(default)]
+    params: serde_json::Value,
+}
+
+enum SubscriptionKind {
+    NewHeads,
+    Logs(LogFilter),
+}
+
+struct Subscription {
+    kind: SubscriptionKind,
+    /// First block not yet delivered to this subscription.
+    next_block: u64,
+}
+
+struct Session {
+    service: ServiceState,
+    headers: HeaderMap,
+    peer_ip: Option<IpAddr>,
+    subscriptions: HashMap<String, Subscription>,
+}
+
+impl Session {
+    fn new(service: ServiceState, headers: HeaderMap, peer_ip: Option<IpAddr>) -> Self {
+        Self {
+            service,
+            headers,
+            peer_ip,
+            subscriptions: HashMap::new(),
+        }
+    }
+
+    async fn run(mut self, mut socket: WebSocket) {
+        // Subscribe before any subscription reads the tip, so an advance that
+        // lands between the read and the first `recv` is not missed.
+        let mut tips = self.service.tip_notifier.subscribe();
+        metrics::gauge!("rpc_ws_connections").increment(1.0);
+        loop {
+            tokio::select! {
+                message = socket.recv() => {
+                    let body = match message {
+                        Some(Ok(Message::Text(text))) => text.as_str().as_bytes().to_vec(),
+                        Some(Ok(Message::Binary(bytes))) => bytes.to_vec(),
+                        // Pings are answered by the protocol layer.
+                        Some(Ok(Message::Ping(_) | Message::Pong(_))) => continue,
+                        Some(Ok(Message::Close(_)) | Err(_)) | None => break,
+                    };
+                    let reply = self.handle_message(&body).await;
+                    if send_json(&mut socket, &reply).await.is_err() {
+                        break;
+                    }
+                }
+                tip = tips.recv() => {
+                    let tip = match tip {
+                        Ok(tip) => tip,
+                        Err(RecvError::Lagged(skipped)) => {
+                            tracing::debug!(skipped, "ws session lagged the tip channel; resyncing from store");
+                            match self.service.store.get_latest_block_number().await {
+                                Ok(tip) => tip,
+                                Err(e) => {
+                                    tracing::warn!("ws session: tip resync failed: {e:#}");
+                                    continue;
+                                }
+                            }
+                        }
+                        Err(RecvError::Closed) => break,
+                    };
+                    if self.publish(&mut socket, tip).await.is_err() {
+                        break;
+                    }
+                }
+            }
+        }
+        metrics::gauge!("rpc_ws_subscriptions").decrement(self.subscriptions.len() as f64);
+        metrics::gauge!("rpc_ws_connections").decrement(1.0);
+    }
+
+    async fn handle_message(&mut self, body: &[u8]) -> JsonRpcResponse {
+        if body.iter().find(|b| !b.is_ascii_whitespace()) == Some(&b'[') {
+            return error(
+                (),
+                JsonRpcErrorReason::InvalidRequest,
+                "batch requests are not supported over WebSocket; send one request per message"
+                    .to_string(),
+            );
+        }
+        let request: WsRequest = match serde_json::from_slice(body) {
+            Ok(request) => request,
+            Err(e) => return error((), JsonRpcErrorReason::InvalidRequest, e.to_string()),
+        };
+        let answer_id = request.id.unwrap_or(Id::None(()));
+        if request.jsonrpc != "2.0" {
+            return error(
+                answer_id,
+                JsonRpcErrorReason::InvalidRequest,
+                "Unknown jsonrpc version".to_string(),
+            );
+        }
+
+        // The upgrade request paid one governor cell; every message after it
+        // draws its own from the same per-IP bucket, as batch entries do.
+        #[allow(clippy::collapsible_if)]
+        if let (Some(limiter), Some(ip)) = (&self.service.rpc_entry_limiter, self.peer_ip) {
+            if !limiter.admit(ip) {
+                metrics::counter!("rpc_ws_messages_rate_limited_total").increment(1);
+                return error(
+                    answer_id,
+                    JsonRpcErrorReason::ServerError(-32005),
+                    "rate limit exceeded".to_string(),
+                );
+            }
+        }
+
+        let request = JsonRpcExtractor {
+            parsed: request.params,
+            method: request.method,
+            id: answer_id,
+        };
+        match request.method() {
+            "eth_subscribe" => {
+                metrics::counter!("rpc_requests_total", "method" => "eth_subscribe").increment(1);
+                self.subscribe(request).await
+            }
+            "eth_unsubscribe" => {
+                metrics::counter!("rpc_requests_total", "method" => "eth_unsubscribe").increment(1);
+                self.unsubscribe(request)
+            }
+            _ => json_rpc_call(&self.service, &self.headers, self.peer_ip, request).await,
+        }
+    }
+
+    async fn subscribe(&mut self, request: JsonRpcExtractor) -> JsonRpcResponse {
+        let answer_id = request.get_answer_id();
+        if self.subscriptions.len() >= MAX_SUBSCRIPTIONS_PER_CONNECTION {
+            return error(
+                answer_id,
+                JsonRpcErrorReason::ServerError(-32005),
+                format!(
+                    "subscription limit reached: at most {MAX_SUBSCRIPTIONS_PER_CONNECTION} per connection"
+                ),
+            );
+        }
+        let params: Vec<serde_json::Value> = match request.parse_params() {
+            Ok(params) => params,
+            Err(e) => return e,
+        };
+        let kind = match parse_subscription(&params) {
+            Ok(kind) => kind,
+            Err(msg) => return error(answer_id, JsonRpcErrorReason::InvalidParams, msg),
+        };
+        let tip = match self.service.store.get_latest_block_number().await {
+            Ok(tip) => tip,
+            Err(e) => return store_error(answer_id, e),
+        };
+
+        let id = format!("0x{:032x}", ulid::Ulid::new().0);
+        self.subscriptions.insert(
+            id.clone(),
+            Subscription {
+                kind,
+                next_block: tip + 1,
+            },
+        );
+        metrics::gauge!("rpc_ws_subscriptions").increment(1.0);
+        JsonRpcResponse::success(answer_id, id)
+    }
+
+    fn unsubscribe(&mut self, request: JsonRpcExtractor) -> JsonRpcResponse {
+        let answer_id = request.get_answer_id();
+        let (id,): (String,) = match request.parse_params() {
+            Ok(params) => params,
+            Err(e) => return e,
+        };
+        let removed = self.subscriptions.remove(&id).is_some();
+        if removed {
+            metrics::gauge!("rpc_ws_subscriptions").decrement(1.0);
+        }
+        JsonRpcResponse::success(answer_id, removed)
+    }
+
+    /// Deliver everything up to `tip` to each subscription. A store error
+    /// leaves that subscription's cursor in place so the next advance retries
+    /// the same blocks.
+    async fn publish(&mut self, socket: &mut WebSocket, tip: u64) -> Result<(), axum::Error> {
+        let Self {
+            service,
+            subscriptions,
+            ..
+        } = self;
+        for (id, subscription) in subscriptions.iter_mut() {
+            match &subscription.kind {
+                SubscriptionKind::NewHeads => {
+                    let from = subscription
+                        .next_block
+                        .max((tip + 1).saturating_sub(MAX_NEW_HEADS_BACKFILL));
+                    for number in from..=tip {
+                        let Some(block) = service.block_state.get_block_by_number(number) else {
+                            continue;
+                        };
+                        send_notification(socket, id, block.to_json(false)).await?;
+                        metrics::counter!("rpc_ws_notifications_total", "kind" => "newHeads")
+                            .increment(1);
+                    }
+                    subscription.next_block = subscription.next_block.max(tip + 1);
+                }
+                SubscriptionKind::Logs(filter) => {
+                    while subscription.next_block <= tip {
+                        let from = subscription.next_block;
+                        let to = tip.min(from.saturating_add(MAX_GETLOGS_BLOCK_RANGE - 1));
+                        let window = LogFilter {
+                            from_block: Some(format!("0x{from:x}")),
+                            to_block: Some(format!("0x{to:x}")),
+                            ..filter.clone()
+                        };
+                        let logs = match service.store.get_logs(&window, tip).await {
+                            Ok(logs) => logs,
+                            Err(e) => {
+                                tracing::warn!(
+                                    subscription = %id,
+                                    "ws logs subscription: get_logs({from}..={to}) failed, retrying on next tip: {e:#}"
+                                );
+                                break;
+                            }
+                        };
+                        for log in &logs {
+                            send_notification(socket, id, log.to_json()).await?;
+                        }
+                        metrics::counter!("rpc_ws_notifications_total", "kind" => "logs")
+                            .increment(logs.len() as u64);
+                        subscription.next_block = to + 1;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Parse `eth_subscribe` params: `["newHeads"]` or `["logs", filter?]`.
+/// A logs filter may carry only `address` and `topics`; block bounds are
+/// meaningless for a live feed.
+fn parse_subscription(params: &[serde_json::Value]) -> Result<SubscriptionKind, String> {
+    match (params.first().and_then(|v| v.as_str()), params.len()) {
+        (Some("newHeads"), 1) => Ok(SubscriptionKind::NewHeads),
+        (Some("logs"), 1 | 2) => {
+            let filter: LogFilter = match params.get(1) {
+                Some(value) => serde_json::from_value(value.clone())
+                    .map_err(|e| format!("invalid logs filter: {e}"))?,
+                None => LogFilter::default(),
+            };
+            if filter.from_block.is_some()
+                || filter.to_block.is_some()
+                || filter.block_hash.is_some()
+            {
+                return Err(
+                    "logs subscription filter accepts only `address` and `topics`".to_string(),
+                );
+            }
+            validate_getlogs_filter(&filter, 0)?;
+            Ok(SubscriptionKind::Logs(filter))
+        }
+        _ => Err(
+            "unsupported subscription: expected [\"newHeads\"] or [\"logs\", filter]".to_string(),
+        ),
+    }
+}
+
+fn error(id: impl Into<Id>, reason: JsonRpcErrorReason, message: String) -> JsonRpcResponse {
+    JsonRpcResponse::error(
+        id.into(),
+        JsonRpcError::new(reason, message, serde_json::Value::Null),
+    )
+}
+
+async fn send_notification(
+    socket: &mut WebSocket,
+    subscription: &str,
+    result: serde_json::Value,
+) -> Result<(), axum::Error> {
+    let notification = serde_json::json!({
+        "jsonrpc": "2.0",
+        "method": "eth_subscription",
+        "params": {"subscription": subscription, "result": result},
+    });
+    send_json(socket, &notification).await
+}
+
+async fn send_json(
+    socket: &mut WebSocket,
+    value: &impl serde::Serialize,
+) -> Result<(), axum::Error> {
+    let text = serde_json::to_string(value).expect("JSON-RPC payloads always serialize");
+    socket.send(Message::Text(text.into())).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tip_notifier_fans_out_and_tolerates_no_receivers() {
+        let notifier = TipNotifier::new();
+        // No receivers yet: must not panic or block.
+        notifier.notify(1);
+        let mut a = notifier.subscribe();
+        let mut b = notifier.clone().subscribe();
+        notifier.notify(2);
+        assert_eq!(a.try_recv().unwrap(), 2);
+        assert_eq!(b.try_recv().unwrap(), 2);
+    }
+
+    #[test]
+    fn subscription_params_are_validated() {
+        use serde_json::json;
+
+        assert!(matches!(
+            parse_subscription(&[json!("newHeads")]),
+            Ok(SubscriptionKind::NewHeads)
+        ));
+        assert!(matches!(
+            parse_subscription(&[json!("logs")]),
+            Ok(SubscriptionKind::Logs(_))
+        ));
+        assert!(matches!(
+            parse_subscription(&[json!("logs"), json!({"address": "0x01", "topics": [null]})]),
+            Ok(SubscriptionKind::Logs(_))
+        ));
+        for bad in [
+            vec![],
+            vec![json!("newPendingTransactions")],
+            vec![json!("newHeads"), json!({})],
+            vec![json!("logs"), json!({"fromBlock": "0x1"})],
+            vec![
+                json!("logs"),
+                json!({"topics": [null, null, null, null, null]}),
+            ],
+        ] {
+            assert!(
+                parse_subscription(&bad).is_err(),
+                "{bad:?} must be rejected"
+            );
+        }
+    }
+
+    /// Cross-site WebSocket hijacking guard: a browser origin must be on the
+    /// CORS allow-list; clients that send no Origin are unaffected.
+    #[test]
+    fn ws_origin_follows_cors_allow_list() {
+        let origin = HeaderValue::from_static("https://app.example");
+        let allowed = ["https://app.example".to_string()];
+        assert!(origin_allowed(None, None));
+        assert!(!origin_allowed(Some(&origin), None));
+        assert!(origin_allowed(Some(&origin), Some(&allowed)));
+        assert!(!origin_allowed(
+            Some(&HeaderValue::from_static("https://evil.example")),
+            Some(&allowed)
+        ));
+        assert!(origin_allowed(Some(&origin), Some(&["*".to_string()])));
+    }
+}
@@ -56,7 +56,7 @@ use crate::miden_client::{
 };
 use crate::restore::{
     B2AggRestoreOutcome, ClaimProjectOutcome, GerProjectOutcome, project_b2agg_note,
-    project_claim_note, project_ger_note,
+    project_claim_note, project_ger_note, project_ger_removal_note,
 };
 use crate::store::Store;
 use crate::writer_worker::DecodedWriteCall;
@@ -129,6 +129,29 @@ fn env_u64(name: &str, default: u64) -> u64 {
     }
 }
 
+/// Reconciler sweep knobs after env overrides and clamping.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ReconcileTuning {
+    pub(crate) chunk: u64,
+    pub(crate) concurrency: usize,
+    pub(crate) budget: Duration,
+}
+
+pub(crate) fn reconcile_tuning() -> ReconcileTuning {
+    ReconcileTuning {
+        chunk: env_u64("RECONCILE_CHUNK", RECONCILE_CHUNK).max(1),
+        concurrency: (env_u64(
+            "RECONCILE_CONCURRENCY",
+            RECONCILE_CONCURRENCY_DEFAULT as u64,
+        ) as usize)
+            .clamp(1, RECONCILE_CONCURRENCY_MAX),
+        budget: Duration::from_millis(env_u64(
+            "RECONCILE_TICK_BUDGET_MS",
+            RECONCILE_TICK_BUDGET_MS_DEFAULT,
+        )),
+    }
+}
+
 /// Thin seam over the node's `sync_notes` window fetch, so the catch-up driver
 /// (window batching, concurrent fetch, strict-order low-water-mark cursor
 /// advancement, tick budget) is unit-testable without a live node. The live
@@ -269,6 +292,12 @@ pub struct SyntheticProjector {
     /// upgrade, and a metadata-unrecoverable claim keeps alarming until the registry is
     /// repaired, then self-heals. In-memory on purpose: a restart re-checks from scratch.
     claim_calldata_resolved: std::sync::Mutex<HashSet<[u8; 32]>>,
+    /// WebSocket `eth_subscribe` fan-out, fired after each tip write. `None`
+    /// for projectors that serve no subscribers (`--restore`, tests).
+    tip_notifier: Option<crate::subscriptions::TipNotifier>,
+    /// Lags behind the `synthetic_{latest,safe,finalized}_block` gauges
+    /// published after each tip write.
+    finality: crate::finality::FinalityLags,
 }
 
 impl SyntheticProjector {
@@ -307,16 +336,11 @@ impl SyntheticProjector {
         // heal sweep runs — grep target for the e2e restart regression check
         // (scripts/e2e-reconciler-cursor-persistence.sh).
         let start_reconcile = store.get_reconcile_cursor().await?;
-        let reconcile_chunk = env_u64("RECONCILE_CHUNK", RECONCILE_CHUNK).max(1);
-        let reconcile_concurrency = (env_u64(
-            "RECONCILE_CONCURRENCY",
-            RECONCILE_CONCURRENCY_DEFAULT as u64,
-        ) as usize)
-            .clamp(1, RECONCILE_CONCURRENCY_MAX);
-        let reconcile_budget = Duration::from_millis(env_u64(
-            "RECONCILE_TICK_BUDGET_MS",
-            RECONCILE_TICK_BUDGET_MS_DEFAULT,
-        ));
+        let ReconcileTuning {
+            chunk: reconcile_chunk,
+            concurrency: reconcile_concurrency,
+            budget: reconcile_budget,
+        } = reconcile_tuning();
         tracing::info!(
             reconcile_cursor = start_reconcile,
             chunk = reconcile_chunk,
@@ -343,6 +367,8 @@ impl SyntheticProjector {
             audit_resolved: std::sync::Mutex::new(HashSet::new()),
             audit_tick_counter: AtomicU64::new(0),
             claim_calldata_resolved: std::sync::Mutex::new(HashSet::new()),
+            tip_notifier: None,
+            finality: crate::finality::FinalityLags::default(),
         })
     }
 
@@ -390,6 +416,16 @@ impl SyntheticProjector {
                 )
                 .await
             }
+            DecodedWriteCall::GerRemoval { ger_bytes } => {
+                crate::applied_state::reconcile_ger_removal_handoff_with_client(
+                    self.store.as_ref(),
+                    client,
+                    self.bridge_id,
+                    *ger_bytes,
+                    pending.note_id.clone(),
+                )
+                .await
+            }
             DecodedWriteCall::Claim { params } => {
                 crate::applied_state::reconcile_claim_handoff_with_client(
                     self.store.as_ref(),
@@ -415,6 +451,9 @@ impl SyntheticProjector {
         }
         let result = match pending.call {
             DecodedWriteCall::Ger { .. } => Ok(()),
+            DecodedWriteCall::GerRemoval { .. } => {
+                Err("execution reverted: GlobalExitRootNotFound()".to_string())
+            }
             DecodedWriteCall::Claim { .. } => {
                 Err("execution reverted: AlreadyClaimed()".to_string())
             }
@@ -1127,6 +1166,19 @@ impl SyntheticProjector {
         Ok(outcome)
     }
 
+    /// Announce each tip advance on `notifier` (WebSocket `newHeads` / `logs`
+    /// subscriptions).
+    pub fn with_tip_notifier(mut self, notifier: crate::subscriptions::TipNotifier) -> Self {
+        self.tip_notifier = Some(notifier);
+        self
+    }
+
+    /// Use the service's `safe` / `finalized` lags for the frontier gauges.
+    pub fn with_finality(mut self, finality: crate::finality::FinalityLags) -> Self {
+        self.finality = finality;
+        self
+    }
+
     /// Test-only override of the reconciler catch-up knobs (the live values
     /// come from the environment in [`Self::new`]).
     #[cfg(test)]
@@ -1261,6 +1313,10 @@ impl SyntheticProjector {
         // log for this Miden block is written AT block `miden_block`; the tip is
         // advanced exactly ONCE, after the whole block (below). The projector is
         // the SOLE advancer of `latest_block_number` — nothing else may touch it.
+        // The timestamp is the real Miden block time recorded by the
+        // `BlockTimestampRecorder` earlier in the sync tick (legacy value for
+        // blocks hashed before it was recorded) — the same one in the header hash.
+        // `block_hash` is provisional: sealing below replaces it on every log.
         let block_hash = self.block_state.get_block_hash(miden_block);
         let timestamp = self.block_state.get_block_timestamp(miden_block);
 
@@ -1326,6 +1382,22 @@ impl SyntheticProjector {
                 logs += 1;
                 continue;
             }
+
+            if project_ger_removal_note(
+                &self.store,
+                note,
+                output_metadata,
+                self.expected_ger_sender,
+                self.bridge_id,
+                miden_block,
+                block_hash,
+            )
+            .await?
+                == GerProjectOutcome::Emitted
+            {
+                logs += 2;
+                continue;
+            }
         }
 
         // #66 — emitted-frontier gate, enforced AT EMIT TIME (after this block's notes are
@@ -1355,11 +1427,46 @@ impl SyntheticProjector {
                  re-run `--restore` to rebuild from on-chain), then restart."
             );
         }
+        // Seal: every log for `miden_block` is in the DB, so its `logsBloom` /
+        // `receiptsRoot` and transaction order are final. Persist the roots, fix the
+        // header hash, and stamp that hash (the logs were written with the
+        // provisional one) and each log's transaction index BEFORE the tip advance
+        // exposes the block. A crash re-projection of an already sealed block keeps
+        // the header it was exposed with. The seal lock keeps a locally completed
+        // transaction from adding logs between the read below and the tip advance.
+        let seal_guard = self.block_state.lock_seal().await;
+        let filter = crate::log_synthesis::LogFilter {
+            from_block: Some(format!("0x{miden_block:x}")),
+            to_block: Some(format!("0x{miden_block:x}")),
+            ..Default::default()
+        };
+        let block_logs = self.store.get_logs(&filter, miden_block).await?;
+        let tx_order: Vec<String> = crate::block_state::logs_by_transaction(&block_logs)
+            .into_iter()
+            .map(|(tx, _)| tx)
+            .collect();
+        let block_hash = if miden_block > self.block_state.sealed_through() {
+            let roots = crate::block_state::compute_block_roots(&block_logs)?;
+            self.store.put_block_roots(miden_block, roots).await?;
+            self.block_state.seal_block(miden_block, roots)
+        } else {
+            self.block_state.get_block_hash(miden_block)
+        };
+        self.store
+            .seal_logs(miden_block, block_hash, &tx_order)
+            .await?;
         // Write-before-advance: every synthetic log for `miden_block` is now in the
         // DB, so it is safe to advance the synthetic tip to == the Miden block.
         // Runs for EMPTY Miden blocks too (advance the tip even with 0 logs), so the
         // synthetic chain mirrors Miden block-for-block (eth_blockNumber == Miden tip).
         self.store.set_latest_block_number(miden_block).await?;
+        drop(seal_guard);
+        self.finality.frontiers(miden_block).record_metrics();
+        // Subscribers are told only once the tip is durable, so a `logs` /
+        // `newHeads` notification never races ahead of `eth_getLogs`.
+        if let Some(notifier) = &self.tip_notifier {
+            notifier.notify(miden_block);
+        }
 
         Ok(logs)
     }
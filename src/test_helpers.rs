@@ -73,6 +73,30 @@ pub fn create_test_service_with_store(store: Arc<dyn Store>) -> ServiceState {
     state
 }
 
+/// Write a pending transaction row for `hash`: a default legacy envelope
+/// carrying `hash` (signature not checked) from signer `0x42…42`, no logs.
+pub async fn begin_test_txn(service: &ServiceState, hash: alloy::primitives::TxHash) {
+    let envelope = alloy::consensus::TxEnvelope::Legacy(alloy::consensus::Signed::new_unchecked(
+        alloy::consensus::TxLegacy::default(),
+        alloy::primitives::Signature::test_signature(),
+        hash,
+    ));
+    service
+        .store
+        .txn_begin(
+            hash,
+            crate::store::TxnEntry {
+                id: None,
+                envelope,
+                signer: alloy::primitives::Address::from([0x42u8; 20]),
+                expires_at: None,
+                logs: vec![],
+            },
+        )
+        .await
+        .unwrap();
+}
+
 /// Build a REAL `MidenClientLib` backed by a throwaway sqlite store and an RPC
 /// handle pointing at the (unused) localhost endpoint. `ClientBuilder::build`
 /// performs no network I/O — it only initialises the sqlite store and reads the
@@ -0,0 +1,355 @@
+//! Wrapped-token reads for `eth_getBalance` and the ERC-20 `eth_call` shim.
+//!
+//! A registered faucet is addressed on the synthetic chain by its
+//! [`EthEmbeddedAccountId`](miden_base_agglayer::EthEmbeddedAccountId) — the
+//! faucet's `AccountId` zero-padded into 20 bytes, the same encoding the
+//! address mapper resolves. `balanceOf` / `totalSupply` / `decimals` /
+//! `symbol` against that address read the Miden account vault or the faucet's
+//! issuance and report them in the ORIGIN token's decimals
+//! (`miden_amount * 10^scale`, see [`reverse_scale_amount`]), so a wallet sees
+//! the same units it bridged. `eth_getBalance` is the native-ETH faucet's
+//! `balanceOf`.
+//!
+//! Miden state is read through [`TokenBalanceSource`]; production reads the
+//! node's account RPC ([`NodeTokenSource`]), tests substitute a fixed table.
+//! An account the node cannot serve (private, or the node is unreachable) is
+//! an [`UnreadableAccount`], answered as `-32000` rather than a zero balance.
+
+use crate::bridge_out::reverse_scale_amount;
+use crate::service_state::ServiceState;
+use crate::store::FaucetEntry;
+use alloy::primitives::{Address, U256};
+use alloy_core::sol_types::SolCall;
+use axum_jrpc::JsonRpcResponse;
+use axum_jrpc::error::{JsonRpcError, JsonRpcErrorReason};
+use miden_client::rpc::NodeRpcClient;
+use miden_protocol::account::{Account, AccountId};
+use miden_protocol::asset::AssetId;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+alloy_core::sol! {
+    #[derive(Debug)]
+    interface IERC20Shim {
+        function balanceOf(address account) external view returns (uint256);
+        function totalSupply() external view returns (uint256);
+        function decimals() external view returns (uint8);
+        function symbol() external view returns (string);
+    }
+}
+
+/// Miden-side token state, in Miden base units.
+#[async_trait::async_trait]
+pub trait TokenBalanceSource: Send + Sync {
+    /// Amount of `faucet`'s asset held in `account`'s vault.
+    async fn balance(&self, account: AccountId, faucet: AccountId) -> anyhow::Result<u64>;
+    /// Amount `faucet` has issued and not yet burned.
+    async fn issuance(&self, faucet: AccountId) -> anyhow::Result<u64>;
+}
+
+/// The node has no readable state for `account`: it is private, or the node
+/// could not be reached. Not an internal error; see [`token_read_error`].
+#[derive(Debug, thiserror::Error)]
+#[error("balance of account {account} is unreadable: {reason}")]
+pub struct UnreadableAccount {
+    pub account: AccountId,
+    pub reason: String,
+}
+
+/// The JSON-RPC error for a failed token read: `-32000` for an
+/// [`UnreadableAccount`], a store error otherwise.
+pub(crate) fn token_read_error(answer_id: axum_jrpc::Id, e: anyhow::Error) -> JsonRpcResponse {
+    match e.downcast_ref::<UnreadableAccount>() {
+        Some(unreadable) => JsonRpcResponse::error(
+            answer_id,
+            JsonRpcError::new(
+                JsonRpcErrorReason::ServerError(-32000),
+                unreadable.to_string(),
+                serde_json::Value::Null,
+            ),
+        ),
+        None => crate::service_helpers::store_error(answer_id, e),
+    }
+}
+
+/// How long a read account value is served before the node is asked again.
+const TOKEN_READ_TTL: Duration = Duration::from_secs(2);
+/// Cached values kept at most; the cache is cleared when it fills.
+const TOKEN_READ_CACHE_ENTRIES: usize = 4096;
+
+/// [`TokenBalanceSource`] over the Miden node's account RPC. Reads go
+/// straight to the node rather than through the shared [`MidenClient`] actor,
+/// so public read traffic never queues behind writer submissions, and no
+/// account is imported into the client's tracked set. Only public accounts
+/// have readable state; a private one is an [`UnreadableAccount`]. Values are cached
+/// for [`TOKEN_READ_TTL`] to bound node load from repeated reads.
+pub struct NodeTokenSource {
+    rpc: Arc<dyn NodeRpcClient>,
+    cache: Mutex<HashMap<(AccountId, Option<AccountId>), (Instant, u64)>>,
+}
+
+impl NodeTokenSource {
+    pub fn new(node_url: &str, api_key: Option<&str>) -> anyhow::Result<Self> {
+        let endpoint = crate::miden_client::parse_node_url(node_url)?;
+        Ok(Self {
+            rpc: crate::miden_client::build_rpc_client(&endpoint, 10_000, api_key),
+            cache: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// `read` applied to `account`'s public state, cached under
+    /// `(account, faucet)`.
+    async fn read(
+        &self,
+        account: AccountId,
+        faucet: Option<AccountId>,
+        read: impl FnOnce(&Account) -> anyhow::Result<u64>,
+    ) -> anyhow::Result<u64> {
+        let key = (account, faucet);
+        if let Some((at, value)) = self.cache.lock().expect("token cache poisoned").get(&key)
+            && at.elapsed() < TOKEN_READ_TTL
+        {
+            return Ok(*value);
+        }
+        let state = self
+            .rpc
+            .get_account_details(account)
+            .await
+            .map_err(|e| UnreadableAccount {
+                account,
+                reason: format!("node unreachable: {e}"),
+            })?
+            .ok_or_else(|| UnreadableAccount {
+                account,
+                reason: "no public state on the node".to_string(),
+            })?;
+        let value = read(&state)?;
+        let mut cache = self.cache.lock().expect("token cache poisoned");
+        if cache.len() >= TOKEN_READ_CACHE_ENTRIES {
+            cache.clear();
+        }
+        cache.insert(key, (Instant::now(), value));
+        Ok(value)
+    }
+}
+
+#[async_trait::async_trait]
+impl TokenBalanceSource for NodeTokenSource {
+    async fn balance(&self, account: AccountId, faucet: AccountId) -> anyhow::Result<u64> {
+        self.read(account, Some(faucet), |state| {
+            let amount = state
+                .vault()
+                .get_balance(AssetId::new_fungible(faucet))
+                .map_err(|e| anyhow::anyhow!("get_balance({account}, {faucet}): {e}"))?;
+            Ok(amount.as_u64())
+        })
+        .await
+    }
+
+    async fn issuance(&self, faucet: AccountId) -> anyhow::Result<u64> {
+        self.read(faucet, None, |state| {
+            let (_, metadata) = crate::faucet_ops::classify_faucet_account(state)?;
+            Ok(metadata.token_supply().as_u64())
+        })
+        .await
+    }
+}
+
+/// The source until `main` configures [`NodeTokenSource`]: every read fails.
+pub struct UnconfiguredTokenSource;
+
+#[async_trait::async_trait]
+impl TokenBalanceSource for UnconfiguredTokenSource {
+    async fn balance(&self, _account: AccountId, _faucet: AccountId) -> anyhow::Result<u64> {
+        anyhow::bail!("token balance reads are not configured")
+    }
+
+    async fn issuance(&self, _faucet: AccountId) -> anyhow::Result<u64> {
+        anyhow::bail!("token balance reads are not configured")
+    }
+}
+
+/// The wrapped-token address of a registered faucet.
+pub fn token_address(faucet_id: AccountId) -> Address {
+    Address::from(<[u8; 20]>::from(
+        miden_base_agglayer::EthEmbeddedAccountId::from(faucet_id),
+    ))
+}
+
+/// The registered faucet whose wrapped-token address is `address`, if any.
+pub async fn faucet_at(
+    service: &ServiceState,
+    address: Address,
+) -> anyhow::Result<Option<FaucetEntry>> {
+    let Some(faucet_id) = crate::address_mapper::account_id_from_address(address) else {
+        return Ok(None);
+    };
+    service.store.get_faucet_by_id(faucet_id).await
+}
+
+/// `holder`'s balance of `faucet`'s token in origin decimals. An EVM address
+/// with no Miden account behind it holds nothing.
+pub async fn balance_of(
+    service: &ServiceState,
+    faucet: &FaucetEntry,
+    holder: Address,
+) -> anyhow::Result<U256> {
+    let account = if let Some(id) = service.store.get_address_mapping(&holder).await? {
+        id
+    } else if !service.reject_zero_padding_addresses
+        && let Some(id) = crate::address_mapper::account_id_from_address(holder)
+    {
+        id
+    } else {
+        return Ok(U256::ZERO);
+    };
+    let amount = service
+        .token_source
+        .balance(account, faucet.faucet_id)
+        .await?;
+    Ok(U256::from(reverse_scale_amount(amount, faucet.scale)?))
+}
+
+/// `holder`'s native-ETH balance in wei: its balance of the faucet registered
+/// for origin `(0x0, network 0)`; zero when no such faucet is registered.
+pub async fn eth_balance(service: &ServiceState, holder: Address) -> anyhow::Result<U256> {
+    match service.store.get_faucet_by_origin(&[0u8; 20], 0).await? {
+        Some(faucet) => balance_of(service, &faucet, holder).await,
+        None => Ok(U256::ZERO),
+    }
+}
+
+/// Answer an ERC-20 read against `faucet`'s wrapped-token address. `Ok(None)`
+/// for a selector the shim does not implement or calldata it cannot decode;
+/// the caller reverts on both.
+pub async fn erc20_call(
+    service: &ServiceState,
+    faucet: &FaucetEntry,
+    data: &[u8],
+) -> anyhow::Result<Option<Vec<u8>>> {
+    if data.starts_with(&IERC20Shim::balanceOfCall::SELECTOR) {
+        let Ok(call) = IERC20Shim::balanceOfCall::abi_decode(data) else {
+            return Ok(None);
+        };
+        let balance = balance_of(service, faucet, call.account).await?;
+        return Ok(Some(IERC20Shim::balanceOfCall::abi_encode_returns(
+            &balance,
+        )));
+    }
+    if data.starts_with(&IERC20Shim::totalSupplyCall::SELECTOR) {
+        let issued = service.token_source.issuance(faucet.faucet_id).await?;
+        let supply = U256::from(reverse_scale_amount(issued, faucet.scale)?);
+        return Ok(Some(IERC20Shim::totalSupplyCall::abi_encode_returns(
+            &supply,
+        )));
+    }
+    if data.starts_with(&IERC20Shim::decimalsCall::SELECTOR) {
+        return Ok(Some(IERC20Shim::decimalsCall::abi_encode_returns(
+            &faucet.origin_decimals,
+        )));
+    }
+    if data.starts_with(&IERC20Shim::symbolCall::SELECTOR) {
+        return Ok(Some(IERC20Shim::symbolCall::abi_encode_returns(
+            &faucet.symbol,
+        )));
+    }
+    Ok(None)
+}
+
+/// Fixed balances for tests.
+#[cfg(test)]
+#[derive(Default)]
+pub struct StaticTokenSource {
+    pub balances: std::collections::HashMap<(AccountId, AccountId), u64>,
+    pub issuance: std::collections::HashMap<AccountId, u64>,
+    /// Accounts read as private.
+    pub private: std::collections::HashSet<AccountId>,
+}
+
+#[cfg(test)]
+#[async_trait::async_trait]
+impl TokenBalanceSource for StaticTokenSource {
+    async fn balance(&self, account: AccountId, faucet: AccountId) -> anyhow::Result<u64> {
+        if self.private.contains(&account) {
+            return Err(UnreadableAccount {
+                account,
+                reason: "no public state on the node".to_string(),
+            }
+            .into());
+        }
+        Ok(self
+            .balances
+            .get(&(account, faucet))
+            .copied()
+            .unwrap_or_default())
+    }
+
+    async fn issuance(&self, faucet: AccountId) -> anyhow::Result<u64> {
+        Ok(self.issuance.get(&faucet).copied().unwrap_or_default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::{create_test_service, seed_test_faucets};
+
+    #[tokio::test]
+    async fn reads_scale_back_to_origin_decimals() {
+        let mut service = create_test_service();
+        seed_test_faucets(&*service.store).await;
+        let faucet = service
+            .store
+            .get_faucet_by_origin(&[0u8; 20], 0)
+            .await
+            .unwrap()
+            .unwrap();
+        let holder = Address::from([0x77u8; 20]);
+        let holder_id = faucet.faucet_id;
+        service
+            .store
+            .set_address_mapping(holder, holder_id)
+            .await
+            .unwrap();
+        let mut source = StaticTokenSource::default();
+        source.balances.insert((holder_id, faucet.faucet_id), 3);
+        source.issuance.insert(faucet.faucet_id, 5);
+        service.token_source = Arc::new(source);
+
+        // 3 Miden units at scale 10 = 3 * 10^10 wei.
+        let wei = U256::from(30_000_000_000u64);
+        assert_eq!(eth_balance(&service, holder).await.unwrap(), wei);
+        let found = faucet_at(&service, token_address(faucet.faucet_id))
+            .await
+            .unwrap()
+            .expect("faucet at its wrapped-token address");
+        assert_eq!(found.faucet_id, faucet.faucet_id);
+
+        let call = IERC20Shim::balanceOfCall { account: holder }.abi_encode();
+        let reply = erc20_call(&service, &faucet, &call).await.unwrap().unwrap();
+        assert_eq!(U256::from_be_slice(&reply), wei);
+        let call = IERC20Shim::totalSupplyCall {}.abi_encode();
+        let reply = erc20_call(&service, &faucet, &call).await.unwrap().unwrap();
+        assert_eq!(U256::from_be_slice(&reply), U256::from(50_000_000_000u64));
+        let call = IERC20Shim::decimalsCall {}.abi_encode();
+        let reply = erc20_call(&service, &faucet, &call).await.unwrap().unwrap();
+        assert_eq!(reply[31], 18);
+        let call = IERC20Shim::symbolCall {}.abi_encode();
+        let reply = erc20_call(&service, &faucet, &call).await.unwrap().unwrap();
+        assert_eq!(
+            IERC20Shim::symbolCall::abi_decode_returns(&reply).unwrap(),
+            "ETH"
+        );
+
+        // Unmapped, non-embedded holders hold nothing.
+        let stranger = Address::from([0xffu8; 20]);
+        assert_eq!(eth_balance(&service, stranger).await.unwrap(), U256::ZERO);
+        assert!(
+            erc20_call(&service, &faucet, &[0xde, 0xad, 0xbe, 0xef])
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+}
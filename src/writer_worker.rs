@@ -30,6 +30,19 @@
 //!   state has been observed long enough that callers should have polled the
 //!   receipt by now.
 //!
+//! ## Lanes
+//!
+//! Each [`WriteJobKind`] has its own bounded queue and `-32005` saturation,
+//! and the worker serves them weighted round-robin (GER injections first,
+//! four per claim by default). Claims depend on their GER being injected, so
+//! a claim burst from bridge-service must not queue aggoracle's GER updates
+//! behind it. See [`WriterLanes`].
+//!
+//! A claim turn takes the claims already waiting in its lane, up to
+//! `AGGLAYER_WRITER_CLAIM_BATCH_MAX`, and publishes them as one Miden
+//! transaction (`service_send_raw_txn::worker_handle_claim_batch`); receipts,
+//! failures and metrics stay per job.
+//!
 //! ## Phase 1 scope (this module, current commit)
 //!
 //! - WriteJob enum + JobState + InFlightEntry + WriterWorkerHandle + Worker
@@ -49,9 +62,12 @@ use crate::service_state::ServiceState;
 use alloy::consensus::TxEnvelope;
 use alloy::primitives::{Address, TxHash};
 use dashmap::DashMap;
+use parking_lot::Mutex;
+use std::collections::VecDeque;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::{Duration, Instant};
-use tokio::sync::{mpsc, oneshot};
+use tokio::sync::{mpsc, oneshot, watch};
 use tracing::Instrument;
 use ulid::Ulid;
 
@@ -76,40 +92,243 @@ pub const TX_TTL_ENV: &str = "AGGLAYER_WRITER_TX_TTL";
 /// How often the TTL sweeper task wakes up to evict aged-out terminal entries.
 const SWEEPER_INTERVAL: Duration = Duration::from_secs(30);
 
-/// RD-940 Phase 5 — graceful-shutdown queue-depth snapshot location.
-///
-/// Written by the writer-worker process on clean termination with the
-/// number of in-flight jobs still in non-terminal state. Read+reset on the
-/// next process boot to feed the `agglayer_writer_dropped_on_restart_total`
-/// counter. `/tmp` is appropriate for a k8s `emptyDir` (default behaviour on
-/// bali) — survives across container restarts within the same Pod, lost
-/// across Pod evictions, which remains useful as an observability signal even though the signed
-/// envelope is durably recoverable from the transaction store. SIGKILL leaves the tmpfile absent; combined with
-/// pre-kill `agglayer_writer_queue_depth` history this still pinpoints the
-/// loss window.
-pub const DROP_SNAPSHOT_PATH: &str = "/tmp/agglayer-writer-queue-snapshot";
-
-/// Read + remove the dropped-on-restart snapshot left by the previous
-/// shutdown. Returns the residual count (0 if the file is missing or
-/// unparsable). Call this **after** `metrics::init_metrics` so the
-/// counter increment lands in the registered recorder.
-pub fn read_and_clear_drop_snapshot() -> u64 {
-    match std::fs::read_to_string(DROP_SNAPSHOT_PATH) {
-        Ok(s) => {
-            let n: u64 = s.trim().parse().unwrap_or(0);
-            let _ = std::fs::remove_file(DROP_SNAPSHOT_PATH);
-            n
-        }
-        Err(_) => 0,
+/// Default ceiling on the shutdown drain (seconds). The drain deadline itself
+/// is derived from recent job durations; this only caps it so the process
+/// still exits inside the container's termination grace. Override with
+/// `AGGLAYER_WRITER_DRAIN_TIMEOUT`.
+pub const DEFAULT_DRAIN_TIMEOUT_SECS: u64 = 20;
+
+/// Env var consulted by `WriterWorker::parse_drain_timeout_env`.
+pub const DRAIN_TIMEOUT_ENV: &str = "AGGLAYER_WRITER_DRAIN_TIMEOUT";
+
+/// Completed-job durations kept for the drain estimate.
+const RECENT_DURATION_WINDOW: usize = 32;
+
+/// Per-job estimate used before any job has completed in this process — the
+/// spec's p50 commit time.
+const DEFAULT_JOB_ESTIMATE: Duration = Duration::from_secs(10);
+
+// ─── Lanes ───────────────────────────────────────────────────────────────────
+
+/// Default scheduling weights. With both lanes backed up the worker takes up
+/// to four GER injections for every claim: claims cannot land before their
+/// GER, so a claim burst must not hold aggoracle's updates back.
+pub const DEFAULT_GER_LANE_WEIGHT: u32 = 4;
+pub const DEFAULT_CLAIM_LANE_WEIGHT: u32 = 1;
+
+/// Per-lane capacity overrides; each falls back to `AGGLAYER_WRITER_QUEUE_DEPTH`.
+pub const GER_QUEUE_DEPTH_ENV: &str = "AGGLAYER_WRITER_GER_QUEUE_DEPTH";
+pub const CLAIM_QUEUE_DEPTH_ENV: &str = "AGGLAYER_WRITER_CLAIM_QUEUE_DEPTH";
+
+/// Per-lane weight overrides.
+pub const GER_LANE_WEIGHT_ENV: &str = "AGGLAYER_WRITER_GER_WEIGHT";
+pub const CLAIM_LANE_WEIGHT_ENV: &str = "AGGLAYER_WRITER_CLAIM_WEIGHT";
+
+/// Default cap on claims coalesced into one Miden transaction. Proving
+/// dominates a claim's cost, so a backlog is published a few claims per proof;
+/// the cap bounds the transaction (and its proof) size. Override with
+/// `AGGLAYER_WRITER_CLAIM_BATCH_MAX`; 1 disables batching.
+pub const DEFAULT_CLAIM_BATCH_MAX: usize = 4;
+
+/// Env var consulted by `WriterLanes::from_env` for the claim batch cap.
+pub const CLAIM_BATCH_MAX_ENV: &str = "AGGLAYER_WRITER_CLAIM_BATCH_MAX";
+
+/// Lanes in scheduling order: a fresh round starts with GER injections.
+const LANE_ORDER: [WriteJobKind; 2] = [WriteJobKind::GerInsert, WriteJobKind::Claim];
+
+fn lane_index(kind: WriteJobKind) -> usize {
+    match kind {
+        // Removals are rare operator actions on the same GER-manager account;
+        // they share the GER lane rather than getting a lane of their own.
+        WriteJobKind::GerInsert | WriteJobKind::GerRemove => 0,
+        WriteJobKind::Claim => 1,
+    }
+}
+
+/// Capacity and scheduling weight of one job-kind lane.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LaneConfig {
+    /// mpsc capacity; a full lane answers `-32005` for its kind only.
+    pub depth: usize,
+    /// Jobs taken from this lane per round while other lanes have work.
+    pub weight: u32,
+}
+
+/// Lane layout of the writer: one bounded queue per [`WriteJobKind`], served
+/// weighted round-robin.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WriterLanes {
+    pub ger_insert: LaneConfig,
+    pub claim: LaneConfig,
+    /// Most queued claims published as one Miden transaction. A batch is
+    /// one turn of the claim lane.
+    pub claim_batch: usize,
+}
+
+impl WriterLanes {
+    /// Both lanes at `depth` with the default weights and claim batch cap.
+    pub fn uniform(depth: usize) -> Self {
+        Self {
+            ger_insert: LaneConfig {
+                depth,
+                weight: DEFAULT_GER_LANE_WEIGHT,
+            },
+            claim: LaneConfig {
+                depth,
+                weight: DEFAULT_CLAIM_LANE_WEIGHT,
+            },
+            claim_batch: DEFAULT_CLAIM_BATCH_MAX,
+        }
+    }
+
+    /// Read the lane overrides, with `AGGLAYER_WRITER_QUEUE_DEPTH` (or its
+    /// default) as every lane's fallback depth.
+    pub fn from_env() -> Self {
+        let depth = WriterWorker::parse_queue_depth_env();
+        Self {
+            ger_insert: LaneConfig {
+                depth: positive_env(GER_QUEUE_DEPTH_ENV, depth),
+                weight: positive_env(GER_LANE_WEIGHT_ENV, DEFAULT_GER_LANE_WEIGHT as usize) as u32,
+            },
+            claim: LaneConfig {
+                depth: positive_env(CLAIM_QUEUE_DEPTH_ENV, depth),
+                weight: positive_env(CLAIM_LANE_WEIGHT_ENV, DEFAULT_CLAIM_LANE_WEIGHT as usize)
+                    as u32,
+            },
+            claim_batch: positive_env(CLAIM_BATCH_MAX_ENV, DEFAULT_CLAIM_BATCH_MAX),
+        }
+    }
+
+    pub fn get(&self, kind: WriteJobKind) -> LaneConfig {
+        match kind {
+            WriteJobKind::GerInsert | WriteJobKind::GerRemove => self.ger_insert,
+            WriteJobKind::Claim => self.claim,
+        }
+    }
+
+    /// Total capacity across lanes.
+    pub fn total_depth(&self) -> usize {
+        self.ger_insert.depth + self.claim.depth
+    }
+
+    fn channels(&self) -> ([mpsc::Sender<WriteJob>; 2], LaneReceivers) {
+        let (ger_tx, ger_rx) = mpsc::channel(self.ger_insert.depth);
+        let (claim_tx, claim_rx) = mpsc::channel(self.claim.depth);
+        let receivers = LaneReceivers {
+            receivers: [ger_rx, claim_rx],
+            closed: [false; 2],
+            weights: LANE_ORDER.map(|kind| self.get(kind).weight.max(1)),
+            current: 0,
+            served: 0,
+        };
+        ([ger_tx, claim_tx], receivers)
     }
 }
 
-/// Write the dropped-on-restart snapshot at graceful shutdown. Tolerates
-/// I/O failure — if the write fails, the next boot's read returns 0 and
-/// the operator sees "queue depth was high → restart → counter quiet",
-/// which the pre-kill queue-depth history covers as the fallback signal.
-pub fn write_drop_snapshot(count: u64) {
-    let _ = std::fs::write(DROP_SNAPSHOT_PATH, count.to_string());
+/// Read a positive integer env var, falling back to `default` when unset and
+/// warning when set to something unusable.
+fn positive_env(env: &str, default: usize) -> usize {
+    match std::env::var(env) {
+        Ok(v) => match v.parse::<usize>() {
+            Ok(n) if n >= 1 => n,
+            Ok(n) => {
+                tracing::warn!(env, value = n, "{env} must be ≥ 1; using default {default}");
+                default
+            }
+            Err(e) => {
+                tracing::warn!(
+                    env,
+                    value = %v,
+                    err = %e,
+                    "could not parse {env}; using default {default}"
+                );
+                default
+            }
+        },
+        Err(_) => default,
+    }
+}
+
+/// Worker side of the lanes: the receivers plus the weighted round-robin
+/// cursor. A lane keeps the turn while it has work and credit left; an empty
+/// lane passes the turn on immediately, so an idle GER lane never delays
+/// claims and a claim backlog delays a GER by at most one claim weight.
+struct LaneReceivers {
+    receivers: [mpsc::Receiver<WriteJob>; 2],
+    closed: [bool; 2],
+    weights: [u32; 2],
+    current: usize,
+    served: u32,
+}
+
+impl LaneReceivers {
+    /// Next job by weight without waiting.
+    fn try_next(&mut self) -> Option<WriteJob> {
+        // Current lane (if it has credit), every other lane, then the current
+        // lane again with fresh credit.
+        for _ in 0..=self.receivers.len() {
+            if self.served < self.weights[self.current]
+                && let Ok(job) = self.receivers[self.current].try_recv()
+            {
+                self.served += 1;
+                return Some(job);
+            }
+            self.current = (self.current + 1) % self.receivers.len();
+            self.served = 0;
+        }
+        None
+    }
+
+    /// Next job by weight, waiting for one when every lane is empty. `None`
+    /// once every lane is closed and empty.
+    async fn next(&mut self) -> Option<WriteJob> {
+        loop {
+            if let Some(job) = self.try_next() {
+                return Some(job);
+            }
+            let [ger, claim] = &mut self.receivers;
+            let (lane, job) = tokio::select! {
+                job = ger.recv(), if !self.closed[0] => (0, job),
+                job = claim.recv(), if !self.closed[1] => (1, job),
+                else => return None,
+            };
+            match job {
+                Some(job) => {
+                    self.current = lane;
+                    self.served = 1;
+                    return Some(job);
+                }
+                None => self.closed[lane] = true,
+            }
+        }
+    }
+
+    /// `first` plus, when it is a claim, up to `max - 1` more claims already
+    /// waiting in the claim lane. Never waits for a batch to fill.
+    fn coalesce_claims(&mut self, first: WriteJob, max: usize) -> Vec<WriteJob> {
+        let mut jobs = vec![first];
+        if jobs[0].kind() != WriteJobKind::Claim {
+            return jobs;
+        }
+        let claims = &mut self.receivers[lane_index(WriteJobKind::Claim)];
+        while jobs.len() < max
+            && let Ok(job) = claims.try_recv()
+        {
+            jobs.push(job);
+        }
+        jobs
+    }
+
+    fn close(&mut self) {
+        for receiver in &mut self.receivers {
+            receiver.close();
+        }
+    }
+
+    /// Jobs still buffered across lanes.
+    fn len(&self) -> usize {
+        self.receivers.iter().map(|r| r.len()).sum()
+    }
 }
 
 // ─── DecodedWriteCall ───────────────────────────────────────────────────────
@@ -131,6 +350,11 @@ pub enum DecodedWriteCall {
     Ger {
         ger_bytes: [u8; 32],
     },
+    /// `removeGlobalExitRoots` with exactly one GER (one RemoveGerNote per
+    /// eth transaction).
+    GerRemoval {
+        ger_bytes: [u8; 32],
+    },
 }
 
 impl DecodedWriteCall {
@@ -138,6 +362,7 @@ impl DecodedWriteCall {
         match self {
             DecodedWriteCall::Claim { .. } => WriteJobKind::Claim,
             DecodedWriteCall::Ger { .. } => WriteJobKind::GerInsert,
+            DecodedWriteCall::GerRemoval { .. } => WriteJobKind::GerRemove,
         }
     }
 
@@ -158,6 +383,13 @@ impl DecodedWriteCall {
                 eth_tx_hash,
                 job_id,
             },
+            DecodedWriteCall::GerRemoval { ger_bytes } => WriteJob::GerRemoval {
+                ger_bytes,
+                envelope,
+                signer,
+                eth_tx_hash,
+                job_id,
+            },
         }
     }
 }
@@ -168,6 +400,7 @@ impl DecodedWriteCall {
 pub enum WriteJobKind {
     Claim,
     GerInsert,
+    GerRemove,
 }
 
 impl WriteJobKind {
@@ -176,6 +409,7 @@ impl WriteJobKind {
         match self {
             WriteJobKind::Claim => "claim",
             WriteJobKind::GerInsert => "ger_insert",
+            WriteJobKind::GerRemove => "ger_remove",
         }
     }
 }
@@ -202,24 +436,37 @@ pub enum WriteJob {
         eth_tx_hash: TxHash,
         job_id: Ulid,
     },
+    GerRemoval {
+        ger_bytes: [u8; 32],
+        envelope: TxEnvelope,
+        signer: Address,
+        eth_tx_hash: TxHash,
+        job_id: Ulid,
+    },
 }
 
 impl WriteJob {
     pub fn eth_tx_hash(&self) -> TxHash {
         match self {
-            WriteJob::Claim { eth_tx_hash, .. } | WriteJob::Ger { eth_tx_hash, .. } => *eth_tx_hash,
+            WriteJob::Claim { eth_tx_hash, .. }
+            | WriteJob::Ger { eth_tx_hash, .. }
+            | WriteJob::GerRemoval { eth_tx_hash, .. } => *eth_tx_hash,
         }
     }
 
     pub fn signer(&self) -> Address {
         match self {
-            WriteJob::Claim { signer, .. } | WriteJob::Ger { signer, .. } => *signer,
+            WriteJob::Claim { signer, .. }
+            | WriteJob::Ger { signer, .. }
+            | WriteJob::GerRemoval { signer, .. } => *signer,
         }
     }
 
     pub fn job_id(&self) -> Ulid {
         match self {
-            WriteJob::Claim { job_id, .. } | WriteJob::Ger { job_id, .. } => *job_id,
+            WriteJob::Claim { job_id, .. }
+            | WriteJob::Ger { job_id, .. }
+            | WriteJob::GerRemoval { job_id, .. } => *job_id,
         }
     }
 
@@ -227,6 +474,7 @@ impl WriteJob {
         match self {
             WriteJob::Claim { .. } => WriteJobKind::Claim,
             WriteJob::Ger { .. } => WriteJobKind::GerInsert,
+            WriteJob::GerRemoval { .. } => WriteJobKind::GerRemove,
         }
     }
 }
@@ -248,11 +496,29 @@ pub enum JobState {
     /// Worker returned an error or its supervised dispatch task panicked. A failure
     /// receipt was best-effort written; the caller's `eth_getTransactionReceipt` returns `status:0x0`.
     Failed,
+    /// An operator cancelled the job while it was still queued (see
+    /// [`cancel_queued_job`]). The worker drops it on dequeue; the receipt is
+    /// `status:0x0`.
+    Cancelled,
 }
 
 impl JobState {
     pub fn is_terminal(self) -> bool {
-        matches!(self, JobState::Committed { .. } | JobState::Failed)
+        matches!(
+            self,
+            JobState::Committed { .. } | JobState::Failed | JobState::Cancelled
+        )
+    }
+
+    /// Stable label for admin responses.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            JobState::Queued => "queued",
+            JobState::Submitting => "submitting",
+            JobState::Committed { .. } => "committed",
+            JobState::Failed => "failed",
+            JobState::Cancelled => "cancelled",
+        }
     }
 }
 
@@ -271,7 +537,11 @@ pub struct InFlightEntry {
     pub envelope: TxEnvelope,
     /// Wall-clock instant the entry was first inserted (mpsc enqueue moment).
     pub created_at: Instant,
-    /// Wall-clock instant the state transitioned to `Committed` or `Failed`.
+    /// Time the writer spent paused while this entry was queued. Left out of
+    /// the queue wait, so a pause cannot expire the jobs it holds.
+    pub paused_for: Duration,
+    /// Wall-clock instant the state transitioned to `Committed`, `Failed` or
+    /// `Cancelled`.
     /// `None` until the worker writes a terminal state; used by the sweeper to
     /// time TTL eviction.
     pub terminal_at: Option<Instant>,
@@ -287,15 +557,23 @@ impl InFlightEntry {
             job_id: job.job_id(),
             envelope: job.envelope().clone(),
             created_at: Instant::now(),
+            paused_for: Duration::ZERO,
             terminal_at: None,
         }
     }
+
+    /// Time spent queued, not counting writer pauses.
+    pub fn queue_wait(&self) -> Duration {
+        self.created_at.elapsed().saturating_sub(self.paused_for)
+    }
 }
 
 impl WriteJob {
     fn envelope(&self) -> &TxEnvelope {
         match self {
-            WriteJob::Claim { envelope, .. } | WriteJob::Ger { envelope, .. } => envelope,
+            WriteJob::Claim { envelope, .. }
+            | WriteJob::Ger { envelope, .. }
+            | WriteJob::GerRemoval { envelope, .. } => envelope,
         }
     }
 }
@@ -312,9 +590,13 @@ pub enum TryEnqueueError {
     /// ethtxmanager retries `-32005` transparently. See Spec E.
     #[error("writer queue saturated; retry")]
     QueueFull,
+    /// The writer is draining for shutdown and admits no new work. Wire
+    /// response: JSON-RPC `-32005 "service shutting down; retry"`.
+    #[error("service shutting down; retry")]
+    ShuttingDown,
     /// mpsc receiver has been dropped — worker task exited (graceful shutdown
     /// or a panic that crossed the supervision boundary). Wire response:
-    /// JSON-RPC `-32005 "service shutting down"`; aggkit retries.
+    /// JSON-RPC `-32005 "service shutting down; retry"`; aggkit retries.
     #[error("writer worker has shut down")]
     ShutDown,
 }
@@ -329,15 +611,41 @@ pub enum TryEnqueueError {
 #[error("writer queue saturated; retry")]
 pub struct WriterQueueSaturatedError;
 
+/// Sentinel for admission refused because the writer is draining or has
+/// stopped. Mapped to JSON-RPC `-32005 "service shutting down; retry"` by the
+/// same dispatcher downcast as [`WriterQueueSaturatedError`]. Re-sending the
+/// same signed transaction to the next process is safe either way: refused
+/// before admission it was never recorded, refused after it is a durable
+/// orphan that recovery resumes.
+#[derive(Debug, thiserror::Error)]
+#[error("service shutting down; retry")]
+pub struct WriterShuttingDownError;
+
+/// Why [`cancel_queued_job`] refused to cancel a job.
+#[derive(Debug, thiserror::Error)]
+pub enum CancelJobError {
+    #[error("no writer job for this hash (unknown or already evicted)")]
+    NotFound,
+    /// Only `Queued` jobs can be cancelled; a dequeued job may already have
+    /// reached Miden.
+    #[error("writer job is {} and can no longer be cancelled", .0.as_str())]
+    NotQueued(JobState),
+    /// An earlier attempt left a durable note handoff, so the outcome belongs
+    /// to commit/observation reconciliation.
+    #[error("writer job has a durable note handoff; its outcome is left to reconciliation")]
+    HandedOff,
+}
+
 #[derive(Debug, thiserror::Error)]
 #[error("writer dispatch task panicked: {0}")]
 struct WriterDispatchPanic(String);
 
 /// Run one dispatch behind a Tokio task boundary so a panic becomes a normal
 /// job failure and cannot terminate the sole writer loop.
-async fn supervise_dispatch<F>(future: F) -> anyhow::Result<()>
+async fn supervise_dispatch<F, T>(future: F) -> anyhow::Result<T>
 where
-    F: std::future::Future<Output = anyhow::Result<()>> + Send + 'static,
+    F: std::future::Future<Output = anyhow::Result<T>> + Send + 'static,
+    T: Send + 'static,
 {
     match tokio::spawn(future.in_current_span()).await {
         Ok(result) => result,
@@ -355,25 +663,117 @@ where
 /// Producer-side handle to the writer worker. Cloneable via `Arc` so every
 /// `ServiceState` clone shares the same channel + in-flight cache.
 pub struct WriterWorkerHandle {
-    sender: mpsc::Sender<WriteJob>,
+    /// One sender per lane, indexed like [`LANE_ORDER`].
+    senders: [mpsc::Sender<WriteJob>; 2],
+    lanes: WriterLanes,
     inflight: Arc<DashMap<TxHash, InFlightEntry>>,
-    queue_depth: usize,
+    /// Set once shutdown begins; `try_enqueue` refuses new work from then on.
+    draining: Arc<AtomicBool>,
+    /// Durations of the most recently committed jobs, newest last. Feeds the
+    /// drain deadline.
+    recent_durations: Arc<Mutex<VecDeque<Duration>>>,
+    /// Taken by the single [`WriterWorkerHandle::drain`] call.
+    drain_tx: Mutex<Option<oneshot::Sender<DrainRequest>>>,
+    /// Operator pause switch; the worker dispatches nothing while `true`.
+    paused: watch::Sender<bool>,
+    /// When the current pause began; `None` while dispatching.
+    paused_since: Mutex<Option<Instant>>,
+}
+
+/// Sent to the worker to switch it from its receive loop into drain mode.
+struct DrainRequest {
+    deadline: Instant,
+    per_job: Duration,
+    /// Receives the number of jobs dispatched during the drain.
+    done: oneshot::Sender<usize>,
+}
+
+/// How long the shutdown drain may run, computed when it starts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DrainPlan {
+    /// Non-terminal jobs (queued + submitting) when the drain started.
+    pub pending: usize,
+    /// Expected duration of one job: the p90 of recent committed jobs.
+    pub per_job: Duration,
+    /// `per_job × pending`, capped by the configured drain timeout.
+    pub budget: Duration,
+}
+
+impl DrainPlan {
+    /// Plan a drain of `pending` jobs from the recently observed job
+    /// durations. With no history the spec's 10 s p50 stands in.
+    pub fn new(recent: &[Duration], pending: usize, cap: Duration) -> Self {
+        let per_job = if recent.is_empty() {
+            DEFAULT_JOB_ESTIMATE
+        } else {
+            let mut sorted = recent.to_vec();
+            sorted.sort_unstable();
+            // Nearest-rank p90.
+            let rank = (sorted.len() * 9).div_ceil(10);
+            sorted[rank.saturating_sub(1)]
+        };
+        let wanted = per_job.saturating_mul(u32::try_from(pending).unwrap_or(u32::MAX));
+        Self {
+            pending,
+            per_job,
+            budget: wanted.min(cap),
+        }
+    }
+}
+
+/// Result of [`WriterWorkerHandle::drain`].
+#[derive(Debug, Clone)]
+pub struct DrainReport {
+    pub plan: DrainPlan,
+    /// Jobs the worker dispatched after the drain started.
+    pub dispatched: usize,
+    /// Jobs still queued or submitting when the drain ended.
+    pub residual: Vec<TxHash>,
+    pub elapsed: Duration,
 }
 
 /// Keep-alive for a [`WriterWorkerHandle::saturated_for_test`] handle: holds the
-/// un-drained receiver (channel stays open) and the owned permit (the sole slot
-/// stays reserved, so `available_capacity()` stays 0).
+/// un-drained receivers (channels stay open) and the owned permits (each lane's
+/// sole slot stays reserved, so `available_capacity()` stays 0).
 #[cfg(test)]
 pub struct SaturatedWriterGuard {
-    _rx: mpsc::Receiver<WriteJob>,
-    _permit: mpsc::OwnedPermit<WriteJob>,
+    _rx: LaneReceivers,
+    _permits: Vec<mpsc::OwnedPermit<WriteJob>>,
 }
 
 impl WriterWorkerHandle {
-    /// Configured mpsc capacity (the static `queue_depth` argument passed to
-    /// `WriterWorker::spawn`). Read-only.
+    fn new(
+        senders: [mpsc::Sender<WriteJob>; 2],
+        lanes: WriterLanes,
+        inflight: Arc<DashMap<TxHash, InFlightEntry>>,
+        recent_durations: Arc<Mutex<VecDeque<Duration>>>,
+        drain_tx: Option<oneshot::Sender<DrainRequest>>,
+    ) -> Self {
+        Self {
+            senders,
+            lanes,
+            inflight,
+            draining: Arc::new(AtomicBool::new(false)),
+            recent_durations,
+            drain_tx: Mutex::new(drain_tx),
+            paused: watch::channel(false).0,
+            paused_since: Mutex::new(None),
+        }
+    }
+
+    /// Configured capacity summed over all lanes. Read-only.
     pub fn queue_depth(&self) -> usize {
-        self.queue_depth
+        self.lanes.total_depth()
+    }
+
+    /// Configured capacity of the lane serving `kind`.
+    pub fn queue_depth_for(&self, kind: WriteJobKind) -> usize {
+        self.lanes.get(kind).depth
+    }
+
+    /// The lane layout the worker was spawned with.
+    pub fn lanes(&self) -> WriterLanes {
+        self.lanes
     }
 
     /// Current size of the in-flight DashMap (queued + submitting +
@@ -408,43 +808,112 @@ impl WriterWorkerHandle {
         })
     }
 
-    /// Number of slots currently available in the mpsc channel
-    /// (`Sender::capacity()`). Re-published as the
-    /// `agglayer_writer_queue_depth` gauge on each enqueue attempt.
+    /// Every tracked job — queued, submitting, and terminal entries not yet
+    /// TTL-evicted — oldest first.
+    pub fn entries(&self) -> Vec<InFlightEntry> {
+        let mut entries: Vec<InFlightEntry> =
+            self.inflight.iter().map(|e| e.value().clone()).collect();
+        entries.sort_by_key(|e| e.created_at);
+        entries
+    }
+
+    /// Lookup by the ULID assigned at enqueue.
+    pub fn find_job(&self, job_id: Ulid) -> Option<InFlightEntry> {
+        self.inflight
+            .iter()
+            .find(|e| e.job_id == job_id)
+            .map(|e| e.value().clone())
+    }
+
+    /// Move a `Queued` job to `Cancelled`. The transition happens under the
+    /// entry's map lock, the same lock the worker takes to mark a dequeued
+    /// job `Submitting`, so exactly one of the two wins. Receipt writing is
+    /// [`cancel_queued_job`]'s part.
+    fn mark_cancelled(&self, hash: &TxHash) -> Result<InFlightEntry, CancelJobError> {
+        let mut entry = self
+            .inflight
+            .get_mut(hash)
+            .ok_or(CancelJobError::NotFound)?;
+        if entry.state != JobState::Queued {
+            return Err(CancelJobError::NotQueued(entry.state));
+        }
+        entry.state = JobState::Cancelled;
+        entry.terminal_at = Some(Instant::now());
+        Ok(entry.value().clone())
+    }
+
+    /// Stop dispatching after the current job. Admission stays open, so the
+    /// lanes fill and eventually answer `-32005`; the shutdown drain of a
+    /// paused writer dispatches nothing. Paused time does not count towards
+    /// the queue-wait TTL.
+    pub fn pause(&self) {
+        self.paused_since.lock().get_or_insert_with(Instant::now);
+        self.paused.send_replace(true);
+        ::metrics::gauge!("agglayer_writer_paused").set(1.0);
+    }
+
+    /// Resume dispatching after [`WriterWorkerHandle::pause`]. Every queued
+    /// job is credited the time it spent paused before the worker can
+    /// dequeue it again.
+    pub fn resume(&self) {
+        if let Some(since) = self.paused_since.lock().take() {
+            for mut entry in self.inflight.iter_mut() {
+                if entry.state == JobState::Queued {
+                    let paused_for = since.max(entry.created_at).elapsed();
+                    entry.paused_for += paused_for;
+                }
+            }
+        }
+        self.paused.send_replace(false);
+        ::metrics::gauge!("agglayer_writer_paused").set(0.0);
+    }
+
+    pub fn is_paused(&self) -> bool {
+        *self.paused.borrow()
+    }
+
+    /// Free slots summed over all lanes.
     pub fn available_capacity(&self) -> usize {
-        self.sender.capacity()
+        self.senders.iter().map(|s| s.capacity()).sum()
+    }
+
+    /// Free slots in the lane serving `kind` (`Sender::capacity()`). Admission
+    /// answers `-32005` when this is 0, whatever the other lanes hold.
+    pub fn available_capacity_for(&self, kind: WriteJobKind) -> usize {
+        self.senders[lane_index(kind)].capacity()
     }
 
-    /// Build a handle whose write channel reports `available_capacity() == 0`
-    /// deterministically (no draining worker), by holding the single buffer slot
-    /// with an owned permit. Used to test that a durable admission whose enqueue
-    /// is rejected under saturation stays automatically recoverable (#156). The
-    /// returned guard MUST be held for the test's duration — it pins the
-    /// reservation and keeps the channel open.
+    /// Build a handle whose lanes all report no free capacity
+    /// deterministically (no draining worker), by holding each lane's single
+    /// buffer slot with an owned permit. Used to test that a durable admission
+    /// whose enqueue is rejected under saturation stays automatically
+    /// recoverable (#156). The returned guard MUST be held for the test's
+    /// duration — it pins the reservations and keeps the channels open.
     #[cfg(test)]
     pub fn saturated_for_test() -> (Self, SaturatedWriterGuard) {
-        let (sender, rx) = mpsc::channel::<WriteJob>(1);
-        let permit = sender
-            .clone()
-            .try_reserve_owned()
-            .expect("the sole channel slot must be reservable");
-        let handle = Self {
-            sender,
-            inflight: Arc::new(DashMap::new()),
-            queue_depth: 1,
-        };
+        let lanes = WriterLanes::uniform(1);
+        let (handle, rx) = tests::test_handle(lanes);
+        let permits = handle
+            .senders
+            .iter()
+            .map(|sender| {
+                sender
+                    .clone()
+                    .try_reserve_owned()
+                    .expect("the sole channel slot must be reservable")
+            })
+            .collect();
         (
             handle,
             SaturatedWriterGuard {
                 _rx: rx,
-                _permit: permit,
+                _permits: permits,
             },
         )
     }
 
     /// RD-940 Phase 5 — total non-terminal in-flight count (Queued +
-    /// Submitting across all signers). Used at graceful shutdown to size
-    /// the `dropped_on_restart` tmpfile snapshot.
+    /// Submitting across all signers). Sizes the shutdown drain.
     pub fn inflight_non_terminal_count(&self) -> usize {
         self.inflight
             .iter()
@@ -452,6 +921,74 @@ impl WriterWorkerHandle {
             .count()
     }
 
+    /// Hashes of the non-terminal in-flight jobs. What is left of these when
+    /// the drain ends is marked in the store for immediate recovery.
+    pub fn non_terminal_hashes(&self) -> Vec<TxHash> {
+        self.inflight
+            .iter()
+            .filter(|e| !e.state.is_terminal())
+            .map(|e| *e.key())
+            .collect()
+    }
+
+    /// Stop admitting new work. Called from the shutdown signal handler so
+    /// `eth_sendRawTransaction` answers "shutting down" for the rest of the
+    /// process's life, including while HTTP connections wind down. Idempotent.
+    pub fn begin_drain(&self) {
+        if !self.draining.swap(true, Ordering::SeqCst) {
+            tracing::info!(
+                target: "writer_worker::drain",
+                pending = self.inflight_non_terminal_count(),
+                "writer admission closed for shutdown"
+            );
+        }
+    }
+
+    /// Whether admission has been closed by [`Self::begin_drain`].
+    pub fn is_draining(&self) -> bool {
+        self.draining.load(Ordering::SeqCst)
+    }
+
+    /// Plan a drain of the current non-terminal jobs against `cap`.
+    pub fn drain_plan(&self, cap: Duration) -> DrainPlan {
+        let recent: Vec<Duration> = self.recent_durations.lock().iter().copied().collect();
+        DrainPlan::new(&recent, self.inflight_non_terminal_count(), cap)
+    }
+
+    /// Close admission and let the worker keep dispatching queued jobs until
+    /// the planned deadline. The worker starts a job only while at least one
+    /// `per_job` estimate of budget remains, so a proof is not begun that the
+    /// process would kill half-way. Returns once the queue is empty or the
+    /// deadline passes, whichever is first; a job still mid-dispatch at the
+    /// deadline is reported as residual, not cancelled.
+    pub async fn drain(&self, cap: Duration) -> DrainReport {
+        self.begin_drain();
+        let started = Instant::now();
+        let plan = self.drain_plan(cap);
+        let deadline = started + plan.budget;
+        let mut dispatched = 0;
+        let drain_tx = self.drain_tx.lock().take();
+        if let Some(drain_tx) = drain_tx {
+            let (done, done_rx) = oneshot::channel();
+            let request = DrainRequest {
+                deadline,
+                per_job: plan.per_job,
+                done,
+            };
+            if drain_tx.send(request).is_ok()
+                && let Ok(Ok(n)) = tokio::time::timeout_at(deadline.into(), done_rx).await
+            {
+                dispatched = n;
+            }
+        }
+        DrainReport {
+            plan,
+            dispatched,
+            residual: self.non_terminal_hashes(),
+            elapsed: started.elapsed(),
+        }
+    }
+
     /// Count process-local non-terminal jobs for diagnostics and drain tests.
     /// Transaction-count RPCs use the store's durable pending frontier, which
     /// survives process restart.
@@ -462,17 +999,40 @@ impl WriterWorkerHandle {
             .count()
     }
 
-    /// Try to push a job onto the writer-worker queue.
+    /// Admission pre-check, run before the nonce is durably advanced: refuse
+    /// with `ShuttingDown` once draining, or `QueueFull` when the lane for
+    /// `kind` has no free slot. A full lane counts against that lane's
+    /// `agglayer_writer_queue_full_rejections_total{kind}`.
+    pub fn check_admission(&self, kind: WriteJobKind) -> Result<(), TryEnqueueError> {
+        if self.is_draining() {
+            return Err(TryEnqueueError::ShuttingDown);
+        }
+        if self.available_capacity_for(kind) == 0 {
+            ::metrics::counter!(
+                "agglayer_writer_queue_full_rejections_total",
+                "kind" => kind.as_str()
+            )
+            .increment(1);
+            return Err(TryEnqueueError::QueueFull);
+        }
+        Ok(())
+    }
+
+    /// Try to push a job onto its kind's lane.
     ///
     /// **Non-blocking** — uses `mpsc::Sender::try_send`. On full, returns
     /// `Err(TryEnqueueError::QueueFull)` immediately so the request future
-    /// doesn't park; the caller emits JSON-RPC `-32005`.
+    /// doesn't park; the caller emits JSON-RPC `-32005`. Once the drain has
+    /// begun every call returns `Err(TryEnqueueError::ShuttingDown)`.
     ///
     /// Insert into the in-flight map happens **before** the try_send so a
     /// concurrent reader cannot observe an empty map for an enqueued job.
     /// Insert is rolled back on try_send failure so `is_inflight` remains
     /// truthful.
     pub fn try_enqueue(&self, job: WriteJob) -> Result<(), TryEnqueueError> {
+        if self.is_draining() {
+            return Err(TryEnqueueError::ShuttingDown);
+        }
         let hash = job.eth_tx_hash();
         let kind = job.kind();
         let entry = InFlightEntry::from_job(&job);
@@ -482,12 +1042,19 @@ impl WriterWorkerHandle {
         self.inflight.insert(hash, entry);
         ::metrics::gauge!("agglayer_writer_inflight_jobs").set(self.inflight.len() as f64);
 
-        match self.sender.try_send(job) {
+        let lane = lane_index(kind);
+        let sender = &self.senders[lane];
+        match sender.try_send(job) {
             Ok(()) => {
-                // The depth published here is the *fill level* (cap minus
-                // available), the metric all dashboards care about.
-                let depth = self.queue_depth.saturating_sub(self.sender.capacity());
-                ::metrics::gauge!("agglayer_writer_queue_depth").set(depth as f64);
+                // The depth published here is the lane's *fill level* (cap
+                // minus available), the metric all dashboards care about.
+                // Labelled by lane: kinds sharing a lane share its fill.
+                let depth = self.queue_depth_for(kind).saturating_sub(sender.capacity());
+                ::metrics::gauge!(
+                    "agglayer_writer_queue_depth",
+                    "lane" => LANE_ORDER[lane].as_str()
+                )
+                .set(depth as f64);
                 Ok(())
             }
             Err(mpsc::error::TrySendError::Full(_)) => {
@@ -511,14 +1078,18 @@ impl WriterWorkerHandle {
 
 // ─── Worker task ─────────────────────────────────────────────────────────────
 
-/// The writer-worker task itself. Owns the mpsc receiver and the
+/// The writer-worker task itself. Owns the lane receivers and the
 /// `ServiceState` clone it dispatches against. Constructed and immediately
 /// spawned by `WriterWorker::spawn`; not exposed for direct construction.
 pub struct WriterWorker {
-    receiver: mpsc::Receiver<WriteJob>,
+    lanes: LaneReceivers,
     inflight: Arc<DashMap<TxHash, InFlightEntry>>,
     service: ServiceState,
     tx_ttl: Duration,
+    drain_rx: oneshot::Receiver<DrainRequest>,
+    recent_durations: Arc<Mutex<VecDeque<Duration>>>,
+    claim_batch: usize,
+    paused: watch::Receiver<bool>,
 }
 
 impl WriterWorker {
@@ -526,29 +1097,7 @@ impl WriterWorker {
     /// when unset or unparsable. Logs a warning on parse failure so an
     /// operator misconfiguration is loud.
     pub fn parse_queue_depth_env() -> usize {
-        match std::env::var(QUEUE_DEPTH_ENV) {
-            Ok(v) => match v.parse::<usize>() {
-                Ok(n) if n >= 1 => n,
-                Ok(n) => {
-                    tracing::warn!(
-                        env = QUEUE_DEPTH_ENV,
-                        value = n,
-                        "{QUEUE_DEPTH_ENV} must be ≥ 1; using default {DEFAULT_QUEUE_DEPTH}"
-                    );
-                    DEFAULT_QUEUE_DEPTH
-                }
-                Err(e) => {
-                    tracing::warn!(
-                        env = QUEUE_DEPTH_ENV,
-                        value = %v,
-                        err = %e,
-                        "could not parse {QUEUE_DEPTH_ENV}; using default {DEFAULT_QUEUE_DEPTH}"
-                    );
-                    DEFAULT_QUEUE_DEPTH
-                }
-            },
-            Err(_) => DEFAULT_QUEUE_DEPTH,
-        }
+        positive_env(QUEUE_DEPTH_ENV, DEFAULT_QUEUE_DEPTH)
     }
 
     /// Read `AGGLAYER_WRITER_TX_TTL` (seconds), falling back to
@@ -579,25 +1128,72 @@ impl WriterWorker {
         }
     }
 
+    /// Read `AGGLAYER_WRITER_DRAIN_TIMEOUT` (seconds), falling back to
+    /// `DEFAULT_DRAIN_TIMEOUT_SECS`. Zero is allowed and skips the drain:
+    /// every queued job goes straight to next-boot recovery.
+    pub fn parse_drain_timeout_env() -> Duration {
+        match std::env::var(DRAIN_TIMEOUT_ENV) {
+            Ok(v) => match v.parse::<u64>() {
+                Ok(n) => Duration::from_secs(n),
+                Err(e) => {
+                    tracing::warn!(
+                        env = DRAIN_TIMEOUT_ENV,
+                        value = %v,
+                        err = %e,
+                        "could not parse {DRAIN_TIMEOUT_ENV}; using default {DEFAULT_DRAIN_TIMEOUT_SECS}s"
+                    );
+                    Duration::from_secs(DEFAULT_DRAIN_TIMEOUT_SECS)
+                }
+            },
+            Err(_) => Duration::from_secs(DEFAULT_DRAIN_TIMEOUT_SECS),
+        }
+    }
+
     /// Spawn the writer worker and maintenance sweeper. Returns a producer handle
     /// (cloneable via Arc) and a oneshot shutdown channel — send `()` (or
-    /// drop the sender) to request a graceful stop. The worker drains its
-    /// `recv` loop and exits; the sweeper exits when the inflight map's
-    /// last reference drops.
+    /// drop the sender) to stop the worker after its current job. A
+    /// deadline-bounded drain of the queue goes through
+    /// [`WriterWorkerHandle::drain`] instead.
+    ///
+    /// Both lanes get `queue_depth` slots and the default weights; see
+    /// [`WriterWorker::spawn_with_lanes`] for a custom layout.
     pub fn spawn(
         service: ServiceState,
         queue_depth: usize,
         tx_ttl: Duration,
     ) -> (WriterWorkerHandle, oneshot::Sender<()>) {
-        let (tx, rx) = mpsc::channel::<WriteJob>(queue_depth);
+        Self::spawn_with_lanes(service, WriterLanes::uniform(queue_depth), tx_ttl)
+    }
+
+    /// [`WriterWorker::spawn`] with an explicit per-kind lane layout.
+    pub fn spawn_with_lanes(
+        service: ServiceState,
+        lanes: WriterLanes,
+        tx_ttl: Duration,
+    ) -> (WriterWorkerHandle, oneshot::Sender<()>) {
+        let (senders, receivers) = lanes.channels();
         let inflight = Arc::new(DashMap::<TxHash, InFlightEntry>::new());
         let (shutdown_tx, mut shutdown_rx) = oneshot::channel::<()>();
-
+        let (drain_tx, drain_rx) = oneshot::channel::<DrainRequest>();
+        let recent_durations =
+            Arc::new(Mutex::new(VecDeque::with_capacity(RECENT_DURATION_WINDOW)));
+
+        let handle = WriterWorkerHandle::new(
+            senders,
+            lanes,
+            inflight.clone(),
+            recent_durations.clone(),
+            Some(drain_tx),
+        );
         let worker = WriterWorker {
-            receiver: rx,
+            lanes: receivers,
             inflight: inflight.clone(),
             service: service.clone(),
             tx_ttl,
+            drain_rx,
+            recent_durations,
+            claim_batch: lanes.claim_batch.max(1),
+            paused: handle.paused.subscribe(),
         };
 
         tokio::spawn(async move {
@@ -646,7 +1242,7 @@ impl WriterWorker {
                                 crate::service_send_raw_txn::envelope_nonce(&entry.envelope),
                             ));
                         }
-                        JobState::Committed { .. } | JobState::Failed => {
+                        JobState::Committed { .. } | JobState::Failed | JobState::Cancelled => {
                             if let Some(t) = entry.terminal_at
                                 && now.duration_since(t) > sweeper_ttl
                             {
@@ -686,11 +1282,6 @@ impl WriterWorker {
             }
         });
 
-        let handle = WriterWorkerHandle {
-            sender: tx,
-            inflight,
-            queue_depth,
-        };
         (handle, shutdown_tx)
     }
 
@@ -698,130 +1289,334 @@ impl WriterWorker {
         tracing::info!(
             target: "writer_worker",
             tx_ttl_secs = self.tx_ttl.as_secs(),
+            ger_weight = self.lanes.weights[lane_index(WriteJobKind::GerInsert)],
+            claim_weight = self.lanes.weights[lane_index(WriteJobKind::Claim)],
+            claim_batch = self.claim_batch,
             "writer worker starting"
         );
+        // A dropped handle closes the drain channel and the pause switch
+        // without a request; stop polling them then and let the lanes closing
+        // end the loop as before.
+        let mut drain_closed = false;
+        let mut pause_closed = false;
         loop {
+            let paused = !pause_closed && *self.paused.borrow_and_update();
             tokio::select! {
                 biased;
                 _ = &mut *shutdown_rx => {
                     tracing::info!(target: "writer_worker", "shutdown signal received");
                     break;
                 }
-                maybe_job = self.receiver.recv() => {
+                request = &mut self.drain_rx, if !drain_closed => {
+                    match request {
+                        Ok(request) => {
+                            self.drain(request).await;
+                            break;
+                        }
+                        Err(_) => drain_closed = true,
+                    }
+                }
+                // Watched while idle too, so a pause issued while the lanes
+                // are empty holds the next job rather than the one after.
+                changed = self.paused.changed(), if !pause_closed => {
+                    if changed.is_err() {
+                        pause_closed = true;
+                        continue;
+                    }
+                    let now_paused = *self.paused.borrow();
+                    if now_paused != paused {
+                        tracing::info!(
+                            target: "writer_worker",
+                            paused = now_paused,
+                            queued = self.lanes.len(),
+                            "writer dispatch {}",
+                            if now_paused { "paused" } else { "resumed" }
+                        );
+                    }
+                }
+                maybe_job = self.lanes.next(), if !paused => {
                     let Some(job) = maybe_job else {
                         tracing::info!(
                             target: "writer_worker",
-                            "channel closed by all senders; stopping"
+                            "lanes closed by all senders; stopping"
                         );
                         break;
                     };
-                    self.process(job).await;
+                    let jobs = self.lanes.coalesce_claims(job, self.claim_batch);
+                    self.process_jobs(jobs).await;
                 }
             }
         }
         tracing::info!(target: "writer_worker", "writer worker stopped");
     }
 
-    async fn process(&self, job: WriteJob) {
-        let hash = job.eth_tx_hash();
-        let kind = job.kind();
-        let job_id = job.job_id();
-        let signer = job.signer();
-        let started = Instant::now();
-
-        // RD-940 Phase 5 — one tracing span per job, fields per Spec F §4:
-        // tx_hash, job_id, kind, signer, queue_wait_ms, miden_submit_ms,
-        // commit_ms. `queue_wait_ms` is measured from the inflight entry's
-        // `created_at` (set in `try_enqueue`); the remaining elapsed
-        // measurements are recorded inline below.
-        let queue_wait = self
-            .inflight
-            .get(&hash)
-            .map(|e| e.created_at.elapsed())
-            .unwrap_or_default();
-        let queue_wait_ms = queue_wait.as_millis() as u64;
-        let span = tracing::info_span!(
-            target: "writer_worker::job",
-            "writer_job",
-            %hash,
-            %job_id,
-            kind = kind.as_str(),
-            signer = %signer,
-            queue_wait_ms,
-        );
-        let _entered = span.enter();
-
-        // The consuming worker is the only task allowed to expire queued
-        // work. At this point the item has been removed from mpsc and no
-        // dispatch future has been created, so a terminal failure cannot race
-        // a later Miden side effect from the same job.
-        if queue_wait >= self.tx_ttl {
-            let err = anyhow::anyhow!(
-                "writer_worker: TTL expired in queue before dispatch (>{}s)",
-                self.tx_ttl.as_secs()
+    /// Dispatch queued jobs, still by lane weight, until the lanes are empty
+    /// or too little of the budget remains to finish another one. Jobs left
+    /// in a lane keep their `Queued` in-flight entry so the caller can mark
+    /// them for recovery.
+    async fn drain(&mut self, request: DrainRequest) {
+        self.lanes.close();
+        if *self.paused.borrow() {
+            tracing::warn!(
+                target: "writer_worker::drain",
+                queued = self.lanes.len(),
+                "writer drain: dispatch is paused; leaving every queued job for recovery"
             );
-            if preserve_pending_after_handoff(&self.service.store, hash).await {
-                self.inflight.remove(&hash);
+            let _ = request.done.send(0);
+            return;
+        }
+        let mut dispatched = 0usize;
+        loop {
+            if self.lanes.len() == 0 {
+                tracing::info!(
+                    target: "writer_worker::drain",
+                    dispatched,
+                    "writer drain: queue empty"
+                );
+                break;
+            }
+            let remaining = request.deadline.saturating_duration_since(Instant::now());
+            if remaining < request.per_job {
                 tracing::warn!(
-                    target: "writer_worker",
-                    %hash, kind = kind.as_str(), %job_id, signer = %signer,
-                    queue_wait_ms,
-                    "queued retry expired after an existing durable note handoff; \
-                     leaving receipt pending"
+                    target: "writer_worker::drain",
+                    dispatched,
+                    queued = self.lanes.len(),
+                    remaining_secs = remaining.as_secs_f64(),
+                    per_job_secs = request.per_job.as_secs_f64(),
+                    "writer drain: not enough budget left for another job; stopping"
                 );
-                ::metrics::histogram!(
-                    "agglayer_writer_job_duration_seconds",
-                    "kind" => kind.as_str(),
-                    "outcome" => "pending",
-                )
-                .record(started.elapsed().as_secs_f64());
-                ::metrics::gauge!("agglayer_writer_inflight_jobs").set(self.inflight.len() as f64);
-                return;
+                break;
             }
-            if let Some(mut entry) = self.inflight.get_mut(&hash) {
-                entry.state = JobState::Failed;
-                entry.terminal_at = Some(Instant::now());
+            let Some(job) = self.lanes.try_next() else {
+                break;
+            };
+            // Batch only as many claims as the remaining budget covers.
+            let affordable =
+                (remaining.as_secs_f64() / request.per_job.as_secs_f64().max(1e-3)) as usize;
+            let jobs = self
+                .lanes
+                .coalesce_claims(job, self.claim_batch.min(affordable.max(1)));
+            dispatched += jobs.len();
+            self.process_jobs(jobs).await;
+            tracing::info!(
+                target: "writer_worker::drain",
+                dispatched,
+                queued = self.lanes.len(),
+                remaining_secs = request
+                    .deadline
+                    .saturating_duration_since(Instant::now())
+                    .as_secs_f64(),
+                "writer drain: job finished"
+            );
+        }
+        let _ = request.done.send(dispatched);
+    }
+
+    fn record_duration(&self, elapsed: Duration) {
+        let mut recent = self.recent_durations.lock();
+        if recent.len() == RECENT_DURATION_WINDOW {
+            recent.pop_front();
+        }
+        recent.push_back(elapsed);
+    }
+
+    /// Process jobs dispatched together: a single job, or queued claims
+    /// coalesced into one Miden transaction. Queue expiry, state transitions,
+    /// receipts and metrics stay per job.
+    async fn process_jobs(&self, jobs: Vec<WriteJob>) {
+        let started = Instant::now();
+        let mut live = Vec::with_capacity(jobs.len());
+        for job in jobs {
+            let hash = job.eth_tx_hash();
+            let kind = job.kind();
+            let job_id = job.job_id();
+            let signer = job.signer();
+
+            // RD-940 Phase 5 — one tracing span per job, fields per Spec F §4:
+            // tx_hash, job_id, kind, signer, queue_wait_ms, miden_submit_ms,
+            // commit_ms. `queue_wait_ms` is measured from the inflight entry's
+            // `created_at` (set in `try_enqueue`), minus writer pauses; the
+            // remaining elapsed measurements are recorded inline below.
+            let queue_wait = self
+                .inflight
+                .get(&hash)
+                .map(|e| e.queue_wait())
+                .unwrap_or_default();
+            let queue_wait_ms = queue_wait.as_millis() as u64;
+            let span = tracing::info_span!(
+                target: "writer_worker::job",
+                "writer_job",
+                %hash,
+                %job_id,
+                kind = kind.as_str(),
+                signer = %signer,
+                queue_wait_ms,
+            );
+
+            // The consuming worker is the only task allowed to expire queued
+            // work. At this point the item has been removed from mpsc and no
+            // dispatch future has been created, so a terminal failure cannot race
+            // a later Miden side effect from the same job.
+            if self
+                .inflight
+                .get(&hash)
+                .is_some_and(|e| e.state == JobState::Cancelled)
+            {
+                span.in_scope(
+                    || tracing::info!(target: "writer_worker", "dropping cancelled writer job"),
+                );
+                continue;
+            }
+            if queue_wait >= self.tx_ttl {
+                self.expire_in_queue(&job, queue_wait_ms, started)
+                    .instrument(span)
+                    .await;
+                continue;
+            }
+
+            // Transition Queued → Submitting, unless an operator cancel took
+            // the entry first (see `WriterWorkerHandle::mark_cancelled`).
+            match self.inflight.get_mut(&hash) {
+                Some(entry) if entry.state == JobState::Cancelled => {
+                    span.in_scope(
+                        || tracing::info!(target: "writer_worker", "dropping cancelled writer job"),
+                    );
+                    continue;
+                }
+                Some(mut entry) => entry.state = JobState::Submitting,
+                None => {}
+            }
+            live.push((job, span));
+        }
+
+        let results = match live.len() {
+            0 => return,
+            1 => {
+                let (job, span) = &live[0];
+                let dispatch_service = self.service.clone();
+                let job = job.clone();
+                let result =
+                    supervise_dispatch(async move { dispatch_job(&dispatch_service, job).await })
+                        .instrument(span.clone())
+                        .await;
+                vec![result]
             }
+            claims => {
+                let span = tracing::info_span!(
+                    target: "writer_worker::job",
+                    "writer_claim_batch",
+                    claims,
+                );
+                let dispatch_service = self.service.clone();
+                let jobs = live.iter().map(|(job, _)| job.clone()).collect();
+                match supervise_dispatch(async move {
+                    Ok(dispatch_claim_batch(&dispatch_service, jobs).await)
+                })
+                .instrument(span)
+                .await
+                {
+                    Ok(results) => results,
+                    // A panic or cancellation takes the whole batch down; every
+                    // job reports it, keeping its classification.
+                    Err(err) => live
+                        .iter()
+                        .map(|_| match err.downcast_ref::<WriterDispatchPanic>() {
+                            Some(panic) => Err(WriterDispatchPanic(panic.0.clone()).into()),
+                            None => Err(anyhow::anyhow!("{err:#}")),
+                        })
+                        .collect(),
+                }
+            }
+        };
+
+        // Jobs of a batch shared one dispatch; the drain estimate gets each
+        // its share of it.
+        let share = started.elapsed() / live.len() as u32;
+        for ((job, span), result) in live.into_iter().zip(results) {
+            self.finish(&job, result, started, share)
+                .instrument(span)
+                .await;
+        }
+    }
+
+    /// Fail a job that spent its whole TTL in the queue — or leave it pending
+    /// when an earlier attempt already made a durable note handoff.
+    async fn expire_in_queue(&self, job: &WriteJob, queue_wait_ms: u64, started: Instant) {
+        let hash = job.eth_tx_hash();
+        let kind = job.kind();
+        let job_id = job.job_id();
+        let signer = job.signer();
+        let err = anyhow::anyhow!(
+            "writer_worker: TTL expired in queue before dispatch (>{}s)",
+            self.tx_ttl.as_secs()
+        );
+        if preserve_pending_after_handoff(&self.service.store, hash).await {
+            self.inflight.remove(&hash);
             tracing::warn!(
                 target: "writer_worker",
                 %hash, kind = kind.as_str(), %job_id, signer = %signer,
                 queue_wait_ms,
-                "writer job expired in queue before dispatch; writing failure receipt"
+                "queued retry expired after an existing durable note handoff; \
+                 leaving receipt pending"
             );
-            if let Err(store_err) = write_failure_receipt(&self.service, hash, &err).await {
-                tracing::error!(
-                    target: "writer_worker",
-                    %hash,
-                    error = format!("{store_err:#}"),
-                    "writer_worker: failed to write queue-expiry receipt; \
-                     eth_getTransactionReceipt will return null"
-                );
-            }
-            ::metrics::counter!(
-                "agglayer_writer_job_failures_total",
-                "kind" => kind.as_str(),
-                "reason" => "ttl",
-            )
-            .increment(1);
             ::metrics::histogram!(
                 "agglayer_writer_job_duration_seconds",
                 "kind" => kind.as_str(),
-                "outcome" => "failed",
+                "outcome" => "pending",
             )
             .record(started.elapsed().as_secs_f64());
             ::metrics::gauge!("agglayer_writer_inflight_jobs").set(self.inflight.len() as f64);
             return;
         }
-
-        // Transition Queued → Submitting.
         if let Some(mut entry) = self.inflight.get_mut(&hash) {
-            entry.state = JobState::Submitting;
+            entry.state = JobState::Failed;
+            entry.terminal_at = Some(Instant::now());
+        }
+        tracing::warn!(
+            target: "writer_worker",
+            %hash, kind = kind.as_str(), %job_id, signer = %signer,
+            queue_wait_ms,
+            "writer job expired in queue before dispatch; writing failure receipt"
+        );
+        if let Err(store_err) = write_failure_receipt(&self.service, hash, &err).await {
+            tracing::error!(
+                target: "writer_worker",
+                %hash,
+                error = format!("{store_err:#}"),
+                "writer_worker: failed to write queue-expiry receipt; \
+                 eth_getTransactionReceipt will return null"
+            );
         }
+        ::metrics::counter!(
+            "agglayer_writer_job_failures_total",
+            "kind" => kind.as_str(),
+            "reason" => "ttl",
+        )
+        .increment(1);
+        ::metrics::histogram!(
+            "agglayer_writer_job_duration_seconds",
+            "kind" => kind.as_str(),
+            "outcome" => "failed",
+        )
+        .record(started.elapsed().as_secs_f64());
+        ::metrics::gauge!("agglayer_writer_inflight_jobs").set(self.inflight.len() as f64);
+    }
 
+    /// Record a dispatched job's outcome: in-flight state, failure receipt,
+    /// metrics. `share` is the job's part of the dispatch time, fed to the
+    /// drain estimate.
+    async fn finish(
+        &self,
+        job: &WriteJob,
+        result: anyhow::Result<()>,
+        started: Instant,
+        share: Duration,
+    ) {
+        let hash = job.eth_tx_hash();
+        let kind = job.kind();
+        let job_id = job.job_id();
+        let signer = job.signer();
         let outcome_label;
-        let dispatch_service = self.service.clone();
-        let result =
-            supervise_dispatch(async move { dispatch_job(&dispatch_service, job).await }).await;
         match result {
             Ok(()) => {
                 // Best-effort: read the freshly-bumped tip to attribute the
@@ -847,6 +1642,7 @@ impl WriterWorker {
                     entry.terminal_at = Some(Instant::now());
                 }
                 outcome_label = "committed";
+                self.record_duration(share);
                 tracing::info!(
                     target: "writer_worker",
                     %hash, kind = kind.as_str(), %job_id, signer = %signer,
@@ -918,6 +1714,65 @@ impl WriterWorker {
     }
 }
 
+/// Graceful-shutdown drain: close admission, dispatch queued jobs until the
+/// deadline planned from recent job durations (capped by `cap`), then mark
+/// whatever is left in the store for immediate recovery on the next boot.
+/// Progress goes to the `writer_worker::drain` log target; the outcome is
+/// published as `agglayer_writer_drain_outcome_total` and
+/// `agglayer_writer_drain_jobs_total`. No-op without a writer handle.
+pub async fn drain_on_shutdown(service: &ServiceState, cap: Duration) -> Option<DrainReport> {
+    let handle = service.writer_handle.as_ref()?;
+    let plan = handle.drain_plan(cap);
+    tracing::info!(
+        target: "writer_worker::drain",
+        pending = plan.pending,
+        per_job_secs = plan.per_job.as_secs_f64(),
+        budget_secs = plan.budget.as_secs_f64(),
+        cap_secs = cap.as_secs(),
+        "writer drain starting"
+    );
+    let report = handle.drain(cap).await;
+
+    let mut marked = 0;
+    if !report.residual.is_empty() {
+        match service
+            .store
+            .mark_undrained_at_shutdown(&report.residual)
+            .await
+        {
+            Ok(n) => marked = n,
+            Err(err) => tracing::error!(
+                target: "writer_worker::drain",
+                residual = report.residual.len(),
+                error = format!("{err:#}"),
+                "writer drain: failed to mark residual jobs; recovery will find them \
+                 with their existing backoff"
+            ),
+        }
+    }
+
+    let outcome = if report.residual.is_empty() {
+        "clean"
+    } else {
+        "partial"
+    };
+    tracing::info!(
+        target: "writer_worker::drain",
+        outcome,
+        dispatched = report.dispatched,
+        residual = report.residual.len(),
+        marked,
+        elapsed_secs = report.elapsed.as_secs_f64(),
+        "writer drain finished"
+    );
+    ::metrics::counter!("agglayer_writer_drain_outcome_total", "outcome" => outcome).increment(1);
+    ::metrics::counter!("agglayer_writer_drain_jobs_total", "outcome" => "dispatched")
+        .increment(report.dispatched as u64);
+    ::metrics::counter!("agglayer_writer_drain_jobs_total", "outcome" => "marked_for_recovery")
+        .increment(marked);
+    Some(report)
+}
+
 /// Dispatch a `WriteJob` to the matching Phase-1 translator in
 /// `service_send_raw_txn`. Each variant calls the corresponding publish/insert
 /// handler without a per-signer `nonce_increment`: the request thread durably
@@ -956,9 +1811,53 @@ async fn dispatch_job(service: &ServiceState, job: WriteJob) -> anyhow::Result<(
             )
             .await
         }
+        WriteJob::GerRemoval {
+            ger_bytes,
+            envelope,
+            signer,
+            eth_tx_hash,
+            ..
+        } => {
+            crate::service_send_raw_txn::worker_handle_ger_removal(
+                service,
+                ger_bytes,
+                eth_tx_hash,
+                envelope,
+                signer,
+            )
+            .await
+        }
     }
 }
 
+/// Dispatch claim jobs coalesced by the worker through
+/// `service_send_raw_txn::worker_handle_claim_batch`, one result per job in
+/// order. Non-claim jobs never reach here: the lanes only coalesce claims.
+async fn dispatch_claim_batch(
+    service: &ServiceState,
+    jobs: Vec<WriteJob>,
+) -> Vec<anyhow::Result<()>> {
+    let claims = jobs
+        .into_iter()
+        .filter_map(|job| match job {
+            WriteJob::Claim {
+                params,
+                envelope,
+                signer,
+                eth_tx_hash,
+                ..
+            } => Some(crate::service_send_raw_txn::QueuedClaim {
+                params: *params,
+                txn_hash: eth_tx_hash,
+                txn_envelope: envelope,
+                signer,
+            }),
+            WriteJob::Ger { .. } | WriteJob::GerRemoval { .. } => None,
+        })
+        .collect();
+    crate::service_send_raw_txn::worker_handle_claim_batch(service, claims).await
+}
+
 /// Best-effort failure receipt writer. Records a pending tx row (if one
 /// doesn't already exist, e.g. because the failure happened before
 /// `record_local_pending_tx` ran), then `txn_commit` with `Err`. The
@@ -994,6 +1893,50 @@ async fn write_failure_receipt(
         .await
 }
 
+/// Cancel a job that has not been dequeued yet (`admin_cancelWriterJob`).
+///
+/// The nonce was consumed at admission (CAS advance, reservation released as
+/// success and bound to this hash), so cancelling does not hand it back: the
+/// signer's next nonce stays `nonce + 1` and the tx gets a `status:0x0`
+/// receipt, exactly like a queue-TTL expiry. Once the entry is terminal the
+/// sweeper stops renewing its reservation lease. The worker drops the job
+/// when it reaches the front of its lane.
+///
+/// A job with a durable note handoff from an earlier attempt is refused (see
+/// [`preserve_pending_after_handoff`]). If the receipt write fails the entry
+/// stays `Cancelled` and the error is returned; the admission remains pending
+/// in the store, so startup recovery resumes it after a restart.
+pub async fn cancel_queued_job(
+    service: &ServiceState,
+    hash: TxHash,
+) -> anyhow::Result<InFlightEntry> {
+    let handle = service
+        .writer_handle
+        .as_ref()
+        .ok_or_else(|| anyhow::anyhow!("writer worker is not running"))?;
+    if !handle.is_inflight(&hash) {
+        return Err(CancelJobError::NotFound.into());
+    }
+    if preserve_pending_after_handoff(&service.store, hash).await {
+        return Err(CancelJobError::HandedOff.into());
+    }
+    let entry = handle.mark_cancelled(&hash)?;
+    let kind = entry.kind;
+    tracing::warn!(
+        target: "writer_worker",
+        %hash, kind = kind.as_str(), job_id = %entry.job_id, signer = %entry.signer,
+        "writer job cancelled by operator; writing failure receipt"
+    );
+    ::metrics::counter!(
+        "agglayer_writer_jobs_cancelled_total",
+        "kind" => kind.as_str(),
+    )
+    .increment(1);
+    let err = anyhow::anyhow!("cancelled by operator before dispatch");
+    write_failure_receipt(service, hash, &err).await?;
+    Ok(entry)
+}
+
 /// Fail closed when the store cannot disprove an exact note handoff. Once a
 /// handoff exists, only commit/observation or expiration reconciliation may
 /// transition the receipt; worker errors must never publish status 0.
@@ -1065,6 +2008,58 @@ mod tests {
         }
     }
 
+    /// A handle over fresh `lanes` with an empty in-flight map and no drain
+    /// channel. No worker is spawned; the caller gets the receivers.
+    pub(super) fn test_handle(lanes: WriterLanes) -> (WriterWorkerHandle, LaneReceivers) {
+        let (senders, receivers) = lanes.channels();
+        let handle = WriterWorkerHandle::new(
+            senders,
+            lanes,
+            Arc::new(DashMap::new()),
+            Arc::new(Mutex::new(VecDeque::new())),
+            None,
+        );
+        (handle, receivers)
+    }
+
+    /// A worker reading `lanes` that shares `handle`'s in-flight map, job
+    /// durations and pause switch. Its drain channel is never signalled.
+    fn test_worker(
+        service: ServiceState,
+        lanes: LaneReceivers,
+        handle: &WriterWorkerHandle,
+        tx_ttl: Duration,
+    ) -> WriterWorker {
+        WriterWorker {
+            lanes,
+            inflight: handle.inflight.clone(),
+            service,
+            tx_ttl,
+            drain_rx: oneshot::channel().1,
+            recent_durations: handle.recent_durations.clone(),
+            claim_batch: 1,
+            paused: handle.paused.subscribe(),
+        }
+    }
+
+    /// Write the pending transaction row `job` was admitted with.
+    async fn begin_txn(service: &ServiceState, job: &WriteJob) {
+        service
+            .store
+            .txn_begin(
+                job.eth_tx_hash(),
+                crate::store::TxnEntry {
+                    id: None,
+                    envelope: job.envelope().clone(),
+                    signer: job.signer(),
+                    expires_at: None,
+                    logs: vec![],
+                },
+            )
+            .await
+            .unwrap();
+    }
+
     #[tokio::test]
     async fn dispatch_panic_is_contained_and_next_task_still_runs() {
         let err = supervise_dispatch(async {
@@ -1107,35 +2102,16 @@ mod tests {
             *ger_bytes = [0x42; 32];
         }
         let hash = job.eth_tx_hash();
+        begin_txn(&service, &job).await;
 
-        service
-            .store
-            .txn_begin(
-                hash,
-                crate::store::TxnEntry {
-                    id: None,
-                    envelope: job.envelope().clone(),
-                    signer: job.signer(),
-                    expires_at: None,
-                    logs: vec![],
-                },
-            )
-            .await
-            .unwrap();
-
-        let (_sender, receiver) = mpsc::channel(1);
-        let inflight = Arc::new(DashMap::new());
+        let (handle, receivers) = test_handle(WriterLanes::uniform(1));
+        let inflight = handle.inflight.clone();
         let mut entry = InFlightEntry::from_job(&job);
         entry.created_at = Instant::now() - Duration::from_secs(2);
         inflight.insert(hash, entry);
-        let worker = WriterWorker {
-            receiver,
-            inflight: inflight.clone(),
-            service,
-            tx_ttl: Duration::from_secs(1),
-        };
+        let worker = test_worker(service, receivers, &handle, Duration::from_secs(1));
 
-        worker.process(job).await;
+        worker.process_jobs(vec![job]).await;
 
         assert_eq!(
             miden_client.test_call_count(),
@@ -1153,6 +2129,108 @@ mod tests {
         assert!(receipt.0.is_err());
     }
 
+    /// A pause longer than the TTL does not expire the jobs it held: resuming
+    /// credits each queued entry its paused time, so the worker still
+    /// dispatches it.
+    #[tokio::test]
+    async fn paused_time_is_left_out_of_queue_expiry() {
+        let service = crate::test_helpers::create_test_service();
+        let miden_client = service.miden_client.clone();
+        let (handle, receivers) = test_handle(WriterLanes::uniform(1));
+        let inflight = handle.inflight.clone();
+        let nonce = service.store.nonce_get("pause-expiry-test").await.unwrap();
+        let job = fake_ger_job(nonce);
+        let hash = job.eth_tx_hash();
+        let mut entry = InFlightEntry::from_job(&job);
+        entry.created_at = Instant::now() - Duration::from_secs(3);
+        inflight.insert(hash, entry);
+        handle.pause();
+        *handle.paused_since.lock() = Some(Instant::now() - Duration::from_secs(3));
+        handle.resume();
+        assert!(inflight.get(&hash).unwrap().queue_wait() < Duration::from_secs(1));
+
+        let worker = test_worker(service, receivers, &handle, Duration::from_secs(1));
+        worker.process_jobs(vec![job]).await;
+
+        assert_eq!(
+            miden_client.test_call_count(),
+            1,
+            "a job held by a pause is dispatched, not expired"
+        );
+    }
+
+    /// An operator cancel of a queued job writes its status:0x0 receipt up
+    /// front; the worker then drops the job without reaching Miden, and a
+    /// second cancel is refused because the job is no longer queued.
+    #[tokio::test]
+    async fn cancelled_job_gets_failure_receipt_and_is_never_dispatched() {
+        let mut service = crate::test_helpers::create_test_service();
+        let (handle, mut receivers) = test_handle(WriterLanes::uniform(2));
+        let handle = Arc::new(handle);
+        let inflight = handle.inflight.clone();
+        service.writer_handle = Some(handle.clone());
+        let job = fake_ger_job(0);
+        let hash = job.eth_tx_hash();
+        begin_txn(&service, &job).await;
+        handle.try_enqueue(job).unwrap();
+
+        let cancelled = cancel_queued_job(&service, hash).await.unwrap();
+        assert_eq!(cancelled.state, JobState::Cancelled);
+        let receipt = service.store.txn_receipt(hash).await.unwrap();
+        assert!(
+            receipt
+                .expect("cancel writes a terminal receipt")
+                .0
+                .is_err()
+        );
+        let again = cancel_queued_job(&service, hash).await.unwrap_err();
+        assert!(matches!(
+            again.downcast_ref::<CancelJobError>(),
+            Some(CancelJobError::NotQueued(JobState::Cancelled))
+        ));
+        let unknown = cancel_queued_job(&service, TxHash::repeat_byte(0x99))
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            unknown.downcast_ref::<CancelJobError>(),
+            Some(CancelJobError::NotFound)
+        ));
+
+        let worker = test_worker(
+            service.clone(),
+            WriterLanes::uniform(1).channels().1,
+            &handle,
+            Duration::from_secs(60),
+        );
+        worker
+            .process_jobs(vec![receivers.try_next().unwrap()])
+            .await;
+        assert_eq!(service.miden_client.test_call_count(), 0);
+        assert_eq!(inflight.get(&hash).unwrap().state, JobState::Cancelled);
+    }
+
+    /// A paused worker leaves admitted jobs queued; resuming dispatches them.
+    #[tokio::test]
+    async fn paused_worker_holds_jobs_until_resumed() {
+        let service = crate::test_helpers::create_test_service();
+        let (handle, _shutdown) = WriterWorker::spawn(service, 8, Duration::from_secs(60));
+        handle.pause();
+        assert!(handle.is_paused());
+        let job = fake_ger_job(0);
+        let hash = job.eth_tx_hash();
+        handle.try_enqueue(job).unwrap();
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert_eq!(handle.get_inflight(&hash).unwrap().state, JobState::Queued);
+
+        handle.resume();
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while handle.get_inflight(&hash).unwrap().state == JobState::Queued {
+            assert!(Instant::now() < deadline, "resumed worker never dequeued");
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    }
+
     /// Smoke test: parse_*_env returns defaults when env unset.
     #[test]
     fn env_parsers_default_when_unset() {
@@ -1188,6 +2266,7 @@ mod tests {
     fn kind_labels_are_stable() {
         assert_eq!(WriteJobKind::Claim.as_str(), "claim");
         assert_eq!(WriteJobKind::GerInsert.as_str(), "ger_insert");
+        assert_eq!(WriteJobKind::GerRemove.as_str(), "ger_remove");
     }
 
     /// JobState terminality tracks the receipt contract: only Committed and
@@ -1347,6 +2426,38 @@ mod tests {
         );
     }
 
+    /// A drain with budget for the queued work dispatches it and reports a
+    /// clean shutdown.
+    #[tokio::test]
+    async fn drain_dispatches_queued_jobs_within_the_deadline() {
+        let mut service = create_test_service();
+        let (handle, _shutdown) = WriterWorker::spawn(service.clone(), 8, Duration::from_secs(60));
+        let handle = Arc::new(handle);
+        service.writer_handle = Some(handle.clone());
+
+        let calldata = crate::ger::insertGlobalExitRootCall {
+            root: FixedBytes::from([0x5Au8; 32]),
+        }
+        .abi_encode();
+        let (env, signer, hash) = encode_legacy_envelope(calldata);
+        let job = DecodedWriteCall::Ger {
+            ger_bytes: [0x5Au8; 32],
+        }
+        .into_job(env, signer, hash);
+        handle.try_enqueue(job).expect("enqueue before the drain");
+
+        let report = drain_on_shutdown(&service, Duration::from_secs(30))
+            .await
+            .expect("writer handle present");
+        assert!(
+            report.residual.is_empty(),
+            "residual: {:?}",
+            report.residual
+        );
+        assert!(handle.get_inflight(&hash).unwrap().state.is_terminal());
+        assert_eq!(service.store.take_undrained_at_shutdown().await.unwrap(), 0);
+    }
+
     /// Backpressure path: with a `mpsc(1)` channel, the first try_enqueue
     /// is accepted (sits in the channel), the second hits `QueueFull` and
     /// is converted to `WriterQueueSaturatedError`. The inflight map must
@@ -1355,15 +2466,9 @@ mod tests {
     #[tokio::test]
     async fn try_enqueue_returns_queue_full_when_channel_at_cap() {
         let service = create_test_service();
-        // Tiny channel so we can saturate predictably; no real worker
+        // Tiny lanes so we can saturate predictably; no real worker
         // spawned — we just want to drive the `try_send` codepath.
-        let (sender, _receiver) = mpsc::channel::<WriteJob>(1);
-        let inflight = Arc::new(DashMap::new());
-        let handle = WriterWorkerHandle {
-            sender,
-            inflight,
-            queue_depth: 1,
-        };
+        let (handle, _receivers) = test_handle(WriterLanes::uniform(1));
         // mute warnings about unused `service` — we needed it to ensure the
         // test compiles against the same Send/Sync bounds as the real path.
         let _ = service;
@@ -1387,10 +2492,130 @@ mod tests {
         assert_eq!(handle.inflight_len(), 1);
     }
 
-    /// Shutdown drains cleanly: send a job, fire the shutdown signal, and
-    /// verify the worker exits within a small bound. Phase 5 lands the
-    /// fuller graceful-drain semantics (-32005 mid-shutdown, fixed drain
-    /// budget); this is the minimum-viable shape.
+    fn fake_claim_job(nonce: u64) -> WriteJob {
+        let (envelope, signer) = fake_envelope(nonce);
+        let eth_tx_hash = *match &envelope {
+            TxEnvelope::Legacy(signed) => signed.hash(),
+            _ => unreachable!(),
+        };
+        let params = crate::claim::claimAssetCall {
+            smtProofLocalExitRoot: [Default::default(); 32],
+            smtProofRollupExitRoot: [Default::default(); 32],
+            globalIndex: U256::from(nonce),
+            mainnetExitRoot: Default::default(),
+            rollupExitRoot: Default::default(),
+            originNetwork: 0,
+            originTokenAddress: Address::ZERO,
+            destinationNetwork: 1,
+            destinationAddress: signer,
+            amount: U256::from(1u64),
+            metadata: Default::default(),
+        };
+        WriteJob::Claim {
+            params: Box::new(params),
+            envelope,
+            signer,
+            eth_tx_hash,
+            job_id: Ulid::new(),
+        }
+    }
+
+    /// A full claim lane answers `QueueFull` for claims only; GER injections
+    /// keep their own capacity and are admitted.
+    #[tokio::test]
+    async fn full_claim_lane_does_not_block_ger_admission() {
+        let lanes = WriterLanes {
+            ger_insert: LaneConfig {
+                depth: 2,
+                weight: 1,
+            },
+            claim: LaneConfig {
+                depth: 1,
+                weight: 1,
+            },
+            claim_batch: 1,
+        };
+        let (handle, _receivers) = test_handle(lanes);
+        assert_eq!(handle.queue_depth(), 3);
+
+        handle.try_enqueue(fake_claim_job(0)).unwrap();
+        assert!(matches!(
+            handle.try_enqueue(fake_claim_job(1)),
+            Err(TryEnqueueError::QueueFull)
+        ));
+        assert!(matches!(
+            handle.check_admission(WriteJobKind::Claim),
+            Err(TryEnqueueError::QueueFull)
+        ));
+        assert!(handle.check_admission(WriteJobKind::GerInsert).is_ok());
+        handle.try_enqueue(fake_ger_job(2)).unwrap();
+        assert_eq!(handle.available_capacity_for(WriteJobKind::GerInsert), 1);
+        assert_eq!(handle.available_capacity_for(WriteJobKind::Claim), 0);
+    }
+
+    /// With both lanes backed up the worker takes `weight` jobs per lane per
+    /// round, GER lane first; an empty lane passes its turn.
+    #[tokio::test]
+    async fn lanes_are_served_weighted_round_robin() {
+        let lanes = WriterLanes {
+            ger_insert: LaneConfig {
+                depth: 8,
+                weight: 2,
+            },
+            claim: LaneConfig {
+                depth: 8,
+                weight: 1,
+            },
+            claim_batch: 1,
+        };
+        let (senders, mut receivers) = lanes.channels();
+        for nonce in 0..4 {
+            senders[1].try_send(fake_claim_job(nonce)).unwrap();
+        }
+        for nonce in 0..3 {
+            senders[0].try_send(fake_ger_job(nonce)).unwrap();
+        }
+
+        let mut order = Vec::new();
+        while let Some(job) = receivers.try_next() {
+            order.push(job.kind());
+        }
+        use WriteJobKind::{Claim as C, GerInsert as G};
+        assert_eq!(order, [G, G, C, G, C, C, C]);
+
+        // Idle lanes: a single claim is served as soon as it arrives.
+        senders[1].try_send(fake_claim_job(9)).unwrap();
+        let job = tokio::time::timeout(Duration::from_secs(1), receivers.next())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(job.kind(), WriteJobKind::Claim);
+    }
+
+    /// A dequeued claim picks up the claims already waiting behind it, up to
+    /// the cap and without touching the GER lane; a GER job is never batched.
+    #[tokio::test]
+    async fn coalesce_claims_takes_waiting_claims_up_to_the_cap() {
+        let (senders, mut receivers) = WriterLanes::uniform(8).channels();
+        for nonce in 0..3 {
+            senders[1].try_send(fake_claim_job(nonce)).unwrap();
+        }
+        senders[0].try_send(fake_ger_job(3)).unwrap();
+
+        let ger = receivers.try_next().unwrap();
+        assert_eq!(receivers.coalesce_claims(ger, 4).len(), 1);
+        let first = receivers.try_next().unwrap();
+        let batch = receivers.coalesce_claims(first, 2);
+        assert_eq!(batch.len(), 2);
+        assert!(batch.iter().all(|job| job.kind() == WriteJobKind::Claim));
+        assert_eq!(receivers.len(), 1);
+        let last = receivers.try_next().unwrap();
+        assert_eq!(receivers.coalesce_claims(last, 4).len(), 1);
+    }
+
+    /// The stop signal is reachable: fire it and verify the worker exits
+    /// within a small bound. The deadline-bounded drain is covered by
+    /// `drain_marks_jobs_it_cannot_finish_for_immediate_recovery`.
     #[tokio::test]
     async fn worker_shuts_down_on_signal() {
         let service = create_test_service();
@@ -1404,6 +2629,80 @@ mod tests {
         tokio::time::sleep(Duration::from_millis(50)).await;
     }
 
+    #[test]
+    fn drain_plan_budgets_recent_p90_per_job_up_to_the_cap() {
+        let cap = Duration::from_secs(60);
+        let plan = DrainPlan::new(&[], 3, cap);
+        assert_eq!(plan.per_job, DEFAULT_JOB_ESTIMATE);
+        assert_eq!(plan.budget, Duration::from_secs(30));
+
+        let recent: Vec<Duration> = (1..=10).map(Duration::from_secs).collect();
+        let plan = DrainPlan::new(&recent, 2, cap);
+        assert_eq!(plan.per_job, Duration::from_secs(9));
+        assert_eq!(plan.budget, Duration::from_secs(18));
+        assert_eq!(DrainPlan::new(&recent, 100, cap).budget, cap);
+        assert_eq!(DrainPlan::new(&recent, 0, cap).budget, Duration::ZERO);
+    }
+
+    /// Jobs the drain cannot finish before its deadline stay queued, lose
+    /// any recovery backoff, and are flagged for the next boot; admission is
+    /// refused from the moment the drain starts.
+    #[tokio::test]
+    async fn drain_marks_jobs_it_cannot_finish_for_immediate_recovery() {
+        let mut service = crate::test_helpers::create_test_service();
+        let (handle, receivers) = test_handle(WriterLanes::uniform(4));
+        let (drain_tx, drain_rx) = oneshot::channel();
+        *handle.drain_tx.lock() = Some(drain_tx);
+        // Recent jobs took an hour each: nothing fits in the drain budget.
+        handle
+            .recent_durations
+            .lock()
+            .push_back(Duration::from_secs(3_600));
+        let handle = Arc::new(handle);
+        let mut hashes = Vec::new();
+        for nonce in 0..2 {
+            let job = fake_ger_job(nonce);
+            hashes.push(job.eth_tx_hash());
+            begin_txn(&service, &job).await;
+            handle.try_enqueue(job).unwrap();
+        }
+        service
+            .store
+            .record_recovery_attempt(hashes[0], u64::MAX)
+            .await
+            .unwrap();
+
+        let mut worker = test_worker(service.clone(), receivers, &handle, Duration::from_secs(60));
+        worker.drain_rx = drain_rx;
+        tokio::spawn(async move {
+            let (_stop, mut stop_rx) = oneshot::channel();
+            worker.run(&mut stop_rx).await;
+        });
+        service.writer_handle = Some(handle.clone());
+
+        let report = drain_on_shutdown(&service, Duration::from_secs(5))
+            .await
+            .expect("writer handle present");
+        assert_eq!(report.plan.pending, 2);
+        assert_eq!(report.dispatched, 0);
+        assert_eq!(report.residual.len(), 2);
+        assert_eq!(
+            service.miden_client.test_call_count(),
+            0,
+            "a job that cannot finish must not be started"
+        );
+        assert!(matches!(
+            handle.try_enqueue(fake_ger_job(9)),
+            Err(TryEnqueueError::ShuttingDown)
+        ));
+
+        let pending = service.store.recoverable_pending_txns(10).await.unwrap();
+        assert_eq!(pending.len(), 2);
+        assert!(pending.iter().all(|t| t.next_recovery_at.is_none()));
+        assert_eq!(service.store.take_undrained_at_shutdown().await.unwrap(), 2);
+        assert_eq!(service.store.take_undrained_at_shutdown().await.unwrap(), 0);
+    }
+
     /// RD-940 Spec D — the in-flight pending-tx JSON must conform to
     /// geth's wire shape: `blockHash`, `blockNumber`, `transactionIndex`
     /// are the ONLY fields permitted to be JSON `null`. Every other
@@ -1426,6 +2725,7 @@ mod tests {
             job_id: Ulid::new(),
             envelope: env,
             created_at: Instant::now(),
+            paused_for: Duration::ZERO,
             terminal_at: None,
         };
         let chain_id = 2u64;
@@ -1476,13 +2776,8 @@ mod tests {
     async fn count_non_terminal_for_signer_filters_correctly() {
         // No real worker spawned; we just need a handle to mutate the
         // inflight map directly.
-        let (sender, _receiver) = mpsc::channel::<WriteJob>(8);
-        let inflight = Arc::new(DashMap::new());
-        let handle = WriterWorkerHandle {
-            sender,
-            inflight: inflight.clone(),
-            queue_depth: 8,
-        };
+        let (handle, _receivers) = test_handle(WriterLanes::uniform(8));
+        let inflight = handle.inflight.clone();
 
         let signer_a = Address::from([0x11u8; 20]);
         let signer_b = Address::from([0x22u8; 20]);
@@ -1500,6 +2795,7 @@ mod tests {
                     job_id: Ulid::new(),
                     envelope: fake_envelope(0).0,
                     created_at: Instant::now(),
+                    paused_for: Duration::ZERO,
                     terminal_at: None,
                 },
             );